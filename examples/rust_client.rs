@@ -41,6 +41,9 @@ fn print_events() -> Box<dyn Fn(a2a_rust::a2a::client::client_trait::ClientEvent
                         a2a_rust::a2a::core_types::PartRoot::File(_) => {
                             println!("   Part {} (file): [file content]", i + 1);
                         }
+                        a2a_rust::a2a::core_types::PartRoot::Unknown(unknown_part) => {
+                            println!("   Part {} ({}): [unknown part]", i + 1, unknown_part.kind);
+                        }
                     }
                 }
             }
@@ -245,6 +248,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
                                 a2a_rust::a2a::core_types::PartRoot::File(_) => {
                                     println!("   📁 Part {} (file): [file content]", i + 1);
                                 }
+                                a2a_rust::a2a::core_types::PartRoot::Unknown(unknown_part) => {
+                                    println!("   ❔ Part {} ({}): [unknown part]", i + 1, unknown_part.kind);
+                                }
                             }
                         }
                     }
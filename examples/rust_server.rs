@@ -11,6 +11,7 @@ use a2a_rust::a2a::{
         context::DefaultServerCallContextBuilder,
         request_handlers::{RequestHandler, MessageSendResult, TaskPushNotificationConfigQueryParams, Event},
     },
+    utils::logging::{self, LogFormat},
 };
 use futures::Stream;
 use std::net::SocketAddr;
@@ -184,8 +185,12 @@ impl RequestHandler for EchoHandler {
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    // Initialize logging
-    tracing_subscriber::fmt::init();
+    // Initialize logging; set LOG_FORMAT=json for machine-readable output
+    let log_format = std::env::var("LOG_FORMAT")
+        .ok()
+        .and_then(|format| format.parse().ok())
+        .unwrap_or(LogFormat::Pretty);
+    logging::init(log_format, tracing::Level::INFO)?;
 
     // Create agent card with streaming capabilities
     let agent_card = AgentCard::new(
@@ -29,7 +29,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let task_store = Arc::new(task_store);
 
     // 2. Create a TaskManager with the SQLite store
-    let mut task_manager = TaskManager::new(
+    let task_manager = TaskManager::new(
         None, // task_id
         None, // context_id
         task_store.clone(),
@@ -79,13 +79,23 @@ impl RequestHandler for TaskAwareHandler {
         let task_id = Self::generate_task_id();
         let context_id = params.message.context_id.clone().unwrap_or_else(|| "default-context".to_string());
         
+        // Create response message
+        let response_text = format!("Task {} processed successfully: received {} parts", 
+            task_id, params.message.parts.len());
+        
+        let response_message = Message::new(Role::Agent, vec![
+            Part::text(response_text.clone())
+        ])
+        .with_context_id(context_id.clone())
+        .with_task_id(task_id.clone());
+
         // Create a simple task
         let task = Task {
-            id: task_id.clone(),
+            id: task_id.clone().into(),
             context_id: context_id.clone(),
             status: TaskStatus::new(TaskState::Completed),
             artifacts: None,
-            history: None,
+            history: Some(vec![params.message.clone(), response_message]),
             metadata: None,
             kind: "task".to_string(),
         };
@@ -96,16 +106,6 @@ impl RequestHandler for TaskAwareHandler {
             tasks.insert(task_id.clone(), task.clone());
         }
 
-        // Create response message
-        let response_text = format!("Task {} processed successfully: received {} parts", 
-            task_id, params.message.parts.len());
-        
-        let response_message = Message::new(Role::Agent, vec![
-            Part::text(response_text.clone())
-        ])
-        .with_context_id(context_id.clone())
-        .with_task_id(task_id.clone());
-
         Ok(MessageSendResult::Task(task))
     }
 
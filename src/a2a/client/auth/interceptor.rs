@@ -249,20 +249,11 @@ mod tests {
             }),
         );
         
-        AgentCard::new(
-            "Test Agent".to_string(),
-            "Test agent for authentication".to_string(),
-            "http://localhost:8080".to_string(),
-            "1.0.0".to_string(),
-            vec![],
-            vec![],
-            AgentCapabilities::new(),
-            vec![],
-        )
-        .with_security_schemes(security_schemes)
-        .with_security(vec![std::collections::HashMap::from([
-            ("bearerAuth".to_string(), vec![]),
-        ])])
+        AgentCard::test_default("Test Agent", "http://localhost:8080")
+            .with_security_schemes(security_schemes)
+            .with_security(vec![std::collections::HashMap::from([
+                ("bearerAuth".to_string(), vec![]),
+            ])])
     }
     
     
@@ -5,6 +5,7 @@
 
 pub mod credentials;
 pub mod interceptor;
+pub mod oidc;
 
 // Re-export auth types
 pub use credentials::{
@@ -15,3 +16,4 @@ pub use credentials::{
 };
 
 pub use interceptor::AuthInterceptor;
+pub use oidc::{OidcCredentialService, OidcDiscoveryDocument, OidcFlow};
@@ -0,0 +1,639 @@
+//! OpenID Connect credential service
+//!
+//! An `OpenIdConnect` security scheme only carries the provider's discovery
+//! URL. This module fetches the discovery document, obtains access tokens from
+//! the discovered token endpoint and caches the provider's JWKS so tokens can
+//! be validated against its signing keys.
+
+use crate::a2a::client::auth::credentials::CredentialService;
+use crate::a2a::client::client_trait::ClientCallContext;
+use crate::a2a::error::A2AError;
+use crate::a2a::models::{AgentCard, SecurityScheme};
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::Value;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tracing::warn;
+
+/// Margin before a token's expiry at which it is refreshed
+const EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// Minimum time between JWKS refetches triggered by unknown key ids
+const JWKS_REFETCH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Path of the discovery document relative to the issuer
+const DISCOVERY_PATH: &str = "/.well-known/openid-configuration";
+
+/// The subset of an OpenID provider's discovery document used by the client
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct OidcDiscoveryDocument {
+    /// The provider's issuer identifier
+    pub issuer: String,
+    /// Endpoint that issues access tokens
+    pub token_endpoint: String,
+    /// Endpoint serving the provider's JSON Web Key Set
+    pub jwks_uri: String,
+    /// Endpoint where users authorize the client, for the authorization-code flow
+    pub authorization_endpoint: Option<String>,
+}
+
+/// How the client obtains tokens from the provider
+#[derive(Debug, Clone)]
+pub enum OidcFlow {
+    /// Authenticate as the client itself
+    ClientCredentials {
+        client_id: String,
+        client_secret: String,
+        scopes: Vec<String>,
+    },
+    /// Exchange an authorization code obtained by the user
+    AuthorizationCode {
+        client_id: String,
+        client_secret: Option<String>,
+        code: String,
+        redirect_uri: String,
+        /// PKCE verifier matching the challenge sent with the authorization request
+        code_verifier: Option<String>,
+    },
+}
+
+/// Token endpoint response
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: Option<u64>,
+    refresh_token: Option<String>,
+}
+
+#[derive(Debug)]
+struct CachedToken {
+    access_token: String,
+    expires_at: Option<Instant>,
+    refresh_token: Option<String>,
+}
+
+impl CachedToken {
+    fn is_fresh(&self) -> bool {
+        self.expires_at.is_none_or(|expires_at| Instant::now() + EXPIRY_MARGIN < expires_at)
+    }
+}
+
+/// Credential service that obtains bearer tokens from an OpenID provider
+///
+/// Answers only for the scheme name it was created for. The discovery
+/// document is fetched once, and rejected unless its `issuer` matches the
+/// expected issuer. The JWKS is cached and refetched for unknown key ids at
+/// most once a minute. Access tokens are cached until shortly before they
+/// expire, then renewed with the refresh token when the provider issued one.
+/// Without a refresh token, or when the refresh fails, client credentials are
+/// requested again; an authorization code is single-use, so that flow then
+/// fails and the user has to authorize the client again.
+pub struct OidcCredentialService {
+    scheme_name: String,
+    discovery_url: String,
+    expected_issuer: Option<String>,
+    flow: OidcFlow,
+    client: reqwest::Client,
+    discovery: Mutex<Option<OidcDiscoveryDocument>>,
+    jwks: Mutex<Option<(Value, Instant)>>,
+    jwks_refetch_interval: Duration,
+    token: Mutex<Option<CachedToken>>,
+    code_redeemed: AtomicBool,
+}
+
+impl OidcCredentialService {
+    /// Create a service for the given scheme and discovery URL
+    ///
+    /// The expected issuer is the discovery URL without its
+    /// `/.well-known/openid-configuration` suffix; set it with `with_issuer`
+    /// for discovery URLs of another shape.
+    pub fn new(scheme_name: impl Into<String>, discovery_url: impl Into<String>, flow: OidcFlow) -> Self {
+        let discovery_url = discovery_url.into();
+        let expected_issuer = discovery_url.strip_suffix(DISCOVERY_PATH).map(str::to_string);
+        Self {
+            scheme_name: scheme_name.into(),
+            discovery_url,
+            expected_issuer,
+            flow,
+            client: reqwest::Client::new(),
+            discovery: Mutex::new(None),
+            jwks: Mutex::new(None),
+            jwks_refetch_interval: JWKS_REFETCH_INTERVAL,
+            token: Mutex::new(None),
+            code_redeemed: AtomicBool::new(false),
+        }
+    }
+
+    /// Create a service for a scheme declared in the agent card
+    ///
+    /// Fails if the card has no `OpenIdConnect` scheme with that name.
+    pub fn from_card(card: &AgentCard, scheme_name: &str, flow: OidcFlow) -> Result<Self, A2AError> {
+        match card.security_schemes.as_ref().and_then(|schemes| schemes.get(scheme_name)) {
+            Some(SecurityScheme::OpenIdConnect(scheme)) => {
+                Ok(Self::new(scheme_name, scheme.open_id_connect_url.clone(), flow))
+            }
+            _ => Err(A2AError::invalid_params(&format!(
+                "Agent card has no OpenID Connect scheme named '{}'",
+                scheme_name
+            ))),
+        }
+    }
+
+    /// Set the HTTP client used to reach the provider
+    pub fn with_client(mut self, client: reqwest::Client) -> Self {
+        self.client = client;
+        self
+    }
+
+    /// Set the issuer the discovery document must declare
+    pub fn with_issuer(mut self, issuer: impl Into<String>) -> Self {
+        self.expected_issuer = Some(issuer.into());
+        self
+    }
+
+    /// Set the minimum time between JWKS refetches triggered by unknown key ids
+    pub fn with_jwks_refetch_interval(mut self, interval: Duration) -> Self {
+        self.jwks_refetch_interval = interval;
+        self
+    }
+
+    /// Get the provider's discovery document, fetching it on first use
+    ///
+    /// Fails if the document's `issuer` is not the expected issuer.
+    pub async fn discover(&self) -> Result<OidcDiscoveryDocument, A2AError> {
+        let mut discovery = self.discovery.lock().await;
+        if let Some(document) = discovery.as_ref() {
+            return Ok(document.clone());
+        }
+        let document: OidcDiscoveryDocument = self.get_json(&self.discovery_url, "discovery document").await?;
+        let Some(expected_issuer) = self.expected_issuer.as_deref() else {
+            return Err(A2AError::invalid_params(&format!(
+                "Cannot derive the OIDC issuer from {}; set it with with_issuer",
+                self.discovery_url
+            )));
+        };
+        if document.issuer.trim_end_matches('/') != expected_issuer.trim_end_matches('/') {
+            return Err(A2AError::invalid_response(&format!(
+                "OIDC discovery document names issuer {}, expected {}",
+                document.issuer, expected_issuer
+            )));
+        }
+        *discovery = Some(document.clone());
+        Ok(document)
+    }
+
+    /// Get the provider's JSON Web Key Set, fetching it on first use
+    pub async fn jwks(&self) -> Result<Value, A2AError> {
+        let mut jwks = self.jwks.lock().await;
+        if let Some((keys, _)) = jwks.as_ref() {
+            return Ok(keys.clone());
+        }
+        let keys = self.fetch_jwks().await?;
+        *jwks = Some((keys.clone(), Instant::now()));
+        Ok(keys)
+    }
+
+    /// Find the signing key with the given key id in the provider's JWKS
+    ///
+    /// Providers rotate keys, so an unknown key id refetches the JWKS, but at
+    /// most once per refetch interval so unknown ids cannot flood the provider.
+    pub async fn signing_key(&self, kid: &str) -> Result<Option<Value>, A2AError> {
+        let mut jwks = self.jwks.lock().await;
+        if let Some((keys, fetched_at)) = jwks.as_ref() {
+            if let Some(key) = find_key(keys, kid) {
+                return Ok(Some(key));
+            }
+            if fetched_at.elapsed() < self.jwks_refetch_interval {
+                return Ok(None);
+            }
+        }
+        let keys = self.fetch_jwks().await?;
+        let key = find_key(&keys, kid);
+        *jwks = Some((keys, Instant::now()));
+        Ok(key)
+    }
+
+    /// Get an access token, requesting a new one when none is cached or it expired
+    pub async fn access_token(&self) -> Result<String, A2AError> {
+        let mut token = self.token.lock().await;
+        if let Some(cached) = token.as_ref().filter(|cached| cached.is_fresh()) {
+            return Ok(cached.access_token.clone());
+        }
+
+        let refresh_token = token.take().and_then(|cached| cached.refresh_token);
+        let refreshed = match refresh_token.clone() {
+            Some(refresh_token) => match self.request_token(self.refresh_form(refresh_token)).await {
+                Ok(response) => Some(response),
+                Err(e) => {
+                    warn!("OIDC token refresh failed, requesting a new token: {}", e);
+                    None
+                }
+            },
+            None => None,
+        };
+        let response = match refreshed {
+            // Providers may keep the refresh token without reissuing it
+            Some(response) => TokenResponse {
+                refresh_token: response.refresh_token.or(refresh_token),
+                ..response
+            },
+            None => self.request_flow_token().await?,
+        };
+
+        let access_token = response.access_token.clone();
+        *token = Some(CachedToken {
+            access_token: response.access_token,
+            expires_at: response.expires_in.map(|seconds| Instant::now() + Duration::from_secs(seconds)),
+            refresh_token: response.refresh_token,
+        });
+        Ok(access_token)
+    }
+
+    /// Requests a token with the configured flow
+    ///
+    /// An authorization code is redeemed at most once.
+    async fn request_flow_token(&self) -> Result<TokenResponse, A2AError> {
+        let is_code_flow = matches!(self.flow, OidcFlow::AuthorizationCode { .. });
+        if is_code_flow && self.code_redeemed.load(Ordering::SeqCst) {
+            return Err(A2AError::invalid_request(
+                "The OIDC authorization code was already redeemed; authorize the client again",
+            ));
+        }
+        let response = self.request_token(self.flow_form()).await?;
+        if is_code_flow {
+            self.code_redeemed.store(true, Ordering::SeqCst);
+        }
+        Ok(response)
+    }
+
+    async fn fetch_jwks(&self) -> Result<Value, A2AError> {
+        let jwks_uri = self.discover().await?.jwks_uri;
+        self.get_json(&jwks_uri, "JWKS").await
+    }
+
+    fn flow_form(&self) -> Vec<(&'static str, String)> {
+        match &self.flow {
+            OidcFlow::ClientCredentials { client_id, client_secret, scopes } => {
+                let mut form = vec![
+                    ("grant_type", "client_credentials".to_string()),
+                    ("client_id", client_id.clone()),
+                    ("client_secret", client_secret.clone()),
+                ];
+                if !scopes.is_empty() {
+                    form.push(("scope", scopes.join(" ")));
+                }
+                form
+            }
+            OidcFlow::AuthorizationCode { client_id, client_secret, code, redirect_uri, code_verifier } => {
+                let mut form = vec![
+                    ("grant_type", "authorization_code".to_string()),
+                    ("client_id", client_id.clone()),
+                    ("code", code.clone()),
+                    ("redirect_uri", redirect_uri.clone()),
+                ];
+                if let Some(secret) = client_secret {
+                    form.push(("client_secret", secret.clone()));
+                }
+                if let Some(verifier) = code_verifier {
+                    form.push(("code_verifier", verifier.clone()));
+                }
+                form
+            }
+        }
+    }
+
+    fn refresh_form(&self, refresh_token: String) -> Vec<(&'static str, String)> {
+        let (client_id, client_secret) = match &self.flow {
+            OidcFlow::ClientCredentials { client_id, client_secret, .. } => (client_id, Some(client_secret)),
+            OidcFlow::AuthorizationCode { client_id, client_secret, .. } => (client_id, client_secret.as_ref()),
+        };
+        let mut form = vec![
+            ("grant_type", "refresh_token".to_string()),
+            ("refresh_token", refresh_token),
+            ("client_id", client_id.clone()),
+        ];
+        if let Some(secret) = client_secret {
+            form.push(("client_secret", secret.clone()));
+        }
+        form
+    }
+
+    async fn request_token(&self, form: Vec<(&'static str, String)>) -> Result<TokenResponse, A2AError> {
+        let token_endpoint = self.discover().await?.token_endpoint;
+        let response = self.client
+            .post(&token_endpoint)
+            .form(&form)
+            .send()
+            .await
+            .map_err(|e| A2AError::transport_error(format!("Failed to request OIDC token: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(A2AError::http_error(
+                response.status().as_u16(),
+                format!("Failed to request OIDC token: {}", response.status()),
+            ));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| A2AError::json_error(format!("Failed to parse OIDC token response: {}", e)))
+    }
+
+    async fn get_json<T: serde::de::DeserializeOwned>(&self, url: &str, what: &str) -> Result<T, A2AError> {
+        let response = self.client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| A2AError::transport_error(format!("Failed to fetch OIDC {}: {}", what, e)))?;
+
+        if !response.status().is_success() {
+            return Err(A2AError::http_error(
+                response.status().as_u16(),
+                format!("Failed to fetch OIDC {}: {}", what, response.status()),
+            ));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| A2AError::json_error(format!("Failed to parse OIDC {}: {}", what, e)))
+    }
+}
+
+fn find_key(jwks: &Value, kid: &str) -> Option<Value> {
+    jwks.get("keys")?
+        .as_array()?
+        .iter()
+        .find(|key| key.get("kid").and_then(Value::as_str) == Some(kid))
+        .cloned()
+}
+
+#[async_trait]
+impl CredentialService for OidcCredentialService {
+    async fn get_credentials(
+        &self,
+        scheme_name: &str,
+        _context: Option<&ClientCallContext>,
+    ) -> Result<Option<String>, A2AError> {
+        if scheme_name != self.scheme_name {
+            return Ok(None);
+        }
+        self.access_token().await.map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::client::auth::interceptor::AuthInterceptor;
+    use crate::a2a::client::client_trait::ClientCallInterceptor;
+    use crate::a2a::models::OpenIdConnectSecurityScheme;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    async fn mock_discovery(server: &mut mockito::ServerGuard) -> mockito::Mock {
+        let base = server.url();
+        server
+            .mock("GET", "/.well-known/openid-configuration")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "issuer": base,
+                    "token_endpoint": format!("{}/oauth/token", base),
+                    "jwks_uri": format!("{}/oauth/jwks", base),
+                    "authorization_endpoint": format!("{}/oauth/authorize", base)
+                })
+                .to_string(),
+            )
+            .expect(1)
+            .create_async()
+            .await
+    }
+
+    fn client_credentials() -> OidcFlow {
+        OidcFlow::ClientCredentials {
+            client_id: "agent-client".to_string(),
+            client_secret: "secret".to_string(),
+            scopes: vec!["openid".to_string(), "a2a".to_string()],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_client_credentials_uses_discovered_token_endpoint() {
+        let mut server = mockito::Server::new_async().await;
+        let discovery = mock_discovery(&mut server).await;
+        let token = server
+            .mock("POST", "/oauth/token")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("grant_type".to_string(), "client_credentials".to_string()),
+                mockito::Matcher::UrlEncoded("client_id".to_string(), "agent-client".to_string()),
+                mockito::Matcher::UrlEncoded("scope".to_string(), "openid a2a".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"access_token":"oidc-token","token_type":"Bearer","expires_in":3600}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let mut card = AgentCard::test_default("OIDC Agent", &server.url());
+        card.security_schemes = Some(HashMap::from([(
+            "oidc".to_string(),
+            SecurityScheme::OpenIdConnect(OpenIdConnectSecurityScheme {
+                open_id_connect_url: format!("{}/.well-known/openid-configuration", server.url()),
+                description: None,
+            }),
+        )]));
+        card.security = Some(vec![HashMap::from([("oidc".to_string(), vec![])])]);
+
+        let service = OidcCredentialService::from_card(&card, "oidc", client_credentials()).unwrap();
+        let interceptor = AuthInterceptor::new(Arc::new(service));
+        for _ in 0..2 {
+            let (_, kwargs) = interceptor
+                .intercept("message/send", serde_json::json!({}), HashMap::new(), &card, None)
+                .await
+                .unwrap();
+            assert_eq!(kwargs["headers"]["Authorization"], "Bearer oidc-token");
+        }
+
+        discovery.assert_async().await;
+        token.assert_async().await;
+        assert!(OidcCredentialService::from_card(&card, "missing", client_credentials()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_authorization_code_exchange_and_refresh() {
+        let mut server = mockito::Server::new_async().await;
+        let _discovery = mock_discovery(&mut server).await;
+        let exchange = server
+            .mock("POST", "/oauth/token")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("grant_type".to_string(), "authorization_code".to_string()),
+                mockito::Matcher::UrlEncoded("code".to_string(), "auth-code".to_string()),
+                mockito::Matcher::UrlEncoded("code_verifier".to_string(), "verifier".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"access_token":"first","expires_in":0,"refresh_token":"refresh-1"}"#)
+            .create_async()
+            .await;
+        let refresh = server
+            .mock("POST", "/oauth/token")
+            .match_body(mockito::Matcher::UrlEncoded("refresh_token".to_string(), "refresh-1".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"access_token":"second","expires_in":3600}"#)
+            .create_async()
+            .await;
+
+        let service = OidcCredentialService::new(
+            "oidc",
+            format!("{}/.well-known/openid-configuration", server.url()),
+            OidcFlow::AuthorizationCode {
+                client_id: "agent-client".to_string(),
+                client_secret: None,
+                code: "auth-code".to_string(),
+                redirect_uri: "http://localhost/callback".to_string(),
+                code_verifier: Some("verifier".to_string()),
+            },
+        );
+
+        assert_eq!(service.get_credentials("oidc", None).await.unwrap(), Some("first".to_string()));
+        assert_eq!(service.get_credentials("oidc", None).await.unwrap(), Some("second".to_string()));
+        assert_eq!(service.get_credentials("other", None).await.unwrap(), None);
+        exchange.assert_async().await;
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_jwks_is_cached_and_refetched_for_unknown_keys() {
+        let mut server = mockito::Server::new_async().await;
+        let _discovery = mock_discovery(&mut server).await;
+        let jwks = server
+            .mock("GET", "/oauth/jwks")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"keys":[{"kid":"key-1","kty":"RSA","n":"abc","e":"AQAB"}]}"#)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let discovery_url = format!("{}/.well-known/openid-configuration", server.url());
+        let service = OidcCredentialService::new("oidc", discovery_url.clone(), client_credentials());
+
+        assert_eq!(service.discover().await.unwrap().token_endpoint, format!("{}/oauth/token", server.url()));
+        assert_eq!(service.signing_key("key-1").await.unwrap().unwrap()["kty"], "RSA");
+        assert_eq!(service.signing_key("key-1").await.unwrap().unwrap()["e"], "AQAB");
+        // Unknown ids right after a fetch do not refetch
+        assert!(service.signing_key("rotated").await.unwrap().is_none());
+        assert!(service.signing_key("rotated").await.unwrap().is_none());
+
+        let eager = OidcCredentialService::new("oidc", discovery_url, client_credentials())
+            .with_jwks_refetch_interval(Duration::ZERO);
+        assert!(eager.signing_key("key-1").await.unwrap().is_some());
+        assert!(eager.signing_key("rotated").await.unwrap().is_none());
+        jwks.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_discovery_rejects_foreign_issuer() {
+        let mut server = mockito::Server::new_async().await;
+        let _discovery = server
+            .mock("GET", "/.well-known/openid-configuration")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(
+                serde_json::json!({
+                    "issuer": "https://issuer.example",
+                    "token_endpoint": "https://issuer.example/oauth/token",
+                    "jwks_uri": "https://issuer.example/oauth/jwks"
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let discovery_url = format!("{}/.well-known/openid-configuration", server.url());
+
+        let service = OidcCredentialService::new("oidc", discovery_url.clone(), client_credentials());
+        assert!(service.discover().await.is_err());
+
+        let service = OidcCredentialService::new("oidc", discovery_url, client_credentials())
+            .with_issuer("https://issuer.example/");
+        assert_eq!(service.discover().await.unwrap().issuer, "https://issuer.example");
+    }
+
+    #[tokio::test]
+    async fn test_failed_refresh_falls_back_to_the_flow() {
+        let mut server = mockito::Server::new_async().await;
+        let _discovery = mock_discovery(&mut server).await;
+        let issue = server
+            .mock("POST", "/oauth/token")
+            .match_body(mockito::Matcher::UrlEncoded("grant_type".to_string(), "client_credentials".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"access_token":"issued","expires_in":0,"refresh_token":"revoked"}"#)
+            .expect(2)
+            .create_async()
+            .await;
+        let refresh = server
+            .mock("POST", "/oauth/token")
+            .match_body(mockito::Matcher::UrlEncoded("grant_type".to_string(), "refresh_token".to_string()))
+            .with_status(400)
+            .with_body(r#"{"error":"invalid_grant"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let service = OidcCredentialService::new(
+            "oidc",
+            format!("{}/.well-known/openid-configuration", server.url()),
+            client_credentials(),
+        );
+        assert_eq!(service.access_token().await.unwrap(), "issued");
+        assert_eq!(service.access_token().await.unwrap(), "issued");
+        issue.assert_async().await;
+        refresh.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_authorization_code_is_not_redeemed_twice() {
+        let mut server = mockito::Server::new_async().await;
+        let _discovery = mock_discovery(&mut server).await;
+        let exchange = server
+            .mock("POST", "/oauth/token")
+            .match_body(mockito::Matcher::UrlEncoded("grant_type".to_string(), "authorization_code".to_string()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"access_token":"first","expires_in":0,"refresh_token":"revoked"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let refresh = server
+            .mock("POST", "/oauth/token")
+            .match_body(mockito::Matcher::UrlEncoded("grant_type".to_string(), "refresh_token".to_string()))
+            .with_status(400)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let service = OidcCredentialService::new(
+            "oidc",
+            format!("{}/.well-known/openid-configuration", server.url()),
+            OidcFlow::AuthorizationCode {
+                client_id: "agent-client".to_string(),
+                client_secret: None,
+                code: "auth-code".to_string(),
+                redirect_uri: "http://localhost/callback".to_string(),
+                code_verifier: None,
+            },
+        );
+        assert_eq!(service.access_token().await.unwrap(), "first");
+        assert!(service.access_token().await.is_err());
+        exchange.assert_async().await;
+        refresh.assert_async().await;
+    }
+}
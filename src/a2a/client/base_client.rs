@@ -3,12 +3,15 @@
 //! This module provides the base client functionality
 //! matching a2a-python/src/a2a/client/base_client.py
 
+use crate::a2a::client::client_trait::ClientTransport;
+use crate::a2a::client::errors::ClientError;
 use crate::MessageSendParams;
 use crate::Task;
+use crate::TaskOrMessage;
 use crate::TaskQueryParams;
 use crate::TaskIdParams;
-use crate::UnsupportedOperationError;
 use crate::A2AError;
+use std::sync::Arc;
 
 /// Base client trait for A2A protocol
 #[async_trait::async_trait]
@@ -24,38 +27,89 @@ pub trait BaseClient {
 }
 
 /// Default implementation of base client
-#[derive(Debug)]
+///
+/// Requests are sent over the transport set with `with_transport`. Clients
+/// created by `ClientFactory` always have a transport; without one, every
+/// call fails with a `ClientError::Configuration` error.
+#[derive(Default)]
 pub struct DefaultBaseClient {
-    // TODO: Implement actual client logic
+    transport: Option<Arc<dyn ClientTransport>>,
 }
 
 impl DefaultBaseClient {
     /// Create a new default base client
     pub fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+
+    /// Set the transport requests are sent over
+    pub fn with_transport(mut self, transport: Arc<dyn ClientTransport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    fn transport(&self) -> Result<&dyn ClientTransport, A2AError> {
+        self.transport.as_deref().ok_or_else(|| {
+            ClientError::Configuration("No transport configured; create the client with ClientFactory".to_string())
+                .into()
+        })
     }
 }
 
-impl Default for DefaultBaseClient {
-    fn default() -> Self {
-        Self::new()
+impl std::fmt::Debug for DefaultBaseClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DefaultBaseClient")
+            .field("has_transport", &self.transport.is_some())
+            .finish()
     }
 }
 
 #[async_trait::async_trait]
 impl BaseClient for DefaultBaseClient {
-    async fn send_message(&self, _params: MessageSendParams) -> Result<Task, A2AError> {
-        // TODO: Implement actual message sending
-        Err(A2AError::UnsupportedOperation(UnsupportedOperationError::default()))
+    async fn send_message(&self, params: MessageSendParams) -> Result<Task, A2AError> {
+        match self.transport()?.send_message(params, None, None).await? {
+            TaskOrMessage::Task(task) => Ok(task),
+            _ => Err(A2AError::invalid_response("Agent did not reply with a task")),
+        }
     }
     
-    async fn get_task(&self, _params: TaskQueryParams) -> Result<Task, A2AError> {
-        // TODO: Implement actual task retrieval
-        Err(A2AError::UnsupportedOperation(UnsupportedOperationError::default()))
+    async fn get_task(&self, params: TaskQueryParams) -> Result<Task, A2AError> {
+        self.transport()?.get_task(params, None, None).await
     }
     
-    async fn cancel_task(&self, _params: TaskIdParams) -> Result<Task, A2AError> {
-        // TODO: Implement actual task cancellation
-        Err(A2AError::UnsupportedOperation(UnsupportedOperationError::default()))
+    async fn cancel_task(&self, params: TaskIdParams) -> Result<Task, A2AError> {
+        self.transport()?.cancel_task(params, None, None).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::client::transports::in_process::InProcessTransport;
+    use crate::a2a::server::request_handlers::DefaultRequestHandler;
+    use crate::a2a::server::tasks::InMemoryTaskStore;
+    use crate::AgentCard;
+
+    #[tokio::test]
+    async fn test_client_without_transport_reports_configuration_error() {
+        let client = DefaultBaseClient::new();
+
+        let error = client.get_task(TaskQueryParams::new("task-1".to_string())).await.unwrap_err();
+        assert!(matches!(error, A2AError::Internal(_)));
+        assert_eq!(
+            error.message(),
+            "Configuration error: No transport configured; create the client with ClientFactory"
+        );
+        assert!(client.send_message(MessageSendParams::from_text("hi")).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_client_with_transport_sends_requests() {
+        let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None);
+        let card = AgentCard::test_default("Agent", "http://localhost:8080");
+        let client = DefaultBaseClient::new().with_transport(Arc::new(InProcessTransport::new(Arc::new(handler), card)));
+
+        let task = client.send_message(MessageSendParams::from_text("hi")).await.unwrap();
+        assert_eq!(client.get_task(TaskQueryParams::new(task.id.to_string())).await.unwrap().id, task.id);
     }
 }
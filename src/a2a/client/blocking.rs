@@ -0,0 +1,85 @@
+//! Synchronous client wrapper
+//!
+//! `BlockingClient` drives an async [`Client`] on a dedicated Tokio runtime,
+//! so scripts and command line tools can talk to an agent without being
+//! async themselves. Enabled with the `blocking` feature.
+//!
+//! The wrapper must not be used (or dropped) from inside another async
+//! runtime, since blocking a runtime thread on a nested runtime panics.
+
+use futures::StreamExt;
+use tokio::runtime::{Builder, Runtime};
+
+use crate::a2a::client::client_trait::{Client, ClientEventOrMessage, SendOutcome};
+use crate::a2a::core_types::Message;
+use crate::a2a::error::A2AError;
+use crate::a2a::models::{AgentCard, Task, TaskIdParams, TaskQueryParams};
+
+/// Synchronous facade over an async A2A client
+pub struct BlockingClient {
+    client: Box<dyn Client>,
+    runtime: Runtime,
+}
+
+impl BlockingClient {
+    /// Wrap an async client, starting the runtime that will drive it
+    ///
+    /// The runtime has its own worker thread, so agents running in-process
+    /// keep making progress between calls.
+    pub fn new(client: Box<dyn Client>) -> Result<Self, A2AError> {
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("a2a-blocking-client")
+            .enable_all()
+            .build()
+            .map_err(|e| A2AError::internal(&format!("Failed to start blocking client runtime: {}", e)))?;
+        Ok(Self { client, runtime })
+    }
+
+    /// The wrapped async client
+    pub fn inner(&self) -> &dyn Client {
+        self.client.as_ref()
+    }
+
+    /// Send a message and wait for the agent's final answer
+    ///
+    /// Consumes every event the agent emits. Returns the agent's last reply
+    /// message if it sent one, otherwise the task as stored once the
+    /// stream has ended.
+    pub fn send_message(&self, message: Message) -> Result<SendOutcome, A2AError> {
+        self.runtime.block_on(async {
+            let mut events = self.client.send_message(message, None, None, None).await;
+            let mut reply = None;
+            let mut task_id = None;
+            while let Some(event) = events.next().await {
+                match event? {
+                    ClientEventOrMessage::Message(message) => reply = Some(message),
+                    ClientEventOrMessage::Event((task, _)) => task_id = Some(task.id),
+                }
+            }
+            drop(events);
+
+            if let Some(message) = reply {
+                return Ok(SendOutcome::Message(message));
+            }
+            let task_id = task_id.ok_or_else(|| A2AError::invalid_response("Agent sent no task or message"))?;
+            let task = self.client.get_task(TaskQueryParams::new(task_id.to_string()), None, None).await?;
+            Ok(SendOutcome::Task(task))
+        })
+    }
+
+    /// Retrieve the current state and history of a task
+    pub fn get_task(&self, request: TaskQueryParams) -> Result<Task, A2AError> {
+        self.runtime.block_on(self.client.get_task(request, None, None))
+    }
+
+    /// Request the agent to cancel a task
+    pub fn cancel_task(&self, request: TaskIdParams) -> Result<Task, A2AError> {
+        self.runtime.block_on(self.client.cancel_task(request, None, None))
+    }
+
+    /// Retrieve the agent's card
+    pub fn get_card(&self) -> Result<AgentCard, A2AError> {
+        self.runtime.block_on(self.client.get_card(None, None))
+    }
+}
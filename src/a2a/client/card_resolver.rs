@@ -4,26 +4,172 @@
 //! mirroring the functionality of a2a-python's card resolver.
 
 use crate::a2a::models::*;
+use crate::a2a::client::errors::ClientError;
 use crate::a2a::error::A2AError;
+use crate::a2a::utils::canonical::canonical_json;
+use crate::a2a::utils::signing::{verify_agent_card_json, JwkSet};
+use async_stream::stream;
+use futures::Stream;
 use reqwest;
 use serde_json::Value;
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+use tracing::warn;
 use url::Url;
 
+/// Default time allowed for fetching an agent card
+pub const DEFAULT_CARD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// `Accept` header for card requests: JSON preferred, YAML accepted
+const CARD_ACCEPT: &str = "application/json, application/yaml;q=0.9";
+
 /// A2A Card Resolver for fetching agent cards from servers
 /// 
 /// This mirrors a2a-python's A2ACardResolver functionality
+#[derive(Clone)]
 pub struct A2ACardResolver {
     /// Base URL of the agent
     base_url: String,
+    /// Keys trusted to sign agent cards, if signatures are verified
+    trusted_keys: Option<JwkSet>,
+    /// Time allowed for fetching a card
+    timeout: Duration,
 }
 
 impl A2ACardResolver {
     /// Create a new card resolver for the given agent URL
     pub fn new(base_url: String) -> Self {
-        Self { base_url }
+        Self { base_url, trusted_keys: None, timeout: DEFAULT_CARD_TIMEOUT }
+    }
+
+    /// Set the time allowed for fetching a card, `DEFAULT_CARD_TIMEOUT` by default
+    ///
+    /// Requests that take longer fail with a `ClientError::Timeout`. A
+    /// `timeout` in the http kwargs of `get_agent_card_with_path` overrides it.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Only accept cards signed by one of `jwks`
+    ///
+    /// Unsigned cards and cards whose signatures do not verify are rejected
+    /// with `InvalidAgentResponse`.
+    pub fn with_verification(mut self, jwks: JwkSet) -> Self {
+        self.trusted_keys = Some(jwks);
+        self
+    }
+
+    /// Reads a card response, which may be JSON or YAML
+    async fn read_card(&self, response: reqwest::Response) -> Result<AgentCard, A2AError> {
+        if !response.status().is_success() {
+            return Err(A2AError::http_error(
+                response.status().as_u16(),
+                format!("Failed to fetch agent card: {}", response.status()),
+            ));
+        }
+
+        let card_url = response.url().to_string();
+        let is_yaml = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|content_type| content_type.to_ascii_lowercase().contains("yaml"));
+        let card_json: Value = if is_yaml {
+            let body = response
+                .text()
+                .await
+                .map_err(|e| fetch_error(&card_url, e))?;
+            serde_yaml_ng::from_str(&body)
+                .map_err(|e| A2AError::json_error(format!("Failed to parse agent card YAML: {}", e)))?
+        } else {
+            response
+                .json()
+                .await
+                .map_err(|e| if e.is_timeout() {
+                    fetch_error(&card_url, e)
+                } else {
+                    A2AError::json_error(format!("Failed to parse agent card JSON: {}", e))
+                })?
+        };
+
+        self.parse_card(card_json)
+    }
+
+    /// Deserializes a fetched card, verifying its signature if configured
+    fn parse_card(&self, card_json: Value) -> Result<AgentCard, A2AError> {
+        // Signatures cover the card as sent, including fields AgentCard drops
+        if let Some(ref keys) = self.trusted_keys {
+            verify_agent_card_json(&card_json, keys)?;
+        }
+        serde_json::from_value(card_json)
+            .map_err(|e| A2AError::json_error(format!("Failed to deserialize agent card: {}", e)))
     }
     
+    /// Polls the well-known card every `interval`, yielding it when it changes
+    ///
+    /// The first card fetched is yielded right away; after that a card is
+    /// only yielded when its canonical JSON differs from the last one. Polls
+    /// send the last `ETag` in `If-None-Match`, so an unchanged card costs a
+    /// `304 Not Modified` on servers that support it. Failed polls, such as
+    /// while the agent restarts, are logged and retried on the next tick, so
+    /// the stream only ends when dropped.
+    pub fn watch(&self, interval: Duration) -> impl Stream<Item = AgentCard> + Send + 'static {
+        let resolver = self.clone();
+        stream! {
+            let client = reqwest::Client::new();
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            let mut etag: Option<String> = None;
+            let mut last_hash: Option<u64> = None;
+            loop {
+                ticker.tick().await;
+                match resolver.fetch_if_modified(&client, etag.as_deref()).await {
+                    Ok(None) => {}
+                    Ok(Some((card, new_etag))) => {
+                        etag = new_etag;
+                        let hash = card_hash(&card);
+                        if last_hash != Some(hash) {
+                            last_hash = Some(hash);
+                            yield card;
+                        }
+                    }
+                    Err(e) => warn!("Failed to poll agent card of {}: {}", resolver.base_url, e),
+                }
+            }
+        }
+    }
+
+    /// Fetches the well-known card unless it still matches `etag`
+    ///
+    /// Returns `None` on `304 Not Modified`, otherwise the card and its `ETag`.
+    async fn fetch_if_modified(
+        &self,
+        client: &reqwest::Client,
+        etag: Option<&str>,
+    ) -> Result<Option<(AgentCard, Option<String>)>, A2AError> {
+        let card_url = format!("{}/.well-known/agent-card.json", self.base_url.trim_end_matches('/'));
+        let mut request = client
+            .get(&card_url)
+            .header(reqwest::header::ACCEPT, CARD_ACCEPT)
+            .timeout(self.timeout);
+        if let Some(etag) = etag {
+            request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+        }
+        let response = request.send().await.map_err(|e| fetch_error(&card_url, e))?;
+        if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+            return Ok(None);
+        }
+        let etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        Ok(Some((self.read_card(response).await?, etag)))
+    }
+
     /// Get the agent card from the well-known endpoint
     pub async fn get_agent_card(&self) -> Result<AgentCard, A2AError> {
         let card_url = format!("{}/.well-known/agent-card.json", 
@@ -31,24 +177,13 @@ impl A2ACardResolver {
         
         let client = reqwest::Client::new();
         let response = client.get(&card_url)
+            .header(reqwest::header::ACCEPT, CARD_ACCEPT)
+            .timeout(self.timeout)
             .send()
             .await
-            .map_err(|e| A2AError::transport_error(format!("Failed to fetch agent card: {}", e)))?;
-        
-        if !response.status().is_success() {
-            return Err(A2AError::http_error(
-                response.status().as_u16(),
-                format!("Failed to fetch agent card: {}", response.status()),
-            ));
-        }
-        
-        let card_json: Value = response
-            .json()
-            .await
-            .map_err(|e| A2AError::json_error(format!("Failed to parse agent card JSON: {}", e)))?;
+            .map_err(|e| fetch_error(&card_url, e))?;
         
-        serde_json::from_value(card_json)
-            .map_err(|e| A2AError::json_error(format!("Failed to deserialize agent card: {}", e)))
+        self.read_card(response).await
     }
     
     /// Get agent card with optional relative path and additional HTTP kwargs
@@ -69,7 +204,10 @@ impl A2ACardResolver {
         };
         
         let client = reqwest::Client::new();
-        let mut request = client.get(&card_url);
+        let mut request = client
+            .get(&card_url)
+            .header(reqwest::header::ACCEPT, CARD_ACCEPT)
+            .timeout(self.timeout);
         
         // Apply HTTP kwargs if provided
         if let Some(kwargs) = http_kwargs {
@@ -104,22 +242,28 @@ impl A2ACardResolver {
         let response = request
             .send()
             .await
-            .map_err(|e| A2AError::transport_error(format!("Failed to fetch agent card: {}", e)))?;
+            .map_err(|e| fetch_error(&card_url, e))?;
         
-        if !response.status().is_success() {
-            return Err(A2AError::http_error(
-                response.status().as_u16(),
-                format!("Failed to fetch agent card: {}", response.status()),
-            ));
-        }
-        
-        let card_json: Value = response
-            .json()
-            .await
-            .map_err(|e| A2AError::json_error(format!("Failed to parse agent card JSON: {}", e)))?;
-        
-        serde_json::from_value(card_json)
-            .map_err(|e| A2AError::json_error(format!("Failed to deserialize agent card: {}", e)))
+        self.read_card(response).await
+    }
+}
+
+/// Hash of a card's canonical JSON, used to detect changes
+fn card_hash(card: &AgentCard) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_value(card)
+        .map(|value| canonical_json(&value))
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Maps a failed card request, reporting timeouts as `ClientError::Timeout`
+fn fetch_error(card_url: &str, e: reqwest::Error) -> A2AError {
+    if e.is_timeout() {
+        ClientError::Timeout(format!("Fetching agent card from {} timed out", card_url)).into()
+    } else {
+        A2AError::transport_error(format!("Failed to fetch agent card: {}", e))
     }
 }
 
@@ -5,7 +5,11 @@
 
 use crate::a2a::models::*;
 use crate::a2a::core_types::*;
-use crate::a2a::client::config::ClientConfig;
+use crate::a2a::client::config::{ClientConfig, MessageSendConfiguration};
+use crate::a2a::client::errors::ClientError;
+use crate::a2a::client::task_cache::TaskCache;
+use crate::a2a::utils::artifact::get_artifact_text;
+use crate::a2a::utils::message::{get_message_text, PARTIAL_MESSAGE_METADATA_KEY};
 use serde::{Deserialize, Serialize};
 
 /// Task update events that can occur during task execution
@@ -22,10 +26,16 @@ use futures::{Stream, StreamExt};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
 
 /// Type alias for client events - either a task with optional update, or a message
 pub type ClientEvent = (Task, Option<TaskUpdateEvent>);
 
+/// Stream of events returned when sending a message
+pub type ClientEventStream<'a> = Pin<Box<dyn Stream<Item = Result<ClientEventOrMessage, crate::a2a::error::A2AError>> + Send + 'a>>;
+
 /// Type alias for event consuming callback
 pub type Consumer = Box<dyn Fn(ClientEventOrMessage, AgentCard) + Send + Sync>;
 
@@ -36,6 +46,47 @@ pub enum ClientEventOrMessage {
     Message(Message),
 }
 
+/// The final result of a non-streaming send
+///
+/// Agents answer a message either directly with a `Message` or by creating a
+/// `Task` that tracks the work.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SendOutcome {
+    Message(Message),
+    Task(Task),
+}
+
+/// Cancels the operation started by `Client::send_message_cancellable`
+///
+/// Cloning is cheap and any clone can cancel. The `tasks/cancel` request is
+/// sent by the event stream itself, so keep polling the stream after calling
+/// `cancel` to receive the canceled task.
+#[derive(Debug, Clone)]
+pub struct CancelHandle {
+    cancelled: Arc<watch::Sender<bool>>,
+}
+
+impl CancelHandle {
+    fn new() -> Self {
+        Self { cancelled: Arc::new(watch::channel(false).0) }
+    }
+
+    /// Request cancellation of the in-flight task
+    pub fn cancel(&self) {
+        self.cancelled.send_replace(true);
+    }
+
+    /// Whether `cancel` has been called
+    pub fn is_cancelled(&self) -> bool {
+        *self.cancelled.borrow()
+    }
+
+    async fn cancelled(&self) {
+        let mut receiver = self.cancelled.subscribe();
+        let _ = receiver.wait_for(|cancelled| *cancelled).await;
+    }
+}
+
 /// Context for client calls, similar to Python's ClientCallContext
 #[derive(Debug, Clone)]
 pub struct ClientCallContext {
@@ -44,6 +95,12 @@ pub struct ClientCallContext {
     
     /// HTTP-specific arguments
     pub http_kwargs: HashMap<String, Value>,
+
+    /// Settings overriding the client configuration when sending a message
+    pub send_configuration: Option<MessageSendConfiguration>,
+
+    /// Whether `get_task` fetches the task from the agent even if it is cached
+    pub bypass_task_cache: bool,
 }
 
 impl Default for ClientCallContext {
@@ -51,6 +108,8 @@ impl Default for ClientCallContext {
         Self {
             metadata: HashMap::new(),
             http_kwargs: HashMap::new(),
+            send_configuration: None,
+            bypass_task_cache: false,
         }
     }
 }
@@ -72,6 +131,18 @@ impl ClientCallContext {
         self.http_kwargs.insert(key.into(), value.into());
         self
     }
+    
+    /// Override the client configuration for the message sent with this context
+    pub fn with_send_configuration(mut self, configuration: MessageSendConfiguration) -> Self {
+        self.send_configuration = Some(configuration);
+        self
+    }
+    
+    /// Always fetch tasks from the agent, ignoring the client's task cache
+    pub fn with_task_cache_bypass(mut self) -> Self {
+        self.bypass_task_cache = true;
+        self
+    }
 }
 
 /// Trait for intercepting client calls, similar to Python's ClientCallInterceptor
@@ -103,8 +174,36 @@ pub trait Client: Send + Sync {
     where
         'life1: 'life0;
     
+    /// Send a message and wait for the agent's final answer
+    /// 
+    /// `BaseClient` always uses the non-streaming `message/send` method,
+    /// regardless of the `streaming` setting, so callers get a single outcome.
+    /// The default implementation drains `send_message` and returns the last
+    /// task or message it yields.
+    async fn send_message_sync(
+        &self,
+        request: Message,
+        context: Option<&ClientCallContext>,
+        request_metadata: Option<HashMap<String, Value>>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<SendOutcome, crate::a2a::error::A2AError> {
+        let mut events = self.send_message(request, context, request_metadata, extensions).await;
+        let mut outcome = None;
+        while let Some(event) = events.next().await {
+            outcome = Some(match event? {
+                ClientEventOrMessage::Message(message) => SendOutcome::Message(message),
+                ClientEventOrMessage::Event((task, _)) => SendOutcome::Task(task),
+            });
+        }
+        outcome.ok_or_else(|| crate::a2a::error::A2AError::invalid_response("The agent did not answer the message"))
+    }
     
     /// Retrieve the current state and history of a specific task
+    ///
+    /// `BaseClient` answers repeated queries for tasks in a terminal state
+    /// from a cache sized by `ClientConfig::task_cache_size` (off by default),
+    /// unless the context asks to bypass it. Canceling a task drops it from
+    /// the cache.
     async fn get_task(
         &self,
         request: TaskQueryParams,
@@ -136,6 +235,21 @@ pub trait Client: Send + Sync {
         extensions: Option<Vec<String>>,
     ) -> Result<TaskPushNotificationConfig, crate::a2a::error::A2AError>;
     
+    /// Delete a push notification configuration of a task
+    ///
+    /// Clients that cannot delete configurations fail with
+    /// `UnsupportedOperation`, which the default implementation returns.
+    async fn delete_task_callback(
+        &self,
+        _request: DeleteTaskPushNotificationConfigParams,
+        _context: Option<&ClientCallContext>,
+        _extensions: Option<Vec<String>>,
+    ) -> Result<(), crate::a2a::error::A2AError> {
+        Err(crate::a2a::error::A2AError::unsupported_operation(
+            "Deleting push notification configs is not supported by this client",
+        ))
+    }
+    
     /// Resubscribe to a task's event stream
     async fn resubscribe<'a>(
         &'a self,
@@ -151,18 +265,252 @@ pub trait Client: Send + Sync {
         extensions: Option<Vec<String>>,
     ) -> Result<AgentCard, crate::a2a::error::A2AError>;
     
+    /// Retrieve the agent's authenticated extended card
+    /// 
+    /// Configured interceptors (e.g. `AuthInterceptor`) are applied to the
+    /// request. Fails with `AuthenticatedExtendedCardNotConfigured` if the
+    /// agent does not offer an extended card. The default implementation
+    /// fails with `UnsupportedOperation`.
+    async fn get_authenticated_extended_card(
+        &self,
+        _context: Option<&ClientCallContext>,
+    ) -> Result<AgentCard, crate::a2a::error::A2AError> {
+        Err(crate::a2a::error::A2AError::unsupported_operation(
+            "The authenticated extended card is not supported by this client",
+        ))
+    }
+    
     /// Add an event consumer to the client
     async fn add_event_consumer(&self, consumer: Consumer);
     
     /// Add request middleware to the client
     async fn add_request_middleware(&self, middleware: Box<dyn ClientCallInterceptor>);
     
+    /// Check locally that the agent accepts the message's content types
+    ///
+    /// Fails with `ContentTypeNotSupported` when a part's MIME type is not
+    /// among the card's default input modes, saving a round trip. Sends run
+    /// this check only when `ClientConfig::precheck_messages` is set.
+    fn precheck_message(&self, _msg: &Message) -> Result<(), crate::a2a::error::A2AError> {
+        Ok(())
+    }
+    
     /// Process events via all registered consumers
     async fn consume(
         &self,
         event: Option<ClientEventOrMessage>,
         card: &AgentCard,
     ) -> Result<(), crate::a2a::error::A2AError>;
+
+    /// Send a message like `send_message`, also returning a handle to cancel it
+    ///
+    /// After `CancelHandle::cancel`, the stream issues `tasks/cancel` for the
+    /// task the agent created, yields the canceled task and ends. If no task
+    /// has been announced yet, the stream waits for it before canceling; a
+    /// reply that is a plain message ends the stream as usual.
+    async fn send_message_cancellable<'life0, 'life1>(
+        &'life0 self,
+        request: Message,
+        context: Option<&'life1 ClientCallContext>,
+        request_metadata: Option<HashMap<String, Value>>,
+        extensions: Option<Vec<String>>,
+    ) -> (ClientEventStream<'life0>, CancelHandle)
+    where
+        'life1: 'life0,
+    {
+        let handle = CancelHandle::new();
+        let signal = handle.clone();
+        let mut events = self.send_message(request, context, request_metadata, extensions).await;
+
+        let stream: ClientEventStream<'life0> = Box::pin(stream! {
+            let mut task_id: Option<String> = None;
+            loop {
+                let next = match (&task_id, signal.is_cancelled()) {
+                    (Some(id), true) => {
+                        let result = self.cancel_task(TaskIdParams::new(id.clone()), context, None).await;
+                        yield result.map(|task| ClientEventOrMessage::Event((task, None)));
+                        break;
+                    }
+                    (None, true) => events.next().await,
+                    (_, false) => tokio::select! {
+                        biased;
+                        _ = signal.cancelled() => continue,
+                        event = events.next() => event,
+                    },
+                };
+                let Some(event) = next else { break };
+                if let Ok(ClientEventOrMessage::Event((task, _))) = &event {
+                    task_id = Some(task.id.to_string());
+                }
+                yield event;
+            }
+        });
+        (stream, handle)
+    }
+
+    /// Send a text prompt and return the agent's answer as text
+    ///
+    /// Uses `send_message_sync`. A message reply yields its text parts; a task
+    /// yields the text of its artifacts, or of its status message if it has
+    /// no text artifacts. Parts are joined with newlines. A task that ended
+    /// in any terminal state other than `Completed` is an error carrying its
+    /// status message.
+    async fn ask(&self, prompt: &str) -> Result<String, crate::a2a::error::A2AError> {
+        let params = MessageSendParams::from_text(prompt);
+        match self.send_message_sync(params.message, None, None, None).await? {
+            SendOutcome::Message(message) => Ok(get_message_text(&message, "\n")),
+            SendOutcome::Task(task) if task.status.state.is_terminal() && task.status.state != TaskState::Completed => {
+                let reason = task.status.message.as_deref().map(|message| get_message_text(message, "\n")).unwrap_or_default();
+                Err(crate::a2a::error::A2AError::internal(&format!(
+                    "Task {} ended as {:?}: {}",
+                    task.id, task.status.state, reason
+                )))
+            }
+            SendOutcome::Task(task) => Ok(task_text(&task)),
+        }
+    }
+
+    /// Send one chunk of a user message that is still being composed
+    ///
+    /// The chunk is flagged as partial, so the agent buffers its parts
+    /// instead of running; the reply is the message accumulated so far.
+    /// Finish the message by sending its last chunk with `send_message` or
+    /// `send_message_sync`, reusing the `message_id` of the earlier chunks:
+    /// the agent then runs on the whole message, with consecutive text
+    /// chunks joined without a delimiter.
+    async fn send_message_chunk(
+        &self,
+        mut chunk: Message,
+        context: Option<&ClientCallContext>,
+    ) -> Result<Message, crate::a2a::error::A2AError> {
+        chunk
+            .metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(PARTIAL_MESSAGE_METADATA_KEY.to_string(), Value::Bool(true));
+        match self.send_message_sync(chunk, context, None, None).await? {
+            SendOutcome::Message(accumulated) => Ok(accumulated),
+            SendOutcome::Task(task) => Err(crate::a2a::error::A2AError::invalid_response(&format!(
+                "Agent started task {} instead of buffering the message chunk",
+                task.id
+            ))),
+        }
+    }
+
+    /// Wait until a task reaches one of the target states
+    ///
+    /// Follows the task's event stream via `resubscribe`, and falls back to
+    /// polling `get_task` when streaming is unsupported or the stream ends.
+    /// Returns the task as of the first update in a target state, the first
+    /// other error of the stream, or a `ClientError::Timeout` once `timeout`
+    /// elapses. A task ending in a terminal state that is not a target fails
+    /// right away with an error naming that state.
+    async fn wait_for_state(
+        &self,
+        task_id: &str,
+        target: &[TaskState],
+        timeout: Duration,
+    ) -> Result<Task, crate::a2a::error::A2AError> {
+        let wait = async {
+            let task = self.get_task(TaskQueryParams::new(task_id.to_string()), None, None).await?;
+            if let Some(outcome) = wait_outcome(task, target) {
+                return outcome;
+            }
+
+            let mut events = self.resubscribe(TaskIdParams::new(task_id.to_string()), None, None).await;
+            while let Some(event) = events.next().await {
+                let (mut task, update) = match event {
+                    Ok(event) => event,
+                    Err(crate::a2a::error::A2AError::UnsupportedOperation(_)) => break,
+                    Err(e) => return Err(e),
+                };
+                if let Some(TaskUpdateEvent::Status(update)) = update {
+                    task.status = update.status;
+                }
+                if let Some(outcome) = wait_outcome(task, target) {
+                    return outcome;
+                }
+            }
+            drop(events);
+
+            loop {
+                let task = self.get_task(TaskQueryParams::new(task_id.to_string()), None, None).await?;
+                if let Some(outcome) = wait_outcome(task, target) {
+                    return outcome;
+                }
+                tokio::time::sleep(WAIT_POLL_INTERVAL).await;
+            }
+        };
+
+        tokio::time::timeout(timeout, wait).await.map_err(|_| {
+            crate::a2a::error::A2AError::from(ClientError::Timeout(format!(
+                "Task {} did not reach {:?} within {:?}",
+                task_id, target, timeout
+            )))
+        })?
+    }
+}
+
+/// Result of `Client::wait_for_state` for a task, `None` while it can still reach a target state
+fn wait_outcome(task: Task, target: &[TaskState]) -> Option<Result<Task, crate::a2a::error::A2AError>> {
+    if target.contains(&task.status.state) {
+        Some(Ok(task))
+    } else if task.status.state.is_terminal() {
+        Some(Err(crate::a2a::error::A2AError::internal(&format!(
+            "Task {} ended as {:?} instead of reaching {:?}",
+            task.id, task.status.state, target
+        ))))
+    } else {
+        None
+    }
+}
+
+/// Text of a task's artifacts, falling back to its status message
+fn task_text(task: &Task) -> String {
+    let artifacts: Vec<String> = task
+        .artifacts
+        .iter()
+        .flatten()
+        .map(|artifact| get_artifact_text(artifact, "\n"))
+        .filter(|text| !text.is_empty())
+        .collect();
+    if !artifacts.is_empty() {
+        return artifacts.join("\n");
+    }
+    task.status
+        .message
+        .as_deref()
+        .map(|message| get_message_text(message, "\n"))
+        .unwrap_or_default()
+}
+
+/// Interval between `get_task` polls in `Client::wait_for_state`
+const WAIT_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Fails `events` with a `ClientError::Timeout` once it goes `window` without an item
+///
+/// Every item, including heartbeat status updates, restarts the window.
+fn with_inactivity_timeout<'a, T: Send + 'a>(
+    mut events: Pin<Box<dyn Stream<Item = Result<T, crate::a2a::error::A2AError>> + Send + 'a>>,
+    window: Option<Duration>,
+) -> Pin<Box<dyn Stream<Item = Result<T, crate::a2a::error::A2AError>> + Send + 'a>> {
+    let Some(window) = window else {
+        return events;
+    };
+    Box::pin(stream! {
+        loop {
+            match tokio::time::timeout(window, events.next()).await {
+                Ok(Some(item)) => yield item,
+                Ok(None) => break,
+                Err(_) => {
+                    yield Err(crate::a2a::error::A2AError::from(ClientError::Timeout(format!(
+                        "No event received from the agent within {:?}",
+                        window
+                    ))));
+                    break;
+                }
+            }
+        }
+    })
 }
 
 /// Base client implementation with common functionality
@@ -174,6 +522,7 @@ pub struct BaseClient {
     consumers: Vec<Consumer>,
     #[allow(dead_code)] // TODO: Implement middleware functionality
     middleware: Vec<Box<dyn ClientCallInterceptor>>,
+    task_cache: TaskCache,
 }
 
 impl BaseClient {
@@ -187,6 +536,7 @@ impl BaseClient {
     ) -> Self {
         Self {
             card,
+            task_cache: TaskCache::new(config.task_cache_size),
             config,
             transport,
             consumers,
@@ -208,6 +558,57 @@ impl BaseClient {
     pub fn transport(&self) -> &dyn ClientTransport {
         &*self.transport
     }
+    
+    /// With `precheck_messages`, fails if the card does not accept the message's content types
+    fn precheck_if_enabled(&self, msg: &Message) -> Result<(), crate::a2a::error::A2AError> {
+        if !self.config.precheck_messages {
+            return Ok(());
+        }
+        self.precheck_message(msg)
+    }
+    
+    /// With `strict_capabilities`, fails if the card does not declare `method`
+    fn check_method(&self, method: &str) -> Result<(), crate::a2a::error::A2AError> {
+        if self.config.strict_capabilities && !self.card.supports_method(method) {
+            return Err(crate::a2a::error::A2AError::unsupported_operation(&format!(
+                "Agent card does not declare support for {}",
+                method
+            )));
+        }
+        Ok(())
+    }
+
+    /// Build send parameters for a message from the client configuration
+    ///
+    /// Accepted output modes and a push notification config set in the
+    /// context's `send_configuration` take precedence.
+    fn send_params(
+        &self,
+        request: Message,
+        request_metadata: Option<HashMap<String, Value>>,
+        context: Option<&ClientCallContext>,
+    ) -> MessageSendParams {
+        let overrides = context.and_then(|context| context.send_configuration.as_ref());
+        let accepted_output_modes = match overrides.and_then(|o| o.accepted_output_modes.clone()) {
+            Some(modes) => Some(modes),
+            None if self.config.accepted_output_modes.is_empty() => None,
+            None => Some(self.config.accepted_output_modes.clone()),
+        };
+        let config = crate::a2a::models::MessageSendConfiguration {
+            accepted_output_modes,
+            blocking: Some(!self.config.polling),
+            history_length: overrides.and_then(|o| o.history_length),
+            push_notification_config: overrides
+                .and_then(|o| o.push_notification_config.clone())
+                .or_else(|| self.config.push_notification_configs.first().cloned()),
+        };
+        
+        MessageSendParams {
+            message: request,
+            configuration: Some(config),
+            metadata: request_metadata,
+        }
+    }
 }
 
 #[async_trait]
@@ -222,26 +623,19 @@ impl Client for BaseClient {
     where
         'life1: 'life0,
     {
-        // Create base configuration from client config
-        let config = crate::a2a::models::MessageSendConfiguration {
-            accepted_output_modes: if self.config.accepted_output_modes.is_empty() {
-                None
-            } else {
-                Some(self.config.accepted_output_modes.clone())
-            },
-            blocking: Some(!self.config.polling),
-            history_length: None,
-            push_notification_config: self.config.push_notification_configs.first().cloned(),
-        };
+        // Agents whose card does not declare streaming are sent message/send
+        let streaming = self.config.streaming && self.card.capabilities.streaming.unwrap_or(false);
+        let checked = self.precheck_if_enabled(&request).and_then(|_| {
+            self.check_method(if streaming { "message/stream" } else { "message/send" })
+        });
+        if let Err(e) = checked {
+            return Box::pin(futures::stream::once(async move { Err(e) }));
+        }
         
-        let params = MessageSendParams {
-            message: request,
-            configuration: Some(config),
-            metadata: request_metadata,
-        };
+        let params = self.send_params(request, request_metadata, context);
         
         // Choose between streaming and non-streaming based on configuration
-        if self.config.streaming {
+        if streaming {
             // Try streaming first
             match self.transport.send_message_streaming(params.clone(), context, extensions.clone()).await {
                 Ok(stream) => {
@@ -274,7 +668,7 @@ impl Client for BaseClient {
                             Err(e) => Err(e),
                         }
                     });
-                    Box::pin(mapped_stream)
+                    with_inactivity_timeout(Box::pin(mapped_stream), self.config.stream_inactivity_timeout)
                 }
                 Err(_) => {
                     // Fall back to non-streaming if streaming fails
@@ -341,13 +735,40 @@ impl Client for BaseClient {
         }
     }
     
+    async fn send_message_sync(
+        &self,
+        request: Message,
+        context: Option<&ClientCallContext>,
+        request_metadata: Option<HashMap<String, Value>>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<SendOutcome, crate::a2a::error::A2AError> {
+        self.precheck_if_enabled(&request)?;
+        let params = self.send_params(request, request_metadata, context);
+        match self.transport.send_message(params, context, extensions).await? {
+            TaskOrMessage::Message(message) => Ok(SendOutcome::Message(message)),
+            TaskOrMessage::Task(task) => Ok(SendOutcome::Task(task)),
+            other => Err(crate::a2a::error::A2AError::invalid_response(&format!(
+                "Expected a task or message from message/send, got {:?}",
+                other
+            ))),
+        }
+    }
+    
     async fn get_task(
         &self,
         request: TaskQueryParams,
         context: Option<&ClientCallContext>,
         extensions: Option<Vec<String>>,
     ) -> Result<Task, crate::a2a::error::A2AError> {
-        self.transport.get_task(request, context, extensions).await
+        let bypass_cache = context.is_some_and(|context| context.bypass_task_cache);
+        if !bypass_cache {
+            if let Some(task) = self.task_cache.get(&request) {
+                return Ok(task);
+            }
+        }
+        let task = self.transport.get_task(request.clone(), context, extensions).await?;
+        self.task_cache.insert(&request, &task);
+        Ok(task)
     }
     
     async fn cancel_task(
@@ -356,6 +777,7 @@ impl Client for BaseClient {
         context: Option<&ClientCallContext>,
         extensions: Option<Vec<String>>,
     ) -> Result<Task, crate::a2a::error::A2AError> {
+        self.task_cache.invalidate(&request.id);
         self.transport.cancel_task(request, context, extensions).await
     }
     
@@ -365,6 +787,7 @@ impl Client for BaseClient {
         context: Option<&ClientCallContext>,
         extensions: Option<Vec<String>>,
     ) -> Result<TaskPushNotificationConfig, crate::a2a::error::A2AError> {
+        self.check_method("tasks/pushNotificationConfig/set")?;
         self.transport.set_task_callback(request, context, extensions).await
     }
     
@@ -374,15 +797,29 @@ impl Client for BaseClient {
         context: Option<&ClientCallContext>,
         extensions: Option<Vec<String>>,
     ) -> Result<TaskPushNotificationConfig, crate::a2a::error::A2AError> {
+        self.check_method("tasks/pushNotificationConfig/get")?;
         self.transport.get_task_callback(request, context, extensions).await
     }
     
+    async fn delete_task_callback(
+        &self,
+        request: DeleteTaskPushNotificationConfigParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<(), crate::a2a::error::A2AError> {
+        self.check_method("tasks/pushNotificationConfig/delete")?;
+        self.transport.delete_task_callback(request, context, extensions).await
+    }
+    
     async fn resubscribe<'a>(
         &'a self,
         request: TaskIdParams,
         context: Option<&ClientCallContext>,
         extensions: Option<Vec<String>>,
     ) -> Pin<Box<dyn Stream<Item = Result<ClientEvent, crate::a2a::error::A2AError>> + Send + 'a>> {
+        if let Err(e) = self.check_method("tasks/resubscribe") {
+            return Box::pin(futures::stream::once(async move { Err(e) }));
+        }
         if !self.config.streaming || !self.card.capabilities.streaming.unwrap_or(false) {
             return Box::pin(stream! {
                 yield Err(crate::a2a::error::A2AError::unsupported_operation(
@@ -392,7 +829,7 @@ impl Client for BaseClient {
         }
         
         match self.transport.resubscribe(request, context, extensions).await {
-            Ok(stream) => stream,
+            Ok(stream) => with_inactivity_timeout(stream, self.config.stream_inactivity_timeout),
             Err(e) => Box::pin(stream! {
                 yield Err(e);
             }),
@@ -410,6 +847,24 @@ impl Client for BaseClient {
         Ok(card)
     }
     
+    async fn get_authenticated_extended_card(
+        &self,
+        context: Option<&ClientCallContext>,
+    ) -> Result<AgentCard, crate::a2a::error::A2AError> {
+        self.check_method("agent/getAuthenticatedExtendedCard")?;
+        if !self.card.supports_authenticated_extended_card.unwrap_or(false) {
+            return Err(crate::a2a::error::A2AError::authenticated_extended_card_not_configured(
+                "Agent does not support an authenticated extended card"
+            ));
+        }
+        
+        self.transport.get_authenticated_extended_card(context, None).await
+    }
+    
+    fn precheck_message(&self, msg: &Message) -> Result<(), crate::a2a::error::A2AError> {
+        crate::a2a::utils::negotiation::validate_message_input_modes(&self.card, msg)
+    }
+    
     async fn add_event_consumer(&self, _consumer: Consumer) {
         // In a real implementation, we would need interior mutability
         // For now, this is a placeholder
@@ -436,6 +891,10 @@ impl Client for BaseClient {
 
 /// Transport trait for different communication protocols
 /// This mirrors a2a-python's ClientTransport
+///
+/// Requests live only as long as the returned future or stream: dropping it
+/// aborts the underlying request and closes its connection. Implementations
+/// must therefore not hand requests off to spawned tasks.
 #[async_trait]
 pub trait ClientTransport: Send + Sync {
     /// Send a non-streaming message
@@ -486,6 +945,18 @@ pub trait ClientTransport: Send + Sync {
         extensions: Option<Vec<String>>,
     ) -> Result<TaskPushNotificationConfig, crate::a2a::error::A2AError>;
     
+    /// Delete a task callback
+    async fn delete_task_callback(
+        &self,
+        _request: DeleteTaskPushNotificationConfigParams,
+        _context: Option<&ClientCallContext>,
+        _extensions: Option<Vec<String>>,
+    ) -> Result<(), crate::a2a::error::A2AError> {
+        Err(crate::a2a::error::A2AError::unsupported_operation(
+            "Deleting push notification configs is not supported by this transport"
+        ))
+    }
+    
     /// Resubscribe to task events
     async fn resubscribe<'a>(
         &'a self,
@@ -501,6 +972,26 @@ pub trait ClientTransport: Send + Sync {
         extensions: Option<Vec<String>>,
     ) -> Result<AgentCard, crate::a2a::error::A2AError>;
     
+    /// Get the authenticated extended agent card
+    async fn get_authenticated_extended_card(
+        &self,
+        _context: Option<&ClientCallContext>,
+        _extensions: Option<Vec<String>>,
+    ) -> Result<AgentCard, crate::a2a::error::A2AError> {
+        Err(crate::a2a::error::A2AError::unsupported_operation(
+            "Authenticated extended card is not supported by this transport"
+        ))
+    }
+    
+    /// Check that the agent endpoint is reachable
+    /// 
+    /// Used by the client factory, when `ClientConfig::probe_transports` is
+    /// set, to fall back to the next transport when an endpoint is down.
+    /// Transports without a cheap probe report success.
+    async fn check_connection(&self) -> Result<(), crate::a2a::error::A2AError> {
+        Ok(())
+    }
+    
     /// Close the transport
     async fn close(&self) -> Result<(), crate::a2a::error::A2AError>;
 }
@@ -5,10 +5,16 @@
 
 use crate::a2a::models::*;
 use crate::a2a::core_types::*;
+use crate::a2a::client::errors::ClientError;
+use crate::a2a::client::task_cache::DEFAULT_TASK_CACHE_SIZE;
+use crate::a2a::error::A2AError;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::Duration;
 
+/// Output modes accepted by `ClientConfig::with_default_accepted_output_modes`
+pub const DEFAULT_ACCEPTED_OUTPUT_MODES: &[&str] = &["text/plain", "application/json"];
+
 /// Configuration for the A2A client
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClientConfig {
@@ -20,6 +26,13 @@ pub struct ClientConfig {
     
     /// Request timeout
     pub timeout: Option<Duration>,
+
+    /// Longest time a stream may go without an event before it fails
+    ///
+    /// Agents doing long work emit heartbeat status updates, so a quiet
+    /// stream means the agent is gone. `None` waits indefinitely.
+    #[serde(default)]
+    pub stream_inactivity_timeout: Option<Duration>,
     
     /// Ordered list of transports for connecting to agent (in order of preference)
     /// Empty implies JSON-RPC only
@@ -30,6 +43,9 @@ pub struct ClientConfig {
     pub use_client_preference: bool,
     
     /// The set of accepted output modes for the client
+    ///
+    /// Sent with every message unless the call context overrides it. Empty
+    /// sends none, which means any mode is accepted.
     pub accepted_output_modes: Vec<String>,
     
     /// Push notification callbacks to use for every request
@@ -37,9 +53,44 @@ pub struct ClientConfig {
     
     /// A list of extension URIs the client supports
     pub extensions: Vec<String>,
+
+    /// Further extension URIs the client implements but only requests when
+    /// the agent card marks them as required
+    #[serde(default)]
+    pub supported_extensions: Vec<String>,
     
     /// HTTP headers to include in all requests
     pub headers: HashMap<String, String>,
+
+    /// HTTP headers to include only when a given transport is selected
+    ///
+    /// These are merged over `headers`, so a transport-specific value wins.
+    #[serde(default)]
+    pub transport_headers: HashMap<TransportProtocol, HashMap<String, String>>,
+
+    /// Whether to check outgoing messages against the card's input modes
+    /// before sending them
+    #[serde(default)]
+    pub precheck_messages: bool,
+
+    /// Whether to fail calls to methods the card does not declare locally,
+    /// with an `UnsupportedOperationError`, instead of sending them
+    #[serde(default)]
+    pub strict_capabilities: bool,
+
+    /// Number of terminal tasks `get_task` serves from a local cache,
+    /// 0 (the default) to always fetch tasks from the agent
+    #[serde(default = "default_task_cache_size")]
+    pub task_cache_size: usize,
+
+    /// Whether the factory checks that each candidate endpoint is reachable,
+    /// falling back to the next transport when it is not
+    #[serde(default)]
+    pub probe_transports: bool,
+}
+
+fn default_task_cache_size() -> usize {
+    DEFAULT_TASK_CACHE_SIZE
 }
 
 impl Default for ClientConfig {
@@ -48,12 +99,19 @@ impl Default for ClientConfig {
             streaming: true,
             polling: false,
             timeout: Some(Duration::from_secs(30)),
+            stream_inactivity_timeout: None,
             supported_transports: vec![TransportProtocol::Jsonrpc],
             use_client_preference: false,
             accepted_output_modes: vec![],
             push_notification_configs: vec![],
             extensions: vec![],
+            supported_extensions: vec![],
             headers: HashMap::new(),
+            transport_headers: HashMap::new(),
+            precheck_messages: false,
+            strict_capabilities: false,
+            task_cache_size: DEFAULT_TASK_CACHE_SIZE,
+            probe_transports: false,
         }
     }
 }
@@ -82,6 +140,12 @@ impl ClientConfig {
         self
     }
     
+    /// Set the stream inactivity timeout
+    pub fn with_stream_inactivity_timeout(mut self, timeout: Duration) -> Self {
+        self.stream_inactivity_timeout = Some(timeout);
+        self
+    }
+    
     /// Set supported transports
     pub fn with_supported_transports(mut self, transports: Vec<TransportProtocol>) -> Self {
         self.supported_transports = transports;
@@ -100,6 +164,11 @@ impl ClientConfig {
         self
     }
     
+    /// Accept `DEFAULT_ACCEPTED_OUTPUT_MODES`, for servers rejecting messages without any
+    pub fn with_default_accepted_output_modes(self) -> Self {
+        self.with_accepted_output_modes(DEFAULT_ACCEPTED_OUTPUT_MODES.iter().map(|mode| mode.to_string()).collect())
+    }
+    
     /// Set push notification configurations
     pub fn with_push_notification_configs(mut self, configs: Vec<PushNotificationConfig>) -> Self {
         self.push_notification_configs = configs;
@@ -112,6 +181,12 @@ impl ClientConfig {
         self
     }
     
+    /// Set extensions requested only when the agent requires them
+    pub fn with_supported_extensions(mut self, extensions: Vec<String>) -> Self {
+        self.supported_extensions = extensions;
+        self
+    }
+    
     /// Set HTTP headers
     pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
         self.headers = headers;
@@ -123,9 +198,144 @@ impl ClientConfig {
         self.headers.insert(key.into(), value.into());
         self
     }
+    
+    /// Set whether to check outgoing messages against the card's input modes
+    /// before sending them
+    pub fn with_precheck_messages(mut self, precheck_messages: bool) -> Self {
+        self.precheck_messages = precheck_messages;
+        self
+    }
+    
+    /// Set whether calls to methods the card does not declare fail locally
+    pub fn with_strict_capabilities(mut self, strict: bool) -> Self {
+        self.strict_capabilities = strict;
+        self
+    }
+    
+    /// Set how many terminal tasks `get_task` caches, 0 to disable the cache
+    pub fn with_task_cache_size(mut self, size: usize) -> Self {
+        self.task_cache_size = size;
+        self
+    }
+    
+    /// Set whether the factory probes endpoints and falls back to reachable ones
+    pub fn with_probe_transports(mut self, probe: bool) -> Self {
+        self.probe_transports = probe;
+        self
+    }
+    
+    /// Add a single HTTP header sent only over the given transport
+    pub fn with_transport_header(
+        mut self,
+        transport: TransportProtocol,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        self.transport_headers
+            .entry(transport)
+            .or_default()
+            .insert(key.into(), value.into());
+        self
+    }
+    
+    /// Layer `overrides` over this config
+    ///
+    /// Scalar fields and `supported_transports` are taken from `overrides`
+    /// where they differ from the defaults. Headers are merged, with the keys
+    /// of `overrides` replacing existing ones. Extensions, accepted output
+    /// modes and push notification configs are concatenated without
+    /// duplicates.
+    pub fn merge(mut self, overrides: ClientConfig) -> ClientConfig {
+        let defaults = ClientConfig::default();
+        if overrides.streaming != defaults.streaming {
+            self.streaming = overrides.streaming;
+        }
+        if overrides.polling != defaults.polling {
+            self.polling = overrides.polling;
+        }
+        if overrides.timeout != defaults.timeout {
+            self.timeout = overrides.timeout;
+        }
+        if overrides.stream_inactivity_timeout != defaults.stream_inactivity_timeout {
+            self.stream_inactivity_timeout = overrides.stream_inactivity_timeout;
+        }
+        if overrides.supported_transports != defaults.supported_transports {
+            self.supported_transports = overrides.supported_transports;
+        }
+        if overrides.use_client_preference != defaults.use_client_preference {
+            self.use_client_preference = overrides.use_client_preference;
+        }
+        if overrides.precheck_messages != defaults.precheck_messages {
+            self.precheck_messages = overrides.precheck_messages;
+        }
+        if overrides.strict_capabilities != defaults.strict_capabilities {
+            self.strict_capabilities = overrides.strict_capabilities;
+        }
+        if overrides.task_cache_size != defaults.task_cache_size {
+            self.task_cache_size = overrides.task_cache_size;
+        }
+        if overrides.probe_transports != defaults.probe_transports {
+            self.probe_transports = overrides.probe_transports;
+        }
+
+        merge_unique(&mut self.accepted_output_modes, overrides.accepted_output_modes);
+        merge_unique(&mut self.push_notification_configs, overrides.push_notification_configs);
+        merge_unique(&mut self.extensions, overrides.extensions);
+        merge_unique(&mut self.supported_extensions, overrides.supported_extensions);
+        self.headers.extend(overrides.headers);
+        for (transport, headers) in overrides.transport_headers {
+            self.transport_headers.entry(transport).or_default().extend(headers);
+        }
+        self
+    }
+
+    /// Get the headers to send over the given transport
+    ///
+    /// Global headers are merged with the transport's own headers, the
+    /// latter taking precedence.
+    pub fn headers_for(&self, transport: &TransportProtocol) -> HashMap<String, String> {
+        let mut headers = self.headers.clone();
+        if let Some(transport_headers) = self.transport_headers.get(transport) {
+            headers.extend(transport_headers.clone());
+        }
+        headers
+    }
+
+    /// Check that the settings do not contradict each other
+    ///
+    /// Rejects a config that disables both streaming and polling, prefers
+    /// its own transports without listing any, or sets a zero timeout.
+    /// `ClientFactory::create` calls this before connecting.
+    pub fn validate(&self) -> Result<(), A2AError> {
+        let problem = if !self.streaming && !self.polling {
+            "streaming and polling are both disabled, so the client cannot follow tasks that do not complete immediately"
+        } else if self.use_client_preference && self.supported_transports.is_empty() {
+            "use_client_preference is set but supported_transports is empty"
+        } else if self.timeout == Some(Duration::ZERO) {
+            "timeout must be greater than zero"
+        } else if self.stream_inactivity_timeout == Some(Duration::ZERO) {
+            "stream_inactivity_timeout must be greater than zero"
+        } else {
+            return Ok(());
+        };
+        Err(ClientError::Configuration(problem.to_string()).into())
+    }
+}
+
+/// Appends the items of `extra` not already in `items`
+fn merge_unique<T: PartialEq>(items: &mut Vec<T>, extra: Vec<T>) {
+    for item in extra {
+        if !items.contains(&item) {
+            items.push(item);
+        }
+    }
 }
 
 /// Configuration for sending a message
+///
+/// Set on a `ClientCallContext` to override the client's accepted output
+/// modes and push notification config for one message, or to limit the
+/// history returned with the task.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MessageSendConfiguration {
     /// Accepted output modes for this specific message
@@ -134,6 +344,10 @@ pub struct MessageSendConfiguration {
     /// Whether to wait for completion (blocking) or return immediately
     pub blocking: Option<bool>,
     
+    /// Number of most recent history messages to return with the task
+    #[serde(default)]
+    pub history_length: Option<i32>,
+    
     /// Push notification configuration for this message
     pub push_notification_config: Option<PushNotificationConfig>,
 }
@@ -143,6 +357,7 @@ impl Default for MessageSendConfiguration {
         Self {
             accepted_output_modes: None,
             blocking: Some(true),
+            history_length: None,
             push_notification_config: None,
         }
     }
@@ -166,6 +381,12 @@ impl MessageSendConfiguration {
         self
     }
     
+    /// Set how many recent history messages the task is returned with
+    pub fn with_history_length(mut self, history_length: i32) -> Self {
+        self.history_length = Some(history_length);
+        self
+    }
+    
     /// Set push notification configuration
     pub fn with_push_notification_config(mut self, config: PushNotificationConfig) -> Self {
         self.push_notification_config = Some(config);
@@ -184,6 +405,7 @@ mod tests {
         assert!(!config.polling);
         assert_eq!(config.supported_transports, vec![TransportProtocol::Jsonrpc]);
         assert!(!config.use_client_preference);
+        assert_eq!(config.stream_inactivity_timeout, None);
     }
 
     #[test]
@@ -202,6 +424,102 @@ mod tests {
         assert_eq!(config.headers.get("Authorization"), Some(&"Bearer token".to_string()));
     }
 
+    #[test]
+    fn test_client_config_transport_headers() {
+        let config = ClientConfig::new()
+            .with_header("Authorization", "Bearer token")
+            .with_header("X-Tenant", "global")
+            .with_transport_header(TransportProtocol::Jsonrpc, "X-Tenant", "jsonrpc")
+            .with_transport_header(TransportProtocol::Grpc, "X-Grpc-Only", "1");
+        
+        let jsonrpc = config.headers_for(&TransportProtocol::Jsonrpc);
+        assert_eq!(jsonrpc.get("Authorization"), Some(&"Bearer token".to_string()));
+        assert_eq!(jsonrpc.get("X-Tenant"), Some(&"jsonrpc".to_string()));
+        assert!(!jsonrpc.contains_key("X-Grpc-Only"));
+        
+        let rest = config.headers_for(&TransportProtocol::HttpJson);
+        assert_eq!(rest.get("X-Tenant"), Some(&"global".to_string()));
+        assert!(!rest.contains_key("X-Grpc-Only"));
+        
+        let json = serde_json::to_value(&config).unwrap();
+        let restored: ClientConfig = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.headers_for(&TransportProtocol::Grpc).get("X-Grpc-Only"), Some(&"1".to_string()));
+    }
+
+    #[test]
+    fn test_client_config_merge_scalars() {
+        let base = ClientConfig::new()
+            .with_polling(true)
+            .with_timeout(Duration::from_secs(60))
+            .with_supported_transports(vec![TransportProtocol::Jsonrpc, TransportProtocol::HttpJson]);
+
+        // Fields left at their defaults keep the base values
+        let merged = base.clone().merge(ClientConfig::new().with_streaming(false));
+        assert!(!merged.streaming);
+        assert!(merged.polling);
+        assert_eq!(merged.timeout, Some(Duration::from_secs(60)));
+        assert_eq!(merged.supported_transports, base.supported_transports);
+
+        let merged = base.merge(
+            ClientConfig::new()
+                .with_timeout(Duration::from_secs(5))
+                .with_supported_transports(vec![TransportProtocol::Grpc]),
+        );
+        assert_eq!(merged.timeout, Some(Duration::from_secs(5)));
+        assert_eq!(merged.supported_transports, vec![TransportProtocol::Grpc]);
+    }
+
+    #[test]
+    fn test_client_config_merge_headers_and_lists() {
+        let base = ClientConfig::new()
+            .with_header("Authorization", "Bearer base")
+            .with_header("X-Tenant", "base")
+            .with_transport_header(TransportProtocol::Jsonrpc, "X-Rpc", "base")
+            .with_extensions(vec!["ext:a".to_string(), "ext:b".to_string()])
+            .with_accepted_output_modes(vec!["text/plain".to_string()]);
+        let overrides = ClientConfig::new()
+            .with_header("Authorization", "Bearer user")
+            .with_transport_header(TransportProtocol::Jsonrpc, "X-Trace", "1")
+            .with_extensions(vec!["ext:b".to_string(), "ext:c".to_string()])
+            .with_accepted_output_modes(vec!["application/json".to_string(), "text/plain".to_string()]);
+
+        let merged = base.merge(overrides);
+        assert_eq!(merged.headers.get("Authorization"), Some(&"Bearer user".to_string()));
+        assert_eq!(merged.headers.get("X-Tenant"), Some(&"base".to_string()));
+        let jsonrpc = merged.headers_for(&TransportProtocol::Jsonrpc);
+        assert_eq!(jsonrpc.get("X-Rpc"), Some(&"base".to_string()));
+        assert_eq!(jsonrpc.get("X-Trace"), Some(&"1".to_string()));
+        assert_eq!(merged.extensions, vec!["ext:a", "ext:b", "ext:c"]);
+        assert_eq!(merged.accepted_output_modes, vec!["text/plain", "application/json"]);
+    }
+
+    #[test]
+    fn test_client_config_validate() {
+        assert!(ClientConfig::default().validate().is_ok());
+        assert!(ClientConfig::new().with_streaming(false).with_polling(true).validate().is_ok());
+
+        let invalid = [
+            (ClientConfig::new().with_streaming(false), "streaming and polling"),
+            (
+                ClientConfig::new().with_client_preference(true).with_supported_transports(vec![]),
+                "supported_transports is empty",
+            ),
+            (ClientConfig::new().with_timeout(Duration::ZERO), "timeout must be"),
+            (
+                ClientConfig::new().with_stream_inactivity_timeout(Duration::ZERO),
+                "stream_inactivity_timeout must be",
+            ),
+        ];
+        for (config, expected) in invalid {
+            let error = config.validate().unwrap_err();
+            assert!(error.message().starts_with("Configuration error: "), "{}", error.message());
+            assert!(error.message().contains(expected), "{}", error.message());
+        }
+
+        // An empty transport list is fine when the server's preference is used
+        assert!(ClientConfig::new().with_supported_transports(vec![]).validate().is_ok());
+    }
+
     #[test]
     fn test_message_send_configuration() {
         let config = MessageSendConfiguration::new()
@@ -2,6 +2,7 @@
 
 use crate::a2a::error::A2AError;
 use crate::InternalError;
+use serde_json::json;
 
 /// Client-specific errors
 #[derive(Debug, thiserror::Error)]
@@ -12,14 +13,64 @@ pub enum ClientError {
     Authentication(String),
     #[error("Configuration error: {0}")]
     Configuration(String),
+    #[error("Timeout: {0}")]
+    Timeout(String),
+    /// A `CircuitBreaker` refused the call without sending it
+    #[error("Circuit breaker open: {0}")]
+    CircuitOpen(String),
 }
 
 impl From<ClientError> for A2AError {
     fn from(err: ClientError) -> Self {
-        A2AError::Internal(InternalError {
-            code: -32603,
-            message: err.to_string(),
-            data: None,
-        })
+        A2AError::Internal(InternalError::new(err.to_string()))
+    }
+}
+
+/// Why the client factory could not connect to an agent over any transport
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum TransportSelectionError {
+    /// The agent offers none of the transports the client supports
+    #[error("No compatible transports found: agent offers {offered:?}, client supports {supported:?}")]
+    NoCommonTransport {
+        offered: Vec<String>,
+        supported: Vec<String>,
+    },
+    /// The agent's only endpoints for the client's transports are not valid URLs
+    #[error("Invalid {transport} endpoint {url:?}: {reason}")]
+    InvalidEndpoint {
+        transport: String,
+        url: String,
+        reason: String,
+    },
+    /// Every candidate transport failed, described as "<transport> at <url>: <error>"
+    #[error("All transports failed: {}", .0.join("; "))]
+    AllTransportsFailed(Vec<String>),
+}
+
+impl TransportSelectionError {
+    /// Details for the `data` of the converted error
+    fn data(&self) -> serde_json::Value {
+        match self {
+            TransportSelectionError::NoCommonTransport { offered, supported } => json!({
+                "reason": "no_common_transport",
+                "candidates": offered,
+                "supported": supported,
+            }),
+            TransportSelectionError::InvalidEndpoint { transport, url, reason } => json!({
+                "reason": "invalid_endpoint",
+                "candidates": [{"transport": transport, "url": url, "error": reason}],
+            }),
+            TransportSelectionError::AllTransportsFailed(attempts) => json!({
+                "reason": "all_transports_failed",
+                "candidates": attempts,
+            }),
+        }
+    }
+}
+
+/// Converts to a transport error whose `data` lists the candidate transports
+impl From<TransportSelectionError> for A2AError {
+    fn from(err: TransportSelectionError) -> Self {
+        A2AError::transport_error(err.to_string()).with_data(err.data())
     }
 }
@@ -4,16 +4,18 @@
 //! mirroring the functionality of a2a-python's ClientFactory.
 
 use crate::a2a::client::config::ClientConfig;
-use crate::a2a::client::client_trait::{Client, BaseClient, ClientCallInterceptor, Consumer, ClientTransport};
+use crate::a2a::client::client_trait::{Client, BaseClient, ClientCallContext, ClientCallInterceptor, Consumer, ClientTransport};
 use crate::a2a::client::transports::jsonrpc::JsonRpcTransport;
 use crate::a2a::client::card_resolver::A2ACardResolver;
+use crate::a2a::client::errors::TransportSelectionError;
 use crate::a2a::models::*;
 use crate::a2a::core_types::*;
 use crate::a2a::error::A2AError;
+use async_trait::async_trait;
 use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
-use std::str::FromStr;
+use std::sync::Arc;
 
 /// Type alias for transport producer function
 pub type TransportProducer = Box<
@@ -144,19 +146,45 @@ impl ClientFactory {
         mut interceptors: Option<Vec<Box<dyn ClientCallInterceptor>>>,
         extensions: Option<Vec<String>>,
     ) -> Result<Box<dyn Client>, A2AError> {
-        // Determine transport protocol and URL
-        let (transport_protocol, transport_url) = self.determine_transport(&card)?;
-        
-        // Get transport producer
-        let producer = self.registry.get(&transport_protocol.to_string())
-            .ok_or_else(|| A2AError::transport_error(format!("No client available for {}", transport_protocol)))?;
+        self.config.validate()?;
+        let candidates = self.transport_candidates(&card);
+        if candidates.is_empty() {
+            return Err(self.selection_error(&card).into());
+        }
+        if let Some((preferred, selected)) = self.transport_downgrade(&card) {
+            tracing::warn!(
+                "Agent {} prefers {}, which the client does not support; downgrading to {}",
+                card.name, preferred, selected
+            );
+        }
         
-        // Create transport
-        let config_with_extensions = self.merge_extensions(extensions.clone());
-        let transport = {
-            let transport_interceptors = interceptors.take().unwrap_or_default();
-            producer(card.clone(), transport_url, config_with_extensions, transport_interceptors).await?
-        };
+        // Try transports in preference order, falling through to the next
+        // one when an endpoint cannot be reached
+        let config_with_extensions = Self::request_required_extensions(&card, self.merge_extensions(extensions.clone()))?;
+        let transport_interceptors: Vec<Arc<dyn ClientCallInterceptor>> = interceptors
+            .take()
+            .unwrap_or_default()
+            .into_iter()
+            .map(Arc::from)
+            .collect();
+        let mut tried = Vec::new();
+        let mut transport = None;
+        for (transport_protocol, transport_url) in candidates {
+            match self.connect_transport(
+                &card,
+                transport_protocol,
+                transport_url.clone(),
+                &config_with_extensions,
+                &transport_interceptors,
+            ).await {
+                Ok(connected) => {
+                    transport = Some(connected);
+                    break;
+                }
+                Err(e) => tried.push(format!("{} at {}: {}", transport_protocol, transport_url, e.message())),
+            }
+        }
+        let transport = transport.ok_or(TransportSelectionError::AllTransportsFailed(tried))?;
         
         // Combine consumers - note: we can't clone Fn trait objects, so we'll use the provided ones
         let all_consumers = if self.consumers.is_empty() {
@@ -207,6 +235,7 @@ impl ClientFactory {
         extensions: Option<Vec<String>>,
     ) -> Result<Box<dyn Client>, A2AError> {
         let config = client_config.unwrap_or_default();
+        let card_timeout = config.timeout;
         let mut factory = ClientFactory::with_config(config);
         
         // Register extra transports if provided
@@ -217,7 +246,10 @@ impl ClientFactory {
         }
         
         // Resolve agent card
-        let resolver = A2ACardResolver::new(agent);
+        let mut resolver = A2ACardResolver::new(agent);
+        if let Some(timeout) = card_timeout {
+            resolver = resolver.with_timeout(timeout);
+        }
         let card = resolver.get_agent_card_with_path(relative_card_path, resolver_http_kwargs).await?;
         
         factory.create(card, consumers, interceptors, extensions).await
@@ -225,58 +257,130 @@ impl ClientFactory {
     
     /// Determine the best transport protocol and URL to use
     pub fn determine_transport(&self, card: &AgentCard) -> Result<(TransportProtocol, String), A2AError> {
-        // Build server transport map
-        let mut server_set = HashMap::new();
-        
-        let server_preferred = card.preferred_transport.as_ref()
-            .and_then(|transport| TransportProtocol::from_str(transport).ok())
-            .unwrap_or(TransportProtocol::Jsonrpc);
-        
-        server_set.insert(server_preferred, card.url.clone());
-        
-        if let Some(additional_interfaces) = &card.additional_interfaces {
-            for interface in additional_interfaces {
-                if let Ok(transport) = TransportProtocol::from_str(&interface.transport) {
-                    server_set.insert(transport, interface.url.clone());
-                }
+        self.transport_candidates(card)
+            .into_iter()
+            .next()
+            .ok_or_else(|| self.selection_error(card).into())
+    }
+    
+    /// Detect a downgrade from the card's preferred transport
+    ///
+    /// Returns the preferred transport and the additional interface's
+    /// transport used in its place when the client does not support the
+    /// preferred one. `None` if there is no downgrade, or no supported
+    /// interface to downgrade to.
+    pub fn transport_downgrade(&self, card: &AgentCard) -> Option<(TransportProtocol, TransportProtocol)> {
+        let (preferred, _) = card.interface_urls().into_iter().next()?;
+        if self.supported_transports().contains(&preferred) {
+            return None;
+        }
+        let (selected, _) = self.transport_candidates(card).into_iter().next()?;
+        Some((preferred, selected))
+    }
+    
+    /// Explains why the card has no transport candidates
+    fn selection_error(&self, card: &AgentCard) -> TransportSelectionError {
+        let supported = self.supported_transports();
+        let declared = std::iter::once((card.preferred_transport.clone().unwrap_or_default(), card.url.clone()))
+            .chain(card.additional_interfaces.iter().flatten().map(|i| (i.transport.clone(), i.url.clone())));
+        for (transport, url) in declared {
+            // An unset preferred transport means JSON-RPC
+            let transport = if transport.is_empty() { TransportProtocol::Jsonrpc.to_string() } else { transport };
+            let Ok(protocol) = transport.parse::<TransportProtocol>() else { continue };
+            if let (true, Err(e)) = (supported.contains(&protocol), url::Url::parse(&url)) {
+                return TransportSelectionError::InvalidEndpoint { transport, url, reason: e.to_string() };
             }
         }
-        
-        // Get client supported transports
-        let client_set = if self.config.supported_transports.is_empty() {
+
+        let mut offered: Vec<String> = Vec::new();
+        for (transport, _) in card.interface_urls() {
+            if !offered.contains(&transport.to_string()) {
+                offered.push(transport.to_string());
+            }
+        }
+        TransportSelectionError::NoCommonTransport {
+            offered,
+            supported: supported.iter().map(|transport| transport.to_string()).collect(),
+        }
+    }
+
+    /// Transports the client can use, JSON-RPC unless configured otherwise
+    fn supported_transports(&self) -> Vec<TransportProtocol> {
+        if self.config.supported_transports.is_empty() {
             vec![TransportProtocol::Jsonrpc]
         } else {
             self.config.supported_transports.clone()
-        };
+        }
+    }
+
+    /// List the transports usable with the agent, most preferred first
+    /// 
+    /// The server order is that of `AgentCard::interfaces`: the card's
+    /// preferred transport followed by its additional interfaces. With `use_client_preference` the client's
+    /// supported transports are ranked first and the server order breaks ties.
+    pub fn transport_candidates(&self, card: &AgentCard) -> Vec<(TransportProtocol, String)> {
+        let server_list = card.interface_urls();
         
-        // Find matching transport
-        let mut transport_protocol = None;
-        let mut transport_url = None;
+        let client_set = self.supported_transports();
         
         if self.config.use_client_preference {
-            // Use client preference order - iterate through client transports first
-            for transport in &client_set {
-                if let Some(url) = server_set.get(transport) {
-                    transport_protocol = Some(*transport);
-                    transport_url = Some(url.clone());
-                    break;
-                }
-            }
+            client_set
+                .iter()
+                .flat_map(|client_transport| {
+                    server_list.iter().filter(move |(transport, _)| transport == client_transport)
+                })
+                .cloned()
+                .collect()
         } else {
-            // Use server preference order
-            for (transport, url) in server_set {
-                if client_set.contains(&transport) {
-                    transport_protocol = Some(transport);
-                    transport_url = Some(url);
-                    break;
-                }
-            }
+            server_list
+                .into_iter()
+                .filter(|(transport, _)| client_set.contains(transport))
+                .collect()
         }
+    }
+    
+    /// Create a transport for one candidate, checking that its endpoint is
+    /// reachable when `probe_transports` is set
+    async fn connect_transport(
+        &self,
+        card: &AgentCard,
+        transport_protocol: TransportProtocol,
+        transport_url: String,
+        config: &ClientConfig,
+        interceptors: &[Arc<dyn ClientCallInterceptor>],
+    ) -> Result<Box<dyn ClientTransport>, A2AError> {
+        let producer = self.registry.get(&transport_protocol.to_string())
+            .ok_or_else(|| A2AError::transport_error(format!("No client available for {}", transport_protocol)))?;
         
-        match (transport_protocol, transport_url) {
-            (Some(protocol), Some(url)) => Ok((protocol, url)),
-            _ => Err(A2AError::transport_error("No compatible transports found".to_string()))
+        let interceptors = interceptors
+            .iter()
+            .map(|interceptor| Box::new(SharedInterceptor(interceptor.clone())) as Box<dyn ClientCallInterceptor>)
+            .collect();
+        let transport = producer(card.clone(), transport_url, config.clone(), interceptors).await?;
+        if config.probe_transports {
+            transport.check_connection().await?;
         }
+        Ok(transport)
+    }
+    
+    /// Add the extensions the card requires to the requested set
+    ///
+    /// Fails with `UnsupportedOperation` if the card requires an extension
+    /// that is neither requested nor in `supported_extensions`.
+    fn request_required_extensions(card: &AgentCard, mut config: ClientConfig) -> Result<ClientConfig, A2AError> {
+        for uri in card.capabilities.required_extensions() {
+            if config.extensions.iter().any(|requested| requested == uri) {
+                continue;
+            }
+            if !config.supported_extensions.iter().any(|supported| supported == uri) {
+                return Err(A2AError::unsupported_operation(&format!(
+                    "Agent requires extension {} which the client does not support",
+                    uri
+                )));
+            }
+            config.extensions.push(uri.to_string());
+        }
+        Ok(config)
     }
     
     /// Merge extensions from config and call
@@ -293,6 +397,23 @@ impl ClientFactory {
     }
 }
 
+/// Interceptor shared between the transports tried while connecting
+struct SharedInterceptor(Arc<dyn ClientCallInterceptor>);
+
+#[async_trait]
+impl ClientCallInterceptor for SharedInterceptor {
+    async fn intercept(
+        &self,
+        method_name: &str,
+        request_payload: serde_json::Value,
+        http_kwargs: HashMap<String, serde_json::Value>,
+        agent_card: &AgentCard,
+        context: Option<&ClientCallContext>,
+    ) -> Result<(serde_json::Value, HashMap<String, serde_json::Value>), A2AError> {
+        self.0.intercept(method_name, request_payload, http_kwargs, agent_card, context).await
+    }
+}
+
 /// Generate a minimal agent card to simplify bootstrapping client creation
 /// 
 /// This minimal card is not viable itself to interact with the remote agent.
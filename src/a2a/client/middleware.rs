@@ -0,0 +1,101 @@
+//! Client call middleware
+//!
+//! This module provides general-purpose interceptors that can be registered
+//! on any client, such as TraceContextInterceptor for distributed tracing.
+
+use crate::a2a::client::client_trait::{ClientCallContext, ClientCallInterceptor};
+use crate::a2a::error::A2AError;
+use crate::a2a::models::AgentCard;
+use crate::a2a::utils::telemetry::{current_traceparent, TRACEPARENT_HEADER};
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// An interceptor that propagates the current trace to the agent
+///
+/// Adds a W3C `traceparent` header derived from the current tracing span, so
+/// the agent's request span joins the caller's trace. Requests made outside
+/// of a traced span are sent unchanged.
+#[derive(Debug, Clone, Default)]
+pub struct TraceContextInterceptor;
+
+impl TraceContextInterceptor {
+    /// Create a new trace context interceptor
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl ClientCallInterceptor for TraceContextInterceptor {
+    async fn intercept(
+        &self,
+        _method_name: &str,
+        request_payload: Value,
+        mut http_kwargs: HashMap<String, Value>,
+        _agent_card: &AgentCard,
+        _context: Option<&ClientCallContext>,
+    ) -> Result<(Value, HashMap<String, Value>), A2AError> {
+        let Some(traceparent) = current_traceparent() else {
+            return Ok((request_payload, http_kwargs));
+        };
+
+        let headers = http_kwargs
+            .entry("headers".to_string())
+            .or_insert_with(|| Value::Object(serde_json::Map::new()))
+            .as_object_mut()
+            .ok_or_else(|| A2AError::invalid_request("headers must be an object"))?;
+        headers.insert(TRACEPARENT_HEADER.to_string(), Value::String(traceparent));
+
+        Ok((request_payload, http_kwargs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+    use opentelemetry_sdk::trace::TracerProvider;
+    use tracing::Instrument;
+    use tracing_opentelemetry::OpenTelemetrySpanExt;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    fn test_card() -> AgentCard {
+        AgentCard::test_default("Test Agent", "http://localhost:8080")
+    }
+
+    #[tokio::test]
+    async fn test_injects_well_formed_traceparent() {
+        let tracer = TracerProvider::builder().build().tracer("a2a-test");
+        let subscriber = tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let card = test_card();
+        let span = tracing::info_span!("client call");
+        let trace_id = span.context().span().span_context().trace_id().to_string();
+
+        let (_, http_kwargs) = TraceContextInterceptor::new()
+            .intercept("message/send", Value::Null, HashMap::new(), &card, None)
+            .instrument(span)
+            .await
+            .unwrap();
+
+        let traceparent = http_kwargs["headers"][TRACEPARENT_HEADER].as_str().unwrap();
+        let fields: Vec<&str> = traceparent.split('-').collect();
+        assert_eq!(fields.len(), 4);
+        assert_eq!(fields[0], "00");
+        assert_eq!(fields[1], trace_id);
+        assert_eq!(fields[2].len(), 16);
+        assert!(fields[1..].iter().all(|field| field.chars().all(|c| c.is_ascii_hexdigit())));
+    }
+
+    #[tokio::test]
+    async fn test_untraced_request_unchanged() {
+        let (_, http_kwargs) = TraceContextInterceptor::new()
+            .intercept("message/send", Value::Null, HashMap::new(), &test_card(), None)
+            .await
+            .unwrap();
+
+        assert!(http_kwargs.is_empty());
+    }
+}
@@ -4,6 +4,8 @@
 //! matching a2a-python/src/a2a/client/
 
 pub mod base_client;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod card_resolver;
 pub mod client_factory;
 pub mod client_task_manager;
@@ -17,6 +19,9 @@ pub mod legacy_grpc;
 pub mod legacy;
 pub mod middleware;
 pub mod optionals;
+pub mod service;
+pub mod stream_item;
+pub mod task_cache;
 
 // Auth submodule
 pub mod auth;
@@ -26,10 +31,15 @@ pub mod transports;
 
 // Re-export main client types
 pub use base_client::BaseClient;
+#[cfg(feature = "blocking")]
+pub use blocking::BlockingClient;
 pub use client_trait::{
-    Client, ClientTransport, ClientCallContext, ClientCallInterceptor, 
-    ClientEvent, ClientEventOrMessage, Consumer, TaskUpdateEvent
+    CancelHandle, Client, ClientTransport, ClientCallContext, ClientCallInterceptor, 
+    ClientEvent, ClientEventOrMessage, ClientEventStream, Consumer, SendOutcome, TaskUpdateEvent
 };
+pub use middleware::TraceContextInterceptor;
+pub use service::A2AClientService;
+pub use stream_item::{dedupe_status_updates, map_to_stream_items, pipe_artifact_text, with_final_timeout, StreamItem};
 pub use client::*;
 pub use config::*;
 pub use errors::*;
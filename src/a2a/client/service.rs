@@ -0,0 +1,109 @@
+//! Tower `Service` adapter for A2A clients
+//!
+//! `A2AClientService` exposes a `Client` as a `tower::Service` taking
+//! `A2ARequest`s, so middleware such as timeouts, retries, rate limits and
+//! load balancing can be layered over it with `tower::ServiceBuilder`.
+
+use crate::a2a::client::client_trait::{Client, ClientCallContext, SendOutcome};
+use crate::a2a::client::config::MessageSendConfiguration;
+use crate::a2a::error::A2AError;
+use crate::a2a::jsonrpc::{A2ARequest, A2AResponse, SendMessageResult};
+use crate::a2a::models::{GetTaskPushNotificationConfigParams, MessageSendParams};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+/// A `Client` usable as a `tower::Service<A2ARequest>`
+///
+/// Each request is answered with the matching `A2AResponse` variant. The
+/// service is always ready and cheap to clone, so it can be shared between
+/// the services of a balancer or retried freely.
+///
+/// Requests whose answer is a stream (`message/stream` and
+/// `tasks/resubscribe`), and push notification config requests the `Client`
+/// trait does not offer (`list` and `delete`), fail with an
+/// `UnsupportedOperation` error.
+#[derive(Clone)]
+pub struct A2AClientService {
+    client: Arc<dyn Client>,
+}
+
+impl A2AClientService {
+    /// Wrap `client` in a service
+    pub fn new(client: Arc<dyn Client>) -> Self {
+        Self { client }
+    }
+
+    /// The wrapped client
+    pub fn client(&self) -> &Arc<dyn Client> {
+        &self.client
+    }
+}
+
+impl std::fmt::Debug for A2AClientService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("A2AClientService").finish_non_exhaustive()
+    }
+}
+
+impl tower::Service<A2ARequest> for A2AClientService {
+    type Response = A2AResponse;
+    type Error = A2AError;
+    type Future = Pin<Box<dyn Future<Output = Result<A2AResponse, A2AError>> + Send>>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: A2ARequest) -> Self::Future {
+        let client = self.client.clone();
+        Box::pin(async move { dispatch(client.as_ref(), request).await })
+    }
+}
+
+async fn dispatch(client: &dyn Client, request: A2ARequest) -> Result<A2AResponse, A2AError> {
+    match request {
+        A2ARequest::SendMessage { params } => send_message(client, params).await,
+        A2ARequest::GetTask { params } => Ok(A2AResponse::GetTask(client.get_task(params, None, None).await?)),
+        A2ARequest::CancelTask { params } => Ok(A2AResponse::CancelTask(client.cancel_task(params, None, None).await?)),
+        A2ARequest::SetTaskPushNotificationConfig { params } => Ok(A2AResponse::SetTaskPushNotificationConfig(
+            client.set_task_callback(params, None, None).await?,
+        )),
+        A2ARequest::GetTaskPushNotificationConfig { params } => {
+            let params = GetTaskPushNotificationConfigParams {
+                id: params.id,
+                push_notification_config_id: None,
+                metadata: params.metadata,
+            };
+            Ok(A2AResponse::GetTaskPushNotificationConfig(
+                client.get_task_callback(params, None, None).await?,
+            ))
+        }
+        A2ARequest::GetAuthenticatedExtendedCard => Ok(A2AResponse::GetAuthenticatedExtendedCard(
+            client.get_authenticated_extended_card(None).await?,
+        )),
+        A2ARequest::SendStreamingMessage { .. } | A2ARequest::TaskResubscription { .. } => Err(
+            A2AError::unsupported_operation("Streaming requests are not supported by A2AClientService; use the client directly"),
+        ),
+        A2ARequest::ListTaskPushNotificationConfig { .. } | A2ARequest::DeleteTaskPushNotificationConfig { .. } => Err(
+            A2AError::unsupported_operation("Listing and deleting push notification configs is not supported by A2AClientService"),
+        ),
+    }
+}
+
+async fn send_message(client: &dyn Client, params: MessageSendParams) -> Result<A2AResponse, A2AError> {
+    let context = params.configuration.map(|configuration| {
+        ClientCallContext::new().with_send_configuration(MessageSendConfiguration {
+            accepted_output_modes: configuration.accepted_output_modes,
+            blocking: configuration.blocking,
+            history_length: configuration.history_length,
+            push_notification_config: configuration.push_notification_config,
+        })
+    });
+    let result = match client.send_message_sync(params.message, context.as_ref(), params.metadata, None).await? {
+        SendOutcome::Task(task) => SendMessageResult::Task(task),
+        SendOutcome::Message(message) => SendMessageResult::Message(message),
+    };
+    Ok(A2AResponse::SendMessage(result))
+}
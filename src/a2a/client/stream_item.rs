@@ -0,0 +1,393 @@
+//! Flat streaming events for A2A clients
+//!
+//! `Client::send_message` and `Client::resubscribe` yield `ClientEventOrMessage`
+//! values, which nest task updates inside a `(Task, Option<TaskUpdateEvent>)`
+//! tuple. This module provides the single-level `StreamItem` enum, an
+//! adapter that converts such streams so consumers can match one level deep,
+//! an opt-in adapter that drops redundant status updates, an adapter that
+//! fails tasks whose stream never ends, and a helper that pipes streamed
+//! artifact text into a writer.
+
+use crate::a2a::client::client_trait::{ClientEventOrMessage, TaskUpdateEvent};
+use crate::a2a::client::errors::ClientError;
+use crate::a2a::core_types::{Message, Part, Role, TaskState, TaskStatus};
+use crate::a2a::error::A2AError;
+use crate::a2a::models::{Task, TaskArtifactUpdateEvent, TaskStatusUpdateEvent};
+use crate::a2a::utils::heartbeat::is_heartbeat;
+use crate::a2a::utils::artifact::get_artifact_text;
+use async_stream::stream;
+use futures::{Stream, StreamExt};
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// A single event received from a streaming client call
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamItem {
+    /// A full task snapshot, typically the first event of a stream
+    TaskCreated(Task),
+    /// A task status update
+    Status(TaskStatusUpdateEvent),
+    /// A task artifact update
+    Artifact(TaskArtifactUpdateEvent),
+    /// A direct message from the agent
+    Message(Message),
+}
+
+impl StreamItem {
+    /// Returns whether no further events are expected after this one
+    ///
+    /// Final status updates, direct messages and task snapshots in a terminal
+    /// state end the stream.
+    pub fn is_terminal(&self) -> bool {
+        match self {
+            StreamItem::TaskCreated(task) => task.status.state.is_terminal(),
+            StreamItem::Status(update) => update.r#final,
+            StreamItem::Artifact(_) => false,
+            StreamItem::Message(_) => true,
+        }
+    }
+}
+
+impl From<ClientEventOrMessage> for StreamItem {
+    fn from(event: ClientEventOrMessage) -> Self {
+        match event {
+            ClientEventOrMessage::Event((task, None)) => StreamItem::TaskCreated(task),
+            ClientEventOrMessage::Event((_, Some(TaskUpdateEvent::Status(update)))) => StreamItem::Status(update),
+            ClientEventOrMessage::Event((_, Some(TaskUpdateEvent::Artifact(update)))) => StreamItem::Artifact(update),
+            ClientEventOrMessage::Message(message) => StreamItem::Message(message),
+        }
+    }
+}
+
+/// Converts a stream of `ClientEventOrMessage` results into `StreamItem` results
+///
+/// Errors are passed through unchanged.
+pub fn map_to_stream_items<S>(stream: S) -> impl Stream<Item = Result<StreamItem, A2AError>>
+where
+    S: Stream<Item = Result<ClientEventOrMessage, A2AError>>,
+{
+    stream.map(|result| result.map(StreamItem::from))
+}
+
+/// Drops status updates that repeat the previous status of their task
+///
+/// A non-final status update is suppressed when its task's last status
+/// update had the same state and the same status message, as sent by agents
+/// that re-announce `Working` while they run. Heartbeats are such updates
+/// too. Final updates, task snapshots, artifacts, messages and errors are
+/// always passed through.
+pub fn dedupe_status_updates<S>(stream: S) -> impl Stream<Item = Result<ClientEventOrMessage, A2AError>>
+where
+    S: Stream<Item = Result<ClientEventOrMessage, A2AError>>,
+{
+    let mut last_status: HashMap<String, (TaskState, Option<Box<Message>>)> = HashMap::new();
+    stream.filter(move |result| {
+        let keep = match result {
+            Ok(ClientEventOrMessage::Event((_, Some(TaskUpdateEvent::Status(update))))) if !update.r#final => {
+                let status = (update.status.state.clone(), update.status.message.clone());
+                last_status.insert(update.task_id.clone(), status.clone()).as_ref() != Some(&status)
+            }
+            _ => true,
+        };
+        futures::future::ready(keep)
+    })
+}
+
+/// Ends `stream` with a synthetic `Failed` final status update when no
+/// terminal event (see `StreamItem::is_terminal`) follows the last event
+/// within `window`
+///
+/// Unlike `ClientConfig::stream_inactivity_timeout`, heartbeats do not
+/// restart the window, so an agent that keeps the stream alive without
+/// making progress is still cut off. The synthetic update is for the task
+/// of the last event; if no task event was seen, the stream fails with a
+/// `ClientError::Timeout` instead. Events after a terminal one are passed
+/// through without a deadline.
+pub fn with_final_timeout<S>(stream: S, window: Duration) -> impl Stream<Item = Result<ClientEventOrMessage, A2AError>>
+where
+    S: Stream<Item = Result<ClientEventOrMessage, A2AError>>,
+{
+    stream! {
+        futures::pin_mut!(stream);
+        let mut deadline = tokio::time::Instant::now() + window;
+        let mut last_task: Option<Task> = None;
+        let mut terminated = false;
+        loop {
+            let next = if terminated {
+                stream.next().await
+            } else {
+                match tokio::time::timeout_at(deadline, stream.next()).await {
+                    Ok(next) => next,
+                    Err(_) => {
+                        yield timed_out(last_task, window);
+                        break;
+                    }
+                }
+            };
+            let Some(item) = next else { break };
+            if let Ok(event) = &item {
+                if let ClientEventOrMessage::Event((task, update)) = event {
+                    last_task = Some(task.clone());
+                    if !matches!(update, Some(TaskUpdateEvent::Status(status)) if is_heartbeat(status)) {
+                        deadline = tokio::time::Instant::now() + window;
+                    }
+                }
+                terminated |= StreamItem::from(event.clone()).is_terminal();
+            }
+            yield item;
+        }
+    }
+}
+
+/// The event ending a stream cut off by `with_final_timeout`
+fn timed_out(task: Option<Task>, window: Duration) -> Result<ClientEventOrMessage, A2AError> {
+    let Some(mut task) = task else {
+        return Err(A2AError::from(ClientError::Timeout(format!(
+            "No terminal event received from the agent within {:?}",
+            window
+        ))));
+    };
+    let message = Message::new(
+        Role::Agent,
+        vec![Part::text(format!("No terminal event received within {:?}", window))],
+    )
+    .with_task_id(task.id.to_string())
+    .with_context_id(task.context_id.clone());
+    task.status = TaskStatus::new(TaskState::Failed).with_message(message);
+    let update = TaskStatusUpdateEvent::new(task.id.to_string(), task.context_id.clone(), task.status.clone(), true);
+    Ok(ClientEventOrMessage::Event((task, Some(TaskUpdateEvent::Status(update)))))
+}
+
+/// Writes the text of each streamed artifact chunk to `writer` as it arrives
+///
+/// The writer is flushed after every chunk so output appears immediately.
+/// Returns once a terminal event (see `StreamItem::is_terminal`) is seen or
+/// the stream ends; the first stream or write error is returned.
+pub async fn pipe_artifact_text<S, T, W>(stream: S, mut writer: W) -> Result<(), A2AError>
+where
+    S: Stream<Item = Result<T, A2AError>>,
+    T: Into<StreamItem>,
+    W: AsyncWrite + Unpin,
+{
+    futures::pin_mut!(stream);
+    while let Some(item) = stream.next().await {
+        let item: StreamItem = item?.into();
+        if let StreamItem::Artifact(ref update) = item {
+            let text = get_artifact_text(&update.artifact, "");
+            if !text.is_empty() {
+                writer.write_all(text.as_bytes()).await?;
+                writer.flush().await?;
+            }
+        }
+        if item.is_terminal() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::models::Artifact;
+
+    fn sample_task() -> Task {
+        Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+            .with_task_id("task-1".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_map_to_stream_items() {
+        let task = sample_task();
+        let status = TaskStatusUpdateEvent::new(
+            task.id.to_string(),
+            task.context_id.clone(),
+            TaskStatus::new(TaskState::Completed),
+            true,
+        );
+        let artifact = TaskArtifactUpdateEvent::new(
+            task.id.to_string(),
+            task.context_id.clone(),
+            Artifact::new(vec![Part::text("result".to_string())]),
+        );
+        let message = Message::new(Role::Agent, vec![Part::text("done".to_string())]);
+
+        let events = vec![
+            Ok(ClientEventOrMessage::Event((task.clone(), None))),
+            Ok(ClientEventOrMessage::Event((task.clone(), Some(TaskUpdateEvent::Artifact(artifact.clone()))))),
+            Ok(ClientEventOrMessage::Event((task.clone(), Some(TaskUpdateEvent::Status(status.clone()))))),
+            Ok(ClientEventOrMessage::Message(message.clone())),
+            Err(A2AError::internal("stream failed")),
+        ];
+
+        let items: Vec<_> = map_to_stream_items(futures::stream::iter(events)).collect().await;
+
+        assert_eq!(items.len(), 5);
+        assert_eq!(items[0].as_ref().unwrap(), &StreamItem::TaskCreated(task));
+        assert_eq!(items[1].as_ref().unwrap(), &StreamItem::Artifact(artifact));
+        assert_eq!(items[2].as_ref().unwrap(), &StreamItem::Status(status));
+        assert_eq!(items[3].as_ref().unwrap(), &StreamItem::Message(message));
+        assert!(items[4].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_dedupe_status_updates() {
+        let task = sample_task();
+        let status = |state: TaskState, r#final: bool| {
+            Ok(ClientEventOrMessage::Event((
+                task.clone(),
+                Some(TaskUpdateEvent::Status(TaskStatusUpdateEvent::new(
+                    task.id.to_string(),
+                    task.context_id.clone(),
+                    TaskStatus::new(state),
+                    r#final,
+                ))),
+            )))
+        };
+        let artifact = Ok(ClientEventOrMessage::Event((
+            task.clone(),
+            Some(TaskUpdateEvent::Artifact(TaskArtifactUpdateEvent::new(
+                task.id.to_string(),
+                task.context_id.clone(),
+                Artifact::new(vec![Part::text("result".to_string())]),
+            ))),
+        )));
+
+        let events = vec![
+            status(TaskState::Working, false),
+            status(TaskState::Working, false),
+            status(TaskState::Working, false),
+            artifact,
+            status(TaskState::Completed, true),
+        ];
+        let items: Vec<StreamItem> = map_to_stream_items(dedupe_status_updates(futures::stream::iter(events)))
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+
+        let states: Vec<_> = items
+            .iter()
+            .filter_map(|item| match item {
+                StreamItem::Status(update) => Some(update.status.state.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(states, vec![TaskState::Working, TaskState::Completed]);
+        assert_eq!(items.len(), 3);
+        assert!(matches!(items[1], StreamItem::Artifact(_)));
+    }
+
+    #[tokio::test]
+    async fn test_final_timeout_synthesizes_failed_terminal() {
+        let task = sample_task();
+        let working = TaskStatusUpdateEvent::new(
+            task.id.to_string(),
+            task.context_id.clone(),
+            TaskStatus::new(TaskState::Working),
+            false,
+        );
+        let events = futures::stream::iter(vec![
+            Ok(ClientEventOrMessage::Event((task.clone(), None))),
+            Ok(ClientEventOrMessage::Event((task.clone(), Some(TaskUpdateEvent::Status(working))))),
+        ])
+        .chain(futures::stream::pending());
+
+        let items: Vec<StreamItem> = map_to_stream_items(with_final_timeout(events, Duration::from_millis(50)))
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(items.len(), 3);
+        match &items[2] {
+            StreamItem::Status(update) => {
+                assert!(update.r#final);
+                assert_eq!(update.task_id, "task-1");
+                assert_eq!(update.status.state, TaskState::Failed);
+            }
+            other => panic!("Expected a synthetic failed update, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_final_timeout_ignores_heartbeats() {
+        let task = sample_task();
+        let mut heartbeat = TaskStatusUpdateEvent::new(
+            task.id.to_string(),
+            task.context_id.clone(),
+            TaskStatus::new(TaskState::Working),
+            false,
+        );
+        heartbeat.metadata = Some(HashMap::from([(
+            crate::a2a::utils::heartbeat::HEARTBEAT_METADATA_KEY.to_string(),
+            serde_json::Value::Bool(true),
+        )]));
+        let heartbeats = futures::stream::unfold((), move |_| {
+            let event = ClientEventOrMessage::Event((sample_task(), Some(TaskUpdateEvent::Status(heartbeat.clone()))));
+            async move {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                Some((Ok(event), ()))
+            }
+        });
+
+        let items: Vec<StreamItem> = map_to_stream_items(with_final_timeout(heartbeats, Duration::from_millis(100)))
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+
+        assert!(items.last().unwrap().is_terminal());
+        assert!(matches!(items.last(), Some(StreamItem::Status(update)) if update.status.state == TaskState::Failed));
+    }
+
+    #[tokio::test]
+    async fn test_final_timeout_passes_completed_stream_through() {
+        let task = sample_task();
+        let completed = TaskStatusUpdateEvent::new(
+            task.id.to_string(),
+            task.context_id.clone(),
+            TaskStatus::new(TaskState::Completed),
+            true,
+        );
+        let events = futures::stream::iter(vec![
+            Ok(ClientEventOrMessage::Event((task.clone(), None))),
+            Ok(ClientEventOrMessage::Event((task.clone(), Some(TaskUpdateEvent::Status(completed))))),
+        ]);
+
+        let items: Vec<_> = with_final_timeout(events, Duration::from_secs(5)).collect().await;
+        assert_eq!(items.len(), 2);
+        assert!(StreamItem::from(items[1].as_ref().unwrap().clone()).is_terminal());
+    }
+
+    #[tokio::test]
+    async fn test_pipe_artifact_text() {
+        let task = sample_task();
+        let chunk = |text: &str| {
+            StreamItem::Artifact(TaskArtifactUpdateEvent::new(
+                task.id.to_string(),
+                task.context_id.clone(),
+                Artifact::new(vec![Part::text(text.to_string())]),
+            ))
+        };
+        let completed = StreamItem::Status(TaskStatusUpdateEvent::new(
+            task.id.to_string(),
+            task.context_id.clone(),
+            TaskStatus::new(TaskState::Completed),
+            true,
+        ));
+
+        let items = vec![
+            Ok(StreamItem::TaskCreated(task.clone())),
+            Ok(chunk("Hello, ")),
+            Ok(chunk("streaming ")),
+            Ok(chunk("world")),
+            Ok(completed),
+            // Nothing after the terminal event is written
+            Ok(chunk("!")),
+        ];
+
+        let mut output = Vec::new();
+        pipe_artifact_text(futures::stream::iter(items), &mut output).await.unwrap();
+
+        assert_eq!(String::from_utf8(output).unwrap(), "Hello, streaming world");
+    }
+}
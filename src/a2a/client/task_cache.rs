@@ -0,0 +1,111 @@
+//! Client-side cache of finished tasks
+//!
+//! A task in a terminal state never changes again, so `BaseClient::get_task`
+//! keeps the most recently fetched ones and answers repeated queries for
+//! them without a round trip. Tasks that are still running are never cached.
+
+use crate::a2a::models::{Task, TaskQueryParams};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+/// Number of terminal tasks cached by default: none, as a cached task
+/// misses changes made to it by other clients
+pub const DEFAULT_TASK_CACHE_SIZE: usize = 0;
+
+/// A task is cached per requested history length, which shapes the response
+type CacheKey = (String, Option<i32>);
+
+#[derive(Default)]
+struct Entries {
+    tasks: HashMap<CacheKey, Task>,
+    /// Keys from least to most recently used
+    order: VecDeque<CacheKey>,
+}
+
+/// A bounded, least-recently-used cache of terminal tasks
+pub struct TaskCache {
+    entries: Mutex<Entries>,
+    capacity: usize,
+}
+
+impl TaskCache {
+    /// Create a cache holding at most `capacity` tasks; 0 disables caching
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(Entries::default()),
+            capacity,
+        }
+    }
+
+    /// Returns the cached task answering `query`, if any
+    pub fn get(&self, query: &TaskQueryParams) -> Option<Task> {
+        let key = cache_key(query);
+        let mut entries = self.entries.lock().unwrap();
+        let task = entries.tasks.get(&key).cloned()?;
+        entries.order.retain(|k| *k != key);
+        entries.order.push_back(key);
+        Some(task)
+    }
+
+    /// Caches the answer to `query` if the task is in a terminal state
+    pub fn insert(&self, query: &TaskQueryParams, task: &Task) {
+        if self.capacity == 0 || !task.status.state.is_terminal() {
+            return;
+        }
+        let key = cache_key(query);
+        let mut entries = self.entries.lock().unwrap();
+        entries.order.retain(|k| *k != key);
+        entries.order.push_back(key.clone());
+        entries.tasks.insert(key, task.clone());
+        while entries.order.len() > self.capacity {
+            if let Some(oldest) = entries.order.pop_front() {
+                entries.tasks.remove(&oldest);
+            }
+        }
+    }
+
+    /// Drops every cached answer for the task `task_id`
+    pub fn invalidate(&self, task_id: &str) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.tasks.retain(|(id, _), _| id != task_id);
+        entries.order.retain(|(id, _)| id != task_id);
+    }
+}
+
+fn cache_key(query: &TaskQueryParams) -> CacheKey {
+    (query.id.clone(), query.history_length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::core_types::{TaskState, TaskStatus};
+
+    fn task(id: &str, state: TaskState) -> Task {
+        Task::new("ctx-1".to_string(), TaskStatus::new(state)).with_task_id(id.to_string())
+    }
+
+    #[test]
+    fn test_cache_keeps_recent_terminal_tasks() {
+        let cache = TaskCache::new(2);
+        let query = |id: &str| TaskQueryParams::new(id.to_string());
+
+        cache.insert(&query("working"), &task("working", TaskState::Working));
+        assert!(cache.get(&query("working")).is_none());
+
+        cache.insert(&query("a"), &task("a", TaskState::Completed));
+        cache.insert(&query("b"), &task("b", TaskState::Failed));
+        // Reading "a" makes "b" the least recently used entry
+        assert!(cache.get(&query("a")).is_some());
+        cache.insert(&query("c"), &task("c", TaskState::Canceled));
+
+        assert!(cache.get(&query("b")).is_none());
+        assert_eq!(cache.get(&query("a")).unwrap().id, "a");
+        assert_eq!(cache.get(&query("c")).unwrap().id, "c");
+        assert!(cache.get(&TaskQueryParams { history_length: Some(1), ..query("a") }).is_none());
+
+        cache.invalidate("a");
+        assert!(cache.get(&query("a")).is_none());
+        assert!(cache.get(&query("c")).is_some());
+    }
+}
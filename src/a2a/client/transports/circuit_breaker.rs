@@ -0,0 +1,502 @@
+//! Circuit breaker transport decorator
+//!
+//! When an agent is struggling, retrying every failed call only adds to its
+//! load. A `CircuitBreaker` counts failed calls and, once too many fail,
+//! opens: calls then fail immediately with a `ClientError::CircuitOpen` error
+//! for a cooldown period. After the cooldown a single probe call is let through
+//! (half-open); its success closes the breaker again, its failure reopens it.
+//!
+//! `CircuitBreakerTransport` applies a breaker to every call of another
+//! transport. Breakers are cheap to clone and clones share their state, so
+//! one breaker can guard several transports or clients talking to the same
+//! agent. Retries made above the transport, by the caller or by middleware
+//! such as `tower::retry` over `A2AClientService`, go through the breaker
+//! too, so they stop reaching the agent while it is open.
+
+use async_trait::async_trait;
+use futures::Stream;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+use crate::a2a::client::client_trait::{ClientCallContext, ClientEvent, ClientTransport};
+use crate::a2a::error::A2AError;
+use crate::a2a::client::errors::ClientError;
+use crate::a2a::jsonrpc::standard_error_codes;
+use crate::a2a::models::*;
+
+/// When a `CircuitBreaker` opens and how long it stays open
+#[derive(Debug, Clone, PartialEq)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures that open the breaker
+    pub failure_threshold: u32,
+    /// Share of failures among the last `window_size` calls that opens the
+    /// breaker, or `None` to only count consecutive failures
+    pub failure_ratio: Option<f64>,
+    /// Number of recent calls `failure_ratio` is computed over; the ratio is
+    /// only checked once that many calls have completed
+    pub window_size: usize,
+    /// How long the breaker stays open before letting a probe call through
+    pub cooldown: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            failure_ratio: None,
+            window_size: 20,
+            cooldown: Duration::from_secs(30),
+        }
+    }
+}
+
+impl CircuitBreakerConfig {
+    /// Create a config with the default settings
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of consecutive failures that open the breaker
+    pub fn with_failure_threshold(mut self, failure_threshold: u32) -> Self {
+        self.failure_threshold = failure_threshold;
+        self
+    }
+
+    /// Open the breaker when `ratio` of the last `window_size` calls failed
+    pub fn with_failure_ratio(mut self, ratio: f64, window_size: usize) -> Self {
+        self.failure_ratio = Some(ratio);
+        self.window_size = window_size;
+        self
+    }
+
+    /// Set how long the breaker stays open
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+/// The state of a `CircuitBreaker`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Calls go through
+    Closed,
+    /// Calls fail immediately until the cooldown has elapsed
+    Open,
+    /// The cooldown has elapsed; the next call probes whether the agent recovered
+    HalfOpen,
+}
+
+#[derive(Debug)]
+struct BreakerState {
+    state: CircuitState,
+    opened_at: Option<Instant>,
+    probe_in_flight: bool,
+    consecutive_failures: u32,
+    /// Outcomes of the most recent calls, `true` for failures
+    recent: VecDeque<bool>,
+}
+
+/// Shared failure counter that stops calls to an agent that keeps failing
+///
+/// Only errors suggesting the agent or the connection is in trouble count
+/// as failures: internal errors, which include exceeded deadlines and
+/// transport errors. Protocol errors such as `TaskNotFound` mean the agent
+/// is answering and count as successes.
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: Arc<Mutex<BreakerState>>,
+}
+
+impl CircuitBreaker {
+    /// Create a closed breaker
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: Arc::new(Mutex::new(BreakerState {
+                state: CircuitState::Closed,
+                opened_at: None,
+                probe_in_flight: false,
+                consecutive_failures: 0,
+                recent: VecDeque::new(),
+            })),
+        }
+    }
+
+    /// The breaker's configuration
+    pub fn config(&self) -> &CircuitBreakerConfig {
+        &self.config
+    }
+
+    /// The current state, reporting `HalfOpen` once an open breaker's cooldown has elapsed
+    pub fn state(&self) -> CircuitState {
+        let state = self.state.lock().unwrap();
+        match state.state {
+            CircuitState::Open if self.cooldown_elapsed(&state) => CircuitState::HalfOpen,
+            current => current,
+        }
+    }
+
+    /// Runs `call` unless the breaker is open, recording its outcome
+    ///
+    /// Fails with a `ClientError::CircuitOpen` error without running `call` while
+    /// the breaker is open, or while another call is probing a half-open
+    /// breaker.
+    pub async fn call<T, F>(&self, call: F) -> Result<T, A2AError>
+    where
+        F: Future<Output = Result<T, A2AError>>,
+    {
+        let permit = self.acquire()?;
+        let result = call.await;
+        permit.record(result.as_ref().err());
+        result
+    }
+
+    fn acquire(&self) -> Result<Permit<'_>, A2AError> {
+        let mut state = self.state.lock().unwrap();
+        let probe = match state.state {
+            CircuitState::Closed => false,
+            CircuitState::Open if self.cooldown_elapsed(&state) => {
+                state.state = CircuitState::HalfOpen;
+                state.probe_in_flight = true;
+                true
+            }
+            CircuitState::HalfOpen if !state.probe_in_flight => {
+                state.probe_in_flight = true;
+                true
+            }
+            CircuitState::Open | CircuitState::HalfOpen => {
+                let retry_in = state
+                    .opened_at
+                    .map(|opened_at| self.config.cooldown.saturating_sub(opened_at.elapsed()))
+                    .unwrap_or_default();
+                return Err(ClientError::CircuitOpen(format!(
+                    "agent failed repeatedly; retry in {:.1}s",
+                    retry_in.as_secs_f64()
+                ))
+                .into());
+            }
+        };
+        Ok(Permit { breaker: self, probe, recorded: false })
+    }
+
+    fn cooldown_elapsed(&self, state: &BreakerState) -> bool {
+        state.opened_at.is_none_or(|opened_at| opened_at.elapsed() >= self.config.cooldown)
+    }
+
+    fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        if state.state == CircuitState::HalfOpen {
+            state.state = CircuitState::Closed;
+            state.opened_at = None;
+            state.probe_in_flight = false;
+            state.recent.clear();
+        }
+        state.consecutive_failures = 0;
+        self.push_outcome(&mut state, false);
+    }
+
+    fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures += 1;
+        self.push_outcome(&mut state, true);
+
+        let ratio_exceeded = self.config.failure_ratio.is_some_and(|ratio| {
+            state.recent.len() >= self.config.window_size
+                && state.recent.iter().filter(|failed| **failed).count() as f64 >= ratio * state.recent.len() as f64
+        });
+        let should_open = state.state == CircuitState::HalfOpen
+            || state.consecutive_failures >= self.config.failure_threshold
+            || ratio_exceeded;
+        if should_open {
+            if state.state == CircuitState::Closed {
+                warn!(
+                    consecutive_failures = state.consecutive_failures,
+                    "Opening circuit breaker for {:?}", self.config.cooldown
+                );
+            }
+            state.state = CircuitState::Open;
+            state.opened_at = Some(Instant::now());
+            state.probe_in_flight = false;
+        }
+    }
+
+    fn push_outcome(&self, state: &mut BreakerState, failed: bool) {
+        state.recent.push_back(failed);
+        while state.recent.len() > self.config.window_size.max(1) {
+            state.recent.pop_front();
+        }
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(CircuitBreakerConfig::default())
+    }
+}
+
+/// Whether `error` counts as a failure for a `CircuitBreaker`
+///
+/// Internal errors count, which covers transport failures and timeouts.
+pub fn is_breaker_failure(error: &A2AError) -> bool {
+    error.code() == standard_error_codes::INTERNAL_ERROR
+}
+
+/// Permission for one call, releasing a half-open probe if the call is dropped
+struct Permit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+    recorded: bool,
+}
+
+impl Permit<'_> {
+    fn record(mut self, error: Option<&A2AError>) {
+        self.recorded = true;
+        match error {
+            Some(error) if is_breaker_failure(error) => self.breaker.record_failure(),
+            _ => self.breaker.record_success(),
+        }
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if self.probe && !self.recorded {
+            self.breaker.state.lock().unwrap().probe_in_flight = false;
+        }
+    }
+}
+
+/// Transport that guards another transport's calls with a `CircuitBreaker`
+///
+/// For streaming calls only opening the stream counts; errors reported by
+/// an open stream are left to the caller.
+pub struct CircuitBreakerTransport<T: ClientTransport> {
+    inner: T,
+    breaker: CircuitBreaker,
+}
+
+impl<T: ClientTransport> CircuitBreakerTransport<T> {
+    /// Wrap `inner`, guarding its calls with `breaker`
+    pub fn new(inner: T, breaker: CircuitBreaker) -> Self {
+        Self { inner, breaker }
+    }
+
+    /// The breaker guarding the transport
+    pub fn breaker(&self) -> &CircuitBreaker {
+        &self.breaker
+    }
+
+    /// The wrapped transport
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwrap the decorated transport
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<T: ClientTransport> ClientTransport for CircuitBreakerTransport<T> {
+    async fn send_message(
+        &self,
+        params: MessageSendParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<TaskOrMessage, A2AError> {
+        self.breaker.call(self.inner.send_message(params, context, extensions)).await
+    }
+
+    async fn send_message_streaming<'a>(
+        &'a self,
+        params: MessageSendParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<TaskOrMessage, A2AError>> + Send + 'a>>, A2AError> {
+        self.breaker.call(self.inner.send_message_streaming(params, context, extensions)).await
+    }
+
+    async fn get_task(
+        &self,
+        request: TaskQueryParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Task, A2AError> {
+        self.breaker.call(self.inner.get_task(request, context, extensions)).await
+    }
+
+    async fn cancel_task(
+        &self,
+        request: TaskIdParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Task, A2AError> {
+        self.breaker.call(self.inner.cancel_task(request, context, extensions)).await
+    }
+
+    async fn set_task_callback(
+        &self,
+        request: TaskPushNotificationConfig,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        self.breaker.call(self.inner.set_task_callback(request, context, extensions)).await
+    }
+
+    async fn get_task_callback(
+        &self,
+        request: GetTaskPushNotificationConfigParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        self.breaker.call(self.inner.get_task_callback(request, context, extensions)).await
+    }
+
+    async fn delete_task_callback(
+        &self,
+        request: DeleteTaskPushNotificationConfigParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<(), A2AError> {
+        self.breaker.call(self.inner.delete_task_callback(request, context, extensions)).await
+    }
+
+    async fn resubscribe<'a>(
+        &'a self,
+        request: TaskIdParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ClientEvent, A2AError>> + Send + 'a>>, A2AError> {
+        self.breaker.call(self.inner.resubscribe(request, context, extensions)).await
+    }
+
+    async fn get_card(
+        &self,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<AgentCard, A2AError> {
+        self.breaker.call(self.inner.get_card(context, extensions)).await
+    }
+
+    async fn get_authenticated_extended_card(
+        &self,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<AgentCard, A2AError> {
+        self.breaker
+            .call(self.inner.get_authenticated_extended_card(context, extensions))
+            .await
+    }
+
+    async fn check_connection(&self) -> Result<(), A2AError> {
+        self.inner.check_connection().await
+    }
+
+    async fn close(&self) -> Result<(), A2AError> {
+        self.inner.close().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::client::transports::jsonrpc::JsonRpcTransport;
+
+    fn query() -> TaskQueryParams {
+        TaskQueryParams::new("task-1".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_breaker_opens_after_consecutive_failures_and_recovers() {
+        let breaker = CircuitBreaker::new(
+            CircuitBreakerConfig::new()
+                .with_failure_threshold(3)
+                .with_cooldown(Duration::from_millis(100)),
+        );
+        let failing = || async { Err::<(), _>(A2AError::transport_error("connection refused".to_string())) };
+
+        // Errors from a responsive agent do not count
+        for _ in 0..5 {
+            assert!(breaker.call(async { Err::<(), _>(A2AError::task_not_found("task-1")) }).await.is_err());
+        }
+        assert_eq!(breaker.state(), CircuitState::Closed);
+
+        for _ in 0..3 {
+            assert!(breaker.call(failing()).await.unwrap_err().message().contains("connection refused"));
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        // Open: calls fail fast without running
+        let error = breaker
+            .call(async { panic!("call ran while the breaker was open") as Result<(), A2AError> })
+            .await
+            .unwrap_err();
+        assert!(error.message().starts_with("Circuit breaker open: "), "{}", error.message());
+        let shared = breaker.clone();
+        assert!(shared.call(async { Ok(()) }).await.is_err());
+
+        // A failed probe after the cooldown reopens the breaker
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.call(failing()).await.unwrap_err().message().contains("connection refused"));
+        assert_eq!(breaker.state(), CircuitState::Open);
+        let error = breaker.call(async { Ok(()) }).await.unwrap_err();
+        assert!(error.message().starts_with("Circuit breaker open: "));
+
+        // A successful probe closes it
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        breaker.call(async { Ok(()) }).await.unwrap();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.call(async { Ok(()) }).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_breaker_opens_on_failure_ratio() {
+        let breaker = CircuitBreaker::new(
+            CircuitBreakerConfig::new()
+                .with_failure_threshold(u32::MAX)
+                .with_failure_ratio(0.5, 4),
+        );
+        let failure = || async { Err::<(), _>(A2AError::internal("boom")) };
+
+        breaker.call(failure()).await.unwrap_err();
+        breaker.call(async { Ok(()) }).await.unwrap();
+        breaker.call(failure()).await.unwrap_err();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.call(async { Ok(()) }).await.unwrap();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        breaker.call(failure()).await.unwrap_err();
+        assert_eq!(breaker.state(), CircuitState::Open);
+    }
+
+    #[tokio::test]
+    async fn test_transport_stops_calling_failing_agent() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server.mock("POST", "/").with_status(503).expect(2).create_async().await;
+
+        let breaker = CircuitBreaker::new(
+            CircuitBreakerConfig::new()
+                .with_failure_threshold(2)
+                .with_cooldown(Duration::from_secs(60)),
+        );
+        let transport = CircuitBreakerTransport::new(JsonRpcTransport::new(server.url(), Some(AgentCard::test_default("Agent", &server.url()))).unwrap(), breaker);
+
+        for _ in 0..2 {
+            let error = transport.get_task(query(), None, None).await.unwrap_err();
+            assert_eq!(error.code(), standard_error_codes::INTERNAL_ERROR, "{:?}", error);
+        }
+        for _ in 0..3 {
+            let error = transport.get_task(query(), None, None).await.unwrap_err();
+            assert!(error.message().starts_with("Circuit breaker open: "), "{}", error.message());
+        }
+        assert_eq!(transport.breaker().state(), CircuitState::Open);
+        mock.assert_async().await;
+    }
+}
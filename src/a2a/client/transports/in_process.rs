@@ -0,0 +1,193 @@
+//! In-process transport implementation for A2A Rust client
+//!
+//! This module provides a transport that dispatches client calls directly to
+//! a server-side RequestHandler in the same process, without HTTP or sockets.
+//! It is intended for fast end-to-end tests of the client/handler path.
+
+use crate::a2a::client::client_trait::{ClientCallContext, ClientEvent, ClientTransport, TaskUpdateEvent};
+use crate::a2a::error::A2AError;
+use crate::a2a::models::*;
+use crate::a2a::server::context::ServerCallContext;
+use crate::a2a::server::request_handlers::request_handler::{
+    Event, MessageSendResult, RequestHandler, TaskPushNotificationConfigQueryParams,
+};
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use std::pin::Pin;
+use std::sync::Arc;
+
+/// Transport that calls a RequestHandler directly
+///
+/// Each call builds a fresh ServerCallContext carrying the requested
+/// extensions, then invokes the matching handler method and converts the
+/// result into the shape returned by network transports.
+pub struct InProcessTransport {
+    /// The handler that serves requests
+    handler: Arc<dyn RequestHandler>,
+
+    /// The card describing the in-process agent
+    agent_card: AgentCard,
+}
+
+impl InProcessTransport {
+    /// Create a new in-process transport for the given handler and card
+    pub fn new(handler: Arc<dyn RequestHandler>, agent_card: AgentCard) -> Self {
+        Self { handler, agent_card }
+    }
+
+    fn server_context(extensions: Option<Vec<String>>) -> ServerCallContext {
+        let mut context = ServerCallContext::new();
+        for uri in extensions.unwrap_or_default() {
+            context.add_requested_extension(uri);
+        }
+        context
+    }
+}
+
+#[async_trait]
+impl ClientTransport for InProcessTransport {
+    async fn send_message(
+        &self,
+        params: MessageSendParams,
+        _context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<TaskOrMessage, A2AError> {
+        let context = Self::server_context(extensions);
+        Ok(match self.handler.on_message_send(params, Some(&context)).await? {
+            MessageSendResult::Task(task) => TaskOrMessage::Task(task),
+            MessageSendResult::Message(message) => TaskOrMessage::Message(message),
+        })
+    }
+
+    async fn send_message_streaming<'a>(
+        &'a self,
+        params: MessageSendParams,
+        _context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<TaskOrMessage, A2AError>> + Send + 'a>>, A2AError> {
+        let context = Self::server_context(extensions);
+        let events = self.handler.on_message_send_stream(params, Some(&context)).await?;
+        Ok(Box::pin(events.map(|event| event.map(TaskOrMessage::from))))
+    }
+
+    async fn get_task(
+        &self,
+        request: TaskQueryParams,
+        _context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Task, A2AError> {
+        let context = Self::server_context(extensions);
+        let task_id = request.id.clone();
+        self.handler
+            .on_get_task(request, Some(&context))
+            .await?
+            .ok_or_else(|| A2AError::task_not_found(&task_id))
+    }
+
+    async fn cancel_task(
+        &self,
+        request: TaskIdParams,
+        _context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Task, A2AError> {
+        let context = Self::server_context(extensions);
+        let task_id = request.id.clone();
+        self.handler
+            .on_cancel_task(request, Some(&context))
+            .await?
+            .ok_or_else(|| A2AError::task_not_found(&task_id))
+    }
+
+    async fn set_task_callback(
+        &self,
+        request: TaskPushNotificationConfig,
+        _context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        let context = Self::server_context(extensions);
+        self.handler.on_set_task_push_notification_config(request, Some(&context)).await
+    }
+
+    async fn get_task_callback(
+        &self,
+        request: GetTaskPushNotificationConfigParams,
+        _context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        let context = Self::server_context(extensions);
+        let query = TaskPushNotificationConfigQueryParams {
+            task_id: request.id,
+            push_notification_config_id: request.push_notification_config_id,
+            metadata: request.metadata.map(|m| serde_json::to_value(m).unwrap_or_default()),
+        };
+        self.handler.on_get_task_push_notification_config(query, Some(&context)).await
+    }
+
+    async fn delete_task_callback(
+        &self,
+        request: DeleteTaskPushNotificationConfigParams,
+        _context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<(), A2AError> {
+        let context = Self::server_context(extensions);
+        self.handler.on_delete_task_push_notification_config(request, Some(&context)).await
+    }
+
+    async fn resubscribe<'a>(
+        &'a self,
+        request: TaskIdParams,
+        _context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ClientEvent, A2AError>> + Send + 'a>>, A2AError> {
+        let context = Self::server_context(extensions);
+        let mut events = self.handler.on_resubscribe_to_task(request, Some(&context)).await?;
+
+        // Updates are applied to the latest task snapshot so each event carries the full task
+        let stream = async_stream::stream! {
+            let mut current: Option<Task> = None;
+            while let Some(event) = events.next().await {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                };
+                match (event, current.as_mut()) {
+                    (Event::Task(task), _) => {
+                        current = Some(task.clone());
+                        yield Ok((task, None));
+                    }
+                    (Event::TaskStatusUpdate(update), Some(task)) => {
+                        task.status = update.status.clone();
+                        yield Ok((task.clone(), Some(TaskUpdateEvent::Status(update))));
+                    }
+                    (Event::TaskArtifactUpdate(update), Some(task)) => {
+                        task.artifacts.get_or_insert_with(Vec::new).push(update.artifact.clone());
+                        yield Ok((task.clone(), Some(TaskUpdateEvent::Artifact(update))));
+                    }
+                    (Event::Message(_), _) => {
+                        yield Err(A2AError::invalid_response("Unexpected message in resubscribe stream"));
+                    }
+                    (_, None) => {
+                        yield Err(A2AError::invalid_response("Task update received before the task"));
+                    }
+                }
+            }
+        };
+
+        Ok(Box::pin(stream))
+    }
+
+    async fn get_card(
+        &self,
+        _context: Option<&ClientCallContext>,
+        _extensions: Option<Vec<String>>,
+    ) -> Result<AgentCard, A2AError> {
+        Ok(self.agent_card.clone())
+    }
+
+    async fn close(&self) -> Result<(), A2AError> {
+        Ok(())
+    }
+}
@@ -5,10 +5,12 @@
 
 use crate::a2a::client::client_trait::{ClientCallContext, ClientTransport, ClientEvent, ClientCallInterceptor};
 use crate::a2a::client::card_resolver::A2ACardResolver;
+use crate::a2a::client::transports::ndjson::{NdjsonDecoder, NDJSON_CONTENT_TYPE};
 use crate::a2a::models::*;
 use crate::a2a::core_types::*;
-use crate::a2a::error::A2AError;
+use crate::a2a::error::{a2a_error_from_code, A2AError};
 use crate::a2a::jsonrpc::{JSONRPCResponse, JSONRPCError, JSONRPCSuccessResponse, JSONRPCErrorResponse};
+use crate::a2a::utils::constants::DEADLINE_HEADER;
 use async_trait::async_trait;
 use futures::{Stream, StreamExt};
 use reqwest;
@@ -19,13 +21,19 @@ use std::pin::Pin;
 use std::time::Duration;
 
 /// Create a JSON-RPC 2.0 request
+///
+/// Methods without parameters pass `Value::Null`, in which case the `params`
+/// member is omitted entirely rather than sent as `null`.
 fn create_jsonrpc_request(method: &str, params: Value) -> Result<Value, A2AError> {
-    Ok(serde_json::json!({
+    let mut request = serde_json::json!({
         "jsonrpc": "2.0",
         "method": method,
-        "params": params,
         "id": uuid::Uuid::new_v4().to_string()
-    }))
+    });
+    if !params.is_null() {
+        request["params"] = params;
+    }
+    Ok(request)
 }
 
 /// Parse a JSON-RPC response
@@ -69,6 +77,15 @@ pub struct JsonRpcTransport {
     /// Extensions to include in requests
     extensions: Vec<String>,
     
+    /// Extra HTTP headers to include in every request
+    headers: HashMap<String, String>,
+    
+    /// How long a request may take, announced to the server as its deadline
+    /// 
+    /// `None` for transports built around a caller's HTTP client, whose own
+    /// timeout then applies.
+    timeout: Option<Duration>,
+    
     /// Whether we need to fetch the extended card
     needs_extended_card: bool,
 }
@@ -95,6 +112,8 @@ impl JsonRpcTransport {
             agent_card,
             interceptors: Vec::new(),
             extensions: Vec::new(),
+            headers: HashMap::new(),
+            timeout: Some(Duration::from_secs(30)),
             needs_extended_card,
         })
     }
@@ -123,7 +142,9 @@ impl JsonRpcTransport {
             client,
             agent_card,
             interceptors: Vec::new(),
+            headers: config.headers_for(&TransportProtocol::Jsonrpc),
             extensions: config.extensions,
+            timeout: Some(timeout_duration),
             needs_extended_card,
         })
     }
@@ -145,6 +166,8 @@ impl JsonRpcTransport {
             agent_card,
             interceptors: Vec::new(),
             extensions: Vec::new(),
+            headers: HashMap::new(),
+            timeout: None,
             needs_extended_card,
         }
     }
@@ -161,6 +184,21 @@ impl JsonRpcTransport {
         self
     }
     
+    /// Set how long each request may take
+    /// 
+    /// Requests time out after `timeout` and announce it to the server in
+    /// the `X-A2A-Deadline` header.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+    
+    /// Set extra HTTP headers for every request
+    pub fn with_headers(mut self, headers: HashMap<String, String>) -> Self {
+        self.headers = headers;
+        self
+    }
+    
     /// Apply interceptors to a request
     async fn apply_interceptors(
         &self,
@@ -203,6 +241,13 @@ impl JsonRpcTransport {
             headers.insert("A2A-Extensions", extension_header.parse().unwrap());
         }
         
+        // Add configured headers, which per-call headers may override
+        for (key, value) in &self.headers {
+            if let (Ok(header_name), Ok(header_value)) = (HeaderName::from_bytes(key.as_bytes()), HeaderValue::from_str(value)) {
+                headers.insert(header_name, header_value);
+            }
+        }
+        
         // Add custom headers from http_kwargs
         if let Some(headers_map) = http_kwargs.get("headers").and_then(|v| v.as_object()) {
             for (key, value) in headers_map {
@@ -219,6 +264,16 @@ impl JsonRpcTransport {
         headers
     }
     
+    /// Adds the `X-A2A-Deadline` header for a request taking at most `timeout`
+    fn with_deadline(mut headers: HeaderMap, timeout: Option<Duration>) -> HeaderMap {
+        if let Some(timeout) = timeout {
+            if let Ok(value) = HeaderValue::from_str(&timeout.as_millis().to_string()) {
+                headers.insert(DEADLINE_HEADER, value);
+            }
+        }
+        headers
+    }
+    
     /// Send a JSON-RPC request and get the response
     async fn send_jsonrpc_request(
         &self,
@@ -252,9 +307,11 @@ impl JsonRpcTransport {
         // Extract request options
         let timeout = http_kwargs.get("timeout")
             .and_then(|v| v.as_u64())
-            .map(Duration::from_secs);
+            .map(Duration::from_secs)
+            .or(self.timeout);
         
         // Build request
+        let headers = Self::with_deadline(headers, timeout);
         let mut request_builder = self.client.post(&self.url).headers(headers).json(&payload);
         
         if let Some(timeout_duration) = timeout {
@@ -287,7 +344,8 @@ impl JsonRpcTransport {
         match jsonrpc_response {
             JSONRPCResponse::Success(success_response) => Ok(success_response.result),
             JSONRPCResponse::Error(error_response) => {
-                Err(A2AError::jsonrpc_error(error_response.error.code, error_response.error.message))
+                let error = error_response.error;
+                Err(a2a_error_from_code(error.code, error.message, error.data))
             }
         }
     }
@@ -319,8 +377,8 @@ impl JsonRpcTransport {
         // Build headers for SSE
         let mut headers = self.build_headers(extensions.as_ref(), &http_kwargs);
         
-        // Override Accept header for SSE
-        headers.insert("Accept", "text/event-stream".parse().unwrap());
+        // Override Accept header for SSE, which some deployments replace with NDJSON
+        headers.insert("Accept", format!("text/event-stream, {}", NDJSON_CONTENT_TYPE).parse().unwrap());
         
         // Remove headers from http_kwargs since they're handled separately
         http_kwargs.remove("headers");
@@ -328,9 +386,11 @@ impl JsonRpcTransport {
         // Extract request options
         let timeout = http_kwargs.get("timeout")
             .and_then(|v| v.as_u64())
-            .map(Duration::from_secs);
+            .map(Duration::from_secs)
+            .or(self.timeout);
         
         // Send the streaming POST request
+        let headers = Self::with_deadline(headers, timeout);
         let mut request_builder = self.client.post(&self.url).headers(headers).json(&payload);
         
         if let Some(timeout_duration) = timeout {
@@ -355,6 +415,10 @@ impl JsonRpcTransport {
             .and_then(|v| v.to_str().ok())
             .unwrap_or("");
         
+        if content_type.contains(NDJSON_CONTENT_TYPE) {
+            return Ok(Box::pin(self.ndjson_stream(response)));
+        }
+
         if !content_type.contains("text/event-stream") {
             // If not SSE, fallback to regular JSON response
             let response_value: Value = response
@@ -378,7 +442,8 @@ impl JsonRpcTransport {
                     }
                 }
                 JSONRPCResponse::Error(error_response) => {
-                    return Err(A2AError::jsonrpc_error(error_response.error.code, error_response.error.message));
+                    let error = error_response.error;
+                    return Err(a2a_error_from_code(error.code, error.message, error.data));
                 }
             };
             
@@ -454,6 +519,33 @@ impl JsonRpcTransport {
         Ok(Box::pin(stream))
     }
     
+    /// Decode an NDJSON streaming response into TaskOrMessage items
+    fn ndjson_stream(&self, response: reqwest::Response) -> impl Stream<Item = Result<TaskOrMessage, A2AError>> + Send + '_ {
+        let byte_stream = response.bytes_stream();
+        async_stream::stream! {
+            let mut decoder = NdjsonDecoder::new();
+            futures::pin_mut!(byte_stream);
+
+            while let Some(chunk_result) = byte_stream.next().await {
+                match chunk_result {
+                    Ok(chunk) => {
+                        for result in decoder.decode(&chunk) {
+                            yield result.and_then(|r| self.convert_streaming_result(r));
+                        }
+                    }
+                    Err(e) => {
+                        yield Err(A2AError::transport_error(format!("Stream error: {}", e)));
+                        break;
+                    }
+                }
+            }
+
+            if let Some(result) = decoder.finish() {
+                yield result.and_then(|r| self.convert_streaming_result(r));
+            }
+        }
+    }
+
     /// Parse a single SSE message and convert to TaskOrMessage
     fn parse_sse_message(&self, message: &str) -> Result<Option<TaskOrMessage>, A2AError> {
         let mut data_lines = Vec::new();
@@ -496,6 +588,13 @@ impl JsonRpcTransport {
         let json_value: Value = serde_json::from_str(&data)
             .map_err(|e| A2AError::json_error(format!("Failed to parse SSE data as JSON: {} (data: {})", e, data)))?;
         
+        // Errors sent mid-stream end the stream with the agent's error
+        if let Some(error) = json_value.get("error") {
+            let error: JSONRPCError = serde_json::from_value(error.clone())
+                .map_err(|e| A2AError::json_error(format!("Failed to parse JSON-RPC error: {}", e)))?;
+            return Err(a2a_error_from_code(error.code, error.message, error.data));
+        }
+
         // Check if this is a JSON-RPC streaming response
         if let Some(result) = json_value.get("result") {
             // Try to parse as SendStreamingMessageResult
@@ -646,6 +745,28 @@ impl ClientTransport for JsonRpcTransport {
             .map_err(|e| A2AError::json_error(format!("Failed to parse TaskPushNotificationConfig response: {}", e)))
     }
     
+    async fn delete_task_callback(
+        &self,
+        request: DeleteTaskPushNotificationConfigParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<(), A2AError> {
+        let params_value = serde_json::to_value(request)
+            .map_err(|e| A2AError::json_error(format!("Failed to serialize params: {}", e)))?;
+        
+        let result = self.send_jsonrpc_request("tasks/pushNotificationConfig/delete", params_value, context, extensions).await?;
+        
+        // Agents answer with a null result; an empty object is accepted as well
+        match result {
+            Value::Null => Ok(()),
+            Value::Object(ref fields) if fields.is_empty() => Ok(()),
+            other => Err(A2AError::invalid_response(&format!(
+                "Unexpected result for tasks/pushNotificationConfig/delete: {}",
+                other
+            ))),
+        }
+    }
+    
     async fn resubscribe<'a>(
         &'a self,
         request: TaskIdParams,
@@ -693,22 +814,58 @@ impl ClientTransport for JsonRpcTransport {
         }
         
         // Try to get card from agent
-        let resolver = A2ACardResolver::new(self.url.clone());
+        let mut resolver = A2ACardResolver::new(self.url.clone());
+        if let Some(timeout) = self.timeout {
+            resolver = resolver.with_timeout(timeout);
+        }
         let mut card = resolver.get_agent_card().await?;
         
         // If we need extended card and it's supported, fetch it
         if self.needs_extended_card && card.supports_authenticated_extended_card.unwrap_or(false) {
-            let result = self.send_jsonrpc_request("agent/authenticatedExtendedCard", Value::Null, context, extensions).await?;
-            
-            let extended_card: AgentCard = serde_json::from_value(result)
-                .map_err(|e| A2AError::json_error(format!("Failed to parse extended AgentCard: {}", e)))?;
-            
-            card = extended_card;
+            card = self.get_authenticated_extended_card(context, extensions).await?;
         }
         
         Ok(card)
     }
     
+    async fn get_authenticated_extended_card(
+        &self,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<AgentCard, A2AError> {
+        if let Some(ref card) = self.agent_card {
+            if !card.supports_authenticated_extended_card.unwrap_or(false) {
+                return Err(A2AError::authenticated_extended_card_not_configured(
+                    "Agent does not support an authenticated extended card"
+                ));
+            }
+        }
+        
+        let result = self
+            .send_jsonrpc_request("agent/getAuthenticatedExtendedCard", Value::Null, context, extensions)
+            .await
+            .map_err(|e| {
+                if e.code() == crate::a2a::jsonrpc::error_codes::AUTHENTICATED_EXTENDED_CARD_NOT_CONFIGURED {
+                    A2AError::authenticated_extended_card_not_configured(e.message())
+                } else {
+                    e
+                }
+            })?;
+        
+        serde_json::from_value(result)
+            .map_err(|e| A2AError::json_error(format!("Failed to parse extended AgentCard: {}", e)))
+    }
+    
+    async fn check_connection(&self) -> Result<(), A2AError> {
+        // Any HTTP response means the endpoint is up, even an error status
+        self.client
+            .get(&self.url)
+            .send()
+            .await
+            .map(|_| ())
+            .map_err(|e| A2AError::transport_error(e.to_string()))
+    }
+
     async fn close(&self) -> Result<(), A2AError> {
         // reqwest::Client doesn't need explicit closing
         // This is a placeholder for any cleanup that might be needed
@@ -725,6 +882,8 @@ impl Clone for JsonRpcTransport {
             agent_card: self.agent_card.clone(),
             interceptors: Vec::new(), // Note: interceptors are not cloned as they're trait objects
             extensions: self.extensions.clone(),
+            headers: self.headers.clone(),
+            timeout: self.timeout,
             needs_extended_card: self.needs_extended_card,
         }
     }
@@ -734,6 +893,16 @@ impl Clone for JsonRpcTransport {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_create_jsonrpc_request_omits_null_params() {
+        let request = create_jsonrpc_request("agent/getAuthenticatedExtendedCard", Value::Null).unwrap();
+        assert_eq!(request["method"], "agent/getAuthenticatedExtendedCard");
+        assert!(request.get("params").is_none());
+
+        let request = create_jsonrpc_request("tasks/get", serde_json::json!({"id": "task-1"})).unwrap();
+        assert_eq!(request["params"]["id"], "task-1");
+    }
+
     #[test]
     fn test_jsonrpc_transport_creation() {
         let transport = JsonRpcTransport::new("http://localhost:8080".to_string(), None);
@@ -0,0 +1,342 @@
+//! Payload logging transport decorator
+//!
+//! `LoggingTransport` wraps another transport and logs, at debug level, the
+//! JSON of every request and response together with the call's duration.
+//! This helps diagnosing interoperability issues without a network capture.
+//!
+//! Values under sensitive keys (tokens, credentials, inline file bytes) are
+//! replaced before logging.
+
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use serde::Serialize;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Instant;
+use tracing::{debug, Level};
+
+use crate::a2a::client::client_trait::{ClientCallContext, ClientEvent, ClientTransport};
+use crate::a2a::error::A2AError;
+use crate::a2a::models::*;
+use crate::a2a::utils::redact::{redact_json, DEFAULT_REDACTED_KEYS};
+
+type EventStream<'a, I> = Pin<Box<dyn Stream<Item = Result<I, A2AError>> + Send + 'a>>;
+
+/// Transport that logs the payloads of another transport's calls
+pub struct LoggingTransport<T: ClientTransport> {
+    inner: T,
+    redacted_keys: Vec<String>,
+}
+
+impl<T: ClientTransport> LoggingTransport<T> {
+    /// Wrap `inner`, redacting `DEFAULT_REDACTED_KEYS`
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            redacted_keys: DEFAULT_REDACTED_KEYS.iter().map(|key| key.to_string()).collect(),
+        }
+    }
+
+    /// Also redact values stored under `key`
+    pub fn with_redacted_key(mut self, key: impl Into<String>) -> Self {
+        self.redacted_keys.push(key.into());
+        self
+    }
+
+    /// The wrapped transport
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Unwrap the decorated transport
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Serializes `value` for logging with sensitive values redacted
+    ///
+    /// Renders nothing unless debug logging is enabled, so payloads are not
+    /// serialized for logs that are discarded.
+    fn render<S: Serialize + ?Sized>(&self, value: &S) -> String {
+        if !tracing::enabled!(Level::DEBUG) {
+            return String::new();
+        }
+        match serde_json::to_value(value) {
+            Ok(mut json) => {
+                redact_json(&mut json, &self.redacted_keys);
+                json.to_string()
+            }
+            Err(e) => format!("<unserializable: {}>", e),
+        }
+    }
+
+    async fn logged<R, F>(&self, method: &str, request: String, call: F) -> Result<R, A2AError>
+    where
+        R: Serialize,
+        F: Future<Output = Result<R, A2AError>>,
+    {
+        let started = Instant::now();
+        let result = call.await;
+        let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+        match &result {
+            Ok(response) => debug!(
+                method,
+                elapsed_ms,
+                request = %request,
+                response = %self.render(response),
+                "A2A transport call"
+            ),
+            Err(e) => debug!(method, elapsed_ms, request = %request, error = %e, "A2A transport call failed"),
+        }
+        result
+    }
+
+    /// Logs the opening of a stream and then each of its events
+    fn logged_stream<'a, I: Serialize + 'a>(
+        &'a self,
+        method: &'static str,
+        request: String,
+        started: Instant,
+        opened: Result<EventStream<'a, I>, A2AError>,
+    ) -> Result<EventStream<'a, I>, A2AError> {
+        let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+        let stream = match opened {
+            Ok(stream) => stream,
+            Err(e) => {
+                debug!(method, elapsed_ms, request = %request, error = %e, "A2A transport stream failed");
+                return Err(e);
+            }
+        };
+        debug!(method, elapsed_ms, request = %request, "A2A transport stream opened");
+        Ok(Box::pin(stream.inspect(move |item| {
+            let elapsed_ms = started.elapsed().as_secs_f64() * 1000.0;
+            match item {
+                Ok(event) => debug!(method, elapsed_ms, event = %self.render(event), "A2A transport stream event"),
+                Err(e) => debug!(method, elapsed_ms, error = %e, "A2A transport stream error"),
+            }
+        })))
+    }
+}
+
+#[async_trait]
+impl<T: ClientTransport> ClientTransport for LoggingTransport<T> {
+    async fn send_message(
+        &self,
+        params: MessageSendParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<TaskOrMessage, A2AError> {
+        let logged = self.render(&params);
+        self.logged("message/send", logged, self.inner.send_message(params, context, extensions))
+            .await
+    }
+
+    async fn send_message_streaming<'a>(
+        &'a self,
+        params: MessageSendParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<TaskOrMessage, A2AError>> + Send + 'a>>, A2AError> {
+        let request = self.render(&params);
+        let started = Instant::now();
+        let opened = self.inner.send_message_streaming(params, context, extensions).await;
+        self.logged_stream("message/stream", request, started, opened)
+    }
+
+    async fn get_task(
+        &self,
+        request: TaskQueryParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Task, A2AError> {
+        let logged = self.render(&request);
+        self.logged("tasks/get", logged, self.inner.get_task(request, context, extensions))
+            .await
+    }
+
+    async fn cancel_task(
+        &self,
+        request: TaskIdParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Task, A2AError> {
+        let logged = self.render(&request);
+        self.logged("tasks/cancel", logged, self.inner.cancel_task(request, context, extensions))
+            .await
+    }
+
+    async fn set_task_callback(
+        &self,
+        request: TaskPushNotificationConfig,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        let logged = self.render(&request);
+        self.logged(
+            "tasks/pushNotificationConfig/set",
+            logged,
+            self.inner.set_task_callback(request, context, extensions),
+        )
+        .await
+    }
+
+    async fn get_task_callback(
+        &self,
+        request: GetTaskPushNotificationConfigParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        let logged = self.render(&request);
+        self.logged(
+            "tasks/pushNotificationConfig/get",
+            logged,
+            self.inner.get_task_callback(request, context, extensions),
+        )
+        .await
+    }
+
+    async fn delete_task_callback(
+        &self,
+        request: DeleteTaskPushNotificationConfigParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<(), A2AError> {
+        let logged = self.render(&request);
+        self.logged(
+            "tasks/pushNotificationConfig/delete",
+            logged,
+            self.inner.delete_task_callback(request, context, extensions),
+        )
+        .await
+    }
+
+    async fn resubscribe<'a>(
+        &'a self,
+        request: TaskIdParams,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<ClientEvent, A2AError>> + Send + 'a>>, A2AError> {
+        let logged = self.render(&request);
+        let started = Instant::now();
+        let opened = self.inner.resubscribe(request, context, extensions).await;
+        self.logged_stream("tasks/resubscribe", logged, started, opened)
+    }
+
+    async fn get_card(
+        &self,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<AgentCard, A2AError> {
+        self.logged("agent/getCard", String::new(), self.inner.get_card(context, extensions))
+            .await
+    }
+
+    async fn get_authenticated_extended_card(
+        &self,
+        context: Option<&ClientCallContext>,
+        extensions: Option<Vec<String>>,
+    ) -> Result<AgentCard, A2AError> {
+        self.logged(
+            "agent/getAuthenticatedExtendedCard",
+            String::new(),
+            self.inner.get_authenticated_extended_card(context, extensions),
+        )
+        .await
+    }
+
+    async fn check_connection(&self) -> Result<(), A2AError> {
+        self.inner.check_connection().await
+    }
+
+    async fn close(&self) -> Result<(), A2AError> {
+        self.inner.close().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::client::transports::in_process::InProcessTransport;
+    use crate::a2a::core_types::{Message, Part, Role};
+    use crate::a2a::server::request_handlers::request_handler::MockRequestHandler;
+    use crate::a2a::utils::logging::{build_subscriber, LogFormat};
+    use crate::a2a::utils::redact::REDACTED;
+    use serde_json::Value;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use tracing_subscriber::fmt::MakeWriter;
+
+    #[derive(Clone, Default)]
+    struct BufferWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for BufferWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for BufferWriter {
+        type Writer = BufferWriter;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_logging_transport_forwards_and_logs_each_call() {
+        let card = AgentCard::test_default("Mock Agent", "in-process://mock");
+        let transport = LoggingTransport::new(InProcessTransport::new(Arc::new(MockRequestHandler::new()), card))
+            .with_redacted_key("session");
+
+        let message = Message::new(Role::User, vec![Part::text("hello".to_string())])
+            .with_message_id("msg-1".to_string());
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert("session".to_string(), serde_json::json!("s3cr3t"));
+        let params = MessageSendParams::new(message.clone()).with_metadata(metadata);
+
+        let logs = BufferWriter::default();
+        let _guard = tracing::subscriber::set_default(build_subscriber(LogFormat::Json, Level::DEBUG, logs.clone()));
+
+        match transport.send_message(params, None, None).await.unwrap() {
+            TaskOrMessage::Message(reply) => assert_eq!(reply, message),
+            other => panic!("Expected the echoed message, got {:?}", other),
+        }
+        assert_eq!(transport.get_card(None, None).await.unwrap().name, "Mock Agent");
+
+        let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .filter(|line: &Value| line["fields"]["message"] == "A2A transport call")
+            .collect();
+        assert_eq!(lines.len(), 2, "unexpected logs: {}", output);
+        assert_eq!(lines[0]["level"], "DEBUG");
+        assert_eq!(lines[0]["fields"]["method"], "message/send");
+        assert!(lines[0]["fields"]["elapsed_ms"].as_f64().unwrap() >= 0.0);
+        assert!(lines[0]["fields"]["response"].as_str().unwrap().contains("msg-1"));
+        let request = lines[0]["fields"]["request"].as_str().unwrap();
+        assert!(request.contains(REDACTED) && !request.contains("s3cr3t"), "request not redacted: {}", request);
+        assert_eq!(lines[1]["fields"]["method"], "agent/getCard");
+    }
+
+    #[test]
+    fn test_render_only_when_debug_enabled() {
+        let card = AgentCard::test_default("Mock Agent", "in-process://mock");
+        let transport = LoggingTransport::new(InProcessTransport::new(Arc::new(MockRequestHandler::new()), card));
+        let params = TaskIdParams::new("task-1".to_string());
+
+        let logs = BufferWriter::default();
+        let guard = tracing::subscriber::set_default(build_subscriber(LogFormat::Json, Level::INFO, logs.clone()));
+        assert_eq!(transport.render(&params), "");
+        drop(guard);
+
+        let _guard = tracing::subscriber::set_default(build_subscriber(LogFormat::Json, Level::DEBUG, logs));
+        assert!(transport.render(&params).contains("task-1"));
+    }
+}
@@ -4,8 +4,12 @@
 //! matching a2a-python/src/a2a/client/transports/
 
 pub mod base;
+pub mod circuit_breaker;
 pub mod grpc;
+pub mod in_process;
 pub mod jsonrpc;
+pub mod logging;
+pub mod ndjson;
 pub mod rest;
 
 // Re-export transport types
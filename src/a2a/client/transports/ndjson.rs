@@ -0,0 +1,177 @@
+//! Newline-delimited JSON stream decoding for A2A Rust client
+//!
+//! Some deployments stream `message/stream` results as NDJSON
+//! (`application/x-ndjson`) instead of SSE: one JSON-RPC response per line.
+
+use crate::a2a::error::{a2a_error_from_code, A2AError};
+use crate::a2a::jsonrpc::JSONRPCError;
+use crate::a2a::models::SendStreamingMessageResult;
+use serde_json::Value;
+
+/// Content type of newline-delimited JSON responses
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Incremental decoder for NDJSON streaming responses
+///
+/// Chunks are buffered as bytes until a newline completes a line, so lines
+/// (and multi-byte characters) split across chunks decode correctly. Blank
+/// lines are skipped. Each line holds either a JSON-RPC response, whose
+/// `result` or `error` is decoded, or a bare streaming result.
+#[derive(Debug, Default)]
+pub struct NdjsonDecoder {
+    buffer: Vec<u8>,
+}
+
+impl NdjsonDecoder {
+    /// Create a new decoder with an empty buffer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Feeds a chunk and decodes every line it completes
+    pub fn decode(&mut self, chunk: &[u8]) -> Vec<Result<SendStreamingMessageResult, A2AError>> {
+        self.buffer.extend_from_slice(chunk);
+        let mut results = Vec::new();
+        while let Some(newline) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=newline).collect();
+            if let Some(result) = decode_line(&line) {
+                results.push(result);
+            }
+        }
+        results
+    }
+
+    /// Decodes the trailing line once the stream has ended
+    ///
+    /// A final line without a terminating newline is still decoded; a
+    /// truncated JSON value yields an error.
+    pub fn finish(&mut self) -> Option<Result<SendStreamingMessageResult, A2AError>> {
+        let line = std::mem::take(&mut self.buffer);
+        decode_line(&line)
+    }
+}
+
+/// Decodes one line, returning `None` for blank lines
+fn decode_line(line: &[u8]) -> Option<Result<SendStreamingMessageResult, A2AError>> {
+    let line = String::from_utf8_lossy(line);
+    let line = line.trim();
+    if line.is_empty() {
+        return None;
+    }
+    Some(decode_value(line))
+}
+
+fn decode_value(line: &str) -> Result<SendStreamingMessageResult, A2AError> {
+    let mut value: Value = serde_json::from_str(line)
+        .map_err(|e| A2AError::json_error(format!("Failed to parse NDJSON line as JSON: {} (line: {})", e, line)))?;
+
+    // Errors sent mid-stream end the stream with the agent's error
+    if let Some(error) = value.get("error") {
+        let error: JSONRPCError = serde_json::from_value(error.clone())
+            .map_err(|e| A2AError::json_error(format!("Failed to parse JSON-RPC error: {}", e)))?;
+        return Err(a2a_error_from_code(error.code, error.message, error.data));
+    }
+    if let Some(result) = value.get_mut("result") {
+        value = result.take();
+    }
+
+    serde_json::from_value(value.clone())
+        .map_err(|e| A2AError::json_error(format!("Failed to parse NDJSON line as a streaming result: {} (JSON: {})", e, value)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_line(id: i64, state: &str, last: bool) -> String {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "result": {
+                "kind": "status-update",
+                "task_id": "task-1",
+                "context_id": "ctx-1",
+                "status": {"state": state},
+                "final": last
+            }
+        })
+        .to_string()
+    }
+
+    fn state_of(result: &SendStreamingMessageResult) -> String {
+        match result {
+            SendStreamingMessageResult::TaskStatusUpdateEvent(update) => {
+                serde_json::to_value(&update.status.state).unwrap().as_str().unwrap().to_string()
+            }
+            other => panic!("Expected a status update, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_object_split_across_chunks() {
+        let stream = format!("{}\n\n{}\n", status_line(1, "working", false), status_line(1, "completed", true));
+        let (first, rest) = stream.split_at(20);
+        let (second, third) = rest.split_at(rest.len() / 2);
+
+        let mut decoder = NdjsonDecoder::new();
+        let mut results = decoder.decode(first.as_bytes());
+        assert!(results.is_empty());
+        results.extend(decoder.decode(second.as_bytes()));
+        results.extend(decoder.decode(third.as_bytes()));
+        assert!(decoder.finish().is_none());
+
+        let states: Vec<String> = results.iter().map(|r| state_of(r.as_ref().unwrap())).collect();
+        assert_eq!(states, vec!["working", "completed"]);
+    }
+
+    #[test]
+    fn test_decode_trailing_partial_line() {
+        let mut decoder = NdjsonDecoder::new();
+        let stream = format!("{}\r\n{}", status_line(1, "working", false), status_line(1, "completed", true));
+
+        let results = decoder.decode(stream.as_bytes());
+        assert_eq!(results.len(), 1);
+        assert_eq!(state_of(results[0].as_ref().unwrap()), "working");
+
+        let last = decoder.finish().unwrap().unwrap();
+        assert_eq!(state_of(&last), "completed");
+    }
+
+    #[test]
+    fn test_decode_truncated_line_and_errors() {
+        let mut decoder = NdjsonDecoder::new();
+        let error = r#"{"jsonrpc":"2.0","id":1,"error":{"code":-32001,"message":"Task not found"}}"#;
+
+        let results = decoder.decode(format!("{}\n", error).as_bytes());
+        assert_eq!(results.len(), 1);
+        assert!(results[0].as_ref().unwrap_err().message().contains("Task not found"));
+
+        decoder.decode(b"{\"jsonrpc\":\"2.0\",\"res");
+        assert!(decoder.finish().unwrap().is_err());
+    }
+
+    #[test]
+    fn test_decode_bare_result_with_split_utf8() {
+        let line = serde_json::json!({
+            "kind": "message",
+            "messageId": "msg-1",
+            "role": "agent",
+            "parts": [{"kind": "text", "text": "héllo"}]
+        })
+        .to_string()
+            + "\n";
+        let bytes = line.as_bytes();
+        let split = line.find('é').unwrap() + 1;
+
+        let mut decoder = NdjsonDecoder::new();
+        assert!(decoder.decode(&bytes[..split]).is_empty());
+        let results = decoder.decode(&bytes[split..]);
+
+        match results[0].as_ref().unwrap() {
+            SendStreamingMessageResult::Message(message) => {
+                assert_eq!(crate::a2a::utils::message::get_message_text(message, ""), "héllo");
+            }
+            other => panic!("Expected a message, got {:?}", other),
+        }
+    }
+}
@@ -42,6 +42,16 @@ pub enum TaskState {
     Unknown,
 }
 
+impl TaskState {
+    /// Whether a task in this state is finished and accepts no further updates
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            TaskState::Completed | TaskState::Canceled | TaskState::Failed | TaskState::Rejected
+        )
+    }
+}
+
 /// Supported A2A transport protocols
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -74,6 +84,149 @@ impl FromStr for TransportProtocol {
     }
 }
 
+/// Identifier of a task
+///
+/// Ids generated by this crate are UUIDs, but other A2A implementations may
+/// use any string. Parsing never fails and the id is kept exactly as
+/// received, so it always displays and serializes unchanged; `as_uuid`
+/// tells whether it is a UUID in canonical form (lowercase, hyphenated).
+///
+/// `TaskId` dereferences to `str`, so it can be passed wherever a `&str` id
+/// is expected and compared with strings directly.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct TaskId(String);
+
+impl TaskId {
+    /// A new random (v4 UUID) id
+    pub fn new() -> Self {
+        Self(Uuid::new_v4().to_string())
+    }
+
+    /// The UUID this id holds, if it is one
+    pub fn as_uuid(&self) -> Option<Uuid> {
+        Uuid::parse_str(&self.0)
+            .ok()
+            .filter(|uuid| uuid.hyphenated().to_string() == self.0)
+    }
+
+    /// Whether the id is a UUID
+    pub fn is_uuid(&self) -> bool {
+        self.as_uuid().is_some()
+    }
+
+    /// The id as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for TaskId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Uuid> for TaskId {
+    fn from(uuid: Uuid) -> Self {
+        Self(uuid.to_string())
+    }
+}
+
+impl From<String> for TaskId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+impl From<&str> for TaskId {
+    fn from(id: &str) -> Self {
+        Self(id.to_string())
+    }
+}
+
+impl From<&String> for TaskId {
+    fn from(id: &String) -> Self {
+        Self(id.clone())
+    }
+}
+
+impl From<TaskId> for String {
+    fn from(id: TaskId) -> Self {
+        id.0
+    }
+}
+
+impl FromStr for TaskId {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
+impl fmt::Display for TaskId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::ops::Deref for TaskId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for TaskId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::borrow::Borrow<str> for TaskId {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for TaskId {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for TaskId {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<String> for TaskId {
+    fn eq(&self, other: &String) -> bool {
+        &self.0 == other
+    }
+}
+
+impl PartialEq<TaskId> for str {
+    fn eq(&self, other: &TaskId) -> bool {
+        self == other.0
+    }
+}
+
+impl PartialEq<TaskId> for &str {
+    fn eq(&self, other: &TaskId) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialEq<TaskId> for String {
+    fn eq(&self, other: &TaskId) -> bool {
+        *self == other.0
+    }
+}
+
 /// Represents a structured data segment (e.g., JSON) within a message or artifact
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DataPart {
@@ -132,7 +285,7 @@ impl TextPart {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct FileBase {
     /// The MIME type of the file (e.g., "application/pdf")
-    #[serde(rename = "mime_type")]
+    #[serde(rename = "mime_type", alias = "mimeType")]
     pub mime_type: Option<String>,
     /// An optional name for the file (e.g., "document.pdf")
     pub name: Option<String>,
@@ -144,7 +297,7 @@ pub struct FileWithBytes {
     /// The base64-encoded content of the file
     pub bytes: String,
     /// The MIME type of the file (e.g., "application/pdf")
-    #[serde(rename = "mime_type")]
+    #[serde(rename = "mime_type", alias = "mimeType")]
     pub mime_type: Option<String>,
     /// An optional name for the file (e.g., "document.pdf")
     pub name: Option<String>,
@@ -156,7 +309,7 @@ pub struct FileWithUri {
     /// A URL pointing to the file's content
     pub uri: String, // Changed from Url to String to match Python's str type
     /// The MIME type of the file (e.g., "application/pdf")
-    #[serde(rename = "mime_type")]
+    #[serde(rename = "mime_type", alias = "mimeType")]
     pub mime_type: Option<String>,
     /// An optional name for the file (e.g., "document.pdf")
     pub name: Option<String>,
@@ -207,19 +360,104 @@ impl FilePart {
         }
     }
 
+    /// Creates a URI file part with the MIME type inferred from the URI's extension
+    pub fn new_uri_with_inferred_type(uri: Url) -> Self {
+        let mime_type = crate::a2a::utils::mime::infer_mime_type_from_uri(&uri);
+        let mut part = Self::new_uri(uri);
+        part.set_mime_type(mime_type);
+        part
+    }
+
+    /// Creates a base64 file part with the MIME type inferred from its magic bytes
+    pub fn new_bytes_with_inferred_type(bytes: String) -> Self {
+        let mime_type = crate::a2a::utils::mime::infer_mime_type_from_base64(&bytes);
+        let mut part = Self::new_bytes(bytes);
+        part.set_mime_type(mime_type);
+        part
+    }
+
+    fn set_mime_type(&mut self, mime_type: Option<String>) {
+        match self.file {
+            FileContent::Uri(ref mut file) => file.mime_type = mime_type,
+            FileContent::Bytes(ref mut file) => file.mime_type = mime_type,
+        }
+    }
+
     pub fn with_metadata(mut self, metadata: HashMap<String, serde_json::Value>) -> Self {
         self.metadata = Some(metadata);
         self
     }
 }
 
+/// A part of a kind this crate does not define
+///
+/// Peers implementing a newer protocol version, or extensions, may send
+/// parts with other `kind`s. They are kept as raw JSON and serialized back
+/// unchanged, so forwarding or storing them does not lose them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownPart {
+    /// The part's `kind` discriminator
+    pub kind: String,
+    /// The complete part object as received, including `kind`
+    pub value: serde_json::Value,
+}
+
+impl Serialize for UnknownPart {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for UnknownPart {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let kind = value
+            .get("kind")
+            .and_then(|kind| kind.as_str())
+            .ok_or_else(|| serde::de::Error::missing_field("kind"))?
+            .to_string();
+        Ok(Self { kind, value })
+    }
+}
+
 /// Root part types that can be wrapped in a Part
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+///
+/// Deserialization picks the variant from the `kind` field; kinds other
+/// than `text`, `file` and `data` become `Unknown`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum PartRoot {
     Text(TextPart),
     File(FilePart),
     Data(DataPart),
+    Unknown(UnknownPart),
+}
+
+impl<'de> Deserialize<'de> for PartRoot {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let root = match value.get("kind").and_then(|kind| kind.as_str()) {
+            Some("text") => serde_json::from_value(value).map(PartRoot::Text),
+            Some("file") => serde_json::from_value(value).map(PartRoot::File),
+            Some("data") => serde_json::from_value(value).map(PartRoot::Data),
+            Some(_) => serde_json::from_value(value).map(PartRoot::Unknown),
+            None => return Err(D::Error::missing_field("kind")),
+        };
+        root.map_err(D::Error::custom)
+    }
+}
+
+/// The wire shape used when serializing a Part
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartForm {
+    /// Serialize the part content directly, e.g. `{"kind": "text", ...}`
+    #[default]
+    Direct,
+    /// Wrap the part content in a `root` field, as emitted by Python
+    WithRoot,
 }
 
 /// A discriminated union representing a part of a message or artifact
@@ -244,6 +482,16 @@ impl Part {
         Self::Direct(PartRoot::File(FilePart::new_bytes(bytes)))
     }
 
+    /// Like `file_uri`, but infers `mime_type` from the URI's extension
+    pub fn file_uri_with_inferred_type(uri: Url) -> Self {
+        Self::Direct(PartRoot::File(FilePart::new_uri_with_inferred_type(uri)))
+    }
+
+    /// Like `file_bytes`, but infers `mime_type` from the decoded content's magic bytes
+    pub fn file_bytes_with_inferred_type(bytes: String) -> Self {
+        Self::Direct(PartRoot::File(FilePart::new_bytes_with_inferred_type(bytes)))
+    }
+
     pub fn data(data: serde_json::Value) -> Self {
         Self::Direct(PartRoot::Data(DataPart::new(data)))
     }
@@ -256,6 +504,40 @@ impl Part {
         }
     }
 
+    /// Consume the part and return its root content
+    pub fn into_root(self) -> PartRoot {
+        match self {
+            Part::WithRoot { root } => root,
+            Part::Direct(root) => root,
+        }
+    }
+
+    /// Convert to the direct `{...}` representation
+    pub fn into_direct(self) -> Part {
+        Part::Direct(self.into_root())
+    }
+
+    /// Convert to the `{"root": {...}}` representation
+    pub fn into_with_root(self) -> Part {
+        Part::WithRoot { root: self.into_root() }
+    }
+
+    /// Convert to the given representation
+    pub fn into_form(self, form: PartForm) -> Part {
+        match form {
+            PartForm::Direct => self.into_direct(),
+            PartForm::WithRoot => self.into_with_root(),
+        }
+    }
+
+    /// Returns the representation this part currently uses
+    pub fn form(&self) -> PartForm {
+        match self {
+            Part::WithRoot { .. } => PartForm::WithRoot,
+            Part::Direct(_) => PartForm::Direct,
+        }
+    }
+
     /// Custom deserialization to handle both {"root": {...}} and direct {...} formats
     pub fn deserialize_for_compatibility<'de, D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -370,7 +652,7 @@ impl TaskStatus {
 }
 
 // Forward declaration for Message
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Message {
     /// A unique identifier for the message, typically a UUID, generated by the sender
@@ -391,6 +673,78 @@ pub struct Message {
     pub reference_task_ids: Option<Vec<String>>,
     /// The type of this object, used as a discriminator. Always 'message'
     pub kind: String,
+    /// The form all parts are serialized in, or `None` to keep each part's own form
+    #[serde(skip)]
+    pub part_form: Option<PartForm>,
+}
+
+impl Serialize for Message {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct WireMessage<'a> {
+            message_id: &'a String,
+            context_id: &'a Option<String>,
+            task_id: &'a Option<String>,
+            role: &'a Role,
+            parts: WireParts<'a>,
+            metadata: &'a Option<HashMap<String, serde_json::Value>>,
+            extensions: &'a Option<Vec<String>>,
+            reference_task_ids: &'a Option<Vec<String>>,
+            kind: &'a String,
+        }
+
+        WireMessage {
+            message_id: &self.message_id,
+            context_id: &self.context_id,
+            task_id: &self.task_id,
+            role: &self.role,
+            parts: WireParts {
+                parts: &self.parts,
+                form: self.part_form,
+            },
+            metadata: &self.metadata,
+            extensions: &self.extensions,
+            reference_task_ids: &self.reference_task_ids,
+            kind: &self.kind,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Parts serialized in the form chosen on their message
+struct WireParts<'a> {
+    parts: &'a [Part],
+    form: Option<PartForm>,
+}
+
+impl Serialize for WireParts<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        #[derive(Serialize)]
+        struct WithRoot<'a> {
+            root: &'a PartRoot,
+        }
+
+        #[derive(Serialize)]
+        #[serde(untagged)]
+        enum WirePart<'a> {
+            AsIs(&'a Part),
+            WithRoot(WithRoot<'a>),
+            Direct(&'a PartRoot),
+        }
+
+        serializer.collect_seq(self.parts.iter().map(|part| match self.form {
+            None => WirePart::AsIs(part),
+            Some(PartForm::WithRoot) => WirePart::WithRoot(WithRoot { root: part.root() }),
+            Some(PartForm::Direct) => WirePart::Direct(part.root()),
+        }))
+    }
 }
 
 impl Message {
@@ -405,6 +759,7 @@ impl Message {
             extensions: None,
             reference_task_ids: None,
             kind: "message".to_string(),
+            part_form: None,
         }
     }
 
@@ -427,4 +782,13 @@ impl Message {
         self.metadata = Some(metadata);
         self
     }
+
+    /// Serialize all parts in one representation, whichever form they were built in
+    ///
+    /// Peers that only accept one shape (e.g. Python's `{"root": {...}}`)
+    /// can be served consistently regardless of how the parts were built.
+    pub fn with_part_form(mut self, form: PartForm) -> Self {
+        self.part_form = Some(form);
+        self
+    }
 }
@@ -5,9 +5,125 @@
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::sync::Arc;
+
+/// The underlying cause of an error, kept for logging but never serialized
+///
+/// Shared rather than boxed so that errors stay cloneable.
+#[derive(Clone)]
+pub struct ErrorSource(Arc<dyn std::error::Error + Send + Sync>);
+
+impl ErrorSource {
+    /// Wraps an error as a source
+    pub fn new(error: impl std::error::Error + Send + Sync + 'static) -> Self {
+        Self(Arc::new(error))
+    }
+
+    /// Returns the wrapped error
+    pub fn get(&self) -> &(dyn std::error::Error + Send + Sync + 'static) {
+        self.0.as_ref()
+    }
+}
+
+impl fmt::Debug for ErrorSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+/// Implements equality by code, message and data, ignoring the source,
+/// access to the source, and `std::error::Error` formatted like `A2AError`
+macro_rules! error_source_accessors {
+    ($($error:ident),+ $(,)?) => {
+        $(
+            impl PartialEq for $error {
+                fn eq(&self, other: &Self) -> bool {
+                    self.code == other.code && self.message == other.message && self.data == other.data
+                }
+            }
+
+            impl $error {
+                /// The underlying error that caused this one, if any
+                pub fn source(&self) -> Option<&ErrorSource> {
+                    self.source.as_ref()
+                }
+
+                /// Records the underlying error that caused this one
+                pub fn with_source(mut self, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+                    self.source = Some(ErrorSource::new(source));
+                    self
+                }
+            }
+
+            impl fmt::Display for $error {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{} (code: {})", self.message, self.code)
+                }
+            }
+
+            impl std::error::Error for $error {
+                fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                    self.source.as_ref().map(|source| source.get() as &(dyn std::error::Error + 'static))
+                }
+            }
+        )+
+    };
+}
+
+error_source_accessors!(
+    JSONRPCError,
+    JSONParseError,
+    InvalidRequestError,
+    MethodNotFoundError,
+    InvalidParamsError,
+    InternalError,
+    TaskNotFoundError,
+    TaskNotCancelableError,
+    PushNotificationNotSupportedError,
+    UnsupportedOperationError,
+    ContentTypeNotSupportedError,
+    InvalidAgentResponseError,
+    AuthenticatedExtendedCardNotConfiguredError,
+);
+
+/// Implements a constructor taking the message, with the error's default
+/// code, and a builder for the data
+macro_rules! error_constructors {
+    ($($error:ident),+ $(,)?) => {
+        $(
+            impl $error {
+                pub fn new(message: String) -> Self {
+                    Self {
+                        message,
+                        ..Default::default()
+                    }
+                }
+
+                pub fn with_data(mut self, data: serde_json::Value) -> Self {
+                    self.data = Some(data);
+                    self
+                }
+            }
+        )+
+    };
+}
+
+error_constructors!(
+    JSONParseError,
+    InvalidRequestError,
+    MethodNotFoundError,
+    InvalidParamsError,
+    TaskNotFoundError,
+    TaskNotCancelableError,
+    PushNotificationNotSupportedError,
+    UnsupportedOperationError,
+    ContentTypeNotSupportedError,
+    InvalidAgentResponseError,
+    AuthenticatedExtendedCardNotConfiguredError,
+);
 
 /// Represents a JSON-RPC 2.0 Error object, included in an error response
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JSONRPCError {
     /// A number that indicates the error type that occurred
     pub code: i32,
@@ -15,6 +131,9 @@ pub struct JSONRPCError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl JSONRPCError {
@@ -23,6 +142,7 @@ impl JSONRPCError {
             code,
             message,
             data: None,
+            source: None,
         }
     }
 
@@ -33,7 +153,7 @@ impl JSONRPCError {
 }
 
 /// An error indicating that the server received invalid JSON
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JSONParseError {
     /// The error code for a JSON parse error
     pub code: i32,
@@ -41,6 +161,9 @@ pub struct JSONParseError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl Default for JSONParseError {
@@ -49,12 +172,13 @@ impl Default for JSONParseError {
             code: -32700,
             message: "Invalid JSON payload".to_string(),
             data: None,
+            source: None,
         }
     }
 }
 
 /// An error indicating that the JSON sent is not a valid Request object
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InvalidRequestError {
     /// The error code for an invalid request
     pub code: i32,
@@ -62,6 +186,9 @@ pub struct InvalidRequestError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl Default for InvalidRequestError {
@@ -70,12 +197,13 @@ impl Default for InvalidRequestError {
             code: -32600,
             message: "Request payload validation error".to_string(),
             data: None,
+            source: None,
         }
     }
 }
 
 /// An error indicating that the requested method does not exist or is not available
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MethodNotFoundError {
     /// The error code for a method not found error
     pub code: i32,
@@ -83,6 +211,9 @@ pub struct MethodNotFoundError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl Default for MethodNotFoundError {
@@ -91,12 +222,13 @@ impl Default for MethodNotFoundError {
             code: -32601,
             message: "Method not found".to_string(),
             data: None,
+            source: None,
         }
     }
 }
 
 /// An error indicating that the method parameters are invalid
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InvalidParamsError {
     /// The error code for an invalid parameters error
     pub code: i32,
@@ -104,6 +236,9 @@ pub struct InvalidParamsError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl Default for InvalidParamsError {
@@ -112,12 +247,13 @@ impl Default for InvalidParamsError {
             code: -32602,
             message: "Invalid parameters".to_string(),
             data: None,
+            source: None,
         }
     }
 }
 
 /// An error indicating an internal error on the server
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InternalError {
     /// The error code for an internal server error
     pub code: i32,
@@ -125,6 +261,23 @@ pub struct InternalError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
+}
+
+impl InternalError {
+    pub fn new(message: String) -> Self {
+        Self {
+            message,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_data(mut self, data: serde_json::Value) -> Self {
+        self.data = Some(data);
+        self
+    }
 }
 
 impl Default for InternalError {
@@ -133,12 +286,13 @@ impl Default for InternalError {
             code: -32603,
             message: "Internal error".to_string(),
             data: None,
+            source: None,
         }
     }
 }
 
 /// An A2A-specific error indicating that the requested task ID was not found
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskNotFoundError {
     /// The error code for a task not found error
     pub code: i32,
@@ -146,6 +300,9 @@ pub struct TaskNotFoundError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl Default for TaskNotFoundError {
@@ -154,12 +311,13 @@ impl Default for TaskNotFoundError {
             code: -32001,
             message: "Task not found".to_string(),
             data: None,
+            source: None,
         }
     }
 }
 
 /// An A2A-specific error indicating that the task is in a state where it cannot be canceled
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TaskNotCancelableError {
     /// The error code for a task that cannot be canceled
     pub code: i32,
@@ -167,6 +325,9 @@ pub struct TaskNotCancelableError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl Default for TaskNotCancelableError {
@@ -175,12 +336,13 @@ impl Default for TaskNotCancelableError {
             code: -32002,
             message: "Task cannot be canceled".to_string(),
             data: None,
+            source: None,
         }
     }
 }
 
 /// An A2A-specific error indicating that the agent does not support push notifications
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PushNotificationNotSupportedError {
     /// The error code for when push notifications are not supported
     pub code: i32,
@@ -188,6 +350,9 @@ pub struct PushNotificationNotSupportedError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl Default for PushNotificationNotSupportedError {
@@ -196,12 +361,13 @@ impl Default for PushNotificationNotSupportedError {
             code: -32003,
             message: "Push Notification is not supported".to_string(),
             data: None,
+            source: None,
         }
     }
 }
 
 /// An A2A-specific error indicating that the requested operation is not supported by the agent
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UnsupportedOperationError {
     /// The error code for an unsupported operation
     pub code: i32,
@@ -209,6 +375,9 @@ pub struct UnsupportedOperationError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl Default for UnsupportedOperationError {
@@ -217,12 +386,13 @@ impl Default for UnsupportedOperationError {
             code: -32004,
             message: "This operation is not supported".to_string(),
             data: None,
+            source: None,
         }
     }
 }
 
 /// An A2A-specific error indicating an incompatibility between the requested content types and the agent's capabilities
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContentTypeNotSupportedError {
     /// The error code for an unsupported content type
     pub code: i32,
@@ -230,6 +400,9 @@ pub struct ContentTypeNotSupportedError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl Default for ContentTypeNotSupportedError {
@@ -238,12 +411,13 @@ impl Default for ContentTypeNotSupportedError {
             code: -32005,
             message: "Incompatible content types".to_string(),
             data: None,
+            source: None,
         }
     }
 }
 
 /// An A2A-specific error indicating that the agent returned a response that does not conform to the specification
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InvalidAgentResponseError {
     /// The error code for an invalid agent response
     pub code: i32,
@@ -251,6 +425,9 @@ pub struct InvalidAgentResponseError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl Default for InvalidAgentResponseError {
@@ -259,12 +436,13 @@ impl Default for InvalidAgentResponseError {
             code: -32006,
             message: "Invalid agent response".to_string(),
             data: None,
+            source: None,
         }
     }
 }
 
 /// An A2A-specific error indicating that the agent does not have an Authenticated Extended Card configured
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthenticatedExtendedCardNotConfiguredError {
     /// The error code for when an authenticated extended card is not configured
     pub code: i32,
@@ -272,6 +450,9 @@ pub struct AuthenticatedExtendedCardNotConfiguredError {
     pub message: String,
     /// A primitive or structured value containing additional information about the error
     pub data: Option<serde_json::Value>,
+    /// The underlying error that caused this one, if any
+    #[serde(skip)]
+    source: Option<ErrorSource>,
 }
 
 impl Default for AuthenticatedExtendedCardNotConfiguredError {
@@ -280,6 +461,7 @@ impl Default for AuthenticatedExtendedCardNotConfiguredError {
             code: -32007,
             message: "Authenticated Extended Card is not configured".to_string(),
             data: None,
+            source: None,
         }
     }
 }
@@ -357,6 +539,72 @@ impl A2AError {
             A2AError::Generic(e) => e.data.as_ref(),
         }
     }
+
+    /// Replaces the error's `data` with `data`
+    pub fn with_data(mut self, data: serde_json::Value) -> Self {
+        *self.data_mut() = Some(data);
+        self
+    }
+
+    fn data_mut(&mut self) -> &mut Option<serde_json::Value> {
+        match self {
+            A2AError::JSONParse(e) => &mut e.data,
+            A2AError::InvalidRequest(e) => &mut e.data,
+            A2AError::MethodNotFound(e) => &mut e.data,
+            A2AError::InvalidParams(e) => &mut e.data,
+            A2AError::Internal(e) => &mut e.data,
+            A2AError::TaskNotFound(e) => &mut e.data,
+            A2AError::TaskNotCancelable(e) => &mut e.data,
+            A2AError::PushNotificationNotSupported(e) => &mut e.data,
+            A2AError::UnsupportedOperation(e) => &mut e.data,
+            A2AError::ContentTypeNotSupported(e) => &mut e.data,
+            A2AError::InvalidAgentResponse(e) => &mut e.data,
+            A2AError::AuthenticatedExtendedCardNotConfigured(e) => &mut e.data,
+            A2AError::Generic(e) => &mut e.data,
+        }
+    }
+
+    /// Attaches the underlying error that caused this one
+    pub fn with_source(mut self, source: impl std::error::Error + Send + Sync + 'static) -> Self {
+        *self.source_mut() = Some(ErrorSource::new(source));
+        self
+    }
+
+    fn source_ref(&self) -> Option<&ErrorSource> {
+        match self {
+            A2AError::JSONParse(e) => e.source.as_ref(),
+            A2AError::InvalidRequest(e) => e.source.as_ref(),
+            A2AError::MethodNotFound(e) => e.source.as_ref(),
+            A2AError::InvalidParams(e) => e.source.as_ref(),
+            A2AError::Internal(e) => e.source.as_ref(),
+            A2AError::TaskNotFound(e) => e.source.as_ref(),
+            A2AError::TaskNotCancelable(e) => e.source.as_ref(),
+            A2AError::PushNotificationNotSupported(e) => e.source.as_ref(),
+            A2AError::UnsupportedOperation(e) => e.source.as_ref(),
+            A2AError::ContentTypeNotSupported(e) => e.source.as_ref(),
+            A2AError::InvalidAgentResponse(e) => e.source.as_ref(),
+            A2AError::AuthenticatedExtendedCardNotConfigured(e) => e.source.as_ref(),
+            A2AError::Generic(e) => e.source.as_ref(),
+        }
+    }
+
+    fn source_mut(&mut self) -> &mut Option<ErrorSource> {
+        match self {
+            A2AError::JSONParse(e) => &mut e.source,
+            A2AError::InvalidRequest(e) => &mut e.source,
+            A2AError::MethodNotFound(e) => &mut e.source,
+            A2AError::InvalidParams(e) => &mut e.source,
+            A2AError::Internal(e) => &mut e.source,
+            A2AError::TaskNotFound(e) => &mut e.source,
+            A2AError::TaskNotCancelable(e) => &mut e.source,
+            A2AError::PushNotificationNotSupported(e) => &mut e.source,
+            A2AError::UnsupportedOperation(e) => &mut e.source,
+            A2AError::ContentTypeNotSupported(e) => &mut e.source,
+            A2AError::InvalidAgentResponse(e) => &mut e.source,
+            A2AError::AuthenticatedExtendedCardNotConfigured(e) => &mut e.source,
+            A2AError::Generic(e) => &mut e.source,
+        }
+    }
 }
 
 impl From<JSONParseError> for A2AError {
@@ -443,7 +691,11 @@ impl fmt::Display for A2AError {
     }
 }
 
-impl std::error::Error for A2AError {}
+impl std::error::Error for A2AError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source_ref().map(|source| source.get() as &(dyn std::error::Error + 'static))
+    }
+}
 
 // Convenience constructors
 impl A2AError {
@@ -452,6 +704,7 @@ impl A2AError {
             code: -32001,
             message: format!("Task not found: {}", task_id),
             data: Some(serde_json::json!({ "task_id": task_id })),
+            source: None,
         }.into()
     }
 
@@ -460,14 +713,20 @@ impl A2AError {
             code: -32002,
             message: format!("Task cannot be canceled: {}", reason),
             data: Some(serde_json::json!({ "reason": reason })),
+            source: None,
         }.into()
     }
 
+    pub fn push_notification_not_supported() -> Self {
+        PushNotificationNotSupportedError::default().into()
+    }
+
     pub fn invalid_params(message: &str) -> Self {
         InvalidParamsError {
             code: -32602,
             message: message.to_string(),
             data: None,
+            source: None,
         }.into()
     }
 
@@ -476,6 +735,7 @@ impl A2AError {
             code: -32603,
             message: message.to_string(),
             data: None,
+            source: None,
         }.into()
     }
 
@@ -484,6 +744,7 @@ impl A2AError {
             code: -32004,
             message: message.to_string(),
             data: None,
+            source: None,
         }.into()
     }
 
@@ -500,6 +761,7 @@ impl A2AError {
             code: -32700,
             message,
             data: None,
+            source: None,
         }.into()
     }
 
@@ -508,6 +770,7 @@ impl A2AError {
             code,
             message,
             data: None,
+            source: None,
         }.into()
     }
 
@@ -520,6 +783,16 @@ impl A2AError {
             code: -32600,
             message: message.to_string(),
             data: None,
+            source: None,
+        }.into()
+    }
+
+    pub fn content_type_not_supported(message: &str) -> Self {
+        ContentTypeNotSupportedError {
+            code: -32005,
+            message: message.to_string(),
+            data: None,
+            source: None,
         }.into()
     }
 
@@ -528,20 +801,128 @@ impl A2AError {
             code: -32006,
             message: message.to_string(),
             data: None,
+            source: None,
+        }.into()
+    }
+
+    pub fn authenticated_extended_card_not_configured(message: &str) -> Self {
+        AuthenticatedExtendedCardNotConfiguredError {
+            code: -32007,
+            message: message.to_string(),
+            data: None,
+            source: None,
         }.into()
     }
 }
 
+/// Reconstructs the typed A2A error for a JSON-RPC error code
+///
+/// Clients use this to turn the `error` object of a JSON-RPC response back
+/// into the matching variant, keeping the message and `data`. Codes without a
+/// dedicated variant become `A2AError::Generic`.
+pub fn a2a_error_from_code(code: i32, message: String, data: Option<serde_json::Value>) -> A2AError {
+    use crate::a2a::jsonrpc::{error_codes, standard_error_codes};
+
+    macro_rules! variant {
+        ($variant:ident, $error:ident) => {
+            A2AError::$variant($error { code, message, data, source: None })
+        };
+    }
+
+    match code {
+        standard_error_codes::PARSE_ERROR => variant!(JSONParse, JSONParseError),
+        standard_error_codes::INVALID_REQUEST => variant!(InvalidRequest, InvalidRequestError),
+        standard_error_codes::METHOD_NOT_FOUND => variant!(MethodNotFound, MethodNotFoundError),
+        standard_error_codes::INVALID_PARAMS => variant!(InvalidParams, InvalidParamsError),
+        standard_error_codes::INTERNAL_ERROR => variant!(Internal, InternalError),
+        error_codes::TASK_NOT_FOUND => variant!(TaskNotFound, TaskNotFoundError),
+        error_codes::TASK_NOT_CANCELABLE => variant!(TaskNotCancelable, TaskNotCancelableError),
+        error_codes::PUSH_NOTIFICATION_NOT_SUPPORTED => {
+            variant!(PushNotificationNotSupported, PushNotificationNotSupportedError)
+        }
+        error_codes::UNSUPPORTED_OPERATION => variant!(UnsupportedOperation, UnsupportedOperationError),
+        error_codes::CONTENT_TYPE_NOT_SUPPORTED => variant!(ContentTypeNotSupported, ContentTypeNotSupportedError),
+        error_codes::INVALID_AGENT_RESPONSE => variant!(InvalidAgentResponse, InvalidAgentResponseError),
+        error_codes::AUTHENTICATED_EXTENDED_CARD_NOT_CONFIGURED => {
+            variant!(AuthenticatedExtendedCardNotConfigured, AuthenticatedExtendedCardNotConfiguredError)
+        }
+        _ => variant!(Generic, JSONRPCError),
+    }
+}
+
 // Add conversions from common error types
 impl From<serde_json::Error> for A2AError {
     fn from(err: serde_json::Error) -> Self {
-        A2AError::internal(&format!("Serialization error: {}", err))
+        A2AError::internal(&format!("Serialization error: {}", err)).with_source(err)
     }
 }
 
 impl From<std::io::Error> for A2AError {
     fn from(err: std::io::Error) -> Self {
-        A2AError::internal(&format!("IO error: {}", err))
+        A2AError::internal(&format!("IO error: {}", err)).with_source(err)
+    }
+}
+
+impl From<crate::a2a::jsonrpc::JSONRPCError> for A2AError {
+    fn from(err: crate::a2a::jsonrpc::JSONRPCError) -> Self {
+        a2a_error_from_code(err.code, err.message, err.data)
+    }
+}
+
+/// Converts errors of any type, e.g. from request handlers built on `anyhow`
+///
+/// Handlers can propagate arbitrary errors with `?` once they are boxed,
+/// for instance from helpers returning `Result<T, Box<dyn Error + Send +
+/// Sync>>`; an `anyhow::Error` is boxed first, with
+/// `.map_err(|e| A2AError::from(Box::<dyn Error + Send + Sync>::from(e)))`.
+/// Boxed errors of a type `A2AError` converts from, such as `A2AError`
+/// itself, the typed errors, `ClientError`, `QueueError` or `serde_json` and
+/// I/O errors, go through their own conversions. Any other error becomes an
+/// `InternalError` carrying its message, with the error kept as source.
+impl From<Box<dyn std::error::Error + Send + Sync>> for A2AError {
+    fn from(err: Box<dyn std::error::Error + Send + Sync>) -> Self {
+        use crate::a2a::client::errors::{ClientError, TransportSelectionError};
+        use crate::a2a::server::events::{QueueError, QueueManagerError};
+
+        macro_rules! convert_if {
+            ($err:ident, $($error:ty),+ $(,)?) => {
+                $(
+                    let $err = match $err.downcast::<$error>() {
+                        Ok(err) => return (*err).into(),
+                        Err(err) => err,
+                    };
+                )+
+            };
+        }
+
+        convert_if!(
+            err,
+            A2AError,
+            JSONRPCError,
+            JSONParseError,
+            InvalidRequestError,
+            MethodNotFoundError,
+            InvalidParamsError,
+            InternalError,
+            TaskNotFoundError,
+            TaskNotCancelableError,
+            PushNotificationNotSupportedError,
+            UnsupportedOperationError,
+            ContentTypeNotSupportedError,
+            InvalidAgentResponseError,
+            AuthenticatedExtendedCardNotConfiguredError,
+            crate::a2a::jsonrpc::JSONRPCError,
+            ClientError,
+            TransportSelectionError,
+            QueueError,
+            QueueManagerError,
+            tokio::task::JoinError,
+            serde_json::Error,
+            std::io::Error,
+        );
+        let mut error = A2AError::internal(&err.to_string());
+        *error.source_mut() = Some(ErrorSource(Arc::from(err)));
+        error
     }
 }
 
@@ -550,3 +931,133 @@ impl From<tokio::task::JoinError> for A2AError {
         A2AError::internal(&format!("Task join error: {}", err))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    #[test]
+    fn test_a2a_error_from_code() {
+        let data = Some(serde_json::json!({"task_id": "task-1"}));
+        let cases: [(i32, fn(&A2AError) -> bool); 7] = [
+            (-32001, |e| matches!(e, A2AError::TaskNotFound(_))),
+            (-32002, |e| matches!(e, A2AError::TaskNotCancelable(_))),
+            (-32003, |e| matches!(e, A2AError::PushNotificationNotSupported(_))),
+            (-32004, |e| matches!(e, A2AError::UnsupportedOperation(_))),
+            (-32005, |e| matches!(e, A2AError::ContentTypeNotSupported(_))),
+            (-32006, |e| matches!(e, A2AError::InvalidAgentResponse(_))),
+            (-32007, |e| matches!(e, A2AError::AuthenticatedExtendedCardNotConfigured(_))),
+        ];
+
+        for (code, is_expected_variant) in cases {
+            let error = a2a_error_from_code(code, "agent error".to_string(), data.clone());
+            assert!(is_expected_variant(&error), "unexpected variant for {}: {:?}", code, error);
+            assert_eq!(error.code(), code);
+            assert_eq!(error.message(), "agent error");
+            assert_eq!(error.data(), data.as_ref());
+        }
+
+        assert!(matches!(a2a_error_from_code(-32602, String::new(), None), A2AError::InvalidParams(_)));
+        assert!(matches!(a2a_error_from_code(-32099, String::new(), None), A2AError::Generic(_)));
+    }
+
+    #[test]
+    fn test_serde_json_error_source() {
+        let json_err = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+        let expected = json_err.to_string();
+
+        let err: A2AError = json_err.into();
+        let source = err.source().expect("source should be attached");
+        assert_eq!(source.to_string(), expected);
+        assert!(source.downcast_ref::<serde_json::Error>().is_some());
+    }
+
+    #[test]
+    fn test_io_error_source() {
+        let io_err = std::io::Error::new(std::io::ErrorKind::NotFound, "missing file");
+
+        let err: A2AError = io_err.into();
+        let source = err.source().expect("source should be attached");
+        let io_source = source.downcast_ref::<std::io::Error>().unwrap();
+        assert_eq!(io_source.kind(), std::io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_boxed_error_conversion() {
+        #[derive(Debug)]
+        struct QuotaExceeded;
+
+        impl fmt::Display for QuotaExceeded {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "quota exceeded for model")
+            }
+        }
+
+        impl Error for QuotaExceeded {}
+
+        fn call_model() -> Result<String, Box<dyn Error + Send + Sync>> {
+            Err(Box::new(QuotaExceeded))
+        }
+
+        fn handle() -> Result<String, A2AError> {
+            Ok(call_model()?)
+        }
+
+        let err = handle().unwrap_err();
+        assert!(matches!(err, A2AError::Internal(_)));
+        assert_eq!(err.code(), -32603);
+        assert_eq!(err.message(), "quota exceeded for model");
+        assert!(err.source().unwrap().downcast_ref::<QuotaExceeded>().is_some());
+
+        let boxed: Box<dyn Error + Send + Sync> = "plain message".into();
+        assert_eq!(A2AError::from(boxed).message(), "plain message");
+
+        // Typed conversions take precedence
+        let boxed: Box<dyn Error + Send + Sync> = Box::new(A2AError::task_not_found("task-1"));
+        assert!(matches!(A2AError::from(boxed), A2AError::TaskNotFound(_)));
+        let boxed: Box<dyn Error + Send + Sync> =
+            Box::new(TaskNotFoundError::new("Task not found: task-1".to_string()));
+        assert!(matches!(A2AError::from(boxed), A2AError::TaskNotFound(_)));
+        let boxed: Box<dyn Error + Send + Sync> =
+            Box::new(crate::a2a::jsonrpc::JSONRPCError::new(-32002, "Task cannot be canceled".to_string()));
+        assert!(matches!(A2AError::from(boxed), A2AError::TaskNotCancelable(_)));
+        let boxed: Box<dyn Error + Send + Sync> =
+            Box::new(crate::a2a::client::errors::ClientError::Timeout("after 30s".to_string()));
+        assert_eq!(A2AError::from(boxed).message(), "Timeout: after 30s");
+        let boxed: Box<dyn Error + Send + Sync> = Box::new(crate::a2a::server::events::QueueError::Closed);
+        assert_eq!(A2AError::from(boxed).message(), "Queue is closed");
+        let boxed: Box<dyn Error + Send + Sync> = Box::new(std::io::Error::other("disk full"));
+        let err = A2AError::from(boxed);
+        assert_eq!(err.message(), "IO error: disk full");
+        assert!(err.source().unwrap().downcast_ref::<std::io::Error>().is_some());
+    }
+
+    #[test]
+    fn test_source_is_not_serialized() {
+        let err = A2AError::internal("boom").with_source(std::io::Error::other("cause"));
+
+        let json = serde_json::to_value(&err).unwrap();
+        assert_eq!(json, serde_json::json!({"code": -32603, "message": "boom", "data": null}));
+        assert_eq!(err, A2AError::internal("boom").with_source(std::io::Error::other("other")));
+        assert_eq!(err, A2AError::internal("boom"));
+        assert!(A2AError::internal("boom").source().is_none());
+
+        let A2AError::Internal(internal) = err else { panic!("expected an internal error") };
+        assert_eq!(internal.source().unwrap().get().to_string(), "cause");
+    }
+
+    #[test]
+    fn test_typed_error_constructors() {
+        let err = TaskNotFoundError::new("Task not found: task-1".to_string())
+            .with_data(serde_json::json!({"taskId": "task-1"}))
+            .with_source(std::io::Error::other("cause"));
+        assert_eq!(err.code, -32001);
+        assert_eq!(err.data, Some(serde_json::json!({"taskId": "task-1"})));
+        assert_eq!(err.source().unwrap().get().to_string(), "cause");
+
+        let err = A2AError::from(err);
+        assert_eq!(err.message(), "Task not found: task-1");
+        assert!(std::error::Error::source(&err).is_some());
+    }
+}
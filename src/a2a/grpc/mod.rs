@@ -5,5 +5,9 @@
 
 pub mod a2a_pb2;
 pub mod a2a_pb2_grpc;
+#[cfg(feature = "grpc")]
+pub mod status;
 
 // Re-export gRPC types
+#[cfg(feature = "grpc")]
+pub use status::a2a_error_from_grpc_status;
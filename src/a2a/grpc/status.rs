@@ -0,0 +1,106 @@
+//! Mapping of gRPC statuses to A2A errors
+//!
+//! A gRPC agent reports failures as a status code and message. The Python
+//! SDK prefixes the message with the name of the A2A error, e.g.
+//! `TaskNotCancelableError: Task is completed`, since several errors share a
+//! status code; the prefix is used when present and the status code
+//! otherwise.
+
+use crate::a2a::error::{a2a_error_from_code, A2AError};
+use crate::a2a::jsonrpc::{error_codes, standard_error_codes};
+use tonic::{Code, Status};
+
+/// Converts the status of a failed gRPC call into the matching `A2AError`
+///
+/// Statuses without an A2A equivalent become `Internal` errors, except
+/// `Unavailable`, which is reported as a transport error.
+pub fn a2a_error_from_grpc_status(status: Status) -> A2AError {
+    let (code, message) = match error_code_from_prefix(status.message()) {
+        Some((code, message)) => (code, message.to_string()),
+        None => {
+            let message = status.message().to_string();
+            let code = match status.code() {
+                Code::NotFound => error_codes::TASK_NOT_FOUND,
+                Code::Unimplemented => error_codes::UNSUPPORTED_OPERATION,
+                Code::FailedPrecondition => error_codes::TASK_NOT_CANCELABLE,
+                Code::InvalidArgument | Code::OutOfRange => standard_error_codes::INVALID_PARAMS,
+                Code::Unauthenticated | Code::PermissionDenied => standard_error_codes::INVALID_REQUEST,
+                Code::Unavailable => return A2AError::transport_error(message),
+                _ => standard_error_codes::INTERNAL_ERROR,
+            };
+            (code, message)
+        }
+    };
+    a2a_error_from_code(code, message, None)
+}
+
+/// Splits an `<ErrorName>: <message>` status message into its error code and message
+fn error_code_from_prefix(message: &str) -> Option<(i32, &str)> {
+    let (name, rest) = message.split_once(':')?;
+    let code = match name {
+        "JSONParseError" => standard_error_codes::PARSE_ERROR,
+        "InvalidRequestError" => standard_error_codes::INVALID_REQUEST,
+        "MethodNotFoundError" => standard_error_codes::METHOD_NOT_FOUND,
+        "InvalidParamsError" => standard_error_codes::INVALID_PARAMS,
+        "InternalError" => standard_error_codes::INTERNAL_ERROR,
+        "TaskNotFoundError" => error_codes::TASK_NOT_FOUND,
+        "TaskNotCancelableError" => error_codes::TASK_NOT_CANCELABLE,
+        "PushNotificationNotSupportedError" => error_codes::PUSH_NOTIFICATION_NOT_SUPPORTED,
+        "UnsupportedOperationError" => error_codes::UNSUPPORTED_OPERATION,
+        "ContentTypeNotSupportedError" => error_codes::CONTENT_TYPE_NOT_SUPPORTED,
+        "InvalidAgentResponseError" => error_codes::INVALID_AGENT_RESPONSE,
+        "AuthenticatedExtendedCardNotConfiguredError" => error_codes::AUTHENTICATED_EXTENDED_CARD_NOT_CONFIGURED,
+        _ => return None,
+    };
+    Some((code, rest.trim_start()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grpc_codes_map_to_a2a_errors() {
+        let cases: [(Code, i32); 8] = [
+            (Code::NotFound, error_codes::TASK_NOT_FOUND),
+            (Code::Unimplemented, error_codes::UNSUPPORTED_OPERATION),
+            (Code::FailedPrecondition, error_codes::TASK_NOT_CANCELABLE),
+            (Code::InvalidArgument, standard_error_codes::INVALID_PARAMS),
+            (Code::Unauthenticated, standard_error_codes::INVALID_REQUEST),
+            (Code::PermissionDenied, standard_error_codes::INVALID_REQUEST),
+            (Code::DeadlineExceeded, standard_error_codes::INTERNAL_ERROR),
+            (Code::Unknown, standard_error_codes::INTERNAL_ERROR),
+        ];
+        for (grpc_code, expected) in cases {
+            let error = a2a_error_from_grpc_status(Status::new(grpc_code, "request failed"));
+            assert_eq!(error.code(), expected, "for {:?}", grpc_code);
+            assert_eq!(error.message(), "request failed");
+        }
+
+        assert!(matches!(
+            a2a_error_from_grpc_status(Status::not_found("no such task")),
+            A2AError::TaskNotFound(_)
+        ));
+        assert!(matches!(
+            a2a_error_from_grpc_status(Status::unimplemented("no streaming")),
+            A2AError::UnsupportedOperation(_)
+        ));
+        let unavailable = a2a_error_from_grpc_status(Status::unavailable("connection refused"));
+        assert!(matches!(unavailable, A2AError::Internal(_)));
+        assert!(unavailable.message().contains("connection refused"));
+    }
+
+    #[test]
+    fn test_error_name_prefix_takes_precedence() {
+        let error = a2a_error_from_grpc_status(Status::unimplemented("TaskNotCancelableError: Task is completed"));
+        assert!(matches!(error, A2AError::TaskNotCancelable(_)));
+        assert_eq!(error.message(), "Task is completed");
+
+        let error = a2a_error_from_grpc_status(Status::unimplemented("PushNotificationNotSupportedError: Disabled"));
+        assert!(matches!(error, A2AError::PushNotificationNotSupported(_)));
+
+        let error = a2a_error_from_grpc_status(Status::internal("SomethingElse: boom"));
+        assert!(matches!(error, A2AError::Internal(_)));
+        assert_eq!(error.message(), "SomethingElse: boom");
+    }
+}
@@ -43,7 +43,8 @@ pub struct JSONRPCRequest {
     pub jsonrpc: String,
     /// A string containing the name of the method to be invoked
     pub method: String,
-    /// A structured value holding the parameter values
+    /// A structured value holding the parameter values, omitted for methods without parameters
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub params: Option<serde_json::Value>,
 }
 
@@ -105,6 +106,14 @@ impl JSONRPCError {
     }
 }
 
+impl std::fmt::Display for JSONRPCError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} (code: {})", self.message, self.code)
+    }
+}
+
+impl std::error::Error for JSONRPCError {}
+
 /// JSON-RPC 2.0 Error Response object
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct JSONRPCErrorResponse {
@@ -151,6 +160,27 @@ impl JSONRPCResponse {
     }
 }
 
+/// The only JSON-RPC protocol version accepted by A2A
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// Checks that a raw request declares `"jsonrpc": "2.0"`
+///
+/// Requests with a missing, non-string or different version are rejected
+/// with an invalid request error (-32600).
+pub fn validate_jsonrpc_version(request: &serde_json::Value) -> Result<(), JSONRPCError> {
+    match request.get("jsonrpc") {
+        Some(serde_json::Value::String(version)) if version == JSONRPC_VERSION => Ok(()),
+        Some(version) => Err(JSONRPCError::new(
+            standard_error_codes::INVALID_REQUEST,
+            format!("Unsupported JSON-RPC version: {}, expected \"{}\"", version, JSONRPC_VERSION),
+        )),
+        None => Err(JSONRPCError::new(
+            standard_error_codes::INVALID_REQUEST,
+            "Missing 'jsonrpc' version field".to_string(),
+        )),
+    }
+}
+
 /// A2A-specific error codes
 pub mod error_codes {
     pub const TASK_NOT_FOUND: i32 = -32001;
@@ -197,11 +227,15 @@ pub enum A2ARequest {
     GetAuthenticatedExtendedCard,
 }
 
-/// A2A Response types (discriminated union)
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// A2A Response types
+///
+/// JSON-RPC results carry no discriminator and several methods return the
+/// same shape, so responses are not deserialized on their own: parse them
+/// with [`parse_a2a_response`], which knows the method of the request.
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum A2AResponse {
-    SendMessage(Task),
+    SendMessage(SendMessageResult),
     SendStreamingMessage(SendStreamingMessageResult),
     GetTask(Task),
     CancelTask(Task),
@@ -213,6 +247,84 @@ pub enum A2AResponse {
     GetAuthenticatedExtendedCard(AgentCard),
 }
 
+/// Model fields stored under snake_case names that JSON-RPC results carry in camelCase
+const SNAKE_CASE_FIELDS: &[&str] = &[
+    "accepted_output_modes",
+    "additional_interfaces",
+    "artifact_id",
+    "bearer_format",
+    "context_id",
+    "default_input_modes",
+    "default_output_modes",
+    "documentation_url",
+    "history_length",
+    "icon_url",
+    "input_modes",
+    "last_chunk",
+    "mime_type",
+    "open_id_connect_url",
+    "output_modes",
+    "preferred_transport",
+    "protocol_version",
+    "push_notification_config",
+    "push_notification_config_id",
+    "push_notifications",
+    "security_schemes",
+    "state_transition_history",
+    "supports_authenticated_extended_card",
+    "task_id",
+];
+
+/// Fields holding caller-defined JSON whose keys are left as they are
+const OPAQUE_FIELDS: &[&str] = &["metadata", "data", "params"];
+
+fn snake_to_camel_case(name: &str) -> String {
+    let mut camel = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            camel.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+/// Renames the snake_case model fields in `value` to camelCase, in place
+fn camel_case_model_fields(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let fields = std::mem::take(map);
+            for (key, mut field) in fields {
+                if !OPAQUE_FIELDS.contains(&key.as_str()) {
+                    camel_case_model_fields(&mut field);
+                }
+                let key = if SNAKE_CASE_FIELDS.contains(&key.as_str()) { snake_to_camel_case(&key) } else { key };
+                map.insert(key, field);
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(camel_case_model_fields),
+        _ => {}
+    }
+}
+
+/// Wraps an A2A response in a JSON-RPC success response
+///
+/// Each variant's payload becomes the `result` with its fields in camelCase,
+/// so a `Task` carries `contextId` and a `Message` its `messageId`. Keys
+/// inside `metadata`, data parts and extension params are left untouched.
+/// Deleting a push notification config yields a `null` result.
+pub fn a2a_response_to_jsonrpc(id: Option<JSONRPCId>, resp: A2AResponse) -> JSONRPCSuccessResponse {
+    // The response types only contain string-keyed maps, so serializing them cannot fail
+    let mut result = serde_json::to_value(resp).unwrap_or(serde_json::Value::Null);
+    camel_case_model_fields(&mut result);
+    JSONRPCSuccessResponse::new(id, result)
+}
+
 /// Result for streaming message response
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -223,11 +335,90 @@ pub enum SendStreamingMessageResult {
     TaskArtifactUpdate(TaskArtifactUpdateEvent),
 }
 
+/// Result of a `message/send` request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SendMessageResult {
+    Task(Task),
+    Message(Message),
+}
+
+/// Parses the `result` of a JSON-RPC response to a request for `method`
+///
+/// Task and message results are told apart by their `kind`, as are the
+/// events of `message/stream`. Fails with `InvalidAgentResponse` when the
+/// result does not have the shape `method` returns, and with
+/// `MethodNotFound` for methods that are not part of the protocol.
+pub fn parse_a2a_response(method: &str, result: serde_json::Value) -> Result<A2AResponse, crate::A2AError> {
+    fn parse<T: serde::de::DeserializeOwned>(method: &str, result: serde_json::Value) -> Result<T, crate::A2AError> {
+        serde_json::from_value(result)
+            .map_err(|e| crate::A2AError::invalid_response(&format!("Invalid result for {}: {}", method, e)))
+    }
+
+    let kind = result.get("kind").and_then(serde_json::Value::as_str).map(str::to_string);
+    Ok(match method {
+        "message/send" => A2AResponse::SendMessage(match kind.as_deref() {
+            Some("message") => SendMessageResult::Message(parse(method, result)?),
+            _ => SendMessageResult::Task(parse(method, result)?),
+        }),
+        "message/stream" => A2AResponse::SendStreamingMessage(match kind.as_deref() {
+            Some("message") => SendStreamingMessageResult::Message(parse(method, result)?),
+            Some("status-update") => SendStreamingMessageResult::TaskStatusUpdate(parse(method, result)?),
+            Some("artifact-update") => SendStreamingMessageResult::TaskArtifactUpdate(parse(method, result)?),
+            _ => SendStreamingMessageResult::Task(parse(method, result)?),
+        }),
+        "tasks/get" => A2AResponse::GetTask(parse(method, result)?),
+        "tasks/cancel" => A2AResponse::CancelTask(parse(method, result)?),
+        "tasks/resubscribe" => A2AResponse::TaskResubscription(parse(method, result)?),
+        "tasks/pushNotificationConfig/set" => A2AResponse::SetTaskPushNotificationConfig(parse(method, result)?),
+        "tasks/pushNotificationConfig/get" => A2AResponse::GetTaskPushNotificationConfig(parse(method, result)?),
+        "tasks/pushNotificationConfig/list" => A2AResponse::ListTaskPushNotificationConfig(parse(method, result)?),
+        "tasks/pushNotificationConfig/delete" => A2AResponse::DeleteTaskPushNotificationConfig(parse(method, result)?),
+        "agent/getAuthenticatedExtendedCard" => A2AResponse::GetAuthenticatedExtendedCard(parse(method, result)?),
+        _ => {
+            return Err(crate::a2a::error::a2a_error_from_code(
+                standard_error_codes::METHOD_NOT_FOUND,
+                format!("Unknown method: {}", method),
+                None,
+            ))
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use serde_json;
 
+    #[test]
+    fn test_validate_jsonrpc_version() {
+        assert!(validate_jsonrpc_version(&serde_json::json!({"jsonrpc": "2.0"})).is_ok());
+
+        for request in [
+            serde_json::json!({"jsonrpc": "1.0"}),
+            serde_json::json!({"jsonrpc": 2.0}),
+            serde_json::json!({"method": "tasks/get"}),
+        ] {
+            let error = validate_jsonrpc_version(&request).unwrap_err();
+            assert_eq!(error.code, standard_error_codes::INVALID_REQUEST);
+        }
+    }
+
+    #[test]
+    fn test_jsonrpc_request_without_params_omits_field() {
+        let request = JSONRPCRequest::new(
+            "agent/getAuthenticatedExtendedCard".to_string(),
+            None,
+            Some(JSONRPCId::Number(1)),
+        );
+
+        let json = serde_json::to_value(&request).unwrap();
+        assert!(json.get("params").is_none());
+
+        let parsed: JSONRPCRequest = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, request);
+    }
+
     #[test]
     fn test_jsonrpc_request_serialization() {
         let request = JSONRPCRequest::new(
@@ -268,6 +459,109 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_a2a_response_to_jsonrpc() {
+        use crate::a2a::core_types::{Part, Role, TaskState, TaskStatus};
+
+        let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+            .with_task_id("task-1".to_string())
+            .with_metadata(std::collections::HashMap::from([("task_id".to_string(), serde_json::json!({"context_id": "x"}))]));
+        let config = TaskPushNotificationConfig::new(
+            "task-1".to_string(),
+            PushNotificationConfig::new(url::Url::parse("https://example.com/hook").unwrap()).with_id("cfg-1".to_string()),
+        );
+        let message = Message::new(Role::Agent, vec![Part::text("hi".to_string())]).with_message_id("msg-1".to_string());
+        let id = Some(JSONRPCId::Number(7));
+        let result = |resp: A2AResponse| {
+            let response = a2a_response_to_jsonrpc(id.clone(), resp);
+            assert_eq!(response.jsonrpc, "2.0");
+            assert_eq!(response.id, id);
+            response.result
+        };
+
+        for resp in [
+            A2AResponse::SendMessage(SendMessageResult::Task(task.clone())),
+            A2AResponse::GetTask(task.clone()),
+            A2AResponse::CancelTask(task.clone()),
+            A2AResponse::TaskResubscription(task.clone()),
+            A2AResponse::SendStreamingMessage(SendStreamingMessageResult::Task(task.clone())),
+        ] {
+            let value = result(resp);
+            assert_eq!(value["kind"], "task");
+            assert_eq!(value["id"], "task-1");
+            assert_eq!(value["contextId"], "ctx-1");
+            assert_eq!(value["status"]["state"], "working");
+            assert!(value.get("context_id").is_none());
+            assert_eq!(value["metadata"]["task_id"]["context_id"], "x");
+        }
+
+        let value = result(A2AResponse::SendStreamingMessage(SendStreamingMessageResult::Message(message)));
+        assert_eq!(value["kind"], "message");
+        assert_eq!(value["messageId"], "msg-1");
+
+        for resp in [
+            A2AResponse::SetTaskPushNotificationConfig(config.clone()),
+            A2AResponse::GetTaskPushNotificationConfig(config.clone()),
+        ] {
+            let value = result(resp);
+            assert_eq!(value["taskId"], "task-1");
+            assert_eq!(value["pushNotificationConfig"]["id"], "cfg-1");
+        }
+        let value = result(A2AResponse::ListTaskPushNotificationConfig(vec![config]));
+        assert_eq!(value.as_array().unwrap().len(), 1);
+        assert_eq!(value[0]["pushNotificationConfig"]["url"], "https://example.com/hook");
+
+        assert_eq!(result(A2AResponse::DeleteTaskPushNotificationConfig(())), serde_json::Value::Null);
+
+        let card = AgentCard::test_default("Test Agent", "https://example.com");
+        let value = result(A2AResponse::GetAuthenticatedExtendedCard(card));
+        assert_eq!(value["name"], "Test Agent");
+        assert_eq!(value["defaultInputModes"][0], "text/plain");
+
+        let json = serde_json::to_value(a2a_response_to_jsonrpc(None, A2AResponse::DeleteTaskPushNotificationConfig(()))).unwrap();
+        assert_eq!(json, serde_json::json!({"jsonrpc": "2.0", "id": null, "result": null}));
+    }
+
+    #[test]
+    fn test_parse_a2a_response_uses_request_method() {
+        use crate::a2a::core_types::{Part, Role, TaskState, TaskStatus};
+
+        let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Canceled))
+            .with_task_id("task-1".to_string());
+        let result = serde_json::to_value(&task).unwrap();
+
+        // The same JSON is a different response depending on the method
+        assert_eq!(
+            parse_a2a_response("tasks/cancel", result.clone()).unwrap(),
+            A2AResponse::CancelTask(task.clone())
+        );
+        assert_eq!(
+            parse_a2a_response("message/send", result.clone()).unwrap(),
+            A2AResponse::SendMessage(SendMessageResult::Task(task.clone()))
+        );
+
+        let message = Message::new(Role::Agent, vec![Part::text("hi".to_string())]);
+        let parsed = parse_a2a_response("message/send", serde_json::to_value(&message).unwrap()).unwrap();
+        assert_eq!(parsed, A2AResponse::SendMessage(SendMessageResult::Message(message)));
+
+        let update = TaskStatusUpdateEvent::new("task-1".to_string(), "ctx-1".to_string(), task.status.clone(), true);
+        let parsed = parse_a2a_response("message/stream", serde_json::to_value(&update).unwrap()).unwrap();
+        assert_eq!(parsed, A2AResponse::SendStreamingMessage(SendStreamingMessageResult::TaskStatusUpdate(update)));
+
+        assert_eq!(
+            parse_a2a_response("tasks/pushNotificationConfig/delete", serde_json::Value::Null).unwrap(),
+            A2AResponse::DeleteTaskPushNotificationConfig(())
+        );
+        assert!(matches!(
+            parse_a2a_response("tasks/get", serde_json::json!({"kind": "message"})),
+            Err(crate::A2AError::InvalidAgentResponse(_))
+        ));
+        assert!(matches!(
+            parse_a2a_response("tasks/list", result),
+            Err(crate::A2AError::MethodNotFound(_))
+        ));
+    }
+
     #[test]
     fn test_error_response() {
         let error = JSONRPCError::new(
@@ -10,21 +10,81 @@ use url::Url;
 use uuid::Uuid;
 
 /// Authentication schemes for agent security
+///
+/// The `type` tag uses the values of the Python SDK (`http`, `oauth2`,
+/// `openIdConnect`, `apiKey`, `mutualTLS`); the Rust variant names are
+/// still accepted when deserializing.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "type")]
 pub enum SecurityScheme {
     /// HTTP authentication schemes (Basic, Bearer, etc.)
+    #[serde(rename = "http", alias = "HTTPAuth")]
     HTTPAuth(HTTPAuthSecurityScheme),
     /// OAuth2 authentication
+    #[serde(rename = "oauth2", alias = "OAuth2")]
     OAuth2(OAuth2SecurityScheme),
     /// OpenID Connect authentication
+    #[serde(rename = "openIdConnect", alias = "OpenIdConnect")]
     OpenIdConnect(OpenIdConnectSecurityScheme),
     /// API key authentication
+    #[serde(rename = "apiKey", alias = "APIKey")]
     APIKey(APIKeySecurityScheme),
     /// Mutual TLS authentication
+    #[serde(rename = "mutualTLS", alias = "MutualTLS")]
     MutualTLS(MutualTLSSecurityScheme),
 }
 
+impl SecurityScheme {
+    /// HTTP bearer authentication with the given token format (e.g. "JWT")
+    pub fn bearer(format: &str) -> Self {
+        SecurityScheme::HTTPAuth(HTTPAuthSecurityScheme {
+            scheme: "bearer".to_string(),
+            description: None,
+            bearer_format: Some(format.to_string()),
+        })
+    }
+
+    /// API key sent in the request header `name`
+    pub fn api_key_header(name: &str) -> Self {
+        Self::api_key(name, In::Header)
+    }
+
+    /// API key sent in the query parameter `name`
+    pub fn api_key_query(name: &str) -> Self {
+        Self::api_key(name, In::Query)
+    }
+
+    fn api_key(name: &str, in_: In) -> Self {
+        SecurityScheme::APIKey(APIKeySecurityScheme {
+            name: name.to_string(),
+            in_,
+            description: None,
+        })
+    }
+
+    /// OAuth2 authentication with the given flows, keyed by flow name
+    /// (e.g. "client_credentials")
+    pub fn oauth2(flows: HashMap<String, serde_json::Value>) -> Self {
+        SecurityScheme::OAuth2(OAuth2SecurityScheme {
+            flows,
+            description: None,
+        })
+    }
+
+    /// Set the description of the scheme
+    pub fn with_description(mut self, description: &str) -> Self {
+        let slot = match &mut self {
+            SecurityScheme::HTTPAuth(scheme) => &mut scheme.description,
+            SecurityScheme::OAuth2(scheme) => &mut scheme.description,
+            SecurityScheme::OpenIdConnect(scheme) => &mut scheme.description,
+            SecurityScheme::APIKey(scheme) => &mut scheme.description,
+            SecurityScheme::MutualTLS(scheme) => &mut scheme.description,
+        };
+        *slot = Some(description.to_string());
+        self
+    }
+}
+
 /// HTTP authentication scheme
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HTTPAuthSecurityScheme {
@@ -33,6 +93,7 @@ pub struct HTTPAuthSecurityScheme {
     /// A description of the authentication scheme
     pub description: Option<String>,
     /// The format of the bearer token (e.g., "JWT")
+    #[serde(alias = "bearerFormat")]
     pub bearer_format: Option<String>,
 }
 
@@ -49,6 +110,7 @@ pub struct OAuth2SecurityScheme {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct OpenIdConnectSecurityScheme {
     /// The OpenID Connect discovery URL
+    #[serde(alias = "openIdConnectUrl")]
     pub open_id_connect_url: String,
     /// A description of the OpenID Connect configuration
     pub description: Option<String>,
@@ -90,7 +152,7 @@ impl Skill {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Artifact {
     /// A unique identifier (e.g. UUID) for the artifact within the scope of the task
-    #[serde(rename = "artifact_id")]
+    #[serde(rename = "artifact_id", alias = "artifactId")]
     pub artifact_id: String,
     /// An optional, human-readable name for the artifact
     pub name: Option<String>,
@@ -135,6 +197,38 @@ impl Artifact {
         self.metadata = Some(metadata);
         self
     }
+
+    /// Marks the artifact as relevant to the extension with the given URI
+    pub fn with_extension(mut self, uri: impl Into<String>) -> Self {
+        let uri = uri.into();
+        let extensions = self.extensions.get_or_insert_with(Vec::new);
+        if !extensions.contains(&uri) {
+            extensions.push(uri);
+        }
+        self
+    }
+
+    /// Returns whether the artifact lists the extension with the given URI
+    pub fn has_extension(&self, uri: &str) -> bool {
+        self.extensions.iter().flatten().any(|extension| extension == uri)
+    }
+
+    /// Attaches extension-specific metadata, keyed by the extension URI
+    ///
+    /// The extension is also added to `extensions`.
+    pub fn with_extension_metadata(mut self, uri: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        let uri = uri.into();
+        self.metadata.get_or_insert_with(HashMap::new).insert(uri.clone(), value.into());
+        self.with_extension(uri)
+    }
+
+    /// Reads the metadata attached for an extension as `T`
+    ///
+    /// Returns `None` if there is none or it does not deserialize as `T`.
+    pub fn extension_metadata<T: serde::de::DeserializeOwned>(&self, uri: &str) -> Option<T> {
+        let value = self.metadata.as_ref()?.get(uri)?;
+        serde_json::from_value(value.clone()).ok()
+    }
 }
 
 /// Enum that can represent either a Task or a Message
@@ -235,10 +329,10 @@ pub struct AgentSkill {
     /// Example prompts or scenarios that this skill can handle
     pub examples: Option<Vec<String>>,
     /// The set of supported input MIME types for this skill
-    #[serde(rename = "input_modes")]
+    #[serde(rename = "input_modes", alias = "inputModes")]
     pub input_modes: Option<Vec<String>>,
     /// The set of supported output MIME types for this skill
-    #[serde(rename = "output_modes")]
+    #[serde(rename = "output_modes", alias = "outputModes")]
     pub output_modes: Option<Vec<String>>,
     /// Security schemes necessary for the agent to leverage this skill
     pub security: Option<Vec<HashMap<String, Vec<String>>>>,
@@ -324,10 +418,10 @@ pub struct AgentCapabilities {
     /// Indicates if the agent supports Server-Sent Events (SSE) for streaming responses
     pub streaming: Option<bool>,
     /// Indicates if the agent supports sending push notifications for asynchronous task updates
-    #[serde(rename = "push_notifications")]
+    #[serde(rename = "push_notifications", alias = "pushNotifications")]
     pub push_notifications: Option<bool>,
     /// Indicates if the agent provides a history of state transitions for a task
-    #[serde(rename = "state_transition_history")]
+    #[serde(rename = "state_transition_history", alias = "stateTransitionHistory")]
     pub state_transition_history: Option<bool>,
     /// A list of protocol extensions supported by the agent
     pub extensions: Option<Vec<AgentExtension>>,
@@ -362,6 +456,38 @@ impl AgentCapabilities {
         self.extensions = Some(extensions);
         self
     }
+
+    /// Returns whether the agent supports streaming responses
+    pub fn supports_streaming(&self) -> bool {
+        self.streaming.unwrap_or(false)
+    }
+
+    /// Returns whether the agent supports push notifications
+    pub fn supports_push_notifications(&self) -> bool {
+        self.push_notifications.unwrap_or(false)
+    }
+
+    /// Returns whether the agent records the history of task state transitions
+    pub fn supports_state_transition_history(&self) -> bool {
+        self.state_transition_history.unwrap_or(false)
+    }
+
+    /// Returns whether the agent declares the extension with the given URI
+    pub fn supports_extension(&self, uri: &str) -> bool {
+        self.extensions
+            .as_ref()
+            .is_some_and(|extensions| extensions.iter().any(|extension| extension.uri == uri))
+    }
+
+    /// Returns the URIs of the extensions clients must support to use the agent
+    pub fn required_extensions(&self) -> Vec<&str> {
+        self.extensions
+            .iter()
+            .flatten()
+            .filter(|extension| extension.required.unwrap_or(false))
+            .map(|extension| extension.uri.as_str())
+            .collect()
+    }
 }
 
 /// Declares a combination of a target URL and a transport protocol for interacting with an agent
@@ -391,27 +517,27 @@ pub struct AgentCard {
     /// The agent's own version number
     pub version: String,
     /// The version of the A2A protocol this agent supports
-    #[serde(rename = "protocol_version")]
+    #[serde(rename = "protocol_version", alias = "protocolVersion")]
     pub protocol_version: Option<String>,
     /// An optional URL to an icon for the agent
-    #[serde(rename = "icon_url")]
+    #[serde(rename = "icon_url", alias = "iconUrl")]
     pub icon_url: Option<String>,
     /// An optional URL to the agent's documentation
-    #[serde(rename = "documentation_url")]
+    #[serde(rename = "documentation_url", alias = "documentationUrl")]
     pub documentation_url: Option<String>,
     /// Information about the agent's service provider
     pub provider: Option<AgentProvider>,
     /// The transport protocol for the preferred endpoint
-    #[serde(rename = "preferred_transport")]
+    #[serde(rename = "preferred_transport", alias = "preferredTransport")]
     pub preferred_transport: Option<String>,
     /// A list of additional supported interfaces
-    #[serde(rename = "additional_interfaces")]
+    #[serde(rename = "additional_interfaces", alias = "additionalInterfaces")]
     pub additional_interfaces: Option<Vec<AgentInterface>>,
     /// Default set of supported input MIME types for all skills
-    #[serde(rename = "default_input_modes")]
+    #[serde(rename = "default_input_modes", alias = "defaultInputModes")]
     pub default_input_modes: Vec<String>,
     /// Default set of supported output MIME types for all skills
-    #[serde(rename = "default_output_modes")]
+    #[serde(rename = "default_output_modes", alias = "defaultOutputModes")]
     pub default_output_modes: Vec<String>,
     /// A declaration of optional capabilities supported by the agent
     pub capabilities: AgentCapabilities,
@@ -420,12 +546,12 @@ pub struct AgentCard {
     /// A list of security requirement objects that apply to all agent interactions
     pub security: Option<Vec<HashMap<String, Vec<String>>>>,
     /// A declaration of the security schemes available to authorize requests
-    #[serde(rename = "security_schemes")]
+    #[serde(rename = "security_schemes", alias = "securitySchemes")]
     pub security_schemes: Option<HashMap<String, SecurityScheme>>,
     /// JSON Web Signatures computed for this AgentCard
     pub signatures: Option<Vec<serde_json::Value>>,
     /// If true, the agent can provide an extended agent card with additional details to authenticated users
-    #[serde(rename = "supports_authenticated_extended_card")]
+    #[serde(rename = "supports_authenticated_extended_card", alias = "supportsAuthenticatedExtendedCard")]
     pub supports_authenticated_extended_card: Option<bool>,
 }
 
@@ -482,6 +608,41 @@ impl AgentCard {
         self
     }
 
+    /// JSON-RPC methods the card's capabilities say the agent serves
+    ///
+    /// `message/send`, `tasks/get` and `tasks/cancel` are always available.
+    /// Streaming adds `message/stream` and `tasks/resubscribe`, push
+    /// notifications add the `tasks/pushNotificationConfig` methods, and an
+    /// authenticated extended card adds `agent/getAuthenticatedExtendedCard`.
+    pub fn supported_methods(&self) -> Vec<&'static str> {
+        let mut methods = vec!["message/send", "tasks/get", "tasks/cancel"];
+        if self.capabilities.streaming.unwrap_or(false) {
+            methods.extend(["message/stream", "tasks/resubscribe"]);
+        }
+        if self.capabilities.push_notifications.unwrap_or(false) {
+            methods.extend([
+                "tasks/pushNotificationConfig/set",
+                "tasks/pushNotificationConfig/get",
+                "tasks/pushNotificationConfig/list",
+                "tasks/pushNotificationConfig/delete",
+            ]);
+        }
+        if self.supports_authenticated_extended_card.unwrap_or(false) {
+            methods.push("agent/getAuthenticatedExtendedCard");
+        }
+        methods
+    }
+
+    /// Whether the card's capabilities say the agent serves `method`
+    pub fn supports_method(&self, method: &str) -> bool {
+        self.supported_methods().contains(&method)
+    }
+
+    /// The organization providing the agent, if the card names one
+    pub fn provider(&self) -> Option<&AgentProvider> {
+        self.provider.as_ref()
+    }
+
     pub fn with_preferred_transport(mut self, transport: String) -> Self {
         self.preferred_transport = Some(transport);
         self
@@ -511,15 +672,129 @@ impl AgentCard {
         self.supports_authenticated_extended_card = Some(supports);
         self
     }
+
+    /// Creates a minimal valid card for tests and examples
+    ///
+    /// The card accepts and produces `text/plain`, declares no optional
+    /// capabilities and has no skills; adjust it with the `with_*` builders.
+    pub fn test_default(name: &str, url: &str) -> Self {
+        Self::new(
+            name.to_string(),
+            format!("{} for testing", name),
+            url.to_string(),
+            "1.0.0".to_string(),
+            vec!["text/plain".to_string()],
+            vec!["text/plain".to_string()],
+            AgentCapabilities::new(),
+            vec![],
+        )
+    }
+
+    /// Lists the transports the agent can be reached over, most preferred first
+    ///
+    /// The primary `url` with the `preferred_transport` (JSON-RPC if unset
+    /// or unknown) comes first, followed by the additional interfaces.
+    /// Entries with a URL that is not absolute, and additional interfaces
+    /// with an unknown transport, are skipped with a warning. Duplicates are
+    /// dropped.
+    pub fn interfaces(&self) -> Vec<(TransportProtocol, Url)> {
+        self.declared_interfaces()
+            .into_iter()
+            .map(|(transport, _, url)| (transport, url))
+            .collect()
+    }
+
+    /// Like `interfaces`, but with each URL as written on the card
+    pub(crate) fn interface_urls(&self) -> Vec<(TransportProtocol, String)> {
+        self.declared_interfaces()
+            .into_iter()
+            .map(|(transport, url, _)| (transport, url.to_string()))
+            .collect()
+    }
+
+    fn declared_interfaces(&self) -> Vec<(TransportProtocol, &str, Url)> {
+        // The primary URL is always served over some transport, so an unset
+        // or unknown preferred transport means the JSON-RPC default
+        let preferred = match self.preferred_transport.as_deref().filter(|t| !t.is_empty()) {
+            Some(transport) => transport.parse().unwrap_or_else(|e| {
+                tracing::warn!("Agent {} prefers an unsupported transport ({}), assuming JSONRPC", self.name, e);
+                TransportProtocol::Jsonrpc
+            }),
+            None => TransportProtocol::Jsonrpc,
+        };
+        let additional = self.additional_interfaces.iter().flatten().filter_map(|interface| {
+            match interface.transport.parse::<TransportProtocol>() {
+                Ok(transport) => Some((transport, interface.url.as_str())),
+                Err(e) => {
+                    tracing::warn!("Skipping interface {} of agent {}: {}", interface.url, self.name, e);
+                    None
+                }
+            }
+        });
+
+        let mut interfaces: Vec<(TransportProtocol, &str, Url)> = Vec::new();
+        for (transport, raw_url) in std::iter::once((preferred, self.url.as_str())).chain(additional) {
+            let url = match Url::parse(raw_url) {
+                Ok(url) => url,
+                Err(e) => {
+                    tracing::warn!("Skipping {} interface {:?} of agent {}: {}", transport, raw_url, self.name, e);
+                    continue;
+                }
+            };
+            if !interfaces.iter().any(|(t, _, u)| *t == transport && *u == url) {
+                interfaces.push((transport, raw_url, url));
+            }
+        }
+        interfaces
+    }
+
+    /// Checks that the card has the fields clients rely on
+    ///
+    /// Requires a non-empty name, description and version, absolute URLs for
+    /// the agent and its additional interfaces, and skills with non-empty,
+    /// unique IDs and non-empty names. All problems are reported together.
+    pub fn validate(&self) -> Result<(), crate::a2a::error::A2AError> {
+        let mut problems = Vec::new();
+        for (field, value) in [("name", &self.name), ("description", &self.description), ("version", &self.version)] {
+            if value.trim().is_empty() {
+                problems.push(format!("{} must not be empty", field));
+            }
+        }
+        if Url::parse(&self.url).is_err() {
+            problems.push(format!("url {:?} is not an absolute URL", self.url));
+        }
+        for interface in self.additional_interfaces.iter().flatten() {
+            if Url::parse(&interface.url).is_err() {
+                problems.push(format!("interface url {:?} is not an absolute URL", interface.url));
+            }
+        }
+        let mut skill_ids = std::collections::HashSet::new();
+        for skill in &self.skills {
+            if skill.id.trim().is_empty() || skill.name.trim().is_empty() {
+                problems.push("skills must have an id and a name".to_string());
+            } else if !skill_ids.insert(skill.id.as_str()) {
+                problems.push(format!("skill id {:?} is not unique", skill.id));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(crate::a2a::error::A2AError::invalid_params(&format!(
+                "Invalid agent card: {}",
+                problems.join("; ")
+            )))
+        }
+    }
 }
 
 /// Represents a single, stateful operation or conversation between a client and an agent
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Task {
     /// A unique identifier (e.g. UUID) for the task, generated by the server for a new task
-    pub id: String,
+    pub id: TaskId,
     /// A server-generated unique identifier (e.g. UUID) for maintaining context across multiple related tasks or interactions
-    #[serde(rename = "context_id")]
+    #[serde(rename = "context_id", alias = "contextId")]
     pub context_id: String,
     /// The current status of the task, including its state and a descriptive message
     pub status: TaskStatus,
@@ -533,10 +808,32 @@ pub struct Task {
     pub kind: String,
 }
 
+/// Task metadata key holding the task's state transitions
+pub const STATUS_HISTORY_METADATA_KEY: &str = "status_history";
+
+/// A change of a task's state, recorded in `Task::status_history`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskStateTransition {
+    /// The state the task entered
+    pub state: TaskState,
+    /// An ISO 8601 datetime string indicating when the task entered the state
+    pub timestamp: String,
+}
+
+impl TaskStateTransition {
+    /// Creates a transition into the given status, timestamped now if the status has no timestamp
+    pub fn from_status(status: &TaskStatus) -> Self {
+        Self {
+            state: status.state.clone(),
+            timestamp: status.timestamp.clone().unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
+        }
+    }
+}
+
 impl Task {
     pub fn new(context_id: String, status: TaskStatus) -> Self {
         Self {
-            id: Uuid::new_v4().to_string(),
+            id: TaskId::new(),
             context_id,
             status,
             artifacts: None,
@@ -546,8 +843,24 @@ impl Task {
         }
     }
 
+    /// Every state the task has been in, oldest first
+    ///
+    /// Only recorded by agents with the `state_transition_history` capability,
+    /// under `STATUS_HISTORY_METADATA_KEY` in the task's metadata.
+    pub fn status_history(&self) -> Option<Vec<TaskStateTransition>> {
+        let value = self.metadata.as_ref()?.get(STATUS_HISTORY_METADATA_KEY)?;
+        serde_json::from_value(value.clone()).ok()
+    }
+
+    /// Replaces the task's recorded state transitions
+    pub fn set_status_history(&mut self, history: Vec<TaskStateTransition>) {
+        self.metadata
+            .get_or_insert_with(HashMap::new)
+            .insert(STATUS_HISTORY_METADATA_KEY.to_string(), serde_json::json!(history));
+    }
+
     pub fn with_task_id(mut self, task_id: String) -> Self {
-        self.id = task_id;
+        self.id = task_id.into();
         self
     }
 
@@ -571,10 +884,10 @@ impl Task {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskStatusUpdateEvent {
     /// The ID of the task that was updated
-    #[serde(rename = "task_id")]
+    #[serde(rename = "task_id", alias = "taskId")]
     pub task_id: String,
     /// The context ID associated with the task
-    #[serde(rename = "context_id")]
+    #[serde(rename = "context_id", alias = "contextId")]
     pub context_id: String,
     /// The new status of the task
     pub status: TaskStatus,
@@ -608,17 +921,17 @@ impl TaskStatusUpdateEvent {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskArtifactUpdateEvent {
     /// The ID of the task this artifact belongs to
-    #[serde(rename = "task_id")]
+    #[serde(rename = "task_id", alias = "taskId")]
     pub task_id: String,
     /// The context ID associated with the task
-    #[serde(rename = "context_id")]
+    #[serde(rename = "context_id", alias = "contextId")]
     pub context_id: String,
     /// The artifact that was generated or updated
     pub artifact: Artifact,
     /// If true, the content of this artifact should be appended to a previously sent artifact with the same ID
     pub append: Option<bool>,
     /// If true, this is the final chunk of the artifact
-    #[serde(rename = "last_chunk")]
+    #[serde(rename = "last_chunk", alias = "lastChunk")]
     pub last_chunk: Option<bool>,
     /// Optional metadata for extensions
     pub metadata: Option<HashMap<String, serde_json::Value>>,
@@ -721,10 +1034,10 @@ impl PushNotificationConfig {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskPushNotificationConfig {
     /// The unique identifier (e.g. UUID) of the task
-    #[serde(rename = "task_id")]
+    #[serde(rename = "task_id", alias = "taskId")]
     pub task_id: String,
     /// The push notification configuration for this task
-    #[serde(rename = "push_notification_config")]
+    #[serde(rename = "push_notification_config", alias = "pushNotificationConfig")]
     pub push_notification_config: PushNotificationConfig,
 }
 
@@ -741,15 +1054,15 @@ impl TaskPushNotificationConfig {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct MessageSendConfiguration {
     /// A list of output MIME types the client is prepared to accept in the response
-    #[serde(rename = "accepted_output_modes")]
+    #[serde(rename = "accepted_output_modes", alias = "acceptedOutputModes")]
     pub accepted_output_modes: Option<Vec<String>>,
     /// If true, the client will wait for the task to complete
     pub blocking: Option<bool>,
     /// The number of most recent messages from the task's history to retrieve in the response
-    #[serde(rename = "history_length")]
+    #[serde(rename = "history_length", alias = "historyLength")]
     pub history_length: Option<i32>,
     /// Configuration for the agent to send push notifications for updates after the initial response
-    #[serde(rename = "push_notification_config")]
+    #[serde(rename = "push_notification_config", alias = "pushNotificationConfig")]
     pub push_notification_config: Option<PushNotificationConfig>,
 }
 
@@ -804,6 +1117,11 @@ impl MessageSendParams {
         }
     }
 
+    /// Params for a user message with a single text part and a fresh `message_id`
+    pub fn from_text(prompt: &str) -> Self {
+        Self::new(Message::new(Role::User, vec![Part::text(prompt.to_string())]))
+    }
+
     pub fn with_configuration(mut self, configuration: MessageSendConfiguration) -> Self {
         self.configuration = Some(configuration);
         self
@@ -813,6 +1131,64 @@ impl MessageSendParams {
         self.metadata = Some(metadata);
         self
     }
+
+    /// Checks the message against the server's size limits
+    ///
+    /// Returns an `InvalidParamsError` naming the first limit exceeded.
+    pub fn validate(&self, limits: &MessageLimits) -> Result<(), crate::a2a::error::A2AError> {
+        let parts = &self.message.parts;
+        if let Some(max_parts) = limits.max_parts_per_message {
+            if parts.len() > max_parts {
+                return Err(crate::a2a::error::A2AError::invalid_params(&format!(
+                    "Message has {} parts, more than the maximum of {}",
+                    parts.len(),
+                    max_parts
+                )));
+            }
+        }
+        if let Some(max_bytes) = limits.max_part_bytes {
+            for (index, part) in parts.iter().enumerate() {
+                let size = part_size(part);
+                if size > max_bytes {
+                    return Err(crate::a2a::error::A2AError::invalid_params(&format!(
+                        "Part {} is {} bytes, more than the maximum of {}",
+                        index, size, max_bytes
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Limits on the size of messages a server accepts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MessageLimits {
+    /// Maximum number of parts in one message, unlimited if `None`
+    pub max_parts_per_message: Option<usize>,
+    /// Maximum size of one part in bytes, unlimited if `None`
+    ///
+    /// Text parts count their UTF-8 length, data and unknown parts their JSON
+    /// encoding and file parts their decoded content; files sent by URI count
+    /// as 0.
+    pub max_part_bytes: Option<usize>,
+}
+
+/// Returns the size of a part's content as counted by `MessageLimits`
+fn part_size(part: &Part) -> usize {
+    match part.root() {
+        PartRoot::Text(text) => text.text.len(),
+        PartRoot::Data(data) => serde_json::to_string(&data.data).map_or(0, |json| json.len()),
+        PartRoot::File(file) => match &file.file {
+            // The decoded length follows from the encoding without decoding it
+            FileContent::Bytes(bytes) => {
+                let symbols = bytes.bytes.bytes().filter(|b| !b.is_ascii_whitespace() && *b != b'=').count();
+                symbols * 3 / 4
+            }
+            FileContent::Uri(_) => 0,
+        },
+        PartRoot::Unknown(part) => serde_json::to_string(&part.value).map_or(0, |json| json.len()),
+    }
 }
 
 /// Defines parameters containing a task ID, used for simple task operations
@@ -838,13 +1214,32 @@ impl TaskIdParams {
     }
 }
 
+/// Defines parameters naming a context, used for operations on all of its tasks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContextIdParams {
+    /// The context identifier shared by the tasks of a conversation
+    #[serde(rename = "context_id", alias = "contextId")]
+    pub context_id: String,
+    /// Optional metadata associated with the request
+    pub metadata: Option<HashMap<String, serde_json::Value>>,
+}
+
+impl ContextIdParams {
+    pub fn new(context_id: String) -> Self {
+        Self {
+            context_id,
+            metadata: None,
+        }
+    }
+}
+
 /// Defines parameters for querying a task, with an option to limit history length
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TaskQueryParams {
     /// The unique identifier (e.g. UUID) of the task
     pub id: String,
     /// The number of most recent messages from the task's history to retrieve
-    #[serde(rename = "history_length")]
+    #[serde(rename = "history_length", alias = "historyLength")]
     pub history_length: Option<i32>,
     /// Optional metadata associated with the request
     pub metadata: Option<HashMap<String, serde_json::Value>>,
@@ -876,7 +1271,7 @@ pub struct DeleteTaskPushNotificationConfigParams {
     /// The unique identifier (e.g. UUID) of the task
     pub id: String,
     /// The ID of the push notification configuration to delete
-    #[serde(rename = "push_notification_config_id")]
+    #[serde(rename = "push_notification_config_id", alias = "pushNotificationConfigId")]
     pub push_notification_config_id: String,
     /// Optional metadata associated with the request
     pub metadata: Option<HashMap<String, serde_json::Value>>,
@@ -903,7 +1298,7 @@ pub struct GetTaskPushNotificationConfigParams {
     /// The unique identifier (e.g. UUID) of the task
     pub id: String,
     /// The ID of the push notification configuration to retrieve
-    #[serde(rename = "push_notification_config_id")]
+    #[serde(rename = "push_notification_config_id", alias = "pushNotificationConfigId")]
     pub push_notification_config_id: Option<String>,
     /// Optional metadata associated with the request
     pub metadata: Option<HashMap<String, serde_json::Value>>,
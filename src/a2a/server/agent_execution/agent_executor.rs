@@ -6,8 +6,8 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 use crate::a2a::server::agent_execution::RequestContext;
-use crate::a2a::server::events::{EventQueue, Event};
-use crate::{A2AError, TaskStatusUpdateEvent, TaskState, Message, Part, Role};
+use crate::a2a::server::events::EventQueue;
+use crate::A2AError;
 
 /// Agent Executor interface
 /// 
@@ -118,9 +118,6 @@ impl AgentExecutor for MockAgentExecutor {
         let task_id = context.task_id.clone().unwrap_or_else(|| "unknown".to_string());
         let context_id = context.context_id.clone().unwrap_or_else(|| "unknown".to_string());
 
-        // Get user input if available
-        let user_input = context.get_user_input(" ");
-
         // Create initial task status
         use crate::a2a::server::events::Event;
         use crate::TaskStatusUpdateEvent;
@@ -318,8 +315,8 @@ impl AgentExecutor for EchoAgentExecutor {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::a2a::server::events::InMemoryEventQueue;
-    use crate::{Part, Role};
+    use crate::a2a::server::events::{Event, InMemoryEventQueue};
+    use crate::{Message, Part, Role, TaskState};
 
     #[tokio::test]
     async fn test_mock_agent_executor_execute() {
@@ -422,7 +419,7 @@ mod tests {
     #[tokio::test]
     async fn test_echo_agent_executor() {
         let executor = EchoAgentExecutor::new();
-        let queue = Arc::new(InMemoryEventQueue::new().await.unwrap());
+        let queue = Arc::new(InMemoryEventQueue::new().unwrap());
         
         let message = Message::new(
             Role::User,
@@ -447,9 +444,9 @@ mod tests {
         assert!(result.is_ok());
 
         // Should have 3 events: Working status, Message, Completed status
-        let event1: crate::a2a::server::events::Event = queue.dequeue_event().await.unwrap();
-        let event2: crate::a2a::server::events::Event = queue.dequeue_event().await.unwrap();
-        let event3: crate::a2a::server::events::Event = queue.dequeue_event().await.unwrap();
+        let event1: crate::a2a::server::events::Event = queue.dequeue_event(false).await.unwrap();
+        let event2: crate::a2a::server::events::Event = queue.dequeue_event(false).await.unwrap();
+        let event3: crate::a2a::server::events::Event = queue.dequeue_event(false).await.unwrap();
 
         match &event1 {
             Event::TaskStatusUpdate(status) => {
@@ -482,7 +479,7 @@ mod tests {
     #[tokio::test]
     async fn test_echo_agent_executor_with_custom_prefix() {
         let executor = EchoAgentExecutor::with_prefix("Reply: ".to_string());
-        let queue = Arc::new(InMemoryEventQueue::new().await.unwrap());
+        let queue = Arc::new(InMemoryEventQueue::new().unwrap());
         
         let message = Message::new(
             Role::User,
@@ -506,9 +503,9 @@ mod tests {
         executor.execute(context, queue.clone()).await.unwrap();
 
         // Skip the first event (working status)
-        queue.dequeue_event().await.unwrap();
+        queue.dequeue_event(false).await.unwrap();
         
-        let event2: crate::a2a::server::events::Event = queue.dequeue_event().await.unwrap();
+        let event2: crate::a2a::server::events::Event = queue.dequeue_event(false).await.unwrap();
         match &event2 {
             Event::Message(message) => {
                 if let crate::PartRoot::Text(text_part) = &message.parts[0].root() {
@@ -10,6 +10,12 @@ use crate::a2a::server::id_generator::{IDGenerator, IDGeneratorContext, UUIDGene
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Default limit on how deep a chain of related tasks may grow
+pub const DEFAULT_MAX_RELATED_DEPTH: usize = 8;
+
+/// Task metadata key recording the chain of tasks a task was spawned from
+pub const RELATED_CHAIN_METADATA_KEY: &str = "related_chain";
+
 /// Request Context
 /// 
 /// Holds information about the current request being processed by the server,
@@ -31,6 +37,12 @@ pub struct RequestContext {
     /// A list of other tasks related to the current request (e.g., for tool use)
     pub related_tasks: Vec<Task>,
     
+    /// IDs of the tasks that spawned this request, outermost first
+    pub related_chain: Vec<String>,
+    
+    /// Maximum length of a chain of related tasks
+    pub max_related_depth: usize,
+    
     /// The server call context associated with this request
     pub call_context: Option<ServerCallContext>,
     
@@ -53,6 +65,7 @@ impl RequestContext {
     /// * `call_context` - The server call context associated with this request
     /// * `task_id_generator` - ID generator for new task IDs
     /// * `context_id_generator` - ID generator for new context IDs
+    #[allow(clippy::too_many_arguments)]
     pub async fn new(
         request: Option<MessageSendParams>,
         task_id: Option<String>,
@@ -72,6 +85,8 @@ impl RequestContext {
             context_id: context_id.clone(),
             current_task: task,
             related_tasks: related_tasks.unwrap_or_default(),
+            related_chain: Vec::new(),
+            max_related_depth: DEFAULT_MAX_RELATED_DEPTH,
             call_context,
             task_id_generator,
             context_id_generator,
@@ -84,17 +99,17 @@ impl RequestContext {
                 {
                     let params = context.request.as_mut().unwrap();
                     if let Some(ref message) = params.message.task_id {
-                        if message.to_string() != *task_id {
+                        if message != task_id {
                             return Err(A2AError::invalid_params("bad task id"));
                         }
                     } else {
-                        params.message.task_id = Some(uuid::Uuid::parse_str(task_id).map_err(|_| A2AError::invalid_params("invalid task id format"))?.to_string());
+                        params.message.task_id = Some(task_id.clone());
                     }
                 }
                 
                 // Validate against current task if present
                 if let Some(ref current_task) = context.current_task {
-                    if current_task.id.to_string() != *task_id {
+                    if current_task.id != *task_id {
                         return Err(A2AError::invalid_params("bad task id"));
                     }
                 }
@@ -118,7 +133,7 @@ impl RequestContext {
                 
                 // Validate against current task if present
                 if let Some(ref current_task) = context.current_task {
-                    if current_task.context_id.to_string() != *context_id {
+                    if current_task.context_id != *context_id {
                         return Err(A2AError::invalid_params("bad context id"));
                     }
                 }
@@ -148,15 +163,72 @@ impl RequestContext {
         }
     }
     
+    /// Extracts the user's text input, failing if there is none
+    /// 
+    /// For executors that cannot work without a prompt. Text parts are
+    /// joined with a space, as with `get_user_input(" ")`.
+    /// 
+    /// # Errors
+    /// Returns `InvalidParamsError` if there is no user message or its text
+    /// is empty or only whitespace.
+    pub fn require_user_input(&self) -> Result<String, A2AError> {
+        let input = self.get_user_input(" ");
+        if input.trim().is_empty() {
+            return Err(A2AError::invalid_params("Message must contain non-empty text input"));
+        }
+        Ok(input)
+    }
+    
     /// Attaches a related task to the context
     /// 
     /// This is useful for scenarios like tool execution where a new task
-    /// might be spawned.
+    /// might be spawned. Attaching fails if the task is the current task,
+    /// one of the tasks that spawned it, or already attached, since that
+    /// would form a cycle, and if the chain would grow beyond
+    /// `max_related_depth`.
     /// 
     /// # Arguments
     /// * `task` - The Task object to attach
-    pub fn attach_related_task(&mut self, task: Task) {
+    pub fn attach_related_task(&mut self, task: Task) -> Result<(), A2AError> {
+        let is_ancestor = self.task_id.as_deref() == Some(task.id.as_str()) || self.related_chain.iter().any(|id| *id == task.id);
+        if is_ancestor || self.related_tasks.iter().any(|related| related.id == task.id) {
+            return Err(A2AError::invalid_params(&format!(
+                "Related task {} would form a cycle (chain: {:?})",
+                task.id, self.child_related_chain()
+            )));
+        }
+        if self.related_depth() + 1 > self.max_related_depth {
+            return Err(A2AError::invalid_params(&format!(
+                "Related task {} would exceed max_related_depth of {} (chain: {:?})",
+                task.id, self.max_related_depth, self.child_related_chain()
+            )));
+        }
         self.related_tasks.push(task);
+        Ok(())
+    }
+    
+    /// Sets the chain of tasks that spawned this request
+    pub fn with_related_chain(mut self, chain: Vec<String>) -> Self {
+        self.related_chain = chain;
+        self
+    }
+    
+    /// Sets the maximum length of a chain of related tasks
+    pub fn with_max_related_depth(mut self, max_related_depth: usize) -> Self {
+        self.max_related_depth = max_related_depth;
+        self
+    }
+    
+    /// Number of tasks above this request in its chain of related tasks
+    pub fn related_depth(&self) -> usize {
+        self.related_chain.len()
+    }
+    
+    /// The chain to hand to requests for tasks attached to this context
+    pub fn child_related_chain(&self) -> Vec<String> {
+        let mut chain = self.related_chain.clone();
+        chain.extend(self.task_id.clone());
+        chain
     }
     
     /// Adds an extension to the set of activated extensions for this request
@@ -346,11 +418,11 @@ mod tests {
         };
         
         let task = Task {
-            id: Uuid::parse_str(&task_id).unwrap(),
-            context_id: Uuid::parse_str(&context_id).unwrap(),
+            id: task_id.clone().into(),
+            context_id: context_id.clone(),
             status: crate::TaskStatus {
                 state: TaskState::Working,
-                timestamp: Some(chrono::Utc::now()),
+                timestamp: Some(chrono::Utc::now().to_string()),
                 message: None,
             },
             artifacts: None,
@@ -422,6 +494,8 @@ mod tests {
             context_id: None,
             current_task: None,
             related_tasks: Vec::new(),
+            related_chain: Vec::new(),
+            max_related_depth: DEFAULT_MAX_RELATED_DEPTH,
             call_context: None,
             task_id_generator: Arc::new(UUIDGenerator::new()),
             context_id_generator: Arc::new(UUIDGenerator::new()),
@@ -431,6 +505,32 @@ mod tests {
         assert_eq!(context.get_user_input("\n"), "Hello\nWorld");
     }
 
+    #[test]
+    fn test_require_user_input() {
+        let context_with = |parts: Vec<Part>| RequestContext {
+            request: Some(MessageSendParams::new(Message::new(Role::User, parts))),
+            task_id: None,
+            context_id: None,
+            current_task: None,
+            related_tasks: Vec::new(),
+            related_chain: Vec::new(),
+            max_related_depth: DEFAULT_MAX_RELATED_DEPTH,
+            call_context: None,
+            task_id_generator: Arc::new(UUIDGenerator::new()),
+            context_id_generator: Arc::new(UUIDGenerator::new()),
+        };
+
+        let error = context_with(vec![]).require_user_input().unwrap_err();
+        assert!(matches!(error, A2AError::InvalidParams(_)));
+        let whitespace = context_with(vec![Part::text("  ".to_string()), Part::text("\n\t".to_string())]);
+        assert!(matches!(whitespace.require_user_input(), Err(A2AError::InvalidParams(_))));
+        let data_only = context_with(vec![Part::data(serde_json::json!({"k": "v"}))]);
+        assert!(matches!(data_only.require_user_input(), Err(A2AError::InvalidParams(_))));
+
+        let valid = context_with(vec![Part::text("Hello".to_string()), Part::text("World".to_string())]);
+        assert_eq!(valid.require_user_input().unwrap(), "Hello World");
+    }
+
     #[test]
     fn test_attach_related_task() {
         let mut context = RequestContext {
@@ -439,6 +539,8 @@ mod tests {
             context_id: None,
             current_task: None,
             related_tasks: Vec::new(),
+            related_chain: Vec::new(),
+            max_related_depth: DEFAULT_MAX_RELATED_DEPTH,
             call_context: None,
             task_id_generator: Arc::new(UUIDGenerator::new()),
             context_id_generator: Arc::new(UUIDGenerator::new()),
@@ -447,11 +549,11 @@ mod tests {
         assert!(context.related_tasks.is_empty());
         
         let task = Task {
-            id: Uuid::new_v4(),
-            context_id: Uuid::new_v4(),
+            id: Uuid::new_v4().into(),
+            context_id: Uuid::new_v4().to_string(),
             status: crate::TaskStatus {
                 state: TaskState::Working,
-                timestamp: Some(chrono::Utc::now()),
+                timestamp: Some(chrono::Utc::now().to_string()),
                 message: None,
             },
             artifacts: None,
@@ -460,14 +562,54 @@ mod tests {
             kind: "task".to_string(),
         };
         
-        context.attach_related_task(task);
+        context.attach_related_task(task).unwrap();
+        assert_eq!(context.related_tasks.len(), 1);
+    }
+
+    fn related_task(id: &str) -> Task {
+        Task::new("ctx-1".to_string(), crate::TaskStatus::new(TaskState::Working)).with_task_id(id.to_string())
+    }
+
+    async fn chained_context(task_id: &str, chain: Vec<String>) -> RequestContext {
+        RequestContext::new(None, Some(task_id.to_string()), Some("ctx-1".to_string()), None, None, None, None, None)
+            .await
+            .unwrap()
+            .with_related_chain(chain)
+            .with_max_related_depth(3)
+    }
+
+    #[tokio::test]
+    async fn test_related_task_chain_beyond_max_depth_fails() {
+        let mut context = chained_context("task-0", Vec::new()).await;
+        for depth in 1..=3 {
+            let child = format!("task-{}", depth);
+            context.attach_related_task(related_task(&child)).unwrap();
+            context = chained_context(&child, context.child_related_chain()).await;
+            assert_eq!(context.related_depth(), depth);
+        }
+
+        let error = context.attach_related_task(related_task("task-4")).unwrap_err();
+        assert!(error.message().contains("max_related_depth of 3"), "{}", error.message());
+        assert!(context.related_tasks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_related_task_cycle_fails() {
+        let mut context = chained_context("task-2", vec!["task-0".to_string(), "task-1".to_string()]).await;
+
+        for id in ["task-0", "task-2"] {
+            let error = context.attach_related_task(related_task(id)).unwrap_err();
+            assert!(error.message().contains("would form a cycle"), "{}", error.message());
+        }
+        context.attach_related_task(related_task("task-3")).unwrap();
+        assert!(context.attach_related_task(related_task("task-3")).is_err());
         assert_eq!(context.related_tasks.len(), 1);
     }
 
     #[test]
     fn test_add_activated_extension() {
         let user = AuthenticatedUser::new("user123".to_string());
-        let mut call_context = ServerCallContext::with_user(user);
+        let call_context = ServerCallContext::with_user(user);
         
         let mut context = RequestContext {
             request: None,
@@ -475,6 +617,8 @@ mod tests {
             context_id: None,
             current_task: None,
             related_tasks: Vec::new(),
+            related_chain: Vec::new(),
+            max_related_depth: DEFAULT_MAX_RELATED_DEPTH,
             call_context: Some(call_context),
             task_id_generator: Arc::new(UUIDGenerator::new()),
             context_id_generator: Arc::new(UUIDGenerator::new()),
@@ -498,6 +642,8 @@ mod tests {
             context_id: None,
             current_task: None,
             related_tasks: Vec::new(),
+            related_chain: Vec::new(),
+            max_related_depth: DEFAULT_MAX_RELATED_DEPTH,
             call_context: Some(call_context),
             task_id_generator: Arc::new(UUIDGenerator::new()),
             context_id_generator: Arc::new(UUIDGenerator::new()),
@@ -531,6 +677,8 @@ mod tests {
             context_id: None,
             current_task: None,
             related_tasks: Vec::new(),
+            related_chain: Vec::new(),
+            max_related_depth: DEFAULT_MAX_RELATED_DEPTH,
             call_context: None,
             task_id_generator: Arc::new(UUIDGenerator::new()),
             context_id_generator: Arc::new(UUIDGenerator::new()),
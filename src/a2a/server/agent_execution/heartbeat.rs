@@ -0,0 +1,88 @@
+//! Heartbeat events for long-running tasks
+//!
+//! Agents doing long work publish periodic heartbeats so clients can tell a
+//! slow agent from one that has gone away. A heartbeat is a non-final
+//! `working` status update whose metadata marks it as a heartbeat and may
+//! carry the agent's progress; see [`crate::a2a::utils::heartbeat`] for
+//! recognizing them.
+
+use crate::a2a::server::events::{Event, EventQueue};
+use crate::{A2AError, TaskState, TaskStatus, TaskStatusUpdateEvent};
+use serde_json::Value;
+use std::collections::HashMap;
+
+pub use crate::a2a::utils::heartbeat::{
+    heartbeat_progress, is_heartbeat, HEARTBEAT_METADATA_KEY, HEARTBEAT_PROGRESS_KEY,
+};
+
+/// Publishes a heartbeat for a task to the event queue
+///
+/// # Arguments
+/// * `queue` - The queue to publish the heartbeat to
+/// * `task_id` - The ID of the running task
+/// * `context_id` - The context ID of the running task
+/// * `progress` - Optional completed fraction of the work, from 0.0 to 1.0
+///
+/// # Returns
+/// * `Ok(())` - If the heartbeat was enqueued
+/// * `Err(A2AError)` - If `progress` is out of range or the queue is closed
+pub async fn emit_heartbeat(
+    queue: &dyn EventQueue,
+    task_id: &str,
+    context_id: &str,
+    progress: Option<f32>,
+) -> Result<(), A2AError> {
+    let mut metadata = HashMap::new();
+    metadata.insert(HEARTBEAT_METADATA_KEY.to_string(), Value::Bool(true));
+    if let Some(progress) = progress {
+        if !(0.0..=1.0).contains(&progress) {
+            return Err(A2AError::invalid_params(&format!(
+                "Heartbeat progress must be between 0.0 and 1.0, got {}",
+                progress
+            )));
+        }
+        metadata.insert(HEARTBEAT_PROGRESS_KEY.to_string(), Value::from(progress));
+    }
+
+    let mut update = TaskStatusUpdateEvent::new(
+        task_id.to_string(),
+        context_id.to_string(),
+        TaskStatus::new(TaskState::Working),
+        false,
+    );
+    update.metadata = Some(metadata);
+    queue.enqueue_event(Event::TaskStatusUpdate(update)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::server::events::InMemoryEventQueue;
+
+    #[tokio::test]
+    async fn test_emit_heartbeat_with_progress() {
+        let queue = InMemoryEventQueue::new().unwrap();
+        emit_heartbeat(&queue, "task-1", "ctx-1", Some(0.5)).await.unwrap();
+
+        match queue.dequeue_event(true).await.unwrap() {
+            Event::TaskStatusUpdate(update) => {
+                assert_eq!(update.task_id, "task-1");
+                assert_eq!(update.context_id, "ctx-1");
+                assert_eq!(update.status.state, TaskState::Working);
+                assert!(!update.r#final);
+                assert!(is_heartbeat(&update));
+                assert_eq!(heartbeat_progress(&update), Some(0.5));
+            }
+            other => panic!("Expected a status update, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_emit_heartbeat_rejects_out_of_range_progress() {
+        let queue = InMemoryEventQueue::new().unwrap();
+
+        assert!(emit_heartbeat(&queue, "task-1", "ctx-1", Some(1.5)).await.is_err());
+        assert!(emit_heartbeat(&queue, "task-1", "ctx-1", Some(f32::NAN)).await.is_err());
+        assert!(queue.dequeue_event(true).await.is_err());
+    }
+}
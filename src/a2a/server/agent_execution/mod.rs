@@ -5,6 +5,10 @@
 
 pub mod context;
 pub mod agent_executor;
+pub mod heartbeat;
+pub mod task_updates;
 
-pub use context::RequestContext;
+pub use context::{RequestContext, DEFAULT_MAX_RELATED_DEPTH, RELATED_CHAIN_METADATA_KEY};
 pub use agent_executor::AgentExecutor;
+pub use heartbeat::{emit_heartbeat, heartbeat_progress, is_heartbeat, HEARTBEAT_METADATA_KEY, HEARTBEAT_PROGRESS_KEY};
+pub use task_updates::{reject_task, require_auth, required_auth_scheme, AUTH_SCHEME_METADATA_KEY};
@@ -0,0 +1,128 @@
+//! Status updates for rejecting a task or pausing it for authentication
+//!
+//! An agent that will not perform a request rejects the task, which ends it
+//! in the final `rejected` state. An agent that needs the client to
+//! authenticate first moves the task to the non-final `auth-required` state;
+//! the status names the security scheme, from the agent card's
+//! `security_schemes`, the client should use before resuming the task.
+
+use crate::a2a::server::events::{Event, EventQueue};
+use crate::{A2AError, Message, Part, Role, TaskState, TaskStatus, TaskStatusUpdateEvent};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Metadata key naming the security scheme an `auth-required` task needs
+pub const AUTH_SCHEME_METADATA_KEY: &str = "auth_scheme";
+
+/// Publishes a final `rejected` status for a task
+///
+/// # Arguments
+/// * `queue` - The queue to publish the update to
+/// * `task_id` - The ID of the rejected task
+/// * `context_id` - The context ID of the rejected task
+/// * `reason` - Why the agent rejected the task, sent as the status message
+pub async fn reject_task(
+    queue: &dyn EventQueue,
+    task_id: &str,
+    context_id: &str,
+    reason: &str,
+) -> Result<(), A2AError> {
+    let message = status_message(task_id, context_id, reason);
+    let update = TaskStatusUpdateEvent::new(
+        task_id.to_string(),
+        context_id.to_string(),
+        TaskStatus::new(TaskState::Rejected).with_message(message),
+        true,
+    );
+    queue.enqueue_event(Event::TaskStatusUpdate(update)).await
+}
+
+/// Publishes a non-final `auth-required` status for a task
+///
+/// The scheme is set under `AUTH_SCHEME_METADATA_KEY` in the metadata of both
+/// the update and its status message, so it is also kept on the stored task.
+///
+/// # Arguments
+/// * `queue` - The queue to publish the update to
+/// * `task_id` - The ID of the paused task
+/// * `context_id` - The context ID of the paused task
+/// * `scheme` - Name of the security scheme the client must authenticate with
+pub async fn require_auth(
+    queue: &dyn EventQueue,
+    task_id: &str,
+    context_id: &str,
+    scheme: &str,
+) -> Result<(), A2AError> {
+    let metadata = HashMap::from([(AUTH_SCHEME_METADATA_KEY.to_string(), Value::String(scheme.to_string()))]);
+    let mut message = status_message(task_id, context_id, &format!("Authentication required: {}", scheme));
+    message.metadata = Some(metadata.clone());
+
+    let mut update = TaskStatusUpdateEvent::new(
+        task_id.to_string(),
+        context_id.to_string(),
+        TaskStatus::new(TaskState::AuthRequired).with_message(message),
+        false,
+    );
+    update.metadata = Some(metadata);
+    queue.enqueue_event(Event::TaskStatusUpdate(update)).await
+}
+
+/// Returns the security scheme an `auth-required` status asks for, if any
+pub fn required_auth_scheme(status: &TaskStatus) -> Option<&str> {
+    if status.state != TaskState::AuthRequired {
+        return None;
+    }
+    status
+        .message
+        .as_ref()?
+        .metadata
+        .as_ref()?
+        .get(AUTH_SCHEME_METADATA_KEY)
+        .and_then(Value::as_str)
+}
+
+fn status_message(task_id: &str, context_id: &str, text: &str) -> Message {
+    Message::new(Role::Agent, vec![Part::text(text.to_string())])
+        .with_task_id(task_id.to_string())
+        .with_context_id(context_id.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::server::events::InMemoryEventQueue;
+    use crate::a2a::utils::message::get_message_text;
+
+    async fn next_update(queue: &InMemoryEventQueue) -> TaskStatusUpdateEvent {
+        match queue.dequeue_event(true).await.unwrap() {
+            Event::TaskStatusUpdate(update) => update,
+            other => panic!("Expected a status update, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reject_task_is_final() {
+        let queue = InMemoryEventQueue::new().unwrap();
+        reject_task(&queue, "task-1", "ctx-1", "Request violates policy").await.unwrap();
+
+        let update = next_update(&queue).await;
+        assert_eq!(update.task_id, "task-1");
+        assert_eq!(update.status.state, TaskState::Rejected);
+        assert!(update.r#final);
+        let message = update.status.message.as_deref().unwrap();
+        assert_eq!(get_message_text(message, ""), "Request violates policy");
+        assert_eq!(required_auth_scheme(&update.status), None);
+    }
+
+    #[tokio::test]
+    async fn test_require_auth_carries_scheme() {
+        let queue = InMemoryEventQueue::new().unwrap();
+        require_auth(&queue, "task-1", "ctx-1", "oauth").await.unwrap();
+
+        let update = next_update(&queue).await;
+        assert_eq!(update.status.state, TaskState::AuthRequired);
+        assert!(!update.r#final);
+        assert_eq!(update.metadata.unwrap()[AUTH_SCHEME_METADATA_KEY], "oauth");
+        assert_eq!(required_auth_scheme(&update.status), Some("oauth"));
+    }
+}
@@ -4,12 +4,21 @@
 //! A2A protocol requests over HTTP/HTTPS.
 
 use crate::a2a::models::*;
-use crate::a2a::server::context::ServerCallContextBuilder;
+use crate::a2a::server::context::{parse_deadline, ServerCallContext, ServerCallContextBuilder};
+use crate::a2a::server::event_transform::EventTransform;
+use crate::a2a::server::health::HealthCheck;
+use crate::a2a::server::id_generator::{IDGenerator, UUIDGenerator};
+use crate::a2a::server::metrics::TaskMetrics;
+use crate::a2a::server::recorder::RequestRecorder;
 use crate::a2a::server::request_handlers::{RequestHandler, JSONRPCHandler};
+use crate::a2a::server::request_handlers::jsonrpc_handler::normalize_method_name;
+use crate::a2a::server::sse::encode_sse_frame;
 use crate::a2a::utils::constants::*;
+use crate::a2a::utils::redact::REDACTED;
+use crate::a2a::utils::telemetry;
 use axum::{
-    extract::{Request, State},
-    http::{HeaderMap, HeaderValue, StatusCode},
+    extract::{FromRequest, Path, Request, State},
+    http::{header::{ACCEPT, CONTENT_TYPE, ETAG, IF_NONE_MATCH, VARY, WWW_AUTHENTICATE}, HeaderMap, HeaderValue, StatusCode},
     response::{IntoResponse, Json, Response},
     routing::{get, post},
     Router,
@@ -23,7 +32,11 @@ use tower_http::{
     cors::{Any, CorsLayer},
     trace::TraceLayer,
 };
-use tracing::{error, info};
+use tracing::{error, info, Instrument};
+
+mod rejection;
+
+pub use rejection::{JsonRpcPayload, JsonRpcRejection};
 
 /// Server configuration
 #[derive(Debug, Clone)]
@@ -40,6 +53,125 @@ pub struct ServerConfig {
     pub max_content_length: Option<usize>,
     /// CORS configuration
     pub enable_cors: bool,
+    /// Whether to serve the `/healthz` and `/readyz` endpoints
+    pub enable_health: bool,
+    /// Whether the agent card is served as YAML to requests that prefer
+    /// `application/yaml` in their `Accept` header; off by default
+    pub enable_yaml_agent_card: bool,
+    /// Generator used for new task IDs across all requests
+    pub task_id_generator: Arc<dyn IDGenerator>,
+    /// Generator used for new context IDs across all requests
+    pub context_id_generator: Arc<dyn IDGenerator>,
+    /// How much error detail JSON-RPC error responses expose to callers
+    pub error_detail_level: ErrorDetailLevel,
+    /// Records every inbound JSON-RPC request for debugging, if set
+    pub request_recorder: Option<RequestRecorder>,
+    /// API key required to fetch the agent card, if set
+    pub agent_card_api_key: Option<AgentCardApiKey>,
+    /// Whether method names must match exactly
+    ///
+    /// When disabled, trailing slashes and letter case are ignored, so a
+    /// request for `Message/Send/` is handled as `message/send`.
+    pub strict_method_names: bool,
+}
+
+/// An API key protecting the agent card endpoint
+///
+/// Requests without the key in `header`, or with a different key, get a 401
+/// response naming the header in `WWW-Authenticate`, or `public_card` when
+/// one is set.
+#[derive(Clone)]
+pub struct AgentCardApiKey {
+    /// Name of the header carrying the key, e.g. `X-API-Key`
+    pub header: String,
+    /// The key callers must send
+    pub key: String,
+    /// Card served to callers without a valid key instead of rejecting them
+    pub public_card: Option<AgentCard>,
+}
+
+impl AgentCardApiKey {
+    /// Require `key` in the `header` request header
+    pub fn new(header: impl Into<String>, key: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            key: key.into(),
+            public_card: None,
+        }
+    }
+
+    /// Serve `card`, typically a minimal version of the full card, to callers without the key
+    pub fn with_public_card(mut self, card: AgentCard) -> Self {
+        self.public_card = Some(card);
+        self
+    }
+
+    /// Whether the request headers carry the expected key
+    fn accepts(&self, headers: &HeaderMap) -> bool {
+        let Some(sent) = headers.get(self.header.as_str()) else {
+            return false;
+        };
+        // Compare in constant time so the key cannot be guessed byte by byte
+        let (sent, expected) = (sent.as_bytes(), self.key.as_bytes());
+        sent.len() == expected.len() && sent.iter().zip(expected).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+    }
+
+    /// `WWW-Authenticate` challenge telling callers which header to send the key in
+    fn challenge(&self) -> String {
+        format!("ApiKey header=\"{}\"", self.header)
+    }
+}
+
+impl std::fmt::Debug for AgentCardApiKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AgentCardApiKey")
+            .field("header", &self.header)
+            .field("key", &REDACTED)
+            .field("public_card", &self.public_card)
+            .finish()
+    }
+}
+
+/// How much detail JSON-RPC error responses expose
+///
+/// Errors are always logged in full; this only controls what callers see.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ErrorDetailLevel {
+    /// Send the error message and its `data` unchanged
+    #[default]
+    Full,
+    /// Send the error message but omit `data`
+    MessageOnly,
+    /// Omit `data` and replace the messages of standard JSON-RPC errors,
+    /// which may carry parser or internal details, with generic ones
+    Minimal,
+}
+
+impl ErrorDetailLevel {
+    /// Returns the error as it should be sent to the caller
+    pub fn apply(&self, error: &crate::a2a::jsonrpc::JSONRPCError) -> crate::a2a::jsonrpc::JSONRPCError {
+        use crate::a2a::jsonrpc::standard_error_codes::*;
+
+        let mut error = error.clone();
+        if *self == ErrorDetailLevel::Full {
+            return error;
+        }
+        error.data = None;
+        if *self == ErrorDetailLevel::Minimal {
+            let generic = match error.code {
+                PARSE_ERROR => Some("Parse error"),
+                INVALID_REQUEST => Some("Invalid request"),
+                METHOD_NOT_FOUND => Some("Method not found"),
+                INVALID_PARAMS => Some("Invalid params"),
+                INTERNAL_ERROR => Some("Internal error"),
+                _ => None,
+            };
+            if let Some(message) = generic {
+                error.message = message.to_string();
+            }
+        }
+        error
+    }
 }
 
 impl Default for ServerConfig {
@@ -51,6 +183,14 @@ impl Default for ServerConfig {
             extended_agent_card_path: EXTENDED_AGENT_CARD_PATH.to_string(),
             max_content_length: Some(10 * 1024 * 1024), // 10MB
             enable_cors: true,
+            enable_health: false,
+            enable_yaml_agent_card: false,
+            task_id_generator: Arc::new(UUIDGenerator::new()),
+            context_id_generator: Arc::new(UUIDGenerator::new()),
+            error_detail_level: ErrorDetailLevel::default(),
+            request_recorder: None,
+            agent_card_api_key: None,
+            strict_method_names: true,
         }
     }
 }
@@ -62,6 +202,8 @@ struct ServerState {
     extended_agent_card: Option<AgentCard>,
     handler: Arc<JSONRPCHandler>,
     context_builder: Arc<dyn ServerCallContextBuilder>,
+    health_checks: Vec<Arc<dyn HealthCheck>>,
+    task_metrics: Option<TaskMetrics>,
     config: ServerConfig,
 }
 
@@ -96,6 +238,8 @@ impl A2AServer {
             extended_agent_card: None,
             handler,
             context_builder,
+            health_checks: Vec::new(),
+            task_metrics: None,
             config: ServerConfig::default(),
         };
 
@@ -113,6 +257,27 @@ impl A2AServer {
         self
     }
 
+    /// Add a dependency checked by the readiness endpoint
+    pub async fn with_health_check(self, check: Arc<dyn HealthCheck>) -> Self {
+        {
+            let mut state = self.state.write().await;
+            state.health_checks.push(check);
+        }
+        self
+    }
+
+    /// Serve per-task timings from `metrics` on the admin endpoint
+    ///
+    /// The endpoint is only routed when metrics are set, and answers callers
+    /// the context builder leaves anonymous with 401 Unauthorized.
+    pub async fn with_task_metrics(self, metrics: TaskMetrics) -> Self {
+        {
+            let mut state = self.state.write().await;
+            state.task_metrics = Some(metrics);
+        }
+        self
+    }
+
     /// Set the server configuration
     pub async fn with_config(self, config: ServerConfig) -> Self {
         {
@@ -145,6 +310,18 @@ impl A2AServer {
             );
         }
 
+        // Add health endpoints if enabled
+        if state.config.enable_health {
+            router = router
+                .route(HEALTH_PATH, get(get_health))
+                .route(READINESS_PATH, get(get_readiness));
+        }
+
+        // Add the task metrics admin endpoint if metrics are recorded
+        if state.task_metrics.is_some() {
+            router = router.route(TASK_METRICS_PATH, get(get_task_metrics));
+        }
+
         // Add CORS if enabled
         if state.config.enable_cors {
             router = router.layer(
@@ -189,6 +366,9 @@ pub struct A2AServerBuilder {
     request_handler: Option<Arc<dyn RequestHandler>>,
     context_builder: Option<Arc<dyn ServerCallContextBuilder>>,
     extended_agent_card: Option<AgentCard>,
+    health_checks: Vec<Arc<dyn HealthCheck>>,
+    task_metrics: Option<TaskMetrics>,
+    event_transforms: Vec<Arc<dyn EventTransform>>,
     config: ServerConfig,
 }
 
@@ -200,6 +380,9 @@ impl A2AServerBuilder {
             request_handler: None,
             context_builder: None,
             extended_agent_card: None,
+            health_checks: Vec::new(),
+            task_metrics: None,
+            event_transforms: Vec::new(),
             config: ServerConfig::default(),
         }
     }
@@ -228,6 +411,29 @@ impl A2AServerBuilder {
         self
     }
 
+    /// Add a dependency checked by the readiness endpoint
+    pub fn with_health_check(mut self, check: Arc<dyn HealthCheck>) -> Self {
+        self.health_checks.push(check);
+        self
+    }
+
+    /// Serve per-task timings from `metrics` on the admin endpoint
+    ///
+    /// The endpoint is only routed when metrics are set, and answers callers
+    /// the context builder leaves anonymous with 401 Unauthorized.
+    pub fn with_task_metrics(mut self, metrics: TaskMetrics) -> Self {
+        self.task_metrics = Some(metrics);
+        self
+    }
+
+    /// Rewrite or drop streamed events before they reach clients
+    ///
+    /// Transforms run in the order they were added.
+    pub fn with_event_transform(mut self, transform: Arc<dyn EventTransform>) -> Self {
+        self.event_transforms.push(transform);
+        self
+    }
+
     /// Set the server configuration
     pub fn with_config(mut self, config: ServerConfig) -> Self {
         self.config = config;
@@ -241,14 +447,18 @@ impl A2AServerBuilder {
         let context_builder = self.context_builder
             .ok_or("Context builder is required")?;
 
+        let mut handler = JSONRPCHandler::new(agent_card.clone(), request_handler);
+        for transform in self.event_transforms {
+            handler = handler.with_event_transform(transform);
+        }
+
         let state = ServerState {
             agent_card: agent_card.clone(),
             extended_agent_card: self.extended_agent_card,
-            handler: Arc::new(JSONRPCHandler::new(
-                agent_card.clone(),
-                request_handler,
-            )),
+            handler: Arc::new(handler),
             context_builder,
+            health_checks: self.health_checks,
+            task_metrics: self.task_metrics,
             config: self.config,
         };
 
@@ -265,10 +475,107 @@ impl Default for A2AServerBuilder {
 }
 
 /// HTTP handler for getting the agent card
+///
+/// Responses carry an `ETag` of the served representation, and requests whose
+/// `If-None-Match` still matches it get `304 Not Modified` without a body.
 async fn get_agent_card(
     State(state): State<ServerState>,
-) -> impl IntoResponse {
-    Json(serde_json::to_value(&state.agent_card).unwrap())
+    headers: HeaderMap,
+) -> Response {
+    let card = match &state.config.agent_card_api_key {
+        Some(api_key) if !api_key.accepts(&headers) => match &api_key.public_card {
+            Some(public_card) => public_card,
+            None => {
+                return (
+                    StatusCode::UNAUTHORIZED,
+                    [(WWW_AUTHENTICATE, api_key.challenge())],
+                    Json(serde_json::json!({ "error": "A valid API key is required to fetch the agent card." })),
+                )
+                    .into_response()
+            }
+        },
+        _ => &state.agent_card,
+    };
+    let mut content_type = "application/json";
+    let mut body = None;
+    if state.config.enable_yaml_agent_card && prefers_yaml(&headers) {
+        match serde_yaml_ng::to_string(card) {
+            Ok(yaml) => {
+                content_type = YAML_CONTENT_TYPE;
+                body = Some(yaml);
+            }
+            Err(e) => error!("Failed to serialize agent card as YAML: {}", e),
+        }
+    }
+    let body = match body {
+        Some(body) => body,
+        None => match serde_json::to_string(card) {
+            Ok(json) => json,
+            Err(e) => {
+                error!("Failed to serialize agent card: {}", e);
+                return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+            }
+        },
+    };
+
+    let etag = card_etag(&body);
+    let mut response = if etag_matches(&headers, &etag) {
+        StatusCode::NOT_MODIFIED.into_response()
+    } else {
+        ([(CONTENT_TYPE, content_type)], body).into_response()
+    };
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        response.headers_mut().insert(ETAG, value);
+    }
+    // Caches must key the card on the Accept header once it selects the format
+    if state.config.enable_yaml_agent_card {
+        response.headers_mut().append(VARY, HeaderValue::from_static("Accept"));
+    }
+    response
+}
+
+/// Strong `ETag` for a card representation, derived from its bytes
+fn card_etag(body: &str) -> String {
+    use base64::Engine;
+    let digest = ring::digest::digest(&ring::digest::SHA256, body.as_bytes());
+    format!("\"{}\"", base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(digest.as_ref()))
+}
+
+/// Whether an `If-None-Match` header lists `etag` or is `*`
+///
+/// Uses the weak comparison RFC 9110 prescribes for `If-None-Match`.
+fn etag_matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|candidate| candidate.trim())
+        .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+}
+
+/// Whether an `Accept` header ranks YAML above JSON
+///
+/// JSON wins ties and is the default for missing headers and wildcards.
+fn prefers_yaml(headers: &HeaderMap) -> bool {
+    let Some(accept) = headers.get(ACCEPT).and_then(|value| value.to_str().ok()) else {
+        return false;
+    };
+    let (mut json, mut yaml) = (0.0_f32, 0.0_f32);
+    for range in accept.split(',') {
+        let mut params = range.split(';');
+        let media_type = params.next().unwrap_or("").trim().to_ascii_lowercase();
+        let quality = params
+            .filter_map(|param| param.trim().strip_prefix("q="))
+            .find_map(|q| q.trim().parse::<f32>().ok())
+            .unwrap_or(1.0);
+        match media_type.as_str() {
+            "application/json" => json = json.max(quality),
+            "application/yaml" | "application/x-yaml" | "text/yaml" => yaml = yaml.max(quality),
+            _ => {}
+        }
+    }
+    yaml > json
 }
 
 /// HTTP handler for getting the authenticated extended agent card
@@ -296,6 +603,58 @@ async fn get_authenticated_extended_agent_card(
     }
 }
 
+/// HTTP handler for the liveness endpoint
+async fn get_health() -> impl IntoResponse {
+    (StatusCode::OK, Json(serde_json::json!({ "status": "ok" })))
+}
+
+/// HTTP handler for the readiness endpoint
+async fn get_readiness(
+    State(state): State<ServerState>,
+) -> impl IntoResponse {
+    let mut failures = serde_json::Map::new();
+    for check in &state.health_checks {
+        if let Err(e) = check.check().await {
+            error!("Health check {} failed: {}", check.name(), e);
+            failures.insert(check.name().to_string(), Value::String(e.to_string()));
+        }
+    }
+
+    if failures.is_empty() {
+        (StatusCode::OK, Json(serde_json::json!({ "status": "ready" })))
+    } else {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({ "status": "unavailable", "checks": failures })),
+        )
+    }
+}
+
+/// HTTP handler for the task metrics admin endpoint
+///
+/// Only callers the context builder identifies may read the timings.
+async fn get_task_metrics(
+    State(state): State<ServerState>,
+    headers: HeaderMap,
+    Path(task_id): Path<String>,
+) -> Response {
+    if state.context_builder.build(&headers).await.is_anonymous() {
+        return (
+            StatusCode::UNAUTHORIZED,
+            Json(serde_json::json!({ "error": "Authentication is required to read task metrics." })),
+        )
+            .into_response();
+    }
+    match state.task_metrics.as_ref().and_then(|metrics| metrics.get(&task_id)) {
+        Some(timings) => (StatusCode::OK, Json(timings)).into_response(),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": format!("No metrics recorded for task {}", task_id) })),
+        )
+            .into_response(),
+    }
+}
+
 /// HTTP handler for JSON-RPC requests
 async fn handle_jsonrpc_request(
     State(state): State<ServerState>,
@@ -313,52 +672,46 @@ async fn handle_jsonrpc_request(
                             crate::a2a::jsonrpc::standard_error_codes::INVALID_REQUEST,
                             "Payload too large".to_string(),
                         ),
+                        state.config.error_detail_level,
                     );
                 }
             }
         }
     }
 
-    // Parse request body
-    let body = match axum::body::to_bytes(request.into_body(), usize::MAX).await {
-        Ok(body) => body,
-        Err(e) => {
-            error!("Failed to read request body: {}", e);
-            return error_response(
-                None,
-                &crate::a2a::jsonrpc::JSONRPCError::new(
-                    crate::a2a::jsonrpc::standard_error_codes::INVALID_REQUEST,
-                    "Failed to read request body".to_string(),
-                ),
-            );
+    // Read and parse the body, answering failures with JSON-RPC errors
+    let mut json_value = match JsonRpcPayload::from_request(request, &state).await {
+        Ok(JsonRpcPayload(value)) => value,
+        Err(rejection) => {
+            error!("Rejected JSON-RPC request body: {:?}", rejection);
+            return error_response(None, &rejection.into(), state.config.error_detail_level);
         }
     };
 
-    // Parse JSON
-    let json_value: Value = match serde_json::from_slice(&body) {
-        Ok(value) => value,
-        Err(e) => {
-            error!("Failed to parse JSON: {}", e);
-            return error_response(
-                None,
-                &crate::a2a::jsonrpc::JSONRPCError::new(
-                    crate::a2a::jsonrpc::standard_error_codes::PARSE_ERROR,
-                    format!("Invalid JSON: {}", e),
-                ),
-            );
+    if let Some(recorder) = &state.config.request_recorder {
+        recorder.record(&headers, &json_value);
+    }
+
+    if !state.config.strict_method_names {
+        let method = json_value.get("method").and_then(Value::as_str).and_then(normalize_method_name);
+        if let (Some(method), Some(request)) = (method, json_value.as_object_mut()) {
+            request.insert("method".to_string(), Value::String(method.to_string()));
         }
-    };
+    }
 
     // Check if this is a streaming request
     let method = json_value.get("method").and_then(|m| m.as_str()).unwrap_or("");
-    let is_streaming = method == "message/stream";
+    let is_streaming = matches!(method, "message/stream" | "tasks/resubscribe");
+
+    // Continue the caller's trace, if any, for the duration of the request
+    let span = telemetry::request_span(method, &headers);
 
     if is_streaming {
         // Handle streaming request
-        handle_streaming_request(state, headers, json_value).await
+        handle_streaming_request(state, headers, json_value).instrument(span).await
     } else {
         // Handle non-streaming request
-        handle_non_streaming_request(state, headers, json_value).await
+        handle_non_streaming_request(state, headers, json_value).instrument(span).await
     }
 }
 
@@ -369,21 +722,24 @@ async fn handle_streaming_request(
     json_value: Value,
 ) -> Response {
     // Build server call context
-    let context = state.context_builder.build(&headers).await;
+    let mut context = build_call_context(&state, &headers).await;
+    activate_message_extensions(&state.agent_card, &json_value, &mut context);
 
     // Parse the JSON-RPC request to get the ID
     let jsonrpc_request = match state.handler.parse_request(json_value.clone()) {
         Ok(req) => req,
         Err(e) => {
-            return error_response(
-                None,
-                &e,
-            );
+            return error_response(None, &e, state.config.error_detail_level);
         }
     };
 
     // Get the streaming SSE stream
-    match state.handler.handle_message_stream_sse(jsonrpc_request, &context).await {
+    let sse_stream = if jsonrpc_request.method == "tasks/resubscribe" {
+        state.handler.handle_resubscribe_task_sse(jsonrpc_request, &context).await
+    } else {
+        state.handler.handle_message_stream_sse(jsonrpc_request, &context).await
+    };
+    match sse_stream {
         Ok(sse_stream) => {
             let mut response_headers = HeaderMap::new();
             
@@ -393,36 +749,31 @@ async fn handle_streaming_request(
             response_headers.insert("Connection", HeaderValue::from_static("keep-alive"));
             
             // Add extension headers if any
-            let extensions = context.get_activated_extensions();
-            if !extensions.is_empty() {
-                let ext_header = extensions.join(",");
-                response_headers.insert(
-                    "A2A-Extensions",
-                    HeaderValue::from_str(&ext_header).unwrap(),
-                );
+            if let Some(extensions) = extensions_header(&context) {
+                response_headers.insert(EXTENSIONS_HEADER, extensions);
             }
 
-            // Convert SSE stream to Axum response
-            let body_stream = sse_stream.map(|result| {
-                match result {
-                    Ok(sse_data) => Ok::<axum::body::Bytes, axum::Error>(axum::body::Bytes::from(sse_data)),
-                    Err(_) => Ok::<axum::body::Bytes, axum::Error>(axum::body::Bytes::from("data: {\"error\":\"Stream error\"}\n\n")),
-                }
+            // Convert SSE stream to Axum response, sending a failure mid-stream
+            // as a JSON-RPC error response event
+            let request_id = json_value.get("id").cloned();
+            let detail_level = state.config.error_detail_level;
+            let body_stream = sse_stream.map(move |result| {
+                let frame = match result {
+                    Ok(sse_data) => sse_data,
+                    Err(error) => {
+                        let response = jsonrpc_error_response(request_id.clone(), &error, detail_level);
+                        encode_sse_frame(&serde_json::to_string(&response).unwrap(), None)
+                    }
+                };
+                Ok::<axum::body::Bytes, axum::Error>(axum::body::Bytes::from(frame))
             });
 
-            let response = axum::response::Response::builder()
-                .status(StatusCode::OK)
-                .header("Content-Type", "text/event-stream")
-                .header("Cache-Control", "no-cache")
-                .header("Connection", "keep-alive")
-                .body(axum::body::Body::from_stream(body_stream))
-                .unwrap();
-
-            response
+            (StatusCode::OK, response_headers, axum::body::Body::from_stream(body_stream)).into_response()
         }
         Err(error) => error_response(
             json_value.get("id").cloned(),
             &error,
+            state.config.error_detail_level,
         ),
     }
 }
@@ -434,7 +785,8 @@ async fn handle_non_streaming_request(
     json_value: Value,
 ) -> Response {
     // Build server call context
-    let context = state.context_builder.build(&headers).await;
+    let mut context = build_call_context(&state, &headers).await;
+    activate_message_extensions(&state.agent_card, &json_value, &mut context);
 
     // Handle the request
     match state.handler.handle_request(json_value.clone(), &context).await {
@@ -442,27 +794,87 @@ async fn handle_non_streaming_request(
             let mut response_headers = HeaderMap::new();
             
             // Add extension headers if any
-            let extensions = context.get_activated_extensions();
-            if !extensions.is_empty() {
-                let ext_header = extensions.join(",");
-                response_headers.insert(
-                    "A2A-Extensions",
-                    HeaderValue::from_str(&ext_header).unwrap(),
-                );
+            if let Some(extensions) = extensions_header(&context) {
+                response_headers.insert(EXTENSIONS_HEADER, extensions);
             }
 
             (StatusCode::OK, response_headers, Json(response)).into_response()
         }
-        Err(error) => error_response(json_value.get("id").cloned(), &error),
+        Err(error) => error_response(json_value.get("id").cloned(), &error, state.config.error_detail_level),
+    }
+}
+
+/// Activates the extensions a message asks for that the agent card declares
+///
+/// `message/send` and `message/stream` requests name extensions per message
+/// in `Message.extensions`. Those listed in the card's capabilities are
+/// marked as requested and activated on the call context, where executors
+/// see them through `RequestContext`, and are echoed back in the
+/// `A2A-Extensions` response header. Other URIs are ignored.
+fn activate_message_extensions(card: &AgentCard, request: &Value, context: &mut ServerCallContext) {
+    let Some(requested) = request
+        .pointer("/params/message/extensions")
+        .and_then(Value::as_array)
+    else {
+        return;
+    };
+    let supported = card.capabilities.extensions.as_deref().unwrap_or_default();
+    for uri in requested.iter().filter_map(Value::as_str) {
+        if supported.iter().any(|extension| extension.uri == uri) {
+            context.add_requested_extension(uri.to_string());
+            context.add_activated_extension(uri.to_string());
+        }
+    }
+}
+
+/// The `A2A-Extensions` header listing the extensions activated for a request
+fn extensions_header(context: &ServerCallContext) -> Option<HeaderValue> {
+    let mut extensions = context.get_activated_extensions();
+    if extensions.is_empty() {
+        return None;
     }
+    extensions.sort();
+    HeaderValue::from_str(&extensions.join(",")).ok()
 }
 
-/// Create an error response
+/// Build the server call context for a request, applying server-wide settings
+async fn build_call_context(state: &ServerState, headers: &HeaderMap) -> ServerCallContext {
+    let mut context = state.context_builder.build(headers).await;
+    context.task_id_generator = Some(state.config.task_id_generator.clone());
+    context.context_id_generator = Some(state.config.context_id_generator.clone());
+    context.deadline = headers
+        .get(DEADLINE_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_deadline);
+    context
+}
+
+/// Create an error response, exposing as much detail as `detail_level` allows
 fn error_response(
     request_id: Option<Value>,
     error: &crate::a2a::jsonrpc::JSONRPCError,
+    detail_level: ErrorDetailLevel,
 ) -> Response {
-    let error_response = crate::a2a::jsonrpc::JSONRPCErrorResponse::new(
+    let error_response = jsonrpc_error_response(request_id, error, detail_level);
+
+    (
+        StatusCode::OK,
+        Json(serde_json::to_value(error_response).unwrap()),
+    )
+        .into_response()
+}
+
+/// Build the JSON-RPC error response for a request, with the detail level applied
+fn jsonrpc_error_response(
+    request_id: Option<Value>,
+    error: &crate::a2a::jsonrpc::JSONRPCError,
+    detail_level: ErrorDetailLevel,
+) -> crate::a2a::jsonrpc::JSONRPCErrorResponse {
+    let sent = detail_level.apply(error);
+    if sent != *error {
+        error!("JSON-RPC error {}: {} (data: {:?})", error.code, error.message, error.data);
+    }
+    crate::a2a::jsonrpc::JSONRPCErrorResponse::new(
         request_id.and_then(|id| {
             match id {
                 Value::String(s) => Some(crate::a2a::jsonrpc::JSONRPCId::String(s)),
@@ -471,12 +883,6 @@ fn error_response(
                 _ => None,
             }
         }),
-        error.clone(),
-    );
-
-    (
-        StatusCode::OK,
-        Json(serde_json::to_value(error_response).unwrap()),
+        sent,
     )
-        .into_response()
 }
@@ -0,0 +1,145 @@
+//! JSON-RPC aware request body extraction
+//!
+//! Axum answers extraction failures with plain-text 400 responses, which
+//! JSON-RPC clients cannot parse. The conversions here turn those failures
+//! into JSON-RPC errors: bodies that are not valid JSON become parse errors
+//! (`-32700`), and wrong content types or unreadable bodies become invalid
+//! requests (`-32600`).
+
+use crate::a2a::jsonrpc::{standard_error_codes, JSONRPCError, JSONRPCErrorResponse};
+use async_trait::async_trait;
+use axum::{
+    extract::{rejection::JsonRejection, FromRequest, Request},
+    http::header::CONTENT_TYPE,
+    response::{IntoResponse, Json, Response},
+};
+use serde_json::Value;
+
+/// JSON body of a JSON-RPC request
+///
+/// Unlike `axum::Json`, the body size is not capped here; the server applies
+/// its own `max_content_length` before extraction.
+#[derive(Debug, Clone)]
+pub struct JsonRpcPayload(pub Value);
+
+/// Why a JSON-RPC request body could not be extracted
+#[derive(Debug)]
+pub enum JsonRpcRejection {
+    /// The `Content-Type` header is missing or not a JSON media type
+    UnsupportedContentType(Option<String>),
+    /// The body could not be read
+    Body(axum::Error),
+    /// The body is not valid JSON
+    Parse(serde_json::Error),
+}
+
+impl From<axum::Error> for JsonRpcRejection {
+    fn from(error: axum::Error) -> Self {
+        Self::Body(error)
+    }
+}
+
+impl From<serde_json::Error> for JsonRpcRejection {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl From<JsonRpcRejection> for JSONRPCError {
+    fn from(rejection: JsonRpcRejection) -> Self {
+        match rejection {
+            JsonRpcRejection::UnsupportedContentType(Some(content_type)) => JSONRPCError::new(
+                standard_error_codes::INVALID_REQUEST,
+                format!("Unsupported content type: {}, expected application/json", content_type),
+            ),
+            JsonRpcRejection::UnsupportedContentType(None) => JSONRPCError::new(
+                standard_error_codes::INVALID_REQUEST,
+                "Missing content type, expected application/json".to_string(),
+            ),
+            JsonRpcRejection::Body(_) => JSONRPCError::new(
+                standard_error_codes::INVALID_REQUEST,
+                "Failed to read request body".to_string(),
+            ),
+            JsonRpcRejection::Parse(e) => {
+                JSONRPCError::new(standard_error_codes::PARSE_ERROR, format!("Invalid JSON: {}", e))
+            }
+        }
+    }
+}
+
+/// Answers with a JSON-RPC error response without a request id
+impl IntoResponse for JsonRpcRejection {
+    fn into_response(self) -> Response {
+        Json(JSONRPCErrorResponse::new(None, self.into())).into_response()
+    }
+}
+
+/// Maps rejections of `axum::Json` for routes that extract with it directly
+impl From<JsonRejection> for JSONRPCError {
+    fn from(rejection: JsonRejection) -> Self {
+        let code = match rejection {
+            JsonRejection::JsonSyntaxError(_) => standard_error_codes::PARSE_ERROR,
+            _ => standard_error_codes::INVALID_REQUEST,
+        };
+        JSONRPCError::new(code, rejection.body_text())
+    }
+}
+
+/// Whether a `Content-Type` value names JSON, e.g. `application/json` or
+/// `application/vnd.api+json`, with any parameters
+fn is_json_content_type(content_type: &str) -> bool {
+    let essence = content_type.split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+    match essence.split_once('/') {
+        Some(("application", subtype)) => subtype == "json" || subtype.ends_with("+json"),
+        _ => false,
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequest<S> for JsonRpcPayload {
+    type Rejection = JsonRpcRejection;
+
+    async fn from_request(request: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        let content_type = request
+            .headers()
+            .get(CONTENT_TYPE)
+            .map(|value| String::from_utf8_lossy(value.as_bytes()).into_owned());
+        match content_type {
+            Some(ref value) if is_json_content_type(value) => {}
+            other => return Err(JsonRpcRejection::UnsupportedContentType(other)),
+        }
+
+        let body = axum::body::to_bytes(request.into_body(), usize::MAX).await?;
+        Ok(Self(serde_json::from_slice(&body)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_json_content_type() {
+        assert!(is_json_content_type("application/json"));
+        assert!(is_json_content_type("Application/JSON; charset=utf-8"));
+        assert!(is_json_content_type("application/vnd.a2a+json"));
+        assert!(!is_json_content_type("text/plain"));
+        assert!(!is_json_content_type("application/jsonp"));
+        assert!(!is_json_content_type(""));
+    }
+
+    #[test]
+    fn test_rejection_error_codes() {
+        let parse = serde_json::from_slice::<Value>(b"{oops").unwrap_err();
+        let error = JSONRPCError::from(JsonRpcRejection::from(parse));
+        assert_eq!(error.code, standard_error_codes::PARSE_ERROR);
+        assert!(error.message.starts_with("Invalid JSON: "));
+
+        let error = JSONRPCError::from(JsonRpcRejection::UnsupportedContentType(Some("text/plain".to_string())));
+        assert_eq!(error.code, standard_error_codes::INVALID_REQUEST);
+        assert!(error.message.contains("text/plain"));
+
+        let error = JSONRPCError::from(JsonRpcRejection::UnsupportedContentType(None));
+        assert_eq!(error.code, standard_error_codes::INVALID_REQUEST);
+    }
+}
@@ -6,6 +6,10 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use crate::a2a::auth::user::User;
+use crate::a2a::server::id_generator::IDGenerator;
 
 /// Trait for building server call contexts from HTTP requests
 #[async_trait]
@@ -24,6 +28,19 @@ impl ServerCallContextBuilder for DefaultServerCallContextBuilder {
     }
 }
 
+/// How the server treats a message whose `message_id` was already seen in its context
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DuplicateMessagePolicy {
+    /// Reject the message with an invalid params error
+    #[default]
+    Reject,
+    /// Return the task that already holds the message without processing it
+    /// again, provided the message is a retry with the same content; a
+    /// different message reusing the ID is still rejected
+    Ignore,
+}
+
 /// Server Call Context
 /// 
 /// A context passed when calling a server method.
@@ -45,6 +62,18 @@ pub struct ServerCallContext {
     /// Set of extensions that were activated for this request
     #[serde(default, skip_serializing_if = "std::collections::HashSet::is_empty")]
     pub activated_extensions: std::collections::HashSet<String>,
+
+    /// Server-wide generator for new task IDs, if configured
+    #[serde(skip)]
+    pub task_id_generator: Option<Arc<dyn IDGenerator>>,
+
+    /// Server-wide generator for new context IDs, if configured
+    #[serde(skip)]
+    pub context_id_generator: Option<Arc<dyn IDGenerator>>,
+
+    /// When the client stops waiting for the response, from `X-A2A-Deadline`
+    #[serde(skip)]
+    pub deadline: Option<Instant>,
 }
 
 impl Default for ServerCallContext {
@@ -54,6 +83,9 @@ impl Default for ServerCallContext {
             user: crate::a2a::auth::user::AuthenticatedUser::default(),
             requested_extensions: std::collections::HashSet::new(),
             activated_extensions: std::collections::HashSet::new(),
+            task_id_generator: None,
+            context_id_generator: None,
+            deadline: None,
         }
     }
 }
@@ -72,6 +104,11 @@ impl ServerCallContext {
         }
     }
 
+    /// Returns whether the call carries no user, i.e. the default unnamed one
+    pub fn is_anonymous(&self) -> bool {
+        self.user.user_name().is_empty()
+    }
+
     /// Adds a state value to the context
     pub fn set_state(&mut self, key: String, value: serde_json::Value) {
         self.state.insert(key, value);
@@ -118,11 +155,48 @@ impl ServerCallContext {
     }
 }
 
+/// Parses an `X-A2A-Deadline` header value into the instant it refers to
+///
+/// Accepts the milliseconds remaining or an absolute RFC 3339 time. Times
+/// in the past yield the current instant.
+pub fn parse_deadline(value: &str) -> Option<Instant> {
+    let value = value.trim();
+    let remaining = match value.parse::<u64>() {
+        Ok(millis) => Duration::from_millis(millis),
+        Err(_) => {
+            let deadline = chrono::DateTime::parse_from_rfc3339(value).ok()?;
+            (deadline.with_timezone(&chrono::Utc) - chrono::Utc::now())
+                .to_std()
+                .unwrap_or(Duration::ZERO)
+        }
+    };
+    Instant::now().checked_add(remaining)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::a2a::auth::user::{AuthenticatedUser};
 
+    #[test]
+    fn test_parse_deadline() {
+        let now = Instant::now();
+        let deadline = parse_deadline("1500").unwrap();
+        assert!(deadline >= now + Duration::from_millis(1500));
+        assert!(deadline <= Instant::now() + Duration::from_millis(1500));
+
+        let in_two_seconds = (chrono::Utc::now() + chrono::Duration::seconds(2)).to_rfc3339();
+        let deadline = parse_deadline(&in_two_seconds).unwrap();
+        assert!(deadline > now + Duration::from_secs(1));
+        assert!(deadline <= Instant::now() + Duration::from_secs(2));
+
+        let passed = parse_deadline("2001-01-01T00:00:00Z").unwrap();
+        assert!(passed <= Instant::now());
+
+        assert!(parse_deadline("soon").is_none());
+        assert!(parse_deadline("-5").is_none());
+    }
+
     #[test]
     fn test_server_call_context_default() {
         let context = ServerCallContext::default();
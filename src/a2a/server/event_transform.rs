@@ -0,0 +1,52 @@
+//! Server-side transformation of streamed events
+//!
+//! This module defines the EventTransform trait, registered on the server
+//! with `A2AServerBuilder::with_event_transform`. Transforms see every event
+//! of a `message/stream` response before it is sent, so operators can redact
+//! or enrich events, or drop them altogether.
+
+use crate::a2a::error::A2AError;
+use crate::a2a::server::context::ServerCallContext;
+use crate::a2a::server::request_handlers::request_handler::Event;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use std::sync::Arc;
+
+/// Rewrites events before they are streamed to the client
+pub trait EventTransform: Send + Sync {
+    /// Returns the event to send in place of `event`, or `None` to drop it
+    fn transform(&self, event: Event, ctx: &ServerCallContext) -> Option<Event>;
+}
+
+impl<F> EventTransform for F
+where
+    F: Fn(Event, &ServerCallContext) -> Option<Event> + Send + Sync,
+{
+    fn transform(&self, event: Event, ctx: &ServerCallContext) -> Option<Event> {
+        self(event, ctx)
+    }
+}
+
+/// Applies `transforms`, in order, to every event of `events`
+///
+/// An event dropped by one transform is not passed to the next. Errors are
+/// passed through unchanged.
+pub fn apply_event_transforms(
+    events: BoxStream<'static, Result<Event, A2AError>>,
+    transforms: Vec<Arc<dyn EventTransform>>,
+    ctx: ServerCallContext,
+) -> BoxStream<'static, Result<Event, A2AError>> {
+    if transforms.is_empty() {
+        return events;
+    }
+    Box::pin(events.filter_map(move |result| {
+        let result = match result {
+            Ok(event) => transforms
+                .iter()
+                .try_fold(event, |event, transform| transform.transform(event, &ctx))
+                .map(Ok),
+            Err(e) => Some(Err(e)),
+        };
+        futures::future::ready(result)
+    }))
+}
@@ -8,6 +8,7 @@ use crate::a2a::server::events::{Event, EventQueue};
 use async_trait::async_trait;
 use std::pin::Pin;
 use std::sync::Arc;
+use futures::stream::BoxStream;
 use futures::Stream;
 
 /// Consumer for events from an event queue
@@ -41,6 +42,38 @@ impl EventConsumer {
     pub async fn try_consume_one(&self) -> Result<Event, A2AError> {
         self.queue.dequeue_event(true).await
     }
+
+    /// Consume all events until the agent's turn ends
+    ///
+    /// The stream yields each event as it is dequeued. After a final event
+    /// (see `Event::is_final`) the queue is closed and the stream ends; it
+    /// also ends once a queue closed by the producer has been drained.
+    pub fn consume_all(&self) -> BoxStream<'static, Result<Event, A2AError>> {
+        let queue = self.queue.clone();
+        Box::pin(async_stream::stream! {
+            loop {
+                match queue.dequeue_event(false).await {
+                    Ok(event) => {
+                        let is_final = event.is_final();
+                        queue.task_done();
+                        yield Ok(event);
+                        if is_final {
+                            if let Err(e) = queue.close(false).await {
+                                tracing::warn!("Failed to close event queue: {}", e);
+                            }
+                            break;
+                        }
+                    }
+                    // A closed and drained queue means the producer is done
+                    Err(_) if queue.is_closed() => break,
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                }
+            }
+        })
+    }
 }
 
 /// Trait for event processing strategies
@@ -81,6 +114,8 @@ mod tests {
     use super::*;
     use crate::a2a::server::events::InMemoryEventQueue;
     use crate::a2a::core_types::*;
+    use crate::a2a::models::TaskStatusUpdateEvent;
+    use futures::StreamExt;
 
     #[tokio::test]
     async fn test_event_consumer() {
@@ -91,6 +126,42 @@ mod tests {
         assert_eq!(consumer.queue().size(), 0);
     }
 
+    #[tokio::test]
+    async fn test_consume_all_ends_after_final_event() {
+        let queue: Arc<dyn EventQueue> = Arc::new(InMemoryEventQueue::new().unwrap());
+        let consumer = EventConsumer::new(queue.clone());
+
+        let producer = tokio::spawn({
+            let queue = queue.clone();
+            async move {
+                for (state, is_final) in [(TaskState::Working, false), (TaskState::Completed, true)] {
+                    tokio::task::yield_now().await;
+                    let update = TaskStatusUpdateEvent::new(
+                        "task-1".to_string(),
+                        "ctx-1".to_string(),
+                        TaskStatus::new(state),
+                        is_final,
+                    );
+                    queue.enqueue_event(Event::TaskStatusUpdate(update)).await.unwrap();
+                }
+            }
+        });
+
+        let mut stream = consumer.consume_all();
+        let mut states = Vec::new();
+        while let Some(event) = stream.next().await {
+            match event.unwrap() {
+                Event::TaskStatusUpdate(update) => states.push(update.status.state),
+                other => panic!("Unexpected event: {:?}", other),
+            }
+        }
+        producer.await.unwrap();
+
+        assert_eq!(states, vec![TaskState::Working, TaskState::Completed]);
+        assert!(stream.next().await.is_none());
+        assert!(queue.is_closed());
+    }
+
     struct TestProcessor {
         events_processed: Arc<std::sync::atomic::AtomicUsize>,
     }
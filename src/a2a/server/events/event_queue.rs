@@ -27,11 +27,30 @@ pub enum Event {
     TaskArtifactUpdate(TaskArtifactUpdateEvent),
 }
 
+impl Event {
+    /// Returns whether this event ends the agent's turn
+    ///
+    /// A status update marked `final` and a direct message reply are final;
+    /// no further events are expected on the queue after them.
+    pub fn is_final(&self) -> bool {
+        match self {
+            Event::TaskStatusUpdate(update) => update.r#final,
+            Event::Message(_) => true,
+            Event::Task(_) | Event::TaskArtifactUpdate(_) => false,
+        }
+    }
+}
+
 
 /// Trait for event queues that handle asynchronous event processing
 #[async_trait]
 pub trait EventQueue: Send + Sync {
     /// Enqueue an event to this queue and all its children
+    ///
+    /// Queues are FIFO: events are dequeued in the order their enqueue calls
+    /// completed, and children observe the same order as their parent. Events
+    /// from a single producer therefore keep their relative order even when
+    /// several producers enqueue concurrently.
     async fn enqueue_event(&self, event: Event) -> Result<(), A2AError>;
 
     /// Dequeue an event from the queue
@@ -55,29 +55,30 @@ impl InMemoryEventQueue {
     }
 
     /// Internal method to add an event to the queue
+    ///
+    /// The queue lock is held until the event has also been broadcast, so
+    /// concurrent enqueues are serialized into a single order that this queue
+    /// and all of its children observe.
     async fn push_internal(&self, event: Event) -> Result<(), A2AError> {
+        let mut queue = self.queue.lock().await;
         if self.is_closed.load(Ordering::Relaxed) {
             return Err(QueueError::Closed.into());
         }
-
-        {
-            let mut queue = self.queue.lock().await;
-            if queue.len() >= self.max_size {
-                return Err(QueueError::Full.into());
-            }
-
-            queue.push_back(event.clone());
+        if queue.len() >= self.max_size {
+            return Err(QueueError::Full.into());
         }
 
+        queue.push_back(event.clone());
         self.current_size.fetch_add(1, Ordering::Relaxed);
-        self.notifier.notify_one();
 
         // Send to child queues
         if let Err(e) = self.event_sender.send(event) {
             // This happens when there are no receivers, which is fine
             tracing::debug!("No child queues to receive event: {}", e);
         }
+        drop(queue);
 
+        self.notifier.notify_one();
         Ok(())
     }
 
@@ -147,7 +148,11 @@ impl EventQueue for InMemoryEventQueue {
             return Ok(());
         }
 
-        self.is_closed.store(true, Ordering::Relaxed);
+        {
+            // Taken so no enqueue is in flight while the queue closes
+            let _queue = self.queue.lock().await;
+            self.is_closed.store(true, Ordering::Relaxed);
+        }
 
         if immediate {
             self.clear_events().await?;
@@ -379,6 +384,63 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn numbered_event(producer: usize, seq: usize) -> Event {
+        Event::Message(Message::new(
+            Role::Agent,
+            vec![Part::text(format!("{}:{}", producer, seq))],
+        ))
+    }
+
+    fn event_number(event: &Event) -> (usize, usize) {
+        let Event::Message(message) = event else {
+            panic!("Expected Message event");
+        };
+        let text = crate::a2a::utils::message::get_message_text(message, "");
+        let (producer, seq) = text.split_once(':').unwrap();
+        (producer.parse().unwrap(), seq.parse().unwrap())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_enqueue_preserves_order() {
+        const PRODUCERS: usize = 8;
+        const EVENTS_PER_PRODUCER: usize = 100;
+
+        let queue = Arc::new(InMemoryEventQueue::new().unwrap());
+        let child = queue.tap();
+
+        let producers: Vec<_> = (0..PRODUCERS)
+            .map(|producer| {
+                let queue = queue.clone();
+                tokio::spawn(async move {
+                    for seq in 0..EVENTS_PER_PRODUCER {
+                        queue.enqueue_event(numbered_event(producer, seq)).await.unwrap();
+                        tokio::task::yield_now().await;
+                    }
+                })
+            })
+            .collect();
+        for producer in producers {
+            producer.await.unwrap();
+        }
+
+        let total = PRODUCERS * EVENTS_PER_PRODUCER;
+        let mut parent_order = Vec::with_capacity(total);
+        let mut child_order = Vec::with_capacity(total);
+        for _ in 0..total {
+            parent_order.push(event_number(&queue.dequeue_event(true).await.unwrap()));
+            child_order.push(event_number(&child.dequeue_event(true).await.unwrap()));
+        }
+
+        // Each producer's events arrive in the order they were sent
+        let mut next_seq = [0; PRODUCERS];
+        for (producer, seq) in &parent_order {
+            assert_eq!(*seq, next_seq[*producer]);
+            next_seq[*producer] += 1;
+        }
+        // Children observe the same total order as the parent
+        assert_eq!(parent_order, child_order);
+    }
+
     #[tokio::test]
     async fn test_no_wait_dequeue() {
         let queue = InMemoryEventQueue::new().unwrap();
@@ -8,6 +8,7 @@ use crate::a2a::server::events::{
     EventQueue, QueueManager, QueueManagerConfig, QueueManagerError, 
     InMemoryEventQueue, validate_queue_id
 };
+use crate::a2a::server::health::HealthCheck;
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
@@ -134,6 +135,11 @@ impl QueueManager for InMemoryQueueManager {
         self.create_queue_internal(id).await
     }
 
+    async fn get(&self, id: &str) -> Result<Option<Arc<dyn EventQueue>>, A2AError> {
+        validate_queue_id(id)?;
+        Ok(self.queues.read().unwrap().get(id).cloned())
+    }
+
     async fn tap(&self, id: &str) -> Result<Option<Arc<dyn EventQueue>>, A2AError> {
         validate_queue_id(id)?;
 
@@ -200,6 +206,20 @@ impl QueueManager for InMemoryQueueManager {
     }
 }
 
+#[async_trait]
+impl HealthCheck for InMemoryQueueManager {
+    fn name(&self) -> &str {
+        "queue_manager"
+    }
+
+    async fn check(&self) -> Result<(), A2AError> {
+        self.queues
+            .read()
+            .map(|_| ())
+            .map_err(|_| A2AError::internal("Queue manager lock poisoned"))
+    }
+}
+
 impl Default for InMemoryQueueManager {
     fn default() -> Self {
         Self::new().unwrap()
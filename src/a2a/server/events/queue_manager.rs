@@ -17,6 +17,14 @@ pub trait QueueManager: Send + Sync {
     /// Create a new event queue or tap into an existing one
     async fn create_or_tap(&self, id: &str) -> Result<Arc<dyn EventQueue>, A2AError>;
 
+    /// Get an existing event queue itself, to publish events on it
+    ///
+    /// Unlike `tap`, events enqueued on the returned queue reach all of its
+    /// consumers. The default implementation does not support this.
+    async fn get(&self, _id: &str) -> Result<Option<Arc<dyn EventQueue>>, A2AError> {
+        Err(A2AError::unsupported_operation("Getting an event queue not supported"))
+    }
+
     /// Tap into an existing event queue
     async fn tap(&self, id: &str) -> Result<Option<Arc<dyn EventQueue>>, A2AError>;
 
@@ -0,0 +1,18 @@
+//! Health checks for server dependencies
+//!
+//! This module defines the HealthCheck trait used by the server's readiness
+//! endpoint. Backends such as task stores and queue managers implement it so
+//! that `/readyz` only reports ready when all of them are usable.
+
+use async_trait::async_trait;
+use crate::A2AError;
+
+/// A dependency whose health can be probed by the readiness endpoint
+#[async_trait]
+pub trait HealthCheck: Send + Sync {
+    /// Name reported for this dependency in readiness responses
+    fn name(&self) -> &str;
+
+    /// Returns `Ok(())` if the dependency is ready to serve requests
+    async fn check(&self) -> Result<(), A2AError>;
+}
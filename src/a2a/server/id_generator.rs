@@ -9,7 +9,7 @@ use std::sync::Arc;
 use uuid::Uuid;
 
 /// Context for providing additional information to ID generators
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct IDGeneratorContext {
     /// Optional task ID
     pub task_id: Option<String>,
@@ -17,15 +17,6 @@ pub struct IDGeneratorContext {
     pub context_id: Option<String>,
 }
 
-impl Default for IDGeneratorContext {
-    fn default() -> Self {
-        Self {
-            task_id: None,
-            context_id: None,
-        }
-    }
-}
-
 impl IDGeneratorContext {
     /// Creates a new IDGeneratorContext
     pub fn new() -> Self {
@@ -70,6 +61,13 @@ pub trait IDGenerator: Send + Sync {
     async fn generate(&self, context: &IDGeneratorContext) -> Result<String, crate::A2AError>;
 }
 
+/// Lets structs holding a generator derive `Debug` without requiring it of implementors
+impl std::fmt::Debug for dyn IDGenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("dyn IDGenerator")
+    }
+}
+
 /// UUID implementation of the IDGenerator interface
 #[derive(Debug, Clone)]
 pub struct UUIDGenerator;
@@ -294,4 +292,22 @@ mod tests {
             assert!(unique_ids.insert(id.clone()), "Duplicate ID found: {}", id);
         }
     }
+
+    #[test]
+    fn test_generators_need_not_implement_debug() {
+        struct FixedGenerator;
+
+        #[async_trait]
+        impl IDGenerator for FixedGenerator {
+            async fn generate(&self, _context: &IDGeneratorContext) -> Result<String, crate::A2AError> {
+                Ok("fixed".to_string())
+            }
+        }
+
+        let context = crate::a2a::server::context::ServerCallContext {
+            task_id_generator: Some(Arc::new(FixedGenerator)),
+            ..Default::default()
+        };
+        assert!(format!("{:?}", context).contains("dyn IDGenerator"));
+    }
 }
@@ -0,0 +1,219 @@
+//! Per-task timing metrics
+//!
+//! `TaskMetrics` records, for each task the agent runs, how long it took to
+//! publish its first event and to reach a terminal state, along with the
+//! number of events published. Recent timings can be queried through the
+//! server's admin endpoint.
+//!
+//! With the `metrics` feature, both durations are also recorded as
+//! OpenTelemetry histograms on the global meter provider.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Number of tasks whose timings are kept by default
+pub const DEFAULT_MAX_TRACKED_TASKS: usize = 1000;
+
+/// Timings recorded for one task
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskTimings {
+    pub task_id: String,
+    /// Milliseconds from the start of execution to the first event
+    pub first_event_ms: Option<f64>,
+    /// Milliseconds from the start of execution to the terminal event
+    pub total_ms: Option<f64>,
+    /// Number of events the agent published
+    pub event_count: usize,
+}
+
+struct TaskRecord {
+    started: Instant,
+    timings: TaskTimings,
+}
+
+#[derive(Default)]
+struct Records {
+    tasks: HashMap<String, TaskRecord>,
+    order: VecDeque<String>,
+}
+
+/// Records event throughput and latency of running tasks
+///
+/// Cloning is cheap and clones share the same records, so one instance can
+/// be given to both the request handler and the server.
+#[derive(Clone)]
+pub struct TaskMetrics {
+    records: Arc<Mutex<Records>>,
+    max_tasks: usize,
+}
+
+impl TaskMetrics {
+    /// Create a recorder keeping the last `DEFAULT_MAX_TRACKED_TASKS` tasks
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_MAX_TRACKED_TASKS)
+    }
+
+    /// Create a recorder keeping timings for at most `max_tasks` tasks
+    ///
+    /// The oldest tasks are forgotten first.
+    pub fn with_capacity(max_tasks: usize) -> Self {
+        Self {
+            records: Arc::new(Mutex::new(Records::default())),
+            max_tasks: max_tasks.max(1),
+        }
+    }
+
+    /// Mark the start of a task's execution, resetting earlier timings
+    pub fn start(&self, task_id: &str) {
+        let mut records = self.records.lock().unwrap();
+        let record = TaskRecord {
+            started: Instant::now(),
+            timings: TaskTimings {
+                task_id: task_id.to_string(),
+                first_event_ms: None,
+                total_ms: None,
+                event_count: 0,
+            },
+        };
+        if records.tasks.insert(task_id.to_string(), record).is_some() {
+            records.order.retain(|id| id != task_id);
+        }
+        records.order.push_back(task_id.to_string());
+        while records.order.len() > self.max_tasks {
+            if let Some(oldest) = records.order.pop_front() {
+                records.tasks.remove(&oldest);
+            }
+        }
+    }
+
+    /// Record one event published for a task
+    ///
+    /// `terminal` marks the event that ended the task. Events of tasks that
+    /// were never started are ignored.
+    pub fn record_event(&self, task_id: &str, terminal: bool) {
+        let mut records = self.records.lock().unwrap();
+        let Some(record) = records.tasks.get_mut(task_id) else {
+            return;
+        };
+        let elapsed_ms = record.started.elapsed().as_secs_f64() * 1000.0;
+        let timings = &mut record.timings;
+        timings.event_count += 1;
+        if timings.first_event_ms.is_none() {
+            timings.first_event_ms = Some(elapsed_ms);
+            #[cfg(feature = "metrics")]
+            instruments::first_event().record(elapsed_ms, &[]);
+        }
+        if terminal && timings.total_ms.is_none() {
+            timings.total_ms = Some(elapsed_ms);
+            #[cfg(feature = "metrics")]
+            instruments::terminal().record(elapsed_ms, &[]);
+        }
+    }
+
+    /// Record that the agent stopped working on a task
+    ///
+    /// Sets the total time if no terminal event was recorded, as when the
+    /// agent failed or answered with a message.
+    pub fn finish(&self, task_id: &str) {
+        let mut records = self.records.lock().unwrap();
+        let Some(record) = records.tasks.get_mut(task_id) else {
+            return;
+        };
+        if record.timings.total_ms.is_none() {
+            let elapsed_ms = record.started.elapsed().as_secs_f64() * 1000.0;
+            record.timings.total_ms = Some(elapsed_ms);
+            #[cfg(feature = "metrics")]
+            instruments::terminal().record(elapsed_ms, &[]);
+        }
+    }
+
+    /// Timings recorded for a task, if it is still tracked
+    pub fn get(&self, task_id: &str) -> Option<TaskTimings> {
+        let records = self.records.lock().unwrap();
+        records.tasks.get(task_id).map(|record| record.timings.clone())
+    }
+}
+
+impl Default for TaskMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "metrics")]
+mod instruments {
+    use opentelemetry::global;
+    use opentelemetry::metrics::Histogram;
+    use std::sync::OnceLock;
+
+    pub(super) fn first_event() -> &'static Histogram<f64> {
+        static HISTOGRAM: OnceLock<Histogram<f64>> = OnceLock::new();
+        HISTOGRAM.get_or_init(|| {
+            global::meter("a2a-rust")
+                .f64_histogram("a2a.task.time_to_first_event")
+                .with_unit("ms")
+                .with_description("Time from the start of a task to its first event")
+                .build()
+        })
+    }
+
+    pub(super) fn terminal() -> &'static Histogram<f64> {
+        static HISTOGRAM: OnceLock<Histogram<f64>> = OnceLock::new();
+        HISTOGRAM.get_or_init(|| {
+            global::meter("a2a-rust")
+                .f64_histogram("a2a.task.time_to_terminal")
+                .with_unit("ms")
+                .with_description("Time from the start of a task to its terminal event")
+                .build()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_task_metrics_records_timings() {
+        let metrics = TaskMetrics::new();
+        metrics.start("task-1");
+        std::thread::sleep(Duration::from_millis(5));
+        metrics.record_event("task-1", false);
+        std::thread::sleep(Duration::from_millis(5));
+        metrics.record_event("task-1", true);
+        metrics.record_event("unknown", true);
+
+        let timings = metrics.get("task-1").unwrap();
+        assert_eq!(timings.event_count, 2);
+        let first = timings.first_event_ms.unwrap();
+        let total = timings.total_ms.unwrap();
+        assert!(first >= 5.0, "first event after {}ms", first);
+        assert!(total >= first + 5.0, "total {}ms, first {}ms", total, first);
+        assert!(metrics.get("unknown").is_none());
+
+        // Finishing keeps the terminal event's time
+        metrics.finish("task-1");
+        assert_eq!(metrics.get("task-1").unwrap().total_ms, Some(total));
+
+        metrics.start("task-2");
+        metrics.record_event("task-2", false);
+        metrics.finish("task-2");
+        assert!(metrics.get("task-2").unwrap().total_ms.is_some());
+    }
+
+    #[test]
+    fn test_task_metrics_forgets_oldest_tasks() {
+        let metrics = TaskMetrics::with_capacity(2);
+        metrics.start("a");
+        metrics.start("b");
+        metrics.start("a");
+        metrics.start("c");
+
+        assert!(metrics.get("b").is_none());
+        assert!(metrics.get("a").is_some());
+        assert!(metrics.get("c").is_some());
+    }
+}
@@ -3,12 +3,24 @@
 //! This module provides the core server components for implementing an A2A agent,
 //! including HTTP server, WebSocket support, and request handling.
 
+pub mod agent_execution;
 pub mod apps;
 pub mod context;
+pub mod event_transform;
 pub mod events;
+pub mod health;
+pub mod id_generator;
+pub mod metrics;
+pub mod recorder;
 pub mod request_handlers;
+pub mod sse;
 pub mod tasks;
 
 // Re-export commonly used types
-pub use context::{ServerCallContext, ServerCallContextBuilder};
+pub use context::{DuplicateMessagePolicy, ServerCallContext, ServerCallContextBuilder};
+pub use event_transform::EventTransform;
+pub use health::HealthCheck;
+pub use id_generator::{IDGenerator, SequentialIDGenerator, UUIDGenerator};
+pub use metrics::{TaskMetrics, TaskTimings};
+pub use recorder::RequestRecorder;
 pub use request_handlers::{RequestHandler, JSONRPCHandler};
@@ -0,0 +1,278 @@
+//! Recording and replay of inbound JSON-RPC requests
+//!
+//! A `RequestRecorder` set in `ServerConfig::request_recorder` captures every
+//! JSON-RPC request the server receives, with its headers and a timestamp,
+//! either as JSON lines appended to a file or in a bounded in-memory buffer.
+//! Credentials are redacted before anything is stored. Recorded requests can
+//! later be sent again to another server with [`replay`], to reproduce what
+//! an agent saw in production.
+
+use crate::a2a::error::A2AError;
+use crate::a2a::utils::redact::{redact_json, DEFAULT_REDACTED_KEYS, REDACTED};
+use axum::http::HeaderMap;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, VecDeque};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, oneshot};
+use tracing::warn;
+
+/// Headers whose values are never recorded, compared case-insensitively
+pub const REDACTED_HEADERS: &[&str] = &["authorization", "proxy-authorization", "cookie", "x-api-key"];
+
+/// Headers that describe one HTTP exchange and are not sent again on replay
+const HOP_HEADERS: &[&str] = &["host", "content-length", "connection", "transfer-encoding"];
+
+/// One recorded JSON-RPC request
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+    /// When the request was received, in RFC 3339 format
+    pub timestamp: String,
+    /// The JSON-RPC method, if the request named one
+    pub method: Option<String>,
+    /// Request headers, with credentials redacted
+    pub headers: BTreeMap<String, String>,
+    /// The JSON-RPC request body, with credentials redacted
+    pub body: Value,
+}
+
+/// Response received when replaying a recorded request
+#[derive(Debug, Clone)]
+pub struct ReplayResponse {
+    /// The JSON-RPC method of the replayed request
+    pub method: Option<String>,
+    /// HTTP status code of the response
+    pub status: u16,
+    /// Raw response body; an SSE stream for streaming methods
+    pub body: String,
+}
+
+enum WriterCommand {
+    Write(RecordedRequest),
+    Flush(oneshot::Sender<()>),
+}
+
+enum Sink {
+    /// Entries are sent to a dedicated thread that appends them to the file,
+    /// so request handlers never block on disk writes
+    File(mpsc::UnboundedSender<WriterCommand>),
+    Memory(Mutex<MemoryBuffer>),
+}
+
+struct MemoryBuffer {
+    entries: VecDeque<RecordedRequest>,
+    capacity: usize,
+}
+
+/// Captures inbound JSON-RPC requests for later replay
+///
+/// Cloning is cheap and clones record to the same destination.
+#[derive(Clone)]
+pub struct RequestRecorder {
+    sink: Arc<Sink>,
+    redacted_keys: Vec<String>,
+}
+
+impl std::fmt::Debug for RequestRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sink = match &*self.sink {
+            Sink::File(_) => "file",
+            Sink::Memory(_) => "memory",
+        };
+        f.debug_struct("RequestRecorder").field("sink", &sink).finish()
+    }
+}
+
+impl RequestRecorder {
+    /// Append requests as JSON lines to the file at `path`, creating it if needed
+    pub fn to_file(path: impl AsRef<Path>) -> Result<Self, A2AError> {
+        let path = path.as_ref();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(|e| A2AError::internal(&format!("Failed to open request log {}: {}", path.display(), e)))?;
+        let (sender, receiver) = mpsc::unbounded_channel();
+        std::thread::Builder::new()
+            .name("a2a-request-recorder".to_string())
+            .spawn(move || write_entries(file, receiver))
+            .map_err(|e| A2AError::internal(&format!("Failed to start request log writer: {}", e)))?;
+        Ok(Self::with_sink(Sink::File(sender)))
+    }
+
+    /// Keep the last `capacity` requests in memory
+    pub fn in_memory(capacity: usize) -> Self {
+        Self::with_sink(Sink::Memory(Mutex::new(MemoryBuffer { entries: VecDeque::new(), capacity: capacity.max(1) })))
+    }
+
+    fn with_sink(sink: Sink) -> Self {
+        // File contents are kept so that recorded messages can be replayed
+        let redacted_keys = DEFAULT_REDACTED_KEYS
+            .iter()
+            .filter(|key| **key != "bytes")
+            .map(|key| key.to_string())
+            .collect();
+        Self { sink: Arc::new(sink), redacted_keys }
+    }
+
+    /// Also redact values under `key` in request bodies
+    pub fn with_redacted_key(mut self, key: &str) -> Self {
+        self.redacted_keys.push(key.to_string());
+        self
+    }
+
+    /// Record one request
+    ///
+    /// File writes happen in the background; failures are logged rather than
+    /// returned, so recording never delays or fails the request itself.
+    pub fn record(&self, headers: &HeaderMap, body: &Value) {
+        let mut body = body.clone();
+        redact_json(&mut body, &self.redacted_keys);
+        let headers = headers
+            .iter()
+            .map(|(name, value)| {
+                let value = if REDACTED_HEADERS.iter().any(|redacted| name.as_str().eq_ignore_ascii_case(redacted)) {
+                    REDACTED.to_string()
+                } else {
+                    String::from_utf8_lossy(value.as_bytes()).into_owned()
+                };
+                (name.as_str().to_string(), value)
+            })
+            .collect();
+        let entry = RecordedRequest {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            method: body.get("method").and_then(Value::as_str).map(str::to_string),
+            headers,
+            body,
+        };
+
+        match &*self.sink {
+            Sink::File(sender) => {
+                if sender.send(WriterCommand::Write(entry)).is_err() {
+                    warn!("Failed to record request: request log writer has stopped");
+                }
+            }
+            Sink::Memory(buffer) => {
+                let mut buffer = buffer.lock().unwrap();
+                if buffer.entries.len() == buffer.capacity {
+                    buffer.entries.pop_front();
+                }
+                buffer.entries.push_back(entry);
+            }
+        }
+    }
+
+    /// Wait until every request recorded so far has been written
+    pub async fn flush(&self) {
+        if let Sink::File(sender) = &*self.sink {
+            let (done, written) = oneshot::channel();
+            if sender.send(WriterCommand::Flush(done)).is_ok() {
+                let _ = written.await;
+            }
+        }
+    }
+
+    /// Requests held in memory, oldest first; empty when recording to a file
+    pub fn entries(&self) -> Vec<RecordedRequest> {
+        match &*self.sink {
+            Sink::File(_) => Vec::new(),
+            Sink::Memory(buffer) => buffer.lock().unwrap().entries.iter().cloned().collect(),
+        }
+    }
+}
+
+/// Appends entries to `file` until every recorder sharing the channel is dropped
+fn write_entries(mut file: File, mut receiver: mpsc::UnboundedReceiver<WriterCommand>) {
+    while let Some(command) = receiver.blocking_recv() {
+        match command {
+            WriterCommand::Write(entry) => {
+                let written = serde_json::to_string(&entry)
+                    .map_err(|e| e.to_string())
+                    .and_then(|line| writeln!(file, "{}", line).map_err(|e| e.to_string()));
+                if let Err(e) = written {
+                    warn!("Failed to record request: {}", e);
+                }
+            }
+            WriterCommand::Flush(done) => {
+                if let Err(e) = file.flush() {
+                    warn!("Failed to flush request log: {}", e);
+                }
+                let _ = done.send(());
+            }
+        }
+    }
+}
+
+/// Read the requests recorded in a JSON lines file
+pub fn read_recording(path: impl AsRef<Path>) -> Result<Vec<RecordedRequest>, A2AError> {
+    let path = path.as_ref();
+    let file = File::open(path)
+        .map_err(|e| A2AError::internal(&format!("Failed to open request log {}: {}", path.display(), e)))?;
+    BufReader::new(file)
+        .lines()
+        .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(|line| {
+            let line = line.map_err(|e| A2AError::internal(&format!("Failed to read request log: {}", e)))?;
+            serde_json::from_str(&line)
+                .map_err(|e| A2AError::internal(&format!("Invalid request log entry: {}", e)))
+        })
+        .collect()
+}
+
+/// Send recorded requests, in order, to the JSON-RPC endpoint at `url`
+///
+/// Recorded headers are sent again except redacted ones, so requests that
+/// need credentials must have them added by the target's configuration.
+pub async fn replay(requests: &[RecordedRequest], url: &str) -> Result<Vec<ReplayResponse>, A2AError> {
+    let client = reqwest::Client::new();
+    let mut responses = Vec::with_capacity(requests.len());
+    for request in requests {
+        let mut builder = client.post(url).json(&request.body);
+        for (name, value) in &request.headers {
+            if value == REDACTED || HOP_HEADERS.iter().any(|hop| name.eq_ignore_ascii_case(hop)) {
+                continue;
+            }
+            builder = builder.header(name.as_str(), value.as_str());
+        }
+        let response = builder
+            .send()
+            .await
+            .map_err(|e| A2AError::transport_error(format!("HTTP request failed: {}", e)))?;
+        let status = response.status().as_u16();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| A2AError::transport_error(format!("Failed to read response body: {}", e)))?;
+        responses.push(ReplayResponse { method: request.method.clone(), status, body });
+    }
+    Ok(responses)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use serde_json::json;
+
+    #[test]
+    fn test_in_memory_recorder_keeps_last_requests() {
+        let recorder = RequestRecorder::in_memory(2);
+        let mut headers = HeaderMap::new();
+        headers.insert("authorization", HeaderValue::from_static("Bearer s3cr3t"));
+        headers.insert("x-trace", HeaderValue::from_static("abc"));
+
+        for id in 1..=3 {
+            recorder.record(&headers, &json!({"jsonrpc": "2.0", "id": id, "method": "tasks/get"}));
+        }
+
+        let entries = recorder.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].body["id"], 2);
+        assert_eq!(entries[1].method.as_deref(), Some("tasks/get"));
+        assert_eq!(entries[1].headers["authorization"], REDACTED);
+        assert_eq!(entries[1].headers["x-trace"], "abc");
+    }
+}
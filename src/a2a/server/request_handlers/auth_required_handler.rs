@@ -0,0 +1,202 @@
+//! Authentication-enforcing request handler
+//!
+//! This module provides AuthRequiredHandler, a decorator around another
+//! RequestHandler that rejects anonymous callers for a configured set of
+//! JSON-RPC methods before delegating to the wrapped handler.
+
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use std::collections::HashSet;
+
+use crate::a2a::error::A2AError;
+use crate::a2a::models::*;
+use crate::a2a::server::context::ServerCallContext;
+use crate::a2a::server::request_handlers::request_handler::{
+    Event, MessageSendResult, RequestHandler, TaskPushNotificationConfigQueryParams,
+};
+
+/// Request handler that requires an authenticated user for selected methods
+///
+/// Methods are identified by their JSON-RPC names (e.g. `message/send`).
+/// Calls to protected methods without an authenticated user in the
+/// `ServerCallContext` fail with an invalid request error; all other
+/// calls are passed through unchanged.
+pub struct AuthRequiredHandler<H> {
+    inner: H,
+    protected_methods: HashSet<String>,
+}
+
+impl<H: RequestHandler> AuthRequiredHandler<H> {
+    /// Wrap a handler, initially protecting no methods
+    pub fn new(inner: H) -> Self {
+        Self {
+            inner,
+            protected_methods: HashSet::new(),
+        }
+    }
+
+    /// Require authentication for the given methods
+    pub fn with_protected_methods<I, S>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.protected_methods.extend(methods.into_iter().map(Into::into));
+        self
+    }
+
+    /// Returns whether the given method requires authentication
+    pub fn is_protected(&self, method: &str) -> bool {
+        self.protected_methods.contains(method)
+    }
+
+    fn authorize(&self, method: &str, context: Option<&ServerCallContext>) -> Result<(), A2AError> {
+        if !self.is_protected(method) || context.is_some_and(|c| !c.is_anonymous()) {
+            return Ok(());
+        }
+        Err(A2AError::invalid_request(&format!(
+            "Authentication required for method: {}",
+            method
+        )))
+    }
+}
+
+#[async_trait]
+impl<H: RequestHandler> RequestHandler for AuthRequiredHandler<H> {
+    async fn on_get_task(
+        &self,
+        params: TaskQueryParams,
+        context: Option<&ServerCallContext>,
+    ) -> Result<Option<Task>, A2AError> {
+        self.authorize("tasks/get", context)?;
+        self.inner.on_get_task(params, context).await
+    }
+
+    async fn on_cancel_task(
+        &self,
+        params: TaskIdParams,
+        context: Option<&ServerCallContext>,
+    ) -> Result<Option<Task>, A2AError> {
+        self.authorize("tasks/cancel", context)?;
+        self.inner.on_cancel_task(params, context).await
+    }
+
+    async fn on_message_send(
+        &self,
+        params: MessageSendParams,
+        context: Option<&ServerCallContext>,
+    ) -> Result<MessageSendResult, A2AError> {
+        self.authorize("message/send", context)?;
+        self.inner.on_message_send(params, context).await
+    }
+
+    async fn on_message_send_stream(
+        &self,
+        params: MessageSendParams,
+        context: Option<&ServerCallContext>,
+    ) -> Result<BoxStream<'static, Result<Event, A2AError>>, A2AError> {
+        self.authorize("message/stream", context)?;
+        self.inner.on_message_send_stream(params, context).await
+    }
+
+    async fn on_set_task_push_notification_config(
+        &self,
+        params: TaskPushNotificationConfig,
+        context: Option<&ServerCallContext>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        self.authorize("tasks/pushNotificationConfig/set", context)?;
+        self.inner.on_set_task_push_notification_config(params, context).await
+    }
+
+    async fn on_get_task_push_notification_config(
+        &self,
+        params: TaskPushNotificationConfigQueryParams,
+        context: Option<&ServerCallContext>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        self.authorize("tasks/pushNotificationConfig/get", context)?;
+        self.inner.on_get_task_push_notification_config(params, context).await
+    }
+
+    async fn on_resubscribe_to_task(
+        &self,
+        params: TaskIdParams,
+        context: Option<&ServerCallContext>,
+    ) -> Result<BoxStream<'static, Result<Event, A2AError>>, A2AError> {
+        self.authorize("tasks/resubscribe", context)?;
+        self.inner.on_resubscribe_to_task(params, context).await
+    }
+
+    async fn on_list_task_push_notification_config(
+        &self,
+        params: TaskIdParams,
+        context: Option<&ServerCallContext>,
+    ) -> Result<Vec<TaskPushNotificationConfig>, A2AError> {
+        self.authorize("tasks/pushNotificationConfig/list", context)?;
+        self.inner.on_list_task_push_notification_config(params, context).await
+    }
+
+    async fn on_delete_task_push_notification_config(
+        &self,
+        params: DeleteTaskPushNotificationConfigParams,
+        context: Option<&ServerCallContext>,
+    ) -> Result<(), A2AError> {
+        self.authorize("tasks/pushNotificationConfig/delete", context)?;
+        self.inner.on_delete_task_push_notification_config(params, context).await
+    }
+
+    async fn on_cancel_tasks_by_context(
+        &self,
+        params: ContextIdParams,
+        context: Option<&ServerCallContext>,
+    ) -> Result<Vec<String>, A2AError> {
+        self.authorize("tasks/cancelByContext", context)?;
+        self.inner.on_cancel_tasks_by_context(params, context).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::auth::user::AuthenticatedUser;
+    use crate::a2a::core_types::{Message, Part, Role};
+    use crate::a2a::jsonrpc::standard_error_codes::INVALID_REQUEST;
+    use crate::a2a::server::request_handlers::request_handler::MockRequestHandler;
+
+    fn protected_handler() -> AuthRequiredHandler<MockRequestHandler> {
+        AuthRequiredHandler::new(MockRequestHandler::new()).with_protected_methods(["message/send"])
+    }
+
+    fn send_params() -> MessageSendParams {
+        MessageSendParams::new(Message::new(Role::User, vec![Part::text("hi".to_string())]))
+    }
+
+    #[tokio::test]
+    async fn test_anonymous_message_send_rejected() {
+        let handler = protected_handler();
+        let anonymous = ServerCallContext::new();
+
+        let error = handler.on_message_send(send_params(), Some(&anonymous)).await.unwrap_err();
+        assert_eq!(error.code(), INVALID_REQUEST);
+
+        assert!(handler.on_message_send(send_params(), None).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_unprotected_method_passes_through() {
+        let handler = protected_handler();
+        let anonymous = ServerCallContext::new();
+
+        let result = handler
+            .on_get_task(TaskQueryParams::new("task-1".to_string()), Some(&anonymous))
+            .await;
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_authenticated_message_send_allowed() {
+        let handler = protected_handler();
+        let context = ServerCallContext::with_user(AuthenticatedUser::new("alice".to_string()));
+
+        assert!(handler.on_message_send(send_params(), Some(&context)).await.is_ok());
+    }
+}
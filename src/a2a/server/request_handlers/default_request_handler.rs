@@ -5,22 +5,99 @@
 //! Python implementation.
 
 use async_trait::async_trait;
+use futures::future::{AbortHandle, AbortRegistration, Abortable};
 use futures::stream::{BoxStream, StreamExt};
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tracing::error;
 
+use crate::a2a::auth::user::User;
 use crate::a2a::models::*;
-use crate::a2a::core_types::{TaskStatus, TaskState};
-use crate::a2a::server::context::ServerCallContext;
+use crate::a2a::core_types::{Message, TaskStatus, TaskState};
+use crate::a2a::server::agent_execution::{
+    AgentExecutor, RequestContext, DEFAULT_MAX_RELATED_DEPTH, RELATED_CHAIN_METADATA_KEY,
+};
+use crate::a2a::server::context::{DuplicateMessagePolicy, ServerCallContext};
+use crate::a2a::server::events::{Event as QueueEvent, EventConsumer, EventQueue, InMemoryQueueManager, QueueManager};
+use crate::a2a::server::id_generator::{IDGenerator, UUIDGenerator};
+use crate::a2a::server::metrics::TaskMetrics;
 use crate::a2a::server::request_handlers::request_handler::{RequestHandler, MessageSendResult, Event};
-use crate::a2a::server::tasks::{TaskStore, PushNotificationConfigStore, PushNotificationSender, TaskManager};
+use crate::a2a::server::tasks::{default_config_id, TaskEvent, TaskSnapshot, TaskReaper, TaskStore, PushNotificationConfigStore, PushNotificationSender, TaskManager};
 use crate::a2a::error::A2AError;
+use crate::a2a::utils::canonical::canonical_json;
+use crate::a2a::utils::message::{append_message_parts, is_partial_message};
+
+/// Task metadata key recording the authenticated user who created the task
+pub const TASK_OWNER_METADATA_KEY: &str = "owner";
+
+/// A task whose agent is still running
+struct LiveTask {
+    /// The task's current state, including events not yet saved
+    snapshot: TaskSnapshot,
+    /// Stops the agent's execution when the task is canceled
+    abort: AbortHandle,
+}
+
+impl LiveTask {
+    /// Tracks `task`, returning the registration its agent run is aborted through
+    fn new(task: Task) -> (Self, AbortRegistration) {
+        let (abort, registration) = AbortHandle::new_pair();
+        (Self { snapshot: TaskSnapshot::new(task), abort }, registration)
+    }
+}
+
+/// Each task whose agent is still running
+type LiveTasks = Arc<Mutex<HashMap<String, LiveTask>>>;
+
+/// How an agent's execution ended
+enum AgentOutcome {
+    Finished(Result<(), A2AError>),
+    /// Aborted by tasks/cancel
+    Canceled,
+    DeadlineExceeded,
+}
+
+/// How long a partial message is kept after its last chunk, by default
+pub const DEFAULT_PARTIAL_MESSAGE_TTL: Duration = Duration::from_secs(300);
+
+/// Largest partial message buffered, in bytes of its serialized parts, by default
+pub const DEFAULT_MAX_PARTIAL_MESSAGE_BYTES: usize = 1024 * 1024;
+
+/// A user message received partially so far
+struct PendingMessage {
+    message: Message,
+    /// Serialized size of the parts received so far
+    size: usize,
+    /// When the last chunk arrived
+    updated_at: Instant,
+}
+
+/// Identifies a partial message: the caller, the context it is sent in and its message ID
+type PendingMessageKey = (Option<String>, Option<String>, String);
+
+/// User messages received partially so far
+type PendingMessages = Arc<Mutex<HashMap<PendingMessageKey, PendingMessage>>>;
 
 /// Default Request Handler
 pub struct DefaultRequestHandler {
     task_store: Arc<dyn TaskStore>,
     push_config_store: Option<Arc<dyn PushNotificationConfigStore>>,
     push_sender: Option<Arc<dyn PushNotificationSender>>,
+    task_id_generator: Arc<dyn IDGenerator>,
+    context_id_generator: Arc<dyn IDGenerator>,
+    agent_executor: Option<Arc<dyn AgentExecutor>>,
+    queue_manager: Arc<dyn QueueManager>,
+    live_tasks: LiveTasks,
+    pending_messages: PendingMessages,
+    partial_message_ttl: Duration,
+    max_partial_message_bytes: usize,
+    record_status_history: bool,
+    task_metrics: Option<TaskMetrics>,
+    propagate_request_metadata: Vec<String>,
+    duplicate_message_policy: DuplicateMessagePolicy,
+    message_limits: MessageLimits,
+    max_related_depth: usize,
 }
 
 impl DefaultRequestHandler {
@@ -34,7 +111,428 @@ impl DefaultRequestHandler {
             task_store,
             push_config_store,
             push_sender,
+            task_id_generator: Arc::new(UUIDGenerator::new()),
+            context_id_generator: Arc::new(UUIDGenerator::new()),
+            agent_executor: None,
+            queue_manager: Arc::new(InMemoryQueueManager::default()),
+            live_tasks: Arc::new(Mutex::new(HashMap::new())),
+            pending_messages: Arc::new(Mutex::new(HashMap::new())),
+            partial_message_ttl: DEFAULT_PARTIAL_MESSAGE_TTL,
+            max_partial_message_bytes: DEFAULT_MAX_PARTIAL_MESSAGE_BYTES,
+            record_status_history: false,
+            task_metrics: None,
+            propagate_request_metadata: Vec::new(),
+            duplicate_message_policy: DuplicateMessagePolicy::default(),
+            message_limits: MessageLimits::default(),
+            max_related_depth: DEFAULT_MAX_RELATED_DEPTH,
+        }
+    }
+
+    /// Set the agent executor that runs the agent for each message
+    ///
+    /// Without an executor, messages only create or resume a task in the
+    /// `Working` state.
+    pub fn with_agent_executor(mut self, agent_executor: Arc<dyn AgentExecutor>) -> Self {
+        self.agent_executor = Some(agent_executor);
+        self
+    }
+
+    /// Set the queue manager holding the event queues of running tasks
+    pub fn with_queue_manager(mut self, queue_manager: Arc<dyn QueueManager>) -> Self {
+        self.queue_manager = queue_manager;
+        self
+    }
+
+    /// Enable the optional behaviour the agent card's capabilities declare
+    ///
+    /// With `state_transition_history`, tasks record their state transitions
+    /// (see `Task::status_history`).
+    pub fn with_capabilities(mut self, capabilities: &AgentCapabilities) -> Self {
+        self.record_status_history = capabilities.supports_state_transition_history();
+        self
+    }
+
+    /// Record per-task event timings in `metrics`
+    ///
+    /// Share the same recorder with the server to query the timings through
+    /// its admin endpoint.
+    pub fn with_task_metrics(mut self, metrics: TaskMetrics) -> Self {
+        self.task_metrics = Some(metrics);
+        self
+    }
+
+    /// Copy these keys of `MessageSendParams.metadata` onto the resulting task's metadata
+    ///
+    /// For clients that expect correlation metadata reflected back on the task.
+    pub fn with_propagate_request_metadata(mut self, keys: Vec<String>) -> Self {
+        self.propagate_request_metadata = keys;
+        self
+    }
+
+    /// Set how a message whose `message_id` was already seen in its context is handled
+    pub fn with_duplicate_message_policy(mut self, policy: DuplicateMessagePolicy) -> Self {
+        self.duplicate_message_policy = policy;
+        self
+    }
+
+    /// Set the size limits incoming messages are checked against
+    ///
+    /// Messages exceeding them are rejected with `InvalidParams` by
+    /// `on_message_send` and `on_message_send_stream`.
+    pub fn with_message_limits(mut self, limits: MessageLimits) -> Self {
+        self.message_limits = limits;
+        self
+    }
+
+    /// Set the maximum length of a chain of related tasks spawned from one another
+    ///
+    /// Messages whose task would exceed it are rejected with `InvalidParams`,
+    /// and agents cannot attach related tasks beyond it.
+    pub fn with_max_related_depth(mut self, max_related_depth: usize) -> Self {
+        self.max_related_depth = max_related_depth;
+        self
+    }
+
+    /// Limit how partial messages are buffered
+    ///
+    /// A partial message is dropped once `ttl` passes without a new chunk,
+    /// and rejected once its parts exceed `max_bytes` when serialized.
+    pub fn with_partial_message_limits(mut self, ttl: Duration, max_bytes: usize) -> Self {
+        self.partial_message_ttl = ttl;
+        self.max_partial_message_bytes = max_bytes;
+        self
+    }
+
+    /// Set the generators used for new task and context IDs
+    ///
+    /// Generators configured on the server call context take precedence.
+    pub fn with_id_generators(
+        mut self,
+        task_id_generator: Arc<dyn IDGenerator>,
+        context_id_generator: Arc<dyn IDGenerator>,
+    ) -> Self {
+        self.task_id_generator = task_id_generator;
+        self.context_id_generator = context_id_generator;
+        self
+    }
+
+    /// Creates a reaper failing this handler's tasks left open longer than `stale_after`
+    ///
+    /// The reaper shares the handler's task store and queue manager; start it
+    /// with `TaskReaper::spawn`.
+    pub fn task_reaper(&self, stale_after: Duration) -> TaskReaper {
+        TaskReaper::new(self.task_store.clone(), stale_after).with_queue_manager(self.queue_manager.clone())
+    }
+
+    /// Builds the request context for a message, assigning task and context IDs
+    async fn build_request_context(
+        &self,
+        params: &MessageSendParams,
+        existing_task: Option<&Task>,
+        context: Option<&ServerCallContext>,
+    ) -> Result<RequestContext, A2AError> {
+        let task_id_generator = context
+            .and_then(|c| c.task_id_generator.clone())
+            .unwrap_or_else(|| self.task_id_generator.clone());
+        let context_id_generator = context
+            .and_then(|c| c.context_id_generator.clone())
+            .unwrap_or_else(|| self.context_id_generator.clone());
+
+        // The tasks a message refers to are its related tasks. A new task
+        // continues the longest of their chains; a resumed one keeps its own.
+        let mut related_tasks = Vec::new();
+        for task_id in params.message.reference_task_ids.iter().flatten() {
+            related_tasks.extend(self.task_store.get(task_id).await?);
+        }
+        let related_chain = match existing_task {
+            Some(task) => related_chain_of(task),
+            None => related_tasks
+                .iter()
+                .map(|task| {
+                    let mut chain = related_chain_of(task);
+                    chain.push(task.id.to_string());
+                    chain
+                })
+                .max_by_key(Vec::len)
+                .unwrap_or_default(),
+        };
+
+        let request_context = RequestContext::new(
+            Some(params.clone()),
+            None,
+            existing_task.map(|task| task.context_id.clone()),
+            existing_task.cloned(),
+            Some(related_tasks),
+            context.cloned(),
+            Some(task_id_generator),
+            Some(context_id_generator),
+        )
+        .await?
+        .with_related_chain(related_chain)
+        .with_max_related_depth(self.max_related_depth);
+        if request_context.related_depth() > request_context.max_related_depth {
+            return Err(A2AError::invalid_params(&format!(
+                "Task would exceed max_related_depth of {} (chain: {:?})",
+                request_context.max_related_depth, request_context.related_chain
+            )));
+        }
+        Ok(request_context)
+    }
+
+    /// Buffers a partial message, or completes a message from its buffered chunks
+    ///
+    /// A message flagged with `PARTIAL_MESSAGE_METADATA_KEY` is not run: its
+    /// parts are added to those the same caller sent earlier under the same
+    /// context and message ID, and the message accumulated so far is returned
+    /// as the reply. The first such message that is not partial gets the
+    /// buffered parts prepended, so the agent sees the whole input.
+    ///
+    /// The buffer lives as long as the chunks keep coming: it is dropped when
+    /// the message completes or once the partial message TTL passes without
+    /// a new chunk. A message growing past the size limit is dropped and
+    /// rejected with `InvalidParams`.
+    fn accumulate_partial_message(
+        &self,
+        params: &mut MessageSendParams,
+        context: Option<&ServerCallContext>,
+    ) -> Result<Option<Message>, A2AError> {
+        let mut pending = self.pending_messages.lock().unwrap();
+        let ttl = self.partial_message_ttl;
+        pending.retain(|_, message| message.updated_at.elapsed() < ttl);
+
+        let key = (
+            Self::caller(context),
+            params.message.context_id.clone(),
+            params.message.message_id.clone(),
+        );
+        if !is_partial_message(&params.message) {
+            if let Some(mut accumulated) = pending.remove(&key) {
+                append_message_parts(&mut accumulated.message, std::mem::take(&mut params.message.parts));
+                params.message.parts = accumulated.message.parts;
+            }
+            return Ok(None);
         }
+
+        let chunk_size = serde_json::to_vec(&params.message.parts).map(|bytes| bytes.len()).unwrap_or_default();
+        let mut accumulated = match pending.remove(&key) {
+            Some(mut accumulated) => {
+                append_message_parts(&mut accumulated.message, params.message.parts.clone());
+                accumulated.size += chunk_size;
+                accumulated
+            }
+            None => PendingMessage { message: params.message.clone(), size: chunk_size, updated_at: Instant::now() },
+        };
+        if accumulated.size > self.max_partial_message_bytes {
+            return Err(A2AError::invalid_params(&format!(
+                "Partial message {} exceeds the limit of {} bytes",
+                params.message.message_id, self.max_partial_message_bytes
+            )));
+        }
+        accumulated.updated_at = Instant::now();
+        let message = accumulated.message.clone();
+        pending.insert(key, accumulated);
+        Ok(Some(message))
+    }
+
+    /// Loads the task a follow-up message refers to, if any
+    ///
+    /// A message carrying the `task_id` of a task that is still open (for
+    /// example one paused in `InputRequired`) resumes that task rather than
+    /// starting a new one. Tasks in a terminal state cannot be resumed, and
+    /// a `task_id` that names no stored task fails with `TaskNotFound`.
+    async fn load_resumable_task(&self, message: &Message) -> Result<Option<Task>, A2AError> {
+        let task_id = match message.task_id {
+            Some(ref task_id) => task_id,
+            None => return Ok(None),
+        };
+
+        let task = match self.task_store.get(task_id).await? {
+            Some(task) => task,
+            None => return Err(A2AError::task_not_found(task_id)),
+        };
+
+        if task.status.state.is_terminal() {
+            return Err(A2AError::invalid_params(&format!(
+                "Task {} is in terminal state: {:?}",
+                task.id, task.status.state
+            )));
+        }
+
+        if let Some(ref context_id) = message.context_id {
+            if context_id != &task.context_id {
+                return Err(A2AError::invalid_params(&format!(
+                    "Message context {} doesn't match task context {}",
+                    context_id, task.context_id
+                )));
+            }
+        }
+
+        Ok(Some(task))
+    }
+
+    /// Finds the task that already holds a message with the same `message_id`
+    ///
+    /// Both the task named by the message and, when the store supports
+    /// listing by context, every task in the message's context are checked.
+    async fn find_duplicate_message(&self, message: &Message) -> Result<Option<Task>, A2AError> {
+        let mut candidates = Vec::new();
+        if let Some(ref task_id) = message.task_id {
+            candidates.extend(self.task_store.get(task_id).await?);
+        }
+        if let Some(ref context_id) = message.context_id {
+            // Stores without context listing only have the named task checked
+            candidates.extend(self.task_store.list_by_context(context_id).await.unwrap_or_default());
+        }
+
+        Ok(candidates.into_iter().find(|task| {
+            task.history
+                .as_ref()
+                .is_some_and(|history| history.iter().any(|m| m.message_id == message.message_id))
+        }))
+    }
+
+    /// Applies the duplicate message policy, returning the task to reply with if the message is ignored
+    async fn check_duplicate_message(&self, message: &Message) -> Result<Option<Task>, A2AError> {
+        let task = match self.find_duplicate_message(message).await? {
+            Some(task) => task,
+            None => return Ok(None),
+        };
+
+        match self.duplicate_message_policy {
+            DuplicateMessagePolicy::Reject => Err(A2AError::invalid_params(&format!(
+                "Message {} already exists in context {}",
+                message.message_id, task.context_id
+            ))),
+            DuplicateMessagePolicy::Ignore => {
+                let stored = task
+                    .history
+                    .iter()
+                    .flatten()
+                    .find(|m| m.message_id == message.message_id);
+                if stored.is_some_and(|stored| is_same_message(stored, message)) {
+                    Ok(Some(task))
+                } else {
+                    Err(A2AError::invalid_params(&format!(
+                        "Message {} reuses the ID of a different message in context {}",
+                        message.message_id, task.context_id
+                    )))
+                }
+            }
+        }
+    }
+
+    /// Registers the push config sent inline with a message for its task
+    ///
+    /// The config is stored before the agent runs, so the notification sent
+    /// for the task's terminal state reaches the client's webhook.
+    async fn register_inline_push_config(&self, task_id: &str, params: &MessageSendParams) -> Result<(), A2AError> {
+        let (Some(store), Some(config)) = (
+            self.push_config_store.as_ref(),
+            params.configuration.as_ref().and_then(|c| c.push_notification_config.clone()),
+        ) else {
+            return Ok(());
+        };
+        store.set_info(task_id, config).await
+    }
+
+    /// Copies the request metadata keys configured for propagation onto the task
+    fn propagate_request_metadata(&self, task: &mut Task, params: &MessageSendParams) {
+        let Some(metadata) = params.metadata.as_ref() else {
+            return;
+        };
+        for key in &self.propagate_request_metadata {
+            if let Some(value) = metadata.get(key) {
+                task.metadata.get_or_insert_with(HashMap::new).insert(key.clone(), value.clone());
+            }
+        }
+    }
+
+    /// Returns the name of the authenticated caller, if any
+    fn caller(context: Option<&ServerCallContext>) -> Option<String> {
+        context
+            .filter(|c| !c.is_anonymous())
+            .map(|c| c.user.user_name().to_string())
+    }
+
+    /// Metadata for a new task
+    ///
+    /// Records an authenticated caller as the task's owner, and the chain of
+    /// tasks it was spawned from so tasks spawned from it can extend it.
+    fn new_task_metadata(
+        context: Option<&ServerCallContext>,
+        request_context: &RequestContext,
+    ) -> Option<HashMap<String, serde_json::Value>> {
+        let mut metadata = HashMap::new();
+        if let Some(owner) = Self::caller(context) {
+            metadata.insert(TASK_OWNER_METADATA_KEY.to_string(), serde_json::Value::String(owner));
+        }
+        if !request_context.related_chain.is_empty() {
+            metadata.insert(RELATED_CHAIN_METADATA_KEY.to_string(), serde_json::json!(request_context.related_chain));
+        }
+        (!metadata.is_empty()).then_some(metadata)
+    }
+
+    /// Cancels a task
+    ///
+    /// A running agent is asked to stop through its live event queue and
+    /// then aborted, the streams following the task end, and the task is
+    /// saved as canceled. Finished tasks cannot be canceled.
+    async fn cancel_task(&self, task: Task, context: Option<&ServerCallContext>) -> Result<Task, A2AError> {
+        if task.status.state.is_terminal() {
+            return Err(A2AError::task_not_cancelable(&format!(
+                "task {} is already {:?}",
+                task.id, task.status.state
+            )));
+        }
+        if let Some(ref executor) = self.agent_executor {
+            if let Some(queue) = self.queue_manager.tap(&task.id).await? {
+                let request_context = RequestContext::new(
+                    None,
+                    Some(task.id.to_string()),
+                    Some(task.context_id.clone()),
+                    Some(task.clone()),
+                    None,
+                    context.cloned(),
+                    None,
+                    None,
+                )
+                .await?;
+                if let Err(e) = executor.cancel(request_context, queue).await {
+                    error!("Agent failed to cancel task {}: {}", task.id, e);
+                }
+            }
+        }
+
+        // The live snapshot of a running agent no longer describes the task,
+        // and closing its queue ends the streams following it. Without its
+        // live entry, the agent run stops applying events to the task.
+        let live = self.live_tasks.lock().unwrap().remove(task.id.as_str());
+        if let Some(live) = live {
+            live.abort.abort();
+            if let Err(e) = self.queue_manager.close(&task.id).await {
+                error!("Failed to close event queue for task {}: {}", task.id, e);
+            }
+        }
+
+        let mut task_manager = TaskManager::new(
+            Some(task.id.to_string()),
+            Some(task.context_id.clone()),
+            self.task_store.clone(),
+            None,
+            None,
+        )?
+        .with_status_history(self.record_status_history);
+        let canceled = TaskStatusUpdateEvent::new(task.id.into(), task.context_id, TaskStatus::new(TaskState::Canceled), true);
+        let task = task_manager.save_task_event(TaskEvent::StatusUpdate(canceled)).await?;
+
+        // Trigger push notification on cancellation
+        self.send_push_notification_if_needed(&task).await;
+        Ok(task)
+    }
+
+    /// Builds the current snapshot of a task whose agent is still running
+    fn live_snapshot(&self, task_id: &str) -> Option<Task> {
+        self.live_tasks.lock().unwrap().get(task_id).map(|live| live.snapshot.task().clone())
     }
 
     async fn send_push_notification_if_needed(&self, task: &Task) {
@@ -46,6 +544,188 @@ impl DefaultRequestHandler {
     }
 }
 
+/// Whether a resent message is a retry of `stored`
+///
+/// Messages are compared by their canonical JSON, ignoring the task and
+/// context IDs the server fills in when storing a message.
+fn is_same_message(stored: &Message, resent: &Message) -> bool {
+    let canonical = |message: &Message| {
+        let mut message = message.clone();
+        message.task_id = None;
+        message.context_id = None;
+        serde_json::to_value(&message).map(|value| canonical_json(&value)).ok()
+    };
+    canonical(stored) == canonical(resent)
+}
+
+/// The chain of tasks a task was spawned from, as recorded in its metadata
+fn related_chain_of(task: &Task) -> Vec<String> {
+    task.metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(RELATED_CHAIN_METADATA_KEY))
+        .and_then(|chain| serde_json::from_value(chain.clone()).ok())
+        .unwrap_or_default()
+}
+
+/// Runs the agent for one request
+///
+/// Executes the agent while applying every event it publishes to the task,
+/// and resolves to the final task (or the agent's direct reply message).
+/// A `pending_task` is a new task not saved yet: it is saved once the agent
+/// publishes a task event, and never when the agent only answers with a
+/// direct message, as the reply does not belong to a task.
+///
+/// An execution still running at the client's `deadline` is dropped and the
+/// task is canceled, which the streams following it see as their final
+/// event; the run then fails with an internal error. An execution aborted
+/// through `abort_registration` by tasks/cancel stops without touching the
+/// task, which the cancellation has already saved.
+#[allow(clippy::too_many_arguments)]
+async fn run_agent(
+    executor: Arc<dyn AgentExecutor>,
+    queue: Arc<dyn EventQueue>,
+    queue_manager: Arc<dyn QueueManager>,
+    request_context: RequestContext,
+    mut task_manager: TaskManager,
+    pending_task: Option<Task>,
+    push_sender: Option<Arc<dyn PushNotificationSender>>,
+    live_tasks: LiveTasks,
+    task_metrics: Option<TaskMetrics>,
+    deadline: Option<Instant>,
+    abort_registration: AbortRegistration,
+) -> Result<MessageSendResult, A2AError> {
+    let task_id = request_context.task_id.clone().unwrap_or_default();
+    let context_id = request_context.context_id.clone().unwrap_or_default();
+    if let Some(ref metrics) = task_metrics {
+        metrics.start(&task_id);
+    }
+
+    let producer = async {
+        let execution = Abortable::new(executor.execute(request_context, queue.clone()), abort_registration);
+        // `None` once the deadline passed
+        let result = match deadline {
+            Some(deadline) => tokio::time::timeout_at(deadline.into(), execution).await.ok(),
+            None => Some(execution.await),
+        };
+        let outcome = match result {
+            Some(Ok(result)) => AgentOutcome::Finished(result),
+            // tasks/cancel has already closed the queue
+            Some(Err(_)) => return AgentOutcome::Canceled,
+            None => {
+                let canceled = TaskStatusUpdateEvent::new(
+                    task_id.clone(),
+                    context_id.clone(),
+                    TaskStatus::new(TaskState::Canceled),
+                    true,
+                );
+                if let Err(e) = queue.enqueue_event(QueueEvent::TaskStatusUpdate(canceled)).await {
+                    error!("Failed to cancel task {} at its deadline: {}", task_id, e);
+                }
+                AgentOutcome::DeadlineExceeded
+            }
+        };
+        // Closing lets the consumer drain the remaining events and stop
+        if let Err(e) = queue_manager.close(&task_id).await {
+            error!("Failed to close event queue for task {}: {}", task_id, e);
+        }
+        outcome
+    };
+    let consumer = async {
+        let mut reply = None;
+        let mut pending_task = pending_task;
+        let mut events = EventConsumer::new(queue.clone()).consume_all();
+        while let Some(event) = events.next().await {
+            let event = event?;
+            if let Some(ref metrics) = task_metrics {
+                metrics.record_event(&task_id, event.is_final());
+            }
+            // A canceled task no longer takes the agent's events
+            match live_tasks.lock().unwrap().get_mut(&task_id) {
+                Some(live) => live.snapshot.apply(&event),
+                None => break,
+            }
+            if let QueueEvent::Message(ref message) = event {
+                reply = Some(message.clone());
+            } else if let Some(task) = pending_task.take() {
+                save_new_task(&mut task_manager, task, push_sender.as_ref()).await?;
+            }
+            task_manager.process_event(&event).await?;
+        }
+        Ok::<_, A2AError>((reply, pending_task))
+    };
+    let (outcome, reply) = tokio::join!(producer, consumer);
+    // The task store is authoritative again once the agent has stopped
+    live_tasks.lock().unwrap().remove(&task_id);
+    if let Some(ref metrics) = task_metrics {
+        metrics.finish(&task_id);
+    }
+    let (reply, pending_task) = reply?;
+    // A canceled task was saved by the cancellation
+    if let Some(task) = pending_task.filter(|_| !matches!(outcome, AgentOutcome::Canceled)) {
+        if let (AgentOutcome::Finished(Ok(())), Some(message)) = (&outcome, &reply) {
+            return Ok(MessageSendResult::Message(message.clone()));
+        }
+        save_new_task(&mut task_manager, task, push_sender.as_ref()).await?;
+    }
+
+    if let AgentOutcome::Finished(Err(ref e)) = outcome {
+        error!("Agent execution failed for task {}: {}", task_id, e);
+        // A task the agent already finished, or that was canceled, keeps its state
+        let finished = task_manager.reload_task().await?.is_some_and(|task| task.status.state.is_terminal());
+        if !finished {
+            task_manager
+                .save_task_event(TaskEvent::StatusUpdate(TaskStatusUpdateEvent::new(
+                    task_id.clone(),
+                    context_id,
+                    TaskStatus::new(TaskState::Failed),
+                    true,
+                )))
+                .await?;
+        }
+    }
+
+    if matches!(outcome, AgentOutcome::Canceled) {
+        // The cancellation has saved the task and notified subscribers
+        let task = task_manager
+            .reload_task()
+            .await?
+            .ok_or_else(|| A2AError::task_not_found(&task_id))?;
+        return Ok(MessageSendResult::Task(task));
+    }
+    let task = task_manager
+        .get_task()
+        .await?
+        .ok_or_else(|| A2AError::task_not_found(&task_id))?;
+    if let Some(ref sender) = push_sender {
+        if let Err(e) = sender.send_notification(&task).await {
+            error!("Failed to send push notification: {}", e);
+        }
+    }
+
+    if matches!(outcome, AgentOutcome::DeadlineExceeded) {
+        return Err(A2AError::internal(&format!("Deadline exceeded while executing task {}", task_id)));
+    }
+    Ok(match reply {
+        Some(message) => MessageSendResult::Message(message),
+        None => MessageSendResult::Task(task),
+    })
+}
+
+/// Saves a new task and sends the push notification for it
+async fn save_new_task(
+    task_manager: &mut TaskManager,
+    task: Task,
+    push_sender: Option<&Arc<dyn PushNotificationSender>>,
+) -> Result<(), A2AError> {
+    let task = task_manager.save_task_event(TaskEvent::Task(task)).await?;
+    if let Some(sender) = push_sender {
+        if let Err(e) = sender.send_notification(&task).await {
+            error!("Failed to send push notification: {}", e);
+        }
+    }
+    Ok(())
+}
+
 #[async_trait]
 impl RequestHandler for DefaultRequestHandler {
     async fn on_get_task(
@@ -53,120 +733,301 @@ impl RequestHandler for DefaultRequestHandler {
         params: TaskQueryParams,
         _context: Option<&ServerCallContext>,
     ) -> Result<Option<Task>, A2AError> {
+        if let Some(task) = self.live_snapshot(&params.id) {
+            return Ok(Some(task));
+        }
         self.task_store.get(&params.id).await
     }
 
     async fn on_cancel_task(
         &self,
         params: TaskIdParams,
-        _context: Option<&ServerCallContext>,
+        context: Option<&ServerCallContext>,
     ) -> Result<Option<Task>, A2AError> {
-        let task = self.task_store.get(&params.id).await?;
-        if let Some(mut task) = task {
-            task.status.state = TaskState::Canceled;
-            task.status.timestamp = Some(chrono::Utc::now().to_string());
-            self.task_store.save(task.clone()).await?;
-            
-            // Trigger push notification on cancellation
-            self.send_push_notification_if_needed(&task).await;
-            
-            Ok(Some(task))
-        } else {
-            Ok(None)
-        }
+        let task = match self.task_store.get(&params.id).await? {
+            Some(task) => task,
+            // A new task is not saved before its agent publishes a task event
+            None => match self.live_snapshot(&params.id) {
+                Some(task) => {
+                    self.task_store.save(task.clone()).await?;
+                    task
+                }
+                None => return Ok(None),
+            },
+        };
+        self.cancel_task(task, context).await.map(Some)
     }
 
     async fn on_message_send(
         &self,
-        params: MessageSendParams,
-        _context: Option<&ServerCallContext>,
+        mut params: MessageSendParams,
+        context: Option<&ServerCallContext>,
     ) -> Result<MessageSendResult, A2AError> {
-        let task_id = params.message.task_id.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
-        let context_id = params.message.context_id.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        params.validate(&self.message_limits)?;
+        if let Some(accumulated) = self.accumulate_partial_message(&mut params, context)? {
+            return Ok(MessageSendResult::Message(accumulated));
+        }
+        if let Some(task) = self.check_duplicate_message(&params.message).await? {
+            return Ok(MessageSendResult::Task(task));
+        }
+
+        let existing_task = self.load_resumable_task(&params.message).await?;
+        let mut request_context = self.build_request_context(&params, existing_task.as_ref(), context).await?;
+        let task_id = request_context.task_id.clone().unwrap_or_default();
+        let context_id = request_context.context_id.clone().unwrap_or_default();
+        let message = request_context.message().cloned().unwrap_or_else(|| params.message.clone());
 
         let mut task_manager = TaskManager::new(
             Some(task_id.clone()),
             Some(context_id.clone()),
             self.task_store.clone(),
-            Some(params.message.clone()),
+            Some(message.clone()),
             None,
-        )?;
+        )?
+        .with_status_history(self.record_status_history);
+
+        self.register_inline_push_config(&task_id, &params).await?;
 
-        // Handle push config if provided in params
-        if let Some(ref config_store) = self.push_config_store {
-            if let Some(config) = params.configuration.as_ref().and_then(|c| c.push_notification_config.clone()) {
-                config_store.set_info(&task_id, config).await?;
+        // A resumed task keeps its history and artifacts and gets the new
+        // message appended. The agent sees the updated task, so parts sent
+        // with the follow-up (such as requested files) are in its history.
+        let mut task = match existing_task {
+            Some(task) => {
+                let mut task = task_manager.update_with_message(message.clone(), task).await;
+                task.status = TaskStatus::new(TaskState::Working);
+                request_context.current_task = Some(task.clone());
+                task
             }
-        }
+            None => Task {
+                id: task_id.into(),
+                context_id,
+                status: TaskStatus::new(TaskState::Working),
+                artifacts: None,
+                history: Some(vec![message.clone()]),
+                metadata: Self::new_task_metadata(context, &request_context),
+                kind: "task".to_string(),
+            },
+        };
+        self.propagate_request_metadata(&mut task, &params);
+        // The queue is created before the task is saved so that a resubscribe
+        // arriving right after the response can always tap into it
+        let queue = match self.agent_executor {
+            Some(_) => Some(self.queue_manager.create_queue(&task.id).await?),
+            None => None,
+        };
 
-        // Mock execution: just return a task in Working state
-        let task = task_manager.save_task_event(crate::a2a::server::tasks::TaskEvent::Task(Task {
-            id: task_id,
-            context_id,
-            status: TaskStatus::new(TaskState::Working),
-            artifacts: None,
-            history: Some(vec![params.message.clone()]),
-            metadata: None,
-            kind: "task".to_string(),
-        })).await?;
+        // Non-blocking sends return the initial task while the agent keeps
+        // running; its progress is visible through tasks/get and resubscribe.
+        let blocking = params.configuration.as_ref().and_then(|c| c.blocking).unwrap_or(true);
+        // A new task awaited by the client is only saved once the agent
+        // publishes a task event, so a direct message reply leaves no task
+        let pending_task = blocking && queue.is_some() && request_context.current_task.is_none();
+        let task = if pending_task {
+            task
+        } else {
+            let task = task_manager.save_task_event(TaskEvent::Task(task)).await?;
+            self.send_push_notification_if_needed(&task).await;
+            task
+        };
 
-        // Trigger push notification
-        self.send_push_notification_if_needed(&task).await;
+        let (executor, queue) = match (self.agent_executor.clone(), queue) {
+            (Some(executor), Some(queue)) => (executor, queue),
+            _ => return Ok(MessageSendResult::Task(task)),
+        };
+        let (live, abort_registration) = LiveTask::new(task.clone());
+        self.live_tasks.lock().unwrap().insert(task.id.to_string(), live);
+        let agent_run = run_agent(
+            executor,
+            queue,
+            self.queue_manager.clone(),
+            request_context,
+            task_manager,
+            pending_task.then(|| task.clone()),
+            self.push_sender.clone(),
+            self.live_tasks.clone(),
+            self.task_metrics.clone(),
+            context.and_then(|c| c.deadline),
+            abort_registration,
+        );
 
-        Ok(MessageSendResult::Task(task))
+        if blocking {
+            agent_run.await
+        } else {
+            tokio::spawn(async move {
+                if let Err(e) = agent_run.await {
+                    error!("Background agent execution failed: {}", e);
+                }
+            });
+            Ok(MessageSendResult::Task(task))
+        }
     }
 
     async fn on_message_send_stream(
         &self,
-        params: MessageSendParams,
-        _context: Option<&ServerCallContext>,
+        mut params: MessageSendParams,
+        context: Option<&ServerCallContext>,
     ) -> Result<BoxStream<'static, Result<Event, A2AError>>, A2AError> {
-        let task_id = params.message.task_id.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
-        let context_id = params.message.context_id.clone().unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
-
-        // Handle push config
-        if let Some(ref config_store) = self.push_config_store {
-            if let Some(config) = params.configuration.as_ref().and_then(|c| c.push_notification_config.clone()) {
-                config_store.set_info(&task_id, config).await?;
-            }
-        }
-
-        let task = Task {
-            id: task_id.clone(),
-            context_id: context_id.clone(),
-            status: TaskStatus::new(TaskState::Working),
-            artifacts: None,
-            history: Some(vec![params.message.clone()]),
-            metadata: None,
-            kind: "task".to_string(),
-        };
-
-        // In a real implementation, we would wrap the stream to trigger push notifications
-        // on each event. For now, we'll just return a mock stream.
-        let sender = self.push_sender.clone();
-        let task_clone = task.clone();
-
-        let stream = futures::stream::iter(vec![
-            Ok(Event::Task(task.clone())),
-            Ok(Event::TaskStatusUpdate(TaskStatusUpdateEvent::new(
-                task_id.clone(),
-                context_id.clone(),
-                TaskStatus::new(TaskState::Completed),
-                true,
-            ))),
-        ]).then(move |res| {
-            let sender = sender.clone();
-            let task = task_clone.clone();
-            async move {
-                if let Ok(_) = res {
-                    if let Some(ref s) = sender {
-                        let _ = s.send_notification(&task).await;
+        params.validate(&self.message_limits)?;
+        if let Some(accumulated) = self.accumulate_partial_message(&mut params, context)? {
+            return Ok(Box::pin(futures::stream::iter(vec![Ok(Event::Message(accumulated))])));
+        }
+        if let Some(task) = self.check_duplicate_message(&params.message).await? {
+            return Ok(Box::pin(futures::stream::iter(vec![Ok(Event::Task(task))])));
+        }
+
+        let existing_task = self.load_resumable_task(&params.message).await?;
+        let mut request_context = self.build_request_context(&params, existing_task.as_ref(), context).await?;
+        let task_id = request_context.task_id.clone().unwrap_or_default();
+        let context_id = request_context.context_id.clone().unwrap_or_default();
+        let message = request_context.message().cloned().unwrap_or_else(|| params.message.clone());
+
+        self.register_inline_push_config(&task_id, &params).await?;
+
+        let mut task_manager = TaskManager::new(
+            Some(task_id.clone()),
+            Some(context_id.clone()),
+            self.task_store.clone(),
+            Some(message.clone()),
+            None,
+        )?
+        .with_status_history(self.record_status_history);
+
+        let mut task = match existing_task {
+            Some(task) => {
+                let mut task = task_manager.update_with_message(message.clone(), task).await;
+                task.status = TaskStatus::new(TaskState::Working);
+                request_context.current_task = Some(task.clone());
+                task
+            }
+            None => Task {
+                id: task_id.clone().into(),
+                context_id: context_id.clone(),
+                status: TaskStatus::new(TaskState::Working),
+                artifacts: None,
+                history: Some(vec![message.clone()]),
+                metadata: Self::new_task_metadata(context, &request_context),
+                kind: "task".to_string(),
+            },
+        };
+        self.propagate_request_metadata(&mut task, &params);
+
+        let executor = match self.agent_executor.clone() {
+            Some(executor) => executor,
+            None => {
+                // Without an agent the task is reported as completed right away
+                let sender = self.push_sender.clone();
+                let task_clone = task.clone();
+
+                let stream = futures::stream::iter(vec![
+                    Ok(Event::Task(task.clone())),
+                    Ok(Event::TaskStatusUpdate(TaskStatusUpdateEvent::new(
+                        task_id.clone(),
+                        context_id.clone(),
+                        TaskStatus::new(TaskState::Completed),
+                        true,
+                    ))),
+                ]).then(move |res| {
+                    let sender = sender.clone();
+                    let task = task_clone.clone();
+                    async move {
+                        if let Ok(_) = res {
+                            if let Some(ref s) = sender {
+                                let _ = s.send_notification(&task).await;
+                            }
+                        }
+                        res
                     }
-                }
-                res
+                });
+
+                return Ok(Box::pin(stream));
+            }
+        };
+
+        let queue = self.queue_manager.create_queue(&task.id).await?;
+        // A new task is only saved once the agent publishes a task event, so
+        // a direct message reply leaves no task behind
+        let pending_task = request_context.current_task.is_none();
+        let task = if pending_task {
+            task
+        } else {
+            let task = task_manager.save_task_event(TaskEvent::Task(task)).await?;
+            self.send_push_notification_if_needed(&task).await;
+            task
+        };
+
+        // The subscriber taps the queue before the agent starts so it sees
+        // every event the agent publishes
+        let subscriber = queue.tap();
+        let (live, abort_registration) = LiveTask::new(task.clone());
+        self.live_tasks.lock().unwrap().insert(task.id.to_string(), live);
+        let agent_run = run_agent(
+            executor,
+            queue,
+            self.queue_manager.clone(),
+            request_context,
+            task_manager,
+            pending_task.then(|| task.clone()),
+            self.push_sender.clone(),
+            self.live_tasks.clone(),
+            self.task_metrics.clone(),
+            context.and_then(|c| c.deadline),
+            abort_registration,
+        );
+
+        // The agent runs independently of the stream. Closing the stream
+        // (for example when the client disconnects) only detaches this
+        // subscriber; the task keeps running until it finishes or is
+        // stopped explicitly with tasks/cancel.
+        tokio::spawn(async move {
+            if let Err(e) = agent_run.await {
+                error!("Background agent execution failed: {}", e);
             }
         });
 
+        let stream = async_stream::stream! {
+            let mut events = EventConsumer::new(subscriber).consume_all();
+            // An agent answering with a direct message is not exposed as a
+            // task: the message is the only event of the stream
+            let first = events.next().await;
+            if let Some(Ok(QueueEvent::Message(message))) = first {
+                yield Ok(Event::Message(message));
+                return;
+            }
+            yield Ok(Event::Task(task));
+            if let Some(event) = first {
+                yield event.map(Event::from);
+            }
+            while let Some(event) = events.next().await {
+                yield event.map(Event::from);
+            }
+        };
+
+        Ok(Box::pin(stream))
+    }
+
+    async fn on_resubscribe_to_task(
+        &self,
+        params: TaskIdParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<BoxStream<'static, Result<Event, A2AError>>, A2AError> {
+        let task = self
+            .task_store
+            .get(&params.id)
+            .await?
+            .ok_or_else(|| A2AError::task_not_found(&params.id))?;
+
+        // Tasks without a running agent only report their current state
+        let queue = self.queue_manager.tap(&task.id).await?;
+        let stream = async_stream::stream! {
+            yield Ok(Event::Task(task));
+            if let Some(queue) = queue {
+                let mut events = EventConsumer::new(queue).consume_all();
+                while let Some(event) = events.next().await {
+                    yield event.map(Event::from);
+                }
+            }
+        };
+
         Ok(Box::pin(stream))
     }
 
@@ -176,6 +1037,8 @@ impl RequestHandler for DefaultRequestHandler {
         _context: Option<&ServerCallContext>,
     ) -> Result<TaskPushNotificationConfig, A2AError> {
         if let Some(ref store) = self.push_config_store {
+            let mut params = params;
+            default_config_id(&mut params.push_notification_config, &params.task_id);
             store.set_info(&params.task_id, params.push_notification_config.clone()).await?;
             Ok(params)
         } else {
@@ -190,7 +1053,11 @@ impl RequestHandler for DefaultRequestHandler {
     ) -> Result<TaskPushNotificationConfig, A2AError> {
         if let Some(ref store) = self.push_config_store {
             let configs = store.get_info(&params.task_id).await?;
-            if let Some(config) = configs.into_iter().next() {
+            let config = match params.push_notification_config_id.as_deref() {
+                Some(config_id) => configs.into_iter().find(|c| c.id.as_deref() == Some(config_id)),
+                None => configs.into_iter().next(),
+            };
+            if let Some(config) = config {
                 Ok(TaskPushNotificationConfig::new(params.task_id, config))
             } else {
                 Err(A2AError::internal("Push notification config not found"))
@@ -224,4 +1091,37 @@ impl RequestHandler for DefaultRequestHandler {
             Err(A2AError::unsupported_operation("Push notification config store not configured"))
         }
     }
+
+    async fn on_cancel_tasks_by_context(
+        &self,
+        params: ContextIdParams,
+        context: Option<&ServerCallContext>,
+    ) -> Result<Vec<String>, A2AError> {
+        let open_tasks: Vec<Task> = self
+            .task_store
+            .list_by_context(&params.context_id)
+            .await?
+            .into_iter()
+            .filter(|task| !task.status.state.is_terminal())
+            .collect();
+
+        // Only the user who created every open task may cancel the context;
+        // tasks created anonymously have no owner
+        let caller = Self::caller(context);
+        if let Some(task) = open_tasks.iter().find(|task| {
+            let owner = task.metadata.as_ref().and_then(|m| m.get(TASK_OWNER_METADATA_KEY)).and_then(|v| v.as_str());
+            caller.is_none() || owner != caller.as_deref()
+        }) {
+            return Err(A2AError::invalid_request(&format!(
+                "Task {} in context {} does not belong to the caller",
+                task.id, params.context_id
+            )));
+        }
+
+        let mut canceled = Vec::new();
+        for task in open_tasks {
+            canceled.push(self.cancel_task(task, context).await?.id.into());
+        }
+        Ok(canceled)
+    }
 }
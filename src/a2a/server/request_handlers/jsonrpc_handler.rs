@@ -5,6 +5,7 @@
 
 use crate::a2a::models::*;
 use crate::a2a::server::context::ServerCallContext;
+use crate::a2a::server::event_transform::{apply_event_transforms, EventTransform};
 use crate::a2a::server::request_handlers::RequestHandler;
 use crate::a2a::jsonrpc::*;
 use serde_json::Value;
@@ -12,6 +13,38 @@ use std::sync::Arc;
 use futures::{Stream, StreamExt};
 use std::pin::Pin;
 
+/// Methods dispatched by `JSONRPCHandler::handle_request`
+///
+/// Must list every arm of its dispatch `match`; a test checks that each name
+/// here is dispatched.
+pub const JSONRPC_METHODS: &[&str] = &[
+    "message/send",
+    "message/stream",
+    "tasks/get",
+    "tasks/cancel",
+    "tasks/cancelByContext",
+    "tasks/pushNotificationConfig/set",
+    "tasks/pushNotificationConfig/get",
+    "tasks/pushNotificationConfig/list",
+    "tasks/pushNotificationConfig/delete",
+    "tasks/resubscribe",
+    "agent/authenticatedExtendedCard",
+    "agent/getAuthenticatedExtendedCard",
+];
+
+/// Returns the method a leniently written method name refers to
+///
+/// Trailing slashes are ignored and names are compared case-insensitively,
+/// so `Message/Send/` refers to `message/send`. Returns `None` if the name
+/// matches no method in `JSONRPC_METHODS`.
+pub fn normalize_method_name(method: &str) -> Option<&'static str> {
+    let trimmed = method.trim_end_matches('/');
+    JSONRPC_METHODS
+        .iter()
+        .copied()
+        .find(|known| known.eq_ignore_ascii_case(trimmed))
+}
+
 /// JSON-RPC Handler
 /// 
 /// Maps incoming JSON-RPC requests to the appropriate request handler methods
@@ -20,6 +53,7 @@ pub struct JSONRPCHandler {
     agent_card: AgentCard,
     #[allow(dead_code)]
     request_handler: Arc<dyn RequestHandler>,
+    event_transforms: Vec<Arc<dyn EventTransform>>,
 }
 
 impl JSONRPCHandler {
@@ -35,9 +69,16 @@ impl JSONRPCHandler {
         Self {
             agent_card,
             request_handler,
+            event_transforms: Vec::new(),
         }
     }
 
+    /// Apply `transform` to every streamed event, after any added before it
+    pub fn with_event_transform(mut self, transform: Arc<dyn EventTransform>) -> Self {
+        self.event_transforms.push(transform);
+        self
+    }
+
     /// Convert JSONRPCId to serde_json::Value
     fn id_to_value(id: &Option<crate::a2a::jsonrpc::JSONRPCId>) -> Value {
         match id {
@@ -70,12 +111,13 @@ impl JSONRPCHandler {
             "message/stream" => self.handle_message_stream(jsonrpc_request, context).await,
             "tasks/get" => self.handle_get_task(jsonrpc_request, context).await,
             "tasks/cancel" => self.handle_cancel_task(jsonrpc_request, context).await,
+            "tasks/cancelByContext" => self.handle_cancel_tasks_by_context(jsonrpc_request, context).await,
             "tasks/pushNotificationConfig/set" => self.handle_set_push_notification_config(jsonrpc_request, context).await,
             "tasks/pushNotificationConfig/get" => self.handle_get_push_notification_config(jsonrpc_request, context).await,
             "tasks/pushNotificationConfig/list" => self.handle_list_push_notification_config(jsonrpc_request, context).await,
             "tasks/pushNotificationConfig/delete" => self.handle_delete_push_notification_config(jsonrpc_request, context).await,
             "tasks/resubscribe" => self.handle_resubscribe_task(jsonrpc_request, context).await,
-            "agent/authenticatedExtendedCard" => self.handle_get_authenticated_extended_card(jsonrpc_request, context).await,
+            "agent/authenticatedExtendedCard" | "agent/getAuthenticatedExtendedCard" => self.handle_get_authenticated_extended_card(jsonrpc_request, context).await,
             _ => Err(JSONRPCError::new(
                 standard_error_codes::METHOD_NOT_FOUND,
                 format!("Method '{}' not found", jsonrpc_request.method),
@@ -86,12 +128,7 @@ impl JSONRPCHandler {
     /// Parse a JSON-RPC request
     pub fn parse_request(&self, request: Value) -> Result<JSONRPCRequest, JSONRPCError> {
         // Check for required JSON-RPC 2.0 fields
-        if !request.get("jsonrpc").and_then(|v| v.as_str()).map(|s| s == "2.0").unwrap_or(false) {
-            return Err(JSONRPCError::new(
-                standard_error_codes::INVALID_REQUEST,
-                "Invalid or missing 'jsonrpc' version field".to_string(),
-            ));
-        }
+        validate_jsonrpc_version(&request)?;
 
         let method = request
             .get("method")
@@ -104,13 +141,14 @@ impl JSONRPCHandler {
             })?
             .to_string();
 
-        let params = request.get("params").cloned().unwrap_or(Value::Null);
+        // An omitted `params` and an explicit `null` both mean "no parameters"
+        let params = request.get("params").filter(|v| !v.is_null()).cloned();
         let id = request.get("id").cloned();
 
         Ok(JSONRPCRequest {
-            jsonrpc: "2.0".to_string(),
+            jsonrpc: JSONRPC_VERSION.to_string(),
             method,
-            params: Some(params),
+            params,
             id: id.and_then(|id| {
                 match id {
                     Value::String(s) => Some(crate::a2a::jsonrpc::JSONRPCId::String(s)),
@@ -149,39 +187,13 @@ impl JSONRPCHandler {
         let result = self.request_handler
             .on_message_send(message_send_params, Some(context))
             .await
-            .map_err(|e| {
-                JSONRPCError::new(
-                    standard_error_codes::INTERNAL_ERROR,
-                    format!("Handler error: {}", e),
-                )
-            })?;
+            .map_err(Self::handler_error)?;
 
-        // Convert the result to the expected format
-        let result_value = match result {
-            crate::a2a::server::request_handlers::request_handler::MessageSendResult::Task(task) => {
-                serde_json::to_value(task).map_err(|e| {
-                    JSONRPCError::new(
-                        standard_error_codes::INTERNAL_ERROR,
-                        format!("Failed to serialize task: {}", e),
-                    )
-                })?
-            }
-            crate::a2a::server::request_handlers::request_handler::MessageSendResult::Message(message) => {
-                serde_json::to_value(message).map_err(|e| {
-                    JSONRPCError::new(
-                        standard_error_codes::INTERNAL_ERROR,
-                        format!("Failed to serialize message: {}", e),
-                    )
-                })?
-            }
+        let result = match result {
+            crate::a2a::server::request_handlers::request_handler::MessageSendResult::Task(task) => SendMessageResult::Task(task),
+            crate::a2a::server::request_handlers::request_handler::MessageSendResult::Message(message) => SendMessageResult::Message(message),
         };
-
-        let response = serde_json::json!({
-            "jsonrpc": "2.0",
-            "result": result_value,
-            "id": Self::id_to_value(&request.id)
-        });
-        Ok(response)
+        Self::a2a_success_response(&request, A2AResponse::SendMessage(result))
     }
 
     /// Handle message/stream requests
@@ -191,7 +203,7 @@ impl JSONRPCHandler {
         context: &ServerCallContext,
     ) -> Result<Value, JSONRPCError> {
         // Check if streaming is supported
-        if !self.agent_card.capabilities.streaming.unwrap_or(false) {
+        if !self.agent_card.capabilities.supports_streaming() {
             return Err(JSONRPCError::new(
                 standard_error_codes::INVALID_REQUEST,
                 "Streaming is not supported by this agent".to_string(),
@@ -219,12 +231,9 @@ impl JSONRPCHandler {
         let event_stream = self.request_handler
             .on_message_send_stream(message_send_params, Some(context))
             .await
-            .map_err(|e| {
-                JSONRPCError::new(
-                    standard_error_codes::INTERNAL_ERROR,
-                    format!("Handler error: {}", e),
-                )
-            })?;
+            .map_err(Self::handler_error)?;
+
+        let event_stream = apply_event_transforms(event_stream, self.event_transforms.clone(), context.clone());
 
         // Convert the event stream to SSE format and return as JSON-RPC response
         // This is a simplified implementation that converts the stream to a JSON array
@@ -251,7 +260,7 @@ impl JSONRPCHandler {
         context: &ServerCallContext,
     ) -> Result<Pin<Box<dyn Stream<Item = Result<String, JSONRPCError>> + Send>>, JSONRPCError> {
         // Check if streaming is supported
-        if !self.agent_card.capabilities.streaming.unwrap_or(false) {
+        if !self.agent_card.capabilities.supports_streaming() {
             return Err(JSONRPCError::new(
                 standard_error_codes::INVALID_REQUEST,
                 "Streaming is not supported by this agent".to_string(),
@@ -279,24 +288,12 @@ impl JSONRPCHandler {
         let event_stream = self.request_handler
             .on_message_send_stream(message_send_params, Some(context))
             .await
-            .map_err(|e| {
-                JSONRPCError::new(
-                    standard_error_codes::INTERNAL_ERROR,
-                    format!("Handler error: {}", e),
-                )
-            })?;
+            .map_err(Self::handler_error)?;
 
-        // Get the request ID as serde_json::Value
-        let request_id = request.id.as_ref().map(|id| {
-            match id {
-                crate::a2a::jsonrpc::JSONRPCId::String(s) => Value::String(s.clone()),
-                crate::a2a::jsonrpc::JSONRPCId::Number(n) => Value::Number(serde_json::Number::from(*n)),
-                crate::a2a::jsonrpc::JSONRPCId::Null => Value::Null,
-            }
-        });
+        let event_stream = apply_event_transforms(event_stream, self.event_transforms.clone(), context.clone());
 
         // Convert the event stream to SSE format
-        Ok(Box::pin(self.events_to_sse_stream(event_stream, request_id)))
+        Ok(Box::pin(self.events_to_sse_stream(event_stream, request.id.clone())))
     }
 
     /// Collect events from a stream into a JSON array
@@ -310,48 +307,10 @@ impl JSONRPCHandler {
         while let Some(event_result) = event_stream.next().await {
             match event_result {
                 Ok(event) => {
-                    let event_value = match event {
-                        crate::a2a::server::request_handlers::request_handler::Event::TaskStatusUpdate(update) => {
-                            serde_json::to_value(update).map_err(|e| {
-                                JSONRPCError::new(
-                                    standard_error_codes::INTERNAL_ERROR,
-                                    format!("Failed to serialize task status update: {}", e),
-                                )
-                            })?
-                        }
-                        crate::a2a::server::request_handlers::request_handler::Event::TaskArtifactUpdate(update) => {
-                            serde_json::to_value(update).map_err(|e| {
-                                JSONRPCError::new(
-                                    standard_error_codes::INTERNAL_ERROR,
-                                    format!("Failed to serialize task artifact update: {}", e),
-                                )
-                            })?
-                        }
-                        crate::a2a::server::request_handlers::request_handler::Event::Message(message) => {
-                            serde_json::to_value(message).map_err(|e| {
-                                JSONRPCError::new(
-                                    standard_error_codes::INTERNAL_ERROR,
-                                    format!("Failed to serialize message: {}", e),
-                                )
-                            })?
-                        }
-                        crate::a2a::server::request_handlers::request_handler::Event::Task(task) => {
-                            serde_json::to_value(task).map_err(|e| {
-                                JSONRPCError::new(
-                                    standard_error_codes::INTERNAL_ERROR,
-                                    format!("Failed to serialize task: {}", e),
-                                )
-                            })?
-                        }
-                    };
+                    let event_value = a2a_response_to_jsonrpc(None, Self::stream_response(event)).result;
                     events.push(event_value);
                 }
-                Err(e) => {
-                    return Err(JSONRPCError::new(
-                        standard_error_codes::INTERNAL_ERROR,
-                        format!("Event stream error: {}", e),
-                    ));
-                }
+                Err(e) => return Err(Self::handler_error(e)),
             }
         }
         
@@ -362,48 +321,21 @@ impl JSONRPCHandler {
     fn events_to_sse_stream(
         &self,
         event_stream: Pin<Box<dyn Stream<Item = Result<crate::a2a::server::request_handlers::request_handler::Event, crate::a2a::error::A2AError>> + Send>>,
-        request_id: Option<serde_json::Value>,
+        request_id: Option<JSONRPCId>,
     ) -> impl Stream<Item = Result<String, crate::a2a::jsonrpc::JSONRPCError>> {
         event_stream.map(move |event_result| {
             match event_result {
                 Ok(event) => {
-                    // Convert the event to SendStreamingMessageResult
-                    let result = match event {
-                        crate::a2a::server::request_handlers::request_handler::Event::TaskStatusUpdate(update) => {
-                            crate::a2a::models::SendStreamingMessageResult::TaskStatusUpdateEvent(update)
-                        }
-                        crate::a2a::server::request_handlers::request_handler::Event::TaskArtifactUpdate(update) => {
-                            crate::a2a::models::SendStreamingMessageResult::TaskArtifactUpdateEvent(update)
-                        }
-                        crate::a2a::server::request_handlers::request_handler::Event::Message(message) => {
-                            crate::a2a::models::SendStreamingMessageResult::Message(message)
-                        }
-                        crate::a2a::server::request_handlers::request_handler::Event::Task(task) => {
-                            crate::a2a::models::SendStreamingMessageResult::Task(task)
-                        }
-                    };
-
-                    // Create the streaming response
-                    let response = crate::a2a::models::SendStreamingMessageResponse::success(
-                        request_id.clone(),
-                        result,
-                    );
-                    
-                    match serde_json::to_value(&response) {
-                        Ok(json) => {
-                            // Format as SSE: data: {json}\n\n
-                            Ok(format!("data: {}\n\n", json.to_string()))
-                        }
+                    let response = a2a_response_to_jsonrpc(request_id.clone(), Self::stream_response(event));
+                    match crate::a2a::server::sse::json_sse_frame(&response, None) {
+                        Ok(frame) => Ok(frame),
                         Err(e) => Err(crate::a2a::jsonrpc::JSONRPCError::new(
                             standard_error_codes::INTERNAL_ERROR,
                             format!("Failed to serialize streaming response to JSON: {}", e),
                         )),
                     }
                 }
-                Err(e) => Err(crate::a2a::jsonrpc::JSONRPCError::new(
-                    standard_error_codes::INTERNAL_ERROR,
-                    format!("Event stream error: {}", e),
-                )),
+                Err(e) => Err(Self::handler_error(e)),
             }
         })
     }
@@ -412,109 +344,214 @@ impl JSONRPCHandler {
     async fn handle_get_task(
         &self,
         request: JSONRPCRequest,
-        _context: &ServerCallContext,
+        context: &ServerCallContext,
     ) -> Result<Value, JSONRPCError> {
-        let response = serde_json::json!({
-            "jsonrpc": "2.0",
-            "result": "tasks/get handled",
-            "id": Self::id_to_value(&request.id)
-        });
-        Ok(response)
+        let params: TaskQueryParams = Self::parse_params(&request)?;
+        let task_id = params.id.clone();
+        let task = self.request_handler
+            .on_get_task(params, Some(context))
+            .await
+            .map_err(Self::handler_error)?
+            .ok_or_else(|| Self::handler_error(crate::a2a::error::A2AError::task_not_found(&task_id)))?;
+        Self::a2a_success_response(&request, A2AResponse::GetTask(task))
     }
 
     /// Handle tasks/cancel requests
     async fn handle_cancel_task(
         &self,
         request: JSONRPCRequest,
-        _context: &ServerCallContext,
+        context: &ServerCallContext,
     ) -> Result<Value, JSONRPCError> {
-        let response = serde_json::json!({
-            "jsonrpc": "2.0",
-            "result": "tasks/cancel handled",
-            "id": Self::id_to_value(&request.id)
-        });
-        Ok(response)
+        let params: TaskIdParams = Self::parse_params(&request)?;
+        let task_id = params.id.clone();
+        let task = self.request_handler
+            .on_cancel_task(params, Some(context))
+            .await
+            .map_err(Self::handler_error)?
+            .ok_or_else(|| Self::handler_error(crate::a2a::error::A2AError::task_not_found(&task_id)))?;
+        Self::a2a_success_response(&request, A2AResponse::CancelTask(task))
+    }
+
+    /// Handle tasks/cancelByContext requests
+    async fn handle_cancel_tasks_by_context(
+        &self,
+        request: JSONRPCRequest,
+        context: &ServerCallContext,
+    ) -> Result<Value, JSONRPCError> {
+        let params: ContextIdParams = Self::parse_params(&request)?;
+        let canceled = self.request_handler
+            .on_cancel_tasks_by_context(params, Some(context))
+            .await
+            .map_err(Self::handler_error)?;
+        Self::success_response(&request, canceled)
     }
 
     /// Handle tasks/pushNotificationConfig/set requests
     async fn handle_set_push_notification_config(
         &self,
         request: JSONRPCRequest,
-        _context: &ServerCallContext,
+        context: &ServerCallContext,
     ) -> Result<Value, JSONRPCError> {
         // Check if push notifications are supported
-        if !self.agent_card.capabilities.push_notifications.unwrap_or(false) {
-            return Err(JSONRPCError::new(
-                standard_error_codes::INVALID_REQUEST,
-                "Push notifications are not supported by this agent".to_string(),
-            ));
+        if !self.agent_card.capabilities.supports_push_notifications() {
+            return Err(Self::handler_error(crate::a2a::error::A2AError::push_notification_not_supported()));
         }
 
-        let response = serde_json::json!({
-            "jsonrpc": "2.0",
-            "result": "tasks/pushNotificationConfig/set handled",
-            "id": Self::id_to_value(&request.id)
-        });
-        Ok(response)
+        let params: TaskPushNotificationConfig = Self::parse_params(&request)?;
+        let config = self.request_handler
+            .on_set_task_push_notification_config(params, Some(context))
+            .await
+            .map_err(Self::handler_error)?;
+        Self::a2a_success_response(&request, A2AResponse::SetTaskPushNotificationConfig(config))
     }
 
     /// Handle tasks/pushNotificationConfig/get requests
     async fn handle_get_push_notification_config(
         &self,
         request: JSONRPCRequest,
-        _context: &ServerCallContext,
+        context: &ServerCallContext,
     ) -> Result<Value, JSONRPCError> {
-        let response = serde_json::json!({
-            "jsonrpc": "2.0",
-            "result": "tasks/pushNotificationConfig/get handled",
-            "id": Self::id_to_value(&request.id)
-        });
-        Ok(response)
+        let params: GetTaskPushNotificationConfigParams = Self::parse_params(&request)?;
+        let query = crate::a2a::server::request_handlers::request_handler::TaskPushNotificationConfigQueryParams {
+            task_id: params.id,
+            push_notification_config_id: params.push_notification_config_id,
+            metadata: params.metadata.map(|m| serde_json::to_value(m).unwrap_or_default()),
+        };
+        let config = self.request_handler
+            .on_get_task_push_notification_config(query, Some(context))
+            .await
+            .map_err(Self::handler_error)?;
+        Self::a2a_success_response(&request, A2AResponse::GetTaskPushNotificationConfig(config))
     }
 
     /// Handle tasks/pushNotificationConfig/list requests
     async fn handle_list_push_notification_config(
         &self,
         request: JSONRPCRequest,
-        _context: &ServerCallContext,
+        context: &ServerCallContext,
     ) -> Result<Value, JSONRPCError> {
-        let response = serde_json::json!({
-            "jsonrpc": "2.0",
-            "result": "tasks/pushNotificationConfig/list handled",
-            "id": Self::id_to_value(&request.id)
-        });
-        Ok(response)
+        let params: TaskIdParams = Self::parse_params(&request)?;
+        let configs = self.request_handler
+            .on_list_task_push_notification_config(params, Some(context))
+            .await
+            .map_err(Self::handler_error)?;
+        Self::a2a_success_response(&request, A2AResponse::ListTaskPushNotificationConfig(configs))
     }
 
     /// Handle tasks/pushNotificationConfig/delete requests
     async fn handle_delete_push_notification_config(
         &self,
         request: JSONRPCRequest,
-        _context: &ServerCallContext,
+        context: &ServerCallContext,
     ) -> Result<Value, JSONRPCError> {
-        let response = serde_json::json!({
+        let params: DeleteTaskPushNotificationConfigParams = Self::parse_params(&request)?;
+        self.request_handler
+            .on_delete_task_push_notification_config(params, Some(context))
+            .await
+            .map_err(Self::handler_error)?;
+        Self::a2a_success_response(&request, A2AResponse::DeleteTaskPushNotificationConfig(()))
+    }
+
+    /// Deserialize the request params into the expected type
+    fn parse_params<T: serde::de::DeserializeOwned>(request: &JSONRPCRequest) -> Result<T, JSONRPCError> {
+        let params = request.params.as_ref().ok_or_else(|| {
+            JSONRPCError::new(
+                standard_error_codes::INVALID_PARAMS,
+                "Missing params field".to_string(),
+            )
+        })?;
+        serde_json::from_value(params.clone()).map_err(|e| {
+            JSONRPCError::new(
+                standard_error_codes::INVALID_PARAMS,
+                format!("Invalid params: {}", e),
+            )
+        })
+    }
+
+    /// Convert a request handler error, keeping its A2A error code and data
+    fn handler_error(error: crate::a2a::error::A2AError) -> JSONRPCError {
+        let mut jsonrpc_error = JSONRPCError::new(error.code(), error.message().to_string());
+        jsonrpc_error.data = error.data().cloned();
+        jsonrpc_error
+    }
+
+    /// The `message/stream` result carrying a stream event
+    fn stream_response(event: crate::a2a::server::request_handlers::request_handler::Event) -> A2AResponse {
+        use crate::a2a::server::request_handlers::request_handler::Event;
+
+        A2AResponse::SendStreamingMessage(match event {
+            Event::TaskStatusUpdate(update) => crate::a2a::jsonrpc::SendStreamingMessageResult::TaskStatusUpdate(update),
+            Event::TaskArtifactUpdate(update) => crate::a2a::jsonrpc::SendStreamingMessageResult::TaskArtifactUpdate(update),
+            Event::Message(message) => crate::a2a::jsonrpc::SendStreamingMessageResult::Message(message),
+            Event::Task(task) => crate::a2a::jsonrpc::SendStreamingMessageResult::Task(task),
+        })
+    }
+
+    /// Build a success response for a typed A2A response
+    fn a2a_success_response(request: &JSONRPCRequest, response: A2AResponse) -> Result<Value, JSONRPCError> {
+        serde_json::to_value(a2a_response_to_jsonrpc(request.id.clone(), response)).map_err(|e| {
+            JSONRPCError::new(
+                standard_error_codes::INTERNAL_ERROR,
+                format!("Failed to serialize result: {}", e),
+            )
+        })
+    }
+
+    /// Build a JSON-RPC success response for the request
+    fn success_response<T: serde::Serialize>(request: &JSONRPCRequest, result: T) -> Result<Value, JSONRPCError> {
+        let result = serde_json::to_value(result).map_err(|e| {
+            JSONRPCError::new(
+                standard_error_codes::INTERNAL_ERROR,
+                format!("Failed to serialize result: {}", e),
+            )
+        })?;
+        Ok(serde_json::json!({
             "jsonrpc": "2.0",
-            "result": "tasks/pushNotificationConfig/delete handled",
+            "result": result,
             "id": Self::id_to_value(&request.id)
-        });
-        Ok(response)
+        }))
     }
 
-    /// Handle tasks/resubscribe requests
+    /// Handle tasks/resubscribe requests outside of an SSE response
+    ///
+    /// The task's events are only served as a stream, through
+    /// `handle_resubscribe_task_sse`, so this fails with `UnsupportedOperation`.
     async fn handle_resubscribe_task(
         &self,
-        request: JSONRPCRequest,
+        _request: JSONRPCRequest,
         _context: &ServerCallContext,
     ) -> Result<Value, JSONRPCError> {
-        let response = serde_json::json!({
-            "jsonrpc": "2.0",
-            "result": "tasks/resubscribe handled",
-            "id": Self::id_to_value(&request.id)
-        });
-        Ok(response)
+        Err(Self::handler_error(crate::a2a::error::A2AError::unsupported_operation(
+            "tasks/resubscribe is only served as a Server-Sent Events stream",
+        )))
+    }
+
+    /// Handle tasks/resubscribe requests with an SSE stream
+    ///
+    /// Each event of the task's stream is sent as a `message/stream` result.
+    pub async fn handle_resubscribe_task_sse(
+        &self,
+        request: JSONRPCRequest,
+        context: &ServerCallContext,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<String, JSONRPCError>> + Send>>, JSONRPCError> {
+        if !self.agent_card.capabilities.supports_streaming() {
+            return Err(JSONRPCError::new(
+                standard_error_codes::INVALID_REQUEST,
+                "Streaming is not supported by this agent".to_string(),
+            ));
+        }
+
+        let params: TaskIdParams = Self::parse_params(&request)?;
+        let event_stream = self.request_handler
+            .on_resubscribe_to_task(params, Some(context))
+            .await
+            .map_err(Self::handler_error)?;
+        let event_stream = apply_event_transforms(event_stream, self.event_transforms.clone(), context.clone());
+
+        Ok(Box::pin(self.events_to_sse_stream(event_stream, request.id.clone())))
     }
 
-    /// Handle agent/authenticatedExtendedCard requests
+    /// Handle agent/getAuthenticatedExtendedCard requests
     async fn handle_get_authenticated_extended_card(
         &self,
         request: JSONRPCRequest,
@@ -528,12 +565,7 @@ impl JSONRPCHandler {
             ));
         }
 
-        let response = serde_json::json!({
-            "jsonrpc": "2.0",
-            "result": "agent/authenticatedExtendedCard handled",
-            "id": Self::id_to_value(&request.id)
-        });
-        Ok(response)
+        Self::a2a_success_response(&request, A2AResponse::GetAuthenticatedExtendedCard(self.agent_card.clone()))
     }
 }
 
@@ -584,6 +616,133 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_parse_request_without_params() {
+        let handler = create_test_handler();
+
+        let omitted = serde_json::json!({"jsonrpc": "2.0", "method": "test", "id": 1});
+        assert_eq!(handler.parse_request(omitted).unwrap().params, None);
+
+        let null = serde_json::json!({"jsonrpc": "2.0", "method": "test", "params": null, "id": 1});
+        assert_eq!(handler.parse_request(null).unwrap().params, None);
+    }
+
+    #[tokio::test]
+    async fn test_handle_extended_card_with_null_params() {
+        let agent_card = AgentCard::new(
+            "Test Agent".to_string(),
+            "A test agent".to_string(),
+            "http://localhost:8080".to_string(),
+            "1.0.0".to_string(),
+            vec!["text/plain".to_string()],
+            vec!["text/plain".to_string()],
+            AgentCapabilities::new(),
+            vec![],
+        )
+        .with_supports_authenticated_extended_card(true);
+        let handler = JSONRPCHandler::new(agent_card, Arc::new(MockRequestHandler::new()));
+        let context = ServerCallContext::new();
+
+        for request in [
+            serde_json::json!({"jsonrpc": "2.0", "method": "agent/getAuthenticatedExtendedCard", "params": null, "id": 1}),
+            serde_json::json!({"jsonrpc": "2.0", "method": "agent/getAuthenticatedExtendedCard", "id": 2}),
+        ] {
+            let response = handler.handle_request(request, &context).await.unwrap();
+            assert!(response.get("result").is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_every_listed_method_is_dispatched() {
+        let handler = create_test_handler();
+        let context = ServerCallContext::new();
+
+        // Requests with empty params may fail, but never as an unknown method
+        for method in JSONRPC_METHODS.iter().copied().chain(["unknown/method"]) {
+            let request = serde_json::json!({"jsonrpc": "2.0", "method": method, "params": {}, "id": 1});
+            let code = match handler.handle_request(request, &context).await {
+                Ok(response) => response["error"]["code"].as_i64().map(|code| code as i32),
+                Err(error) => Some(error.code),
+            };
+            let dispatched = code != Some(standard_error_codes::METHOD_NOT_FOUND);
+            assert_eq!(dispatched, method != "unknown/method", "{}", method);
+        }
+    }
+
+    fn push_config_handler(push_notifications: bool) -> JSONRPCHandler {
+        use crate::a2a::server::request_handlers::DefaultRequestHandler;
+        use crate::a2a::server::tasks::{InMemoryPushNotificationConfigStore, InMemoryTaskStore};
+
+        let agent_card = AgentCard::new(
+            "Test Agent".to_string(),
+            "A test agent".to_string(),
+            "http://localhost:8080".to_string(),
+            "1.0.0".to_string(),
+            vec!["text/plain".to_string()],
+            vec!["text/plain".to_string()],
+            AgentCapabilities::new().with_push_notifications(push_notifications),
+            vec![],
+        );
+        let request_handler = DefaultRequestHandler::new(
+            Arc::new(InMemoryTaskStore::new()),
+            Some(Arc::new(InMemoryPushNotificationConfigStore::new())),
+            None,
+        );
+        JSONRPCHandler::new(agent_card, Arc::new(request_handler))
+    }
+
+    fn set_push_config_request() -> Value {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "tasks/pushNotificationConfig/set",
+            "params": {
+                "task_id": "task-1",
+                "push_notification_config": {"url": "https://example.com/callback"}
+            },
+            "id": 1
+        })
+    }
+
+    #[tokio::test]
+    async fn test_set_push_config_rejected_when_unsupported() {
+        let handler = push_config_handler(false);
+        let context = ServerCallContext::new();
+
+        let error = handler.handle_request(set_push_config_request(), &context).await.unwrap_err();
+        assert_eq!(error.code, error_codes::PUSH_NOTIFICATION_NOT_SUPPORTED);
+    }
+
+    #[tokio::test]
+    async fn test_set_push_config_allowed_when_supported() {
+        let handler = push_config_handler(true);
+        let context = ServerCallContext::new();
+
+        let response = handler.handle_request(set_push_config_request(), &context).await.unwrap();
+        assert_eq!(response["result"]["taskId"], "task-1");
+        assert_eq!(response["result"]["pushNotificationConfig"]["id"], "task-1");
+    }
+
+    #[tokio::test]
+    async fn test_delete_push_config_result_is_null() {
+        let handler = push_config_handler(true);
+        let context = ServerCallContext::new();
+        handler.handle_request(set_push_config_request(), &context).await.unwrap();
+
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "method": "tasks/pushNotificationConfig/delete",
+            "params": {"id": "task-1", "push_notification_config_id": "task-1"},
+            "id": 2
+        });
+        let response = handler.handle_request(request, &context).await.unwrap();
+
+        // Matches the Python SDK, which sends `"result": null` on success
+        assert_eq!(
+            response,
+            serde_json::json!({"jsonrpc": "2.0", "result": null, "id": 2})
+        );
+    }
+
     #[tokio::test]
     async fn test_handle_unknown_method() {
         let handler = create_test_handler();
@@ -727,6 +886,18 @@ mod tests {
         assert!(error.message.contains("Streaming is not supported"));
     }
 
+    #[tokio::test]
+    async fn test_stream_errors_keep_their_code() {
+        let handler = create_test_handler();
+        let events: Pin<Box<dyn Stream<Item = Result<crate::a2a::server::request_handlers::request_handler::Event, crate::a2a::error::A2AError>> + Send>> =
+            Box::pin(futures::stream::iter(vec![Err(crate::a2a::error::A2AError::task_not_found("task-1"))]));
+
+        let results: Vec<_> = handler.events_to_sse_stream(events, None).collect().await;
+        let error = results[0].as_ref().unwrap_err();
+        assert_eq!(error.code, crate::a2a::jsonrpc::error_codes::TASK_NOT_FOUND);
+        assert_eq!(error.data, Some(serde_json::json!({"task_id": "task-1"})));
+    }
+
     fn create_test_handler() -> JSONRPCHandler {
         let agent_card = AgentCard::new(
             "Test Agent".to_string(),
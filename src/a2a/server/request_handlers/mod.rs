@@ -6,8 +6,10 @@
 pub mod request_handler;
 pub mod jsonrpc_handler;
 pub mod default_request_handler;
+pub mod auth_required_handler;
 
 // Re-export main types for convenience
 pub use request_handler::*;
 pub use jsonrpc_handler::*;
 pub use default_request_handler::*;
+pub use auth_required_handler::AuthRequiredHandler;
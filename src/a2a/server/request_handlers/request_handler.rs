@@ -105,6 +105,20 @@ pub trait RequestHandler: Send + Sync {
         params: DeleteTaskPushNotificationConfigParams,
         context: Option<&ServerCallContext>,
     ) -> Result<(), A2AError>;
+
+    /// Handles the 'tasks/cancelByContext' method
+    /// 
+    /// Cancels every non-terminal task of a context and returns the ids of
+    /// the canceled tasks. This is an operator action and requires an
+    /// authenticated caller.
+    async fn on_cancel_tasks_by_context(
+        &self,
+        _params: ContextIdParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<Vec<String>, A2AError> {
+        // Default implementation raises UnsupportedOperationError
+        Err(A2AError::unsupported_operation("Canceling tasks by context is not supported"))
+    }
 }
 
 /// Result type for message send operations
@@ -131,6 +145,29 @@ pub enum Event {
     Task(Task),
 }
 
+impl From<crate::a2a::server::events::Event> for Event {
+    fn from(event: crate::a2a::server::events::Event) -> Self {
+        use crate::a2a::server::events::Event as QueueEvent;
+        match event {
+            QueueEvent::TaskStatusUpdate(update) => Event::TaskStatusUpdate(update),
+            QueueEvent::TaskArtifactUpdate(update) => Event::TaskArtifactUpdate(update),
+            QueueEvent::Message(message) => Event::Message(message),
+            QueueEvent::Task(task) => Event::Task(task),
+        }
+    }
+}
+
+impl From<Event> for TaskOrMessage {
+    fn from(event: Event) -> Self {
+        match event {
+            Event::Task(task) => TaskOrMessage::Task(task),
+            Event::Message(message) => TaskOrMessage::Message(message),
+            Event::TaskStatusUpdate(update) => TaskOrMessage::TaskUpdate(update),
+            Event::TaskArtifactUpdate(update) => TaskOrMessage::TaskArtifactUpdateEvent(update),
+        }
+    }
+}
+
 /// Mock request handler for testing
 pub struct MockRequestHandler;
 
@@ -205,6 +242,7 @@ impl RequestHandler for MockRequestHandler {
                 extensions: None,
                 reference_task_ids: None,
                 kind: "message".to_string(),
+                part_form: None,
             })),
             // Task status update - completed
             Ok(Event::TaskStatusUpdate(TaskStatusUpdateEvent {
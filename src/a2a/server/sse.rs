@@ -0,0 +1,96 @@
+//! Server-Sent Events framing for streaming responses
+//!
+//! Streaming methods answer with `text/event-stream`. Each event is written
+//! as an optional `id:` field followed by `data:` fields and a blank line.
+
+use crate::a2a::models::SendStreamingMessageResult;
+use serde::Serialize;
+
+/// Content type of Server-Sent Events responses
+pub const SSE_CONTENT_TYPE: &str = "text/event-stream";
+
+/// Builds an SSE frame carrying a streaming result as minified JSON
+///
+/// The payload always fits on a single `data:` line because minified JSON
+/// escapes any newlines inside strings.
+pub fn to_sse_frame(result: &SendStreamingMessageResult, event_id: Option<&str>) -> String {
+    json_sse_frame(result, event_id)
+        .unwrap_or_else(|e| {
+            let error = serde_json::json!({"error": format!("Failed to serialize event: {}", e)});
+            encode_sse_frame(&error.to_string(), event_id)
+        })
+}
+
+/// Builds an SSE frame carrying any serializable value as minified JSON
+pub fn json_sse_frame<T: Serialize + ?Sized>(value: &T, event_id: Option<&str>) -> Result<String, serde_json::Error> {
+    serde_json::to_string(value).map(|data| encode_sse_frame(&data, event_id))
+}
+
+/// Builds an SSE frame from raw event data
+///
+/// Each line of `data` becomes its own `data:` field, which clients join
+/// back with newlines. Line breaks are dropped from the event id since a
+/// field cannot span lines.
+pub fn encode_sse_frame(data: &str, event_id: Option<&str>) -> String {
+    let mut frame = String::with_capacity(data.len() + 16);
+    if let Some(id) = event_id {
+        let id: String = id.chars().filter(|c| *c != '\n' && *c != '\r').collect();
+        frame.push_str("id: ");
+        frame.push_str(&id);
+        frame.push('\n');
+    }
+    for line in data.split('\n') {
+        frame.push_str("data: ");
+        frame.push_str(line.strip_suffix('\r').unwrap_or(line));
+        frame.push('\n');
+    }
+    frame.push('\n');
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::core_types::{Message, Part, Role};
+
+    fn message_result(text: &str) -> SendStreamingMessageResult {
+        SendStreamingMessageResult::Message(
+            Message::new(Role::Agent, vec![Part::text(text.to_string())]).with_message_id("msg-1".to_string()),
+        )
+    }
+
+    #[test]
+    fn test_to_sse_frame_format() {
+        let frame = to_sse_frame(&message_result("hello"), Some("7"));
+        let lines: Vec<&str> = frame.split('\n').collect();
+
+        assert_eq!(lines[0], "id: 7");
+        assert!(lines[1].starts_with("data: {"));
+        assert_eq!(&lines[2..], &["", ""]);
+
+        let payload: serde_json::Value = serde_json::from_str(&lines[1]["data: ".len()..]).unwrap();
+        assert_eq!(payload["kind"], "message");
+        assert_eq!(payload["messageId"], "msg-1");
+
+        let without_id = to_sse_frame(&message_result("hello"), None);
+        assert!(without_id.starts_with("data: {"));
+        assert!(without_id.ends_with("}\n\n"));
+    }
+
+    #[test]
+    fn test_to_sse_frame_multiline_text_is_single_data_line() {
+        let frame = to_sse_frame(&message_result("line one\nline two\r\n"), Some("a\nb"));
+
+        assert!(frame.starts_with("id: ab\n"));
+        assert_eq!(frame.matches("data:").count(), 1);
+        assert_eq!(frame.trim_end().lines().count(), 2);
+        assert!(frame.contains(r#"line one\nline two\r\n"#));
+    }
+
+    #[test]
+    fn test_encode_sse_frame_splits_multiline_data() {
+        let pretty = serde_json::to_string_pretty(&serde_json::json!({"a": 1})).unwrap();
+        let frame = encode_sse_frame(&pretty, None);
+        assert_eq!(frame, "data: {\ndata:   \"a\": 1\ndata: }\n\n");
+    }
+}
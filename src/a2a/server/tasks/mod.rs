@@ -9,6 +9,8 @@ pub mod sql_task_store;
 pub mod push_notification_config_store;
 pub mod sql_push_notification_config_store;
 pub mod push_notification_sender;
+pub mod write_behind_task_store;
+pub mod task_reaper;
 
 pub use task_store::*;
 pub use task_manager::*;
@@ -16,3 +18,5 @@ pub use sql_task_store::*;
 pub use push_notification_config_store::*;
 pub use sql_push_notification_config_store::*;
 pub use push_notification_sender::*;
+pub use write_behind_task_store::*;
+pub use task_reaper::*;
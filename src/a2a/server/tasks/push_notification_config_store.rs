@@ -7,9 +7,15 @@ use crate::{PushNotificationConfig, A2AError};
 use async_trait::async_trait;
 
 /// Push Notification Config Store interface
+///
+/// A task may have several configurations, keyed by their `id` which is
+/// unique within the task. Configurations without an `id` are stored under
+/// the task id itself.
 #[async_trait]
 pub trait PushNotificationConfigStore: Send + Sync {
     /// Sets or updates the push notification configuration for a task
+    ///
+    /// A configuration with the same id as an existing one replaces it.
     async fn set_info(&self, task_id: &str, config: PushNotificationConfig) -> Result<(), A2AError>;
     
     /// Retrieves all push notification configurations for a task
@@ -22,6 +28,11 @@ pub trait PushNotificationConfigStore: Send + Sync {
     async fn delete_info(&self, task_id: &str, config_id: Option<&str>) -> Result<(), A2AError>;
 }
 
+/// Gives a config without an `id` the task id, and returns the config's id
+pub fn default_config_id<'a>(config: &'a mut PushNotificationConfig, task_id: &str) -> &'a str {
+    config.id.get_or_insert_with(|| task_id.to_string())
+}
+
 /// In-memory implementation of PushNotificationConfigStore
 pub struct InMemoryPushNotificationConfigStore {
     configs: std::sync::Arc<tokio::sync::RwLock<std::collections::HashMap<String, Vec<PushNotificationConfig>>>>,
@@ -43,19 +54,17 @@ impl Default for InMemoryPushNotificationConfigStore {
 
 #[async_trait]
 impl PushNotificationConfigStore for InMemoryPushNotificationConfigStore {
-    async fn set_info(&self, task_id: &str, config: PushNotificationConfig) -> Result<(), A2AError> {
+    async fn set_info(&self, task_id: &str, mut config: PushNotificationConfig) -> Result<(), A2AError> {
         let mut configs = self.configs.write().await;
         let task_configs = configs.entry(task_id.to_string()).or_insert_with(Vec::new);
+        let config_id = default_config_id(&mut config, task_id).to_string();
         
-        // If config has an ID, check if it already exists and update it
-        if let Some(ref config_id) = config.id {
-            if let Some(pos) = task_configs.iter().position(|c| c.id.as_ref() == Some(config_id)) {
-                task_configs[pos] = config;
-                return Ok(());
-            }
+        // Replace an existing config with the same ID to keep IDs unique per task
+        if let Some(pos) = task_configs.iter().position(|c| c.id.as_deref() == Some(config_id.as_str())) {
+            task_configs[pos] = config;
+        } else {
+            task_configs.push(config);
         }
-        
-        task_configs.push(config);
         Ok(())
     }
     
@@ -120,7 +120,7 @@ mod tests {
 
         let sender = HttpPushNotificationSender::new(config_store);
         let task = Task {
-            id: task_id.to_string(),
+            id: task_id.to_string().into(),
             context_id: "ctx-456".to_string(),
             status: TaskStatus {
                 state: TaskState::Completed,
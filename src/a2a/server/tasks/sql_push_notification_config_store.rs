@@ -4,7 +4,7 @@
 //! implementation using sqlx with support for SQLite and optional encryption.
 
 use crate::{PushNotificationConfig, A2AError};
-use crate::a2a::server::tasks::push_notification_config_store::PushNotificationConfigStore;
+use crate::a2a::server::tasks::push_notification_config_store::{default_config_id, PushNotificationConfigStore};
 use async_trait::async_trait;
 use sqlx::{SqlitePool, sqlite::SqliteConnectOptions};
 use std::str::FromStr;
@@ -103,8 +103,8 @@ impl SqlitePushNotificationConfigStore {
 
 #[async_trait]
 impl PushNotificationConfigStore for SqlitePushNotificationConfigStore {
-    async fn set_info(&self, task_id: &str, config: PushNotificationConfig) -> Result<(), A2AError> {
-        let config_id = config.id.clone().unwrap_or_else(|| task_id.to_string());
+    async fn set_info(&self, task_id: &str, mut config: PushNotificationConfig) -> Result<(), A2AError> {
+        let config_id = default_config_id(&mut config, task_id).to_string();
         let json_data = serde_json::to_vec(&config)
             .map_err(|e| A2AError::internal(&format!("Failed to serialize config: {}", e)))?;
         
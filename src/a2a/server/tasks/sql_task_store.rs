@@ -2,8 +2,19 @@
 //! 
 //! This module provides a persistent task store implementation using sqlx
 //! with support for SQLite.
-
-use crate::{Task, A2AError};
+//!
+//! A task row keeps the history the task was first saved with. Messages
+//! added later, with `append_history` or by saving a task whose history has
+//! grown, go to a separate history table, one row per message, so a growing
+//! conversation is not re-serialized on every message. They are folded back
+//! into the task's history when it is read. Saving never rewrites or removes
+//! stored messages, so saving a stale copy of a task cannot drop messages
+//! appended in the meantime.
+
+use crate::{Message, Task, A2AError};
+use crate::a2a::models::{Artifact, TaskArtifactUpdateEvent};
+use crate::a2a::server::health::HealthCheck;
+use crate::a2a::server::tasks::task_manager::ArtifactAssembler;
 use crate::a2a::server::tasks::task_store::TaskStore;
 use async_trait::async_trait;
 use sqlx::{SqlitePool, sqlite::SqliteConnectOptions};
@@ -67,19 +78,99 @@ impl SqliteTaskStore {
             .await
             .map_err(|e| A2AError::internal(&format!("Failed to initialize database: {}", e)))?;
 
+        let history_table = self.history_table();
+        for query in [
+            format!(
+                "CREATE TABLE IF NOT EXISTS {} (
+                    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+                    task_id TEXT NOT NULL,
+                    message TEXT NOT NULL
+                )",
+                history_table
+            ),
+            format!("CREATE INDEX IF NOT EXISTS {0}_task_id ON {0} (task_id, seq)", history_table),
+        ] {
+            sqlx::query(&query)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| A2AError::internal(&format!("Failed to initialize database: {}", e)))?;
+        }
+
         Ok(())
     }
+
+    /// Table holding messages appended to task histories
+    fn history_table(&self) -> String {
+        format!("{}_history", self.table_name)
+    }
+
+    /// Adds the messages appended since the task was last saved to its history
+    async fn with_appended_history(&self, mut task: Task) -> Result<Task, A2AError> {
+        let query = format!("SELECT message FROM {} WHERE task_id = ? ORDER BY seq", self.history_table());
+        let rows = sqlx::query_as::<_, (String,)>(&query)
+            .bind(task.id.as_str())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| A2AError::internal(&format!("Failed to get task history: {}", e)))?;
+
+        if !rows.is_empty() {
+            let history = task.history.get_or_insert_with(Vec::new);
+            for (message_json,) in rows {
+                let message = serde_json::from_str(&message_json)
+                    .map_err(|e| A2AError::internal(&format!("Failed to deserialize history: {}", e)))?;
+                history.push(message);
+            }
+        }
+        Ok(task)
+    }
+
+    async fn tasks_from_rows(&self, rows: Vec<TaskRow>) -> Result<Vec<Task>, A2AError> {
+        let mut tasks = Vec::with_capacity(rows.len());
+        for row in rows {
+            tasks.push(self.with_appended_history(task_from_row(row)?).await?);
+        }
+        Ok(tasks)
+    }
+}
+
+/// Columns of a stored task, in `TASK_COLUMNS` order
+type TaskRow = (String, String, String, String, Option<String>, Option<String>, Option<String>);
+
+/// Columns selected for a stored task
+const TASK_COLUMNS: &str = "id, context_id, kind, status, artifacts, history, metadata";
+
+/// Rebuilds a task from its stored columns
+fn task_from_row(row: TaskRow) -> Result<Task, A2AError> {
+    let (id, context_id, kind, status_json, artifacts_json, history_json, metadata_json) = row;
+    let status = serde_json::from_str(&status_json)
+        .map_err(|e| A2AError::internal(&format!("Failed to deserialize status: {}", e)))?;
+
+    let artifacts = artifacts_json.map(|s| serde_json::from_str(&s))
+        .transpose()
+        .map_err(|e| A2AError::internal(&format!("Failed to deserialize artifacts: {}", e)))?;
+
+    let history = history_json.map(|s| serde_json::from_str(&s))
+        .transpose()
+        .map_err(|e| A2AError::internal(&format!("Failed to deserialize history: {}", e)))?;
+
+    let metadata = metadata_json.map(|s| serde_json::from_str(&s))
+        .transpose()
+        .map_err(|e| A2AError::internal(&format!("Failed to deserialize metadata: {}", e)))?;
+
+    Ok(Task {
+        id: id.into(),
+        context_id,
+        kind,
+        status,
+        artifacts,
+        history,
+        metadata,
+    })
 }
 
 #[async_trait]
 impl TaskStore for SqliteTaskStore {
     async fn save(&self, task: Task) -> Result<(), A2AError> {
-        let query = format!(
-            "INSERT OR REPLACE INTO {} (id, context_id, kind, status, artifacts, history, metadata)
-             VALUES (?, ?, ?, ?, ?, ?, ?)",
-            self.table_name
-        );
-
         let status_json = serde_json::to_string(&task.status)
             .map_err(|e| A2AError::internal(&format!("Failed to serialize status: {}", e)))?;
         
@@ -87,23 +178,74 @@ impl TaskStore for SqliteTaskStore {
             .transpose()
             .map_err(|e| A2AError::internal(&format!("Failed to serialize artifacts: {}", e)))?;
             
-        let history_json = task.history.as_ref().map(|h| serde_json::to_string(h))
-            .transpose()
-            .map_err(|e| A2AError::internal(&format!("Failed to serialize history: {}", e)))?;
-            
         let metadata_json = task.metadata.as_ref().map(|m| serde_json::to_string(m))
             .transpose()
             .map_err(|e| A2AError::internal(&format!("Failed to serialize metadata: {}", e)))?;
 
-        sqlx::query(&query)
-            .bind(&task.id)
-            .bind(&task.context_id)
-            .bind(task.kind)
-            .bind(status_json)
-            .bind(artifacts_json)
-            .bind(history_json)
-            .bind(metadata_json)
-            .execute(&self.pool)
+        let mut tx = self.pool.begin()
+            .await
+            .map_err(|e| A2AError::internal(&format!("Failed to save task: {}", e)))?;
+
+        // Number of messages stored so far, in the task row and the history table
+        let stored = sqlx::query_as::<_, (i64,)>(&format!(
+            "SELECT COALESCE(json_array_length(history), 0) + (SELECT COUNT(*) FROM {} WHERE task_id = ?) FROM {} WHERE id = ?",
+            self.history_table(), self.table_name
+        ))
+            .bind(task.id.as_str())
+            .bind(task.id.as_str())
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| A2AError::internal(&format!("Failed to save task: {}", e)))?;
+
+        let history = task.history.as_deref().unwrap_or_default();
+        let new_messages = match stored {
+            Some((stored,)) => {
+                sqlx::query(&format!(
+                    "UPDATE {} SET context_id = ?, kind = ?, status = ?, artifacts = ?, metadata = ? WHERE id = ?",
+                    self.table_name
+                ))
+                    .bind(&task.context_id)
+                    .bind(&task.kind)
+                    .bind(status_json)
+                    .bind(artifacts_json)
+                    .bind(metadata_json)
+                    .bind(task.id.as_str())
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| A2AError::internal(&format!("Failed to save task: {}", e)))?;
+                history.get(stored as usize..).unwrap_or_default()
+            }
+            None => {
+                let history_json = task.history.as_ref().map(|h| serde_json::to_string(h))
+                    .transpose()
+                    .map_err(|e| A2AError::internal(&format!("Failed to serialize history: {}", e)))?;
+                sqlx::query(&format!("INSERT INTO {} ({}) VALUES (?, ?, ?, ?, ?, ?, ?)", self.table_name, TASK_COLUMNS))
+                    .bind(task.id.as_str())
+                    .bind(&task.context_id)
+                    .bind(&task.kind)
+                    .bind(status_json)
+                    .bind(artifacts_json)
+                    .bind(history_json)
+                    .bind(metadata_json)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| A2AError::internal(&format!("Failed to save task: {}", e)))?;
+                &[]
+            }
+        };
+
+        for message in new_messages {
+            let message_json = serde_json::to_string(message)
+                .map_err(|e| A2AError::internal(&format!("Failed to serialize history: {}", e)))?;
+            sqlx::query(&format!("INSERT INTO {} (task_id, message) VALUES (?, ?)", self.history_table()))
+                .bind(task.id.as_str())
+                .bind(message_json)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| A2AError::internal(&format!("Failed to save task: {}", e)))?;
+        }
+
+        tx.commit()
             .await
             .map_err(|e| A2AError::internal(&format!("Failed to save task: {}", e)))?;
 
@@ -111,140 +253,129 @@ impl TaskStore for SqliteTaskStore {
     }
 
     async fn get(&self, task_id: &str) -> Result<Option<Task>, A2AError> {
-        let query = format!(
-            "SELECT id, context_id, kind, status, artifacts, history, metadata FROM {} WHERE id = ?",
-            self.table_name
-        );
+        let query = format!("SELECT {} FROM {} WHERE id = ?", TASK_COLUMNS, self.table_name);
 
-        let row = sqlx::query_as::<_, (String, String, String, String, Option<String>, Option<String>, Option<String>)>(&query)
+        let row = sqlx::query_as::<_, TaskRow>(&query)
             .bind(task_id)
             .fetch_optional(&self.pool)
             .await
             .map_err(|e| A2AError::internal(&format!("Failed to get task: {}", e)))?;
 
-        if let Some((id, context_id, kind, status_json, artifacts_json, history_json, metadata_json)) = row {
-            let status = serde_json::from_str(&status_json)
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize status: {}", e)))?;
-                
-            let artifacts = artifacts_json.map(|s| serde_json::from_str(&s))
-                .transpose()
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize artifacts: {}", e)))?;
-                
-            let history = history_json.map(|s| serde_json::from_str(&s))
-                .transpose()
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize history: {}", e)))?;
-                
-            let metadata = metadata_json.map(|s| serde_json::from_str(&s))
-                .transpose()
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize metadata: {}", e)))?;
-
-            Ok(Some(Task {
-                id,
-                context_id,
-                kind,
-                status,
-                artifacts,
-                history,
-                metadata,
-            }))
-        } else {
-            Ok(None)
+        match row {
+            Some(row) => Ok(Some(self.with_appended_history(task_from_row(row)?).await?)),
+            None => Ok(None),
         }
     }
 
     async fn delete(&self, task_id: &str) -> Result<(), A2AError> {
-        let query = format!("DELETE FROM {} WHERE id = ?", self.table_name);
-
-        sqlx::query(&query)
-            .bind(task_id)
-            .execute(&self.pool)
-            .await
-            .map_err(|e| A2AError::internal(&format!("Failed to delete task: {}", e)))?;
+        for query in [
+            format!("DELETE FROM {} WHERE id = ?", self.table_name),
+            format!("DELETE FROM {} WHERE task_id = ?", self.history_table()),
+        ] {
+            sqlx::query(&query)
+                .bind(task_id)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| A2AError::internal(&format!("Failed to delete task: {}", e)))?;
+        }
 
         Ok(())
     }
 
     async fn list(&self) -> Result<Vec<Task>, A2AError> {
-        let query = format!(
-            "SELECT id, context_id, kind, status, artifacts, history, metadata FROM {}",
-            self.table_name
-        );
+        let query = format!("SELECT {} FROM {}", TASK_COLUMNS, self.table_name);
 
-        let rows = sqlx::query_as::<_, (String, String, String, String, Option<String>, Option<String>, Option<String>)>(&query)
+        let rows = sqlx::query_as::<_, TaskRow>(&query)
             .fetch_all(&self.pool)
             .await
             .map_err(|e| A2AError::internal(&format!("Failed to list tasks: {}", e)))?;
 
-        let mut tasks = Vec::new();
-        for (id, context_id, kind, status_json, artifacts_json, history_json, metadata_json) in rows {
-            let status = serde_json::from_str(&status_json)
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize status: {}", e)))?;
-                
-            let artifacts = artifacts_json.map(|s| serde_json::from_str(&s))
-                .transpose()
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize artifacts: {}", e)))?;
-                
-            let history = history_json.map(|s| serde_json::from_str(&s))
-                .transpose()
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize history: {}", e)))?;
-                
-            let metadata = metadata_json.map(|s| serde_json::from_str(&s))
-                .transpose()
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize metadata: {}", e)))?;
-
-            tasks.push(Task {
-                id,
-                context_id,
-                kind,
-                status,
-                artifacts,
-                history,
-                metadata,
-            });
-        }
-        Ok(tasks)
+        self.tasks_from_rows(rows).await
     }
 
     async fn list_by_context(&self, context_id: &str) -> Result<Vec<Task>, A2AError> {
-        let query = format!(
-            "SELECT id, context_id, kind, status, artifacts, history, metadata FROM {} WHERE context_id = ?",
-            self.table_name
-        );
+        let query = format!("SELECT {} FROM {} WHERE context_id = ?", TASK_COLUMNS, self.table_name);
 
-        let rows = sqlx::query_as::<_, (String, String, String, String, Option<String>, Option<String>, Option<String>)>(&query)
+        let rows = sqlx::query_as::<_, TaskRow>(&query)
             .bind(context_id)
             .fetch_all(&self.pool)
             .await
             .map_err(|e| A2AError::internal(&format!("Failed to list tasks by context: {}", e)))?;
 
-        let mut tasks = Vec::new();
-        for (id, context_id, kind, status_json, artifacts_json, history_json, metadata_json) in rows {
-            let status = serde_json::from_str(&status_json)
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize status: {}", e)))?;
-                
-            let artifacts = artifacts_json.map(|s| serde_json::from_str(&s))
-                .transpose()
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize artifacts: {}", e)))?;
-                
-            let history = history_json.map(|s| serde_json::from_str(&s))
-                .transpose()
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize history: {}", e)))?;
-                
-            let metadata = metadata_json.map(|s| serde_json::from_str(&s))
-                .transpose()
-                .map_err(|e| A2AError::internal(&format!("Failed to deserialize metadata: {}", e)))?;
-
-            tasks.push(Task {
-                id,
-                context_id,
-                kind,
-                status,
-                artifacts,
-                history,
-                metadata,
-            });
+        self.tasks_from_rows(rows).await
+    }
+
+    async fn append_history(&self, task_id: &str, message: Message) -> Result<(), A2AError> {
+        let message_json = serde_json::to_string(&message)
+            .map_err(|e| A2AError::internal(&format!("Failed to serialize history: {}", e)))?;
+        let query = format!(
+            "INSERT INTO {} (task_id, message) SELECT id, ? FROM {} WHERE id = ?",
+            self.history_table(), self.table_name
+        );
+
+        let result = sqlx::query(&query)
+            .bind(message_json)
+            .bind(task_id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| A2AError::internal(&format!("Failed to append to task history: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(A2AError::task_not_found(task_id));
         }
-        Ok(tasks)
+        Ok(())
+    }
+
+    async fn append_artifact_chunk(&self, task_id: &str, update: &TaskArtifactUpdateEvent) -> Result<(), A2AError> {
+        let mut tx = self.pool.begin()
+            .await
+            .map_err(|e| A2AError::internal(&format!("Failed to update artifacts: {}", e)))?;
+
+        // Only the artifacts column is read and rewritten
+        let row = sqlx::query_as::<_, (Option<String>,)>(&format!("SELECT artifacts FROM {} WHERE id = ?", self.table_name))
+            .bind(task_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| A2AError::internal(&format!("Failed to update artifacts: {}", e)))?;
+        let Some((artifacts_json,)) = row else {
+            return Err(A2AError::task_not_found(task_id));
+        };
+
+        let mut artifacts: Vec<Artifact> = artifacts_json.map(|s| serde_json::from_str(&s))
+            .transpose()
+            .map_err(|e| A2AError::internal(&format!("Failed to deserialize artifacts: {}", e)))?
+            .unwrap_or_default();
+        ArtifactAssembler::default().merge_artifact_chunk(&mut artifacts, update);
+        let artifacts_json = serde_json::to_string(&artifacts)
+            .map_err(|e| A2AError::internal(&format!("Failed to serialize artifacts: {}", e)))?;
+
+        sqlx::query(&format!("UPDATE {} SET artifacts = ? WHERE id = ?", self.table_name))
+            .bind(artifacts_json)
+            .bind(task_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| A2AError::internal(&format!("Failed to update artifacts: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| A2AError::internal(&format!("Failed to update artifacts: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HealthCheck for SqliteTaskStore {
+    fn name(&self) -> &str {
+        "task_store"
+    }
+
+    async fn check(&self) -> Result<(), A2AError> {
+        sqlx::query("SELECT 1")
+            .execute(&self.pool)
+            .await
+            .map_err(|e| A2AError::internal(&format!("Task store unavailable: {}", e)))?;
+        Ok(())
     }
 }
 
@@ -261,7 +392,7 @@ mod tests {
         let task_id = Uuid::new_v4().to_string();
         let context_id = Uuid::new_v4().to_string();
         let task = Task {
-            id: task_id.clone(),
+            id: task_id.clone().into(),
             context_id: context_id.clone(),
             status: TaskStatus {
                 state: TaskState::Submitted,
@@ -300,4 +431,59 @@ mod tests {
         let deleted = store.get(&task_id.to_string()).await.unwrap();
         assert!(deleted.is_none());
     }
+
+    #[tokio::test]
+    async fn test_appended_history_and_artifacts_persist_in_order() {
+        use crate::{Part, Role};
+        use crate::a2a::utils::artifact::get_artifact_text;
+        use crate::a2a::utils::message::get_message_text;
+
+        let store = SqliteTaskStore::connect("sqlite::memory:").await.unwrap();
+        let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+            .with_history(vec![Message::new(Role::User, vec![Part::text("first".to_string())])]);
+        store.save(task.clone()).await.unwrap();
+
+        for text in ["second", "third"] {
+            let message = Message::new(Role::Agent, vec![Part::text(text.to_string())]);
+            store.append_history(&task.id, message).await.unwrap();
+        }
+        let texts = |task: &Task| -> Vec<String> {
+            task.history.iter().flatten().map(|message| get_message_text(message, "")).collect()
+        };
+        let retrieved = store.get(&task.id).await.unwrap().unwrap();
+        assert_eq!(texts(&retrieved), vec!["first", "second", "third"]);
+        assert_eq!(texts(&store.list().await.unwrap()[0]), texts(&retrieved));
+
+        // Saving the task keeps appended messages without duplicating them,
+        // and saving a stale copy does not drop them
+        store.save(retrieved.clone()).await.unwrap();
+        let message = Message::new(Role::User, vec![Part::text("fourth".to_string())]);
+        store.append_history(&task.id, message).await.unwrap();
+        store.save(retrieved.clone()).await.unwrap();
+        assert_eq!(texts(&store.get(&task.id).await.unwrap().unwrap()), vec!["first", "second", "third", "fourth"]);
+        let mut grown = store.get(&task.id).await.unwrap().unwrap();
+        grown.history.as_mut().unwrap().push(Message::new(Role::Agent, vec![Part::text("fifth".to_string())]));
+        store.save(grown).await.unwrap();
+        assert_eq!(texts(&store.get(&task.id).await.unwrap().unwrap()), vec!["first", "second", "third", "fourth", "fifth"]);
+
+        let chunk = |text: &str, append: bool| {
+            let mut artifact = Artifact::new(vec![Part::text(text.to_string())]);
+            artifact.artifact_id = "report".to_string();
+            TaskArtifactUpdateEvent::new(task.id.to_string(), "ctx-1".to_string(), artifact).with_append(append)
+        };
+        store.append_artifact_chunk(&task.id, &chunk("Hello, ", false)).await.unwrap();
+        store.append_artifact_chunk(&task.id, &chunk("world", true)).await.unwrap();
+        let retrieved = store.get(&task.id).await.unwrap().unwrap();
+        let artifacts = retrieved.artifacts.unwrap();
+        assert_eq!(artifacts.len(), 1);
+        assert_eq!(get_artifact_text(&artifacts[0], ""), "Hello, world");
+        assert_eq!(retrieved.history.unwrap().len(), 5);
+
+        let message = Message::new(Role::User, vec![Part::text("lost".to_string())]);
+        assert!(store.append_history("missing", message).await.is_err());
+        assert!(store.append_artifact_chunk("missing", &chunk("lost", false)).await.is_err());
+
+        store.delete(&task.id).await.unwrap();
+        assert!(store.get(&task.id).await.unwrap().is_none());
+    }
 }
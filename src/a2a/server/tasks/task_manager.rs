@@ -6,13 +6,17 @@
 //! The implementation closely follows the Python version's API and behavior
 //! while adapting to Rust's type system and async patterns.
 
-use crate::{Message, Task, TaskStatus, TaskState, A2AError};
+use crate::{Message, Task, TaskId, TaskStatus, TaskState, A2AError};
+use crate::a2a::core_types::{FileContent, FilePart, FileWithBytes, Part, PartRoot};
+use crate::a2a::server::agent_execution::is_heartbeat;
 use crate::a2a::server::events::{Event};
-use crate::a2a::models::{TaskStatusUpdateEvent, TaskArtifactUpdateEvent};
+use crate::a2a::models::{Artifact, TaskStatusUpdateEvent, TaskArtifactUpdateEvent, TaskStateTransition};
 use crate::a2a::server::tasks::TaskStore;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use std::collections::HashSet;
 use std::sync::Arc;
-use tracing::{debug, info};
-use uuid::Uuid;
+use tracing::{debug, info, warn};
 
 /// Task Manager - helps manage a task's lifecycle during execution of a request
 /// 
@@ -35,6 +39,10 @@ pub struct TaskManager {
     initial_message: Option<Message>,
     /// Current task object in memory
     current_task: Arc<tokio::sync::Mutex<Option<Task>>>,
+    /// Whether to record each state change in the task's `status_history`
+    record_status_history: bool,
+    /// Tracks which streamed artifacts have received their last chunk
+    artifact_assembler: ArtifactAssembler,
 }
 
 impl TaskManager {
@@ -71,9 +79,20 @@ impl TaskManager {
             task_store,
             initial_message,
             current_task: Arc::new(tokio::sync::Mutex::new(None)),
+            record_status_history: false,
+            artifact_assembler: ArtifactAssembler::default(),
         })
     }
 
+    /// Set whether each state change is recorded in the task's `status_history`
+    ///
+    /// Enable this for agents declaring the `state_transition_history`
+    /// capability; otherwise tasks carry no status history.
+    pub fn with_status_history(mut self, record_status_history: bool) -> Self {
+        self.record_status_history = record_status_history;
+        self
+    }
+
     /// Retrieves the current task object, either from memory or the store
     /// 
     /// If task_id is set, it first checks the in-memory current_task,
@@ -111,6 +130,15 @@ impl TaskManager {
         }
     }
 
+    /// Reloads the task from the store, replacing the in-memory copy
+    ///
+    /// For when the task may have been saved by another party, such as a
+    /// cancellation while the agent was running.
+    pub async fn reload_task(&self) -> Result<Option<Task>, A2AError> {
+        self.current_task.lock().await.take();
+        self.get_task().await
+    }
+
     /// Processes a task-related event and saves the updated task state
     /// 
     /// Ensures task and context IDs match or are set from the event.
@@ -152,7 +180,14 @@ impl TaskManager {
         );
 
         match event {
-            TaskEvent::Task(task) => {
+            TaskEvent::Task(mut task) => {
+                if self.record_status_history && task.status_history().is_none() {
+                    // A replacement task keeps the transitions recorded so far
+                    if let Some(history) = self.get_task().await?.and_then(|previous| previous.status_history()) {
+                        task.set_status_history(history);
+                    }
+                }
+                self.record_transition(&mut task);
                 self.save_task(task.clone()).await?;
                 Ok(task)
             }
@@ -161,14 +196,11 @@ impl TaskManager {
                 
                 debug!("Updating task {} status to: {:?}", task.id.to_string(), status_event.status.state);
                 
-                // Move current status message to history if present
-                if let Some(ref message) = task.status.message {
-                    if task.history.is_none() {
-                        task.history = Some(vec![*message.clone()]);
-                    } else if let Some(ref mut history) = task.history {
-                        history.push(*message.clone());
-                    }
-                    task.status.message = None;
+                // Move current status message to history if present; it is
+                // appended on its own so the store need not rewrite the history
+                if let Some(message) = task.status.message.take() {
+                    self.task_store.append_history(&task.id, (*message).clone()).await?;
+                    task.history.get_or_insert_with(Vec::new).push(*message);
                 }
                 
                 // Update metadata if provided
@@ -184,6 +216,7 @@ impl TaskManager {
                 }
                 
                 task.status = status_event.status.clone();
+                self.record_transition(&mut task);
                 self.save_task(task.clone()).await?;
                 Ok(task)
             }
@@ -192,19 +225,35 @@ impl TaskManager {
                 
                 debug!("Appending artifact to task {}", task.id.to_string());
                 
-                // Append artifact to task
-                if task.artifacts.is_none() {
-                    task.artifacts = Some(vec![artifact_event.artifact.clone()]);
-                } else if let Some(ref mut artifacts) = task.artifacts {
-                    artifacts.push(artifact_event.artifact.clone());
-                }
+                // Chunks after an artifact's last chunk are dropped here, since
+                // the store does not know which artifacts are final
+                let artifact_id = &artifact_event.artifact.artifact_id;
+                let ignored = artifact_event.append.unwrap_or(false) && self.artifact_assembler.is_finalized(artifact_id);
+                let artifacts = task.artifacts.get_or_insert_with(Vec::new);
+                self.artifact_assembler.merge_artifact_chunk(artifacts, &artifact_event);
                 
-                self.save_task(task.clone()).await?;
+                // Only the chunk is written, not the whole task
+                if !ignored {
+                    self.task_store.append_artifact_chunk(&task.id, &artifact_event).await?;
+                }
+                *self.current_task.lock().await = Some(task.clone());
                 Ok(task)
             }
         }
     }
 
+    /// Appends the task's current state to its status history if the state changed
+    fn record_transition(&self, task: &mut Task) {
+        if !self.record_status_history {
+            return;
+        }
+        let mut history = task.status_history().unwrap_or_default();
+        if history.last().map(|transition| &transition.state) != Some(&task.status.state) {
+            history.push(TaskStateTransition::from_status(&task.status));
+            task.set_status_history(history);
+        }
+    }
+
     /// Ensures a Task object exists in memory, loading from store or creating new if needed
     async fn ensure_task(&self, event: &dyn TaskEventWrapper) -> Result<Task, A2AError> {
         // Try to get current task from memory
@@ -238,11 +287,15 @@ impl TaskManager {
     }
 
     /// Processes an event, updates the task state if applicable, stores it, and returns the event
+    ///
+    /// Heartbeats only tell followers the agent is alive; they are not stored,
+    /// so they never replace the task's last real status.
     pub async fn process_event(&mut self, event: &Event) -> Result<Event, A2AError> {
         match event {
             Event::Task(task) => {
                 self.save_task_event(TaskEvent::Task(task.clone())).await?;
             }
+            Event::TaskStatusUpdate(status_event) if is_heartbeat(status_event) => {}
             Event::TaskStatusUpdate(status_event) => {
                 self.save_task_event(TaskEvent::StatusUpdate(status_event.clone())).await?;
             }
@@ -264,8 +317,9 @@ impl TaskManager {
             task_id, context_id
         );
         
-        let task_id_uuid = Uuid::parse_str(task_id).unwrap_or_else(|_| Uuid::new_v4());
-        let context_id_uuid = Uuid::parse_str(context_id).unwrap_or_else(|_| Uuid::new_v4());
+        // Ids that are not UUIDs are kept as they are; only missing ids are generated
+        let task_id = if task_id.is_empty() { TaskId::new() } else { TaskId::from(task_id) };
+        let context_id = if context_id.is_empty() { TaskId::new() } else { TaskId::from(context_id) };
         
         let history = if self.initial_message.is_some() {
             Some(vec![self.initial_message.clone().unwrap()])
@@ -273,9 +327,9 @@ impl TaskManager {
             None
         };
 
-        Task {
-            id: task_id_uuid.to_string(),
-            context_id: context_id_uuid.to_string(),
+        let mut task = Task {
+            id: task_id,
+            context_id: context_id.to_string(),
             status: TaskStatus {
                 state: TaskState::Submitted,
                 timestamp: Some(chrono::Utc::now().to_string()),
@@ -285,7 +339,9 @@ impl TaskManager {
             history,
             metadata: None,
             kind: "task".to_string(),
-        }
+        };
+        self.record_transition(&mut task);
+        task
     }
 
     /// Saves the given task to the task store and updates the in-memory current_task
@@ -349,6 +405,183 @@ impl TaskManager {
     }
 }
 
+/// Assembles streamed artifact chunks into a task's artifacts
+///
+/// Chunks are matched to artifacts by `artifact_id`, so chunks of several
+/// artifacts streamed at the same time can arrive interleaved. A chunk sent
+/// with `append` adds its parts to the artifact with the same ID; any other
+/// chunk replaces it. Once a chunk marked `last_chunk` arrives the artifact
+/// is final, and further appended chunks for it are ignored.
+///
+/// A binary file streamed in pieces is reassembled into a single part: when
+/// an appended chunk starts with a `FileWithBytes` part and the artifact ends
+/// with one of the same name, their bytes are joined. Chunks whose MIME type
+/// differs from the file being assembled are ignored, though a rejected last
+/// chunk still finalizes the artifact.
+#[derive(Debug, Clone, Default)]
+pub struct ArtifactAssembler {
+    finalized: HashSet<String>,
+}
+
+impl ArtifactAssembler {
+    /// Applies one artifact chunk to `artifacts`
+    pub fn merge_artifact_chunk(&mut self, artifacts: &mut Vec<Artifact>, update: &TaskArtifactUpdateEvent) {
+        let artifact_id = &update.artifact.artifact_id;
+        let append = update.append.unwrap_or(false);
+        if append && self.finalized.contains(artifact_id) {
+            warn!("Ignoring chunk for artifact {} received after its last chunk", artifact_id);
+            return;
+        }
+
+        let existing = artifacts.iter_mut().find(|artifact| &artifact.artifact_id == artifact_id);
+        match (existing, append) {
+            (Some(artifact), true) => {
+                if let Err(reason) = append_parts(&mut artifact.parts, &update.artifact.parts) {
+                    warn!("Ignoring chunk for artifact {}: {}", artifact_id, reason);
+                }
+            }
+            (Some(artifact), false) => *artifact = update.artifact.clone(),
+            (None, _) => artifacts.push(update.artifact.clone()),
+        }
+
+        if update.last_chunk.unwrap_or(false) {
+            self.finalized.insert(artifact_id.clone());
+        } else if !append {
+            self.finalized.remove(artifact_id);
+        }
+    }
+
+    /// Whether the last chunk of an artifact has been received
+    pub fn is_finalized(&self, artifact_id: &str) -> bool {
+        self.finalized.contains(artifact_id)
+    }
+}
+
+/// Appends the parts of a chunk, joining a file split across chunks
+fn append_parts(parts: &mut Vec<Part>, chunk: &[Part]) -> Result<(), String> {
+    let mut chunk = chunk.iter();
+    let (Some(file), Some(next)) = (parts.last_mut().and_then(file_bytes_mut), chunk.as_slice().first()) else {
+        parts.extend(chunk.cloned());
+        return Ok(());
+    };
+    let PartRoot::File(FilePart { file: FileContent::Bytes(next), .. }) = next.root() else {
+        parts.extend(chunk.cloned());
+        return Ok(());
+    };
+    // A file with another name is a separate part
+    if file.name != next.name {
+        parts.extend(chunk.cloned());
+        return Ok(());
+    }
+
+    if let (Some(expected), Some(actual)) = (&file.mime_type, &next.mime_type) {
+        if expected != actual {
+            return Err(format!("MIME type {} does not match {}", actual, expected));
+        }
+    }
+    append_base64(&mut file.bytes, &next.bytes)?;
+    if file.mime_type.is_none() {
+        file.mime_type = next.mime_type.clone();
+    }
+
+    chunk.next();
+    parts.extend(chunk.cloned());
+    Ok(())
+}
+
+/// Appends base64 `chunk` to the base64 `assembled`
+///
+/// Only the padded final quantum of `assembled` is decoded and re-encoded with
+/// the chunk, so assembling a file costs time linear in its size.
+fn append_base64(assembled: &mut String, chunk: &str) -> Result<(), String> {
+    let chunk = STANDARD.decode(chunk).map_err(|e| format!("invalid base64 in file chunk: {}", e))?;
+    if !assembled.len().is_multiple_of(4) {
+        return Err("invalid base64 in assembled file: length is not a multiple of 4".to_string());
+    }
+    let tail_start = if assembled.ends_with('=') { assembled.len() - 4 } else { assembled.len() };
+    let mut bytes = STANDARD
+        .decode(&assembled[tail_start..])
+        .map_err(|e| format!("invalid base64 in assembled file: {}", e))?;
+    bytes.extend(chunk);
+    assembled.truncate(tail_start);
+    assembled.push_str(&STANDARD.encode(bytes));
+    Ok(())
+}
+
+fn file_bytes_mut(part: &mut Part) -> Option<&mut FileWithBytes> {
+    match part {
+        Part::WithRoot { root: PartRoot::File(FilePart { file: FileContent::Bytes(file), .. }) }
+        | Part::Direct(PartRoot::File(FilePart { file: FileContent::Bytes(file), .. })) => Some(file),
+        _ => None,
+    }
+}
+
+/// Builds a snapshot of a task from the events published for it so far
+///
+/// Starts from the latest `Task` event (or a new submitted task), then applies
+/// status updates and artifact updates in order. Artifact chunks sent with
+/// `append` are added to the artifact with the same ID, so a task that is
+/// still streaming shows its partial artifacts. Events for other tasks and
+/// messages are ignored.
+pub fn snapshot_from_events(task_id: &str, context_id: &str, events: &[Event]) -> Task {
+    let task = Task::new(context_id.to_string(), TaskStatus::new(TaskState::Submitted))
+        .with_task_id(task_id.to_string());
+    let mut snapshot = TaskSnapshot::new(task);
+    for event in events {
+        snapshot.apply(event);
+    }
+    snapshot.into_task()
+}
+
+/// A task kept up to date by applying its events as they are published
+///
+/// Holds only the current task rather than the events themselves, so
+/// following a long-running task costs no more memory than the task.
+#[derive(Debug, Clone)]
+pub struct TaskSnapshot {
+    task: Task,
+    assembler: ArtifactAssembler,
+}
+
+impl TaskSnapshot {
+    /// Starts from the given task
+    pub fn new(task: Task) -> Self {
+        Self {
+            task,
+            assembler: ArtifactAssembler::default(),
+        }
+    }
+
+    /// Applies one event, ignoring heartbeats, messages and events of other tasks
+    pub fn apply(&mut self, event: &Event) {
+        let task = &mut self.task;
+        match event {
+            Event::Task(snapshot) if snapshot.id == task.id => *task = snapshot.clone(),
+            Event::TaskStatusUpdate(update) if update.task_id == task.id && !is_heartbeat(update) => {
+                // The previous status message becomes part of the history
+                if let Some(message) = task.status.message.take() {
+                    task.history.get_or_insert_with(Vec::new).push(*message);
+                }
+                task.status = update.status.clone();
+            }
+            Event::TaskArtifactUpdate(update) if update.task_id == task.id => {
+                self.assembler.merge_artifact_chunk(task.artifacts.get_or_insert_with(Vec::new), update);
+            }
+            _ => {}
+        }
+    }
+
+    /// The task as of the last applied event
+    pub fn task(&self) -> &Task {
+        &self.task
+    }
+
+    /// Consumes the snapshot, returning the task
+    pub fn into_task(self) -> Task {
+        self.task
+    }
+}
+
 /// Enum representing different types of task-related events
 #[derive(Debug, Clone)]
 pub enum TaskEvent {
@@ -417,6 +650,7 @@ mod tests {
     use super::*;
     use crate::{Part, Role};
     use crate::a2a::server::tasks::InMemoryTaskStore;
+    use uuid::Uuid;
 
     fn create_test_task_manager() -> (TaskManager, Arc<InMemoryTaskStore>) {
         let store = Arc::new(InMemoryTaskStore::new());
@@ -430,6 +664,65 @@ mod tests {
         (manager, store)
     }
 
+    #[test]
+    fn test_snapshot_from_partial_events() {
+        use crate::a2a::models::Artifact;
+
+        let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+            .with_task_id("task-1".to_string());
+        let mut first_chunk = Artifact::new(vec![Part::text("Hello".to_string())]);
+        first_chunk.artifact_id = "report".to_string();
+        let mut second_chunk = first_chunk.clone();
+        second_chunk.parts = vec![Part::text(", world".to_string())];
+        let mut second_chunk_event = TaskArtifactUpdateEvent::new(
+            "task-1".to_string(),
+            "ctx-1".to_string(),
+            second_chunk,
+        );
+        second_chunk_event.append = Some(true);
+        let status_message = Message::new(Role::Agent, vec![Part::text("Writing".to_string())]);
+        let mut working = TaskStatus::new(TaskState::Working);
+        working.message = Some(Box::new(status_message));
+
+        let events = vec![
+            Event::Task(task),
+            Event::TaskStatusUpdate(TaskStatusUpdateEvent::new(
+                "task-1".to_string(),
+                "ctx-1".to_string(),
+                working,
+                false,
+            )),
+            Event::TaskArtifactUpdate(TaskArtifactUpdateEvent::new(
+                "task-1".to_string(),
+                "ctx-1".to_string(),
+                first_chunk,
+            )),
+            Event::TaskArtifactUpdate(second_chunk_event),
+            Event::TaskStatusUpdate(TaskStatusUpdateEvent::new(
+                "other-task".to_string(),
+                "ctx-1".to_string(),
+                TaskStatus::new(TaskState::Completed),
+                true,
+            )),
+        ];
+
+        let snapshot = snapshot_from_events("task-1", "ctx-1", &events[..2]);
+        assert_eq!(snapshot.status.state, TaskState::Working);
+        assert!(snapshot.artifacts.is_none());
+
+        let snapshot = snapshot_from_events("task-1", "ctx-1", &events);
+        assert_eq!(snapshot.id, "task-1");
+        assert_eq!(snapshot.status.state, TaskState::Working);
+        assert!(snapshot.status.message.is_some());
+        let artifacts = snapshot.artifacts.unwrap();
+        assert_eq!(artifacts.len(), 1);
+        assert_eq!(crate::a2a::utils::artifact::get_artifact_text(&artifacts[0], ""), "Hello, world");
+
+        let empty = snapshot_from_events("task-1", "ctx-1", &[]);
+        assert_eq!(empty.status.state, TaskState::Submitted);
+        assert_eq!(empty.context_id, "ctx-1");
+    }
+
     #[tokio::test]
     async fn test_task_manager_initialization() {
         let store = Arc::new(InMemoryTaskStore::new());
@@ -469,7 +762,7 @@ mod tests {
         let context_id = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001").unwrap();
         
         let task = Task {
-            id: task_id.to_string(),
+            id: task_id.to_string().into(),
             context_id: context_id.to_string(),
             status: TaskStatus {
                 state: TaskState::Working,
@@ -491,6 +784,148 @@ mod tests {
         assert_eq!(retrieved.unwrap().status.state, TaskState::Working);
     }
 
+    #[tokio::test]
+    async fn test_heartbeats_are_not_persisted() {
+        use crate::a2a::server::agent_execution::HEARTBEAT_METADATA_KEY;
+
+        let (mut manager, store) = create_test_task_manager();
+        let task_id = "550e8400-e29b-41d4-a716-446655440000".to_string();
+        let context_id = "550e8400-e29b-41d4-a716-446655440001".to_string();
+        let mut working = TaskStatus::new(TaskState::Working);
+        working.message = Some(Box::new(Message::new(Role::Agent, vec![Part::text("Crunching".to_string())])));
+        let update = TaskStatusUpdateEvent::new(task_id.clone(), context_id.clone(), working, false);
+        manager.process_event(&Event::TaskStatusUpdate(update)).await.unwrap();
+
+        let mut heartbeat = TaskStatusUpdateEvent::new(task_id.clone(), context_id, TaskStatus::new(TaskState::Working), false);
+        heartbeat.metadata = Some([(HEARTBEAT_METADATA_KEY.to_string(), serde_json::Value::Bool(true))].into());
+        let heartbeat = Event::TaskStatusUpdate(heartbeat);
+        manager.process_event(&heartbeat).await.unwrap();
+
+        let stored = store.get(&task_id).await.unwrap().unwrap();
+        assert!(stored.status.message.is_some());
+
+        let mut snapshot = TaskSnapshot::new(stored.clone());
+        snapshot.apply(&heartbeat);
+        assert_eq!(snapshot.task(), &stored);
+    }
+
+    #[tokio::test]
+    async fn test_interleaved_artifact_chunks_assemble_per_artifact() {
+        use crate::a2a::utils::artifact::get_artifact_text;
+
+        let (mut manager, store) = create_test_task_manager();
+        let task_id = "550e8400-e29b-41d4-a716-446655440000";
+        let context_id = "550e8400-e29b-41d4-a716-446655440001";
+        let chunk = |artifact_id: &str, text: &str, append: bool, last_chunk: bool| {
+            let mut artifact = Artifact::new(vec![Part::text(text.to_string())]);
+            artifact.artifact_id = artifact_id.to_string();
+            TaskEvent::ArtifactUpdate(
+                TaskArtifactUpdateEvent::new(task_id.to_string(), context_id.to_string(), artifact)
+                    .with_append(append)
+                    .with_last_chunk(last_chunk),
+            )
+        };
+
+        for event in [
+            chunk("a", "A1", false, false),
+            chunk("b", "B1", false, false),
+            chunk("a", "A2", true, true),
+            chunk("b", "B2", true, true),
+            chunk("a", "late", true, false),
+        ] {
+            manager.save_task_event(event).await.unwrap();
+        }
+
+        let artifacts = store.get(task_id).await.unwrap().unwrap().artifacts.unwrap();
+        assert_eq!(artifacts.len(), 2);
+        assert_eq!(artifacts[0].artifact_id, "a");
+        assert_eq!(get_artifact_text(&artifacts[0], ""), "A1A2");
+        assert_eq!(artifacts[1].artifact_id, "b");
+        assert_eq!(get_artifact_text(&artifacts[1], ""), "B1B2");
+    }
+
+    #[test]
+    fn test_binary_artifact_chunks_are_reassembled() {
+        let original: Vec<u8> = (0..=255u8).cycle().take(1000).collect();
+        let chunk = |bytes: &[u8], mime_type: &str, append: bool, last_chunk: bool| {
+            let mut file = FilePart::new_bytes(STANDARD.encode(bytes));
+            if let FileContent::Bytes(content) = &mut file.file {
+                content.mime_type = Some(mime_type.to_string());
+            }
+            let mut artifact = Artifact::new(vec![Part::Direct(PartRoot::File(file))]);
+            artifact.artifact_id = "image".to_string();
+            TaskArtifactUpdateEvent::new("task-1".to_string(), "ctx-1".to_string(), artifact)
+                .with_append(append)
+                .with_last_chunk(last_chunk)
+        };
+
+        let mut assembler = ArtifactAssembler::default();
+        let mut artifacts = Vec::new();
+        // Chunk sizes that are not multiples of 3 leave padding inside each chunk
+        assembler.merge_artifact_chunk(&mut artifacts, &chunk(&original[..334], "image/png", false, false));
+        assembler.merge_artifact_chunk(&mut artifacts, &chunk(&original[334..500], "image/png", true, false));
+        assembler.merge_artifact_chunk(&mut artifacts, &chunk(b"not an image", "text/plain", true, false));
+        assembler.merge_artifact_chunk(&mut artifacts, &chunk(&original[500..], "image/png", true, true));
+
+        assert_eq!(artifacts.len(), 1);
+        assert_eq!(artifacts[0].parts.len(), 1);
+        let PartRoot::File(FilePart { file: FileContent::Bytes(file), .. }) = artifacts[0].parts[0].root() else {
+            panic!("Expected a file part");
+        };
+        assert_eq!(file.mime_type.as_deref(), Some("image/png"));
+        assert_eq!(STANDARD.decode(&file.bytes).unwrap(), original);
+    }
+
+    #[test]
+    fn test_binary_artifact_chunks_merge_only_matching_files() {
+        let chunk = |bytes: &[u8], name: &str, mime_type: &str, append: bool, last_chunk: bool| {
+            let mut file = FilePart::new_bytes(STANDARD.encode(bytes));
+            if let FileContent::Bytes(content) = &mut file.file {
+                content.name = Some(name.to_string());
+                content.mime_type = Some(mime_type.to_string());
+            }
+            let mut artifact = Artifact::new(vec![Part::Direct(PartRoot::File(file))]);
+            artifact.artifact_id = "files".to_string();
+            TaskArtifactUpdateEvent::new("task-1".to_string(), "ctx-1".to_string(), artifact)
+                .with_append(append)
+                .with_last_chunk(last_chunk)
+        };
+
+        let mut assembler = ArtifactAssembler::default();
+        let mut artifacts = Vec::new();
+        assembler.merge_artifact_chunk(&mut artifacts, &chunk(b"a", "a.png", "image/png", false, false));
+        assembler.merge_artifact_chunk(&mut artifacts, &chunk(b"b", "b.png", "image/png", true, false));
+        assembler.merge_artifact_chunk(&mut artifacts, &chunk(b"bb", "b.png", "image/png", true, false));
+        let names: Vec<_> = artifacts[0]
+            .parts
+            .iter()
+            .map(|part| match part.root() {
+                PartRoot::File(FilePart { file: FileContent::Bytes(file), .. }) => {
+                    (file.name.clone().unwrap(), STANDARD.decode(&file.bytes).unwrap())
+                }
+                other => panic!("Expected a file part, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(names, vec![("a.png".to_string(), b"a".to_vec()), ("b.png".to_string(), b"bbb".to_vec())]);
+
+        // A rejected last chunk still finalizes the artifact
+        assembler.merge_artifact_chunk(&mut artifacts, &chunk(b"x", "b.png", "text/plain", true, true));
+        assert!(assembler.is_finalized("files"));
+        assert_eq!(artifacts[0].parts.len(), 2);
+    }
+
+    #[test]
+    fn test_init_task_obj_keeps_non_uuid_ids() {
+        let (manager, _) = create_test_task_manager();
+        let task = manager.init_task_obj("task-1", "ctx-1");
+        assert_eq!(task.id, "task-1");
+        assert_eq!(task.context_id, "ctx-1");
+
+        let task = manager.init_task_obj("", "");
+        assert!(task.id.is_uuid());
+        assert!(TaskId::from(task.context_id).is_uuid());
+    }
+
     #[tokio::test]
     async fn test_ensure_task_creates_new() {
         let (manager, store) = create_test_task_manager();
@@ -526,7 +961,7 @@ mod tests {
         let context_id = Uuid::parse_str("550e8400-e29b-41d4-a716-446655440001").unwrap();
         
         let task = Task {
-            id: task_id.to_string(),
+            id: task_id.to_string().into(),
             context_id: context_id.to_string(),
             status: TaskStatus {
                 state: TaskState::Working,
@@ -548,4 +983,50 @@ mod tests {
         assert_eq!(updated_task.history.as_ref().unwrap()[1].role, Role::User);
         assert!(updated_task.status.message.is_none());
     }
+
+    fn status_update(task: &Task, state: TaskState) -> TaskEvent {
+        TaskEvent::StatusUpdate(TaskStatusUpdateEvent::new(
+            task.id.to_string(),
+            task.context_id.clone(),
+            TaskStatus::new(state),
+            false,
+        ))
+    }
+
+    #[tokio::test]
+    async fn test_status_history_records_transitions() {
+        let (manager, store) = create_test_task_manager();
+        let mut manager = manager.with_status_history(true);
+
+        let submitted = Task::new("550e8400-e29b-41d4-a716-446655440001".to_string(), TaskStatus::new(TaskState::Submitted))
+            .with_task_id("550e8400-e29b-41d4-a716-446655440000".to_string());
+        manager.save_task_event(TaskEvent::Task(submitted.clone())).await.unwrap();
+        manager.save_task_event(status_update(&submitted, TaskState::Working)).await.unwrap();
+        // Repeated updates in the same state are not transitions
+        manager.save_task_event(status_update(&submitted, TaskState::Working)).await.unwrap();
+        let completed = manager.save_task_event(status_update(&submitted, TaskState::Completed)).await.unwrap();
+
+        let history = completed.status_history().unwrap();
+        let states: Vec<TaskState> = history.iter().map(|transition| transition.state.clone()).collect();
+        assert_eq!(states, vec![TaskState::Submitted, TaskState::Working, TaskState::Completed]);
+        assert!(history.windows(2).all(|pair| pair[0].timestamp <= pair[1].timestamp));
+        assert_eq!(history[2].timestamp, completed.status.timestamp.clone().unwrap());
+
+        let stored = store.get(&submitted.id).await.unwrap().unwrap();
+        assert_eq!(stored.status_history(), Some(history));
+        assert!(serde_json::to_value(&stored).unwrap()["metadata"].get(crate::a2a::models::STATUS_HISTORY_METADATA_KEY).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_status_history_not_recorded_by_default() {
+        let (mut manager, _) = create_test_task_manager();
+
+        let submitted = Task::new("550e8400-e29b-41d4-a716-446655440001".to_string(), TaskStatus::new(TaskState::Submitted))
+            .with_task_id("550e8400-e29b-41d4-a716-446655440000".to_string());
+        manager.save_task_event(TaskEvent::Task(submitted.clone())).await.unwrap();
+        let completed = manager.save_task_event(status_update(&submitted, TaskState::Completed)).await.unwrap();
+
+        assert_eq!(completed.status_history(), None);
+        assert_eq!(completed.metadata, None);
+    }
 }
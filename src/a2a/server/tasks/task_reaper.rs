@@ -0,0 +1,190 @@
+//! Background reaping of stale tasks
+//!
+//! A task whose agent crashed stays in a non-terminal state forever. The
+//! `TaskReaper` periodically scans the task store and fails every task still
+//! being worked on whose status has not changed for longer than
+//! `stale_after`, so clients waiting on it get an answer and its resources
+//! are released.
+
+use crate::{A2AError, Message, Part, Role, Task, TaskState, TaskStatus};
+use crate::a2a::models::TaskStatusUpdateEvent;
+use crate::a2a::server::events::{Event, QueueManager};
+use crate::a2a::server::tasks::task_store::TaskStore;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{error, warn};
+
+/// Default interval between scans of the task store
+pub const DEFAULT_REAP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Fails tasks that have been stuck in a non-terminal state for too long
+///
+/// A task is stale when its status timestamp is older than `stale_after`;
+/// tasks without a timestamp are never reaped, and neither are tasks waiting
+/// on the user (`InputRequired`, `AuthRequired`), however long they wait. Reaped tasks are saved in the
+/// `Failed` state with a "timed out" status message. With a queue manager,
+/// the final status update is also published on the task's event queue,
+/// which is then closed so that streams following the task end.
+///
+/// The store must support `TaskStore::list`.
+pub struct TaskReaper {
+    task_store: Arc<dyn TaskStore>,
+    queue_manager: Option<Arc<dyn QueueManager>>,
+    stale_after: Duration,
+    interval: Duration,
+}
+
+impl TaskReaper {
+    /// Create a reaper failing tasks unchanged for longer than `stale_after`
+    pub fn new(task_store: Arc<dyn TaskStore>, stale_after: Duration) -> Self {
+        Self {
+            task_store,
+            queue_manager: None,
+            stale_after,
+            interval: DEFAULT_REAP_INTERVAL,
+        }
+    }
+
+    /// Set the interval between scans, `DEFAULT_REAP_INTERVAL` by default
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Publish the final status of reaped tasks on their event queues
+    pub fn with_queue_manager(mut self, queue_manager: Arc<dyn QueueManager>) -> Self {
+        self.queue_manager = Some(queue_manager);
+        self
+    }
+
+    /// Scan the store once, returning the IDs of the tasks marked failed
+    pub async fn reap_once(&self) -> Result<Vec<String>, A2AError> {
+        let cutoff = Utc::now() - chrono::Duration::from_std(self.stale_after).unwrap_or(chrono::Duration::MAX);
+        let mut reaped = Vec::new();
+        for task in self.task_store.list().await? {
+            if !is_reapable(&task.status.state) || last_updated(&task).is_none_or(|updated| updated >= cutoff) {
+                continue;
+            }
+            let task_id = task.id.to_string();
+            match self.fail(task).await {
+                Ok(()) => reaped.push(task_id),
+                Err(e) => error!("Failed to reap stale task {}: {}", task_id, e),
+            }
+        }
+        Ok(reaped)
+    }
+
+    /// Scan the store every interval until the returned handle is aborted
+    ///
+    /// Must be called within a Tokio runtime.
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.interval);
+            loop {
+                interval.tick().await;
+                match self.reap_once().await {
+                    Ok(reaped) if !reaped.is_empty() => warn!("Marked stale tasks as failed: {:?}", reaped),
+                    Ok(_) => {}
+                    Err(e) => error!("Failed to scan for stale tasks: {}", e),
+                }
+            }
+        })
+    }
+
+    async fn fail(&self, mut task: Task) -> Result<(), A2AError> {
+        let message = Message::new(
+            Role::Agent,
+            vec![Part::text(format!("Task timed out after no update for {:?}", self.stale_after))],
+        )
+        .with_task_id(task.id.to_string())
+        .with_context_id(task.context_id.clone());
+        task.status = TaskStatus::new(TaskState::Failed).with_message(message);
+        self.task_store.save(task.clone()).await?;
+
+        if let Some(ref queue_manager) = self.queue_manager {
+            if !queue_manager.has_queue(&task.id) {
+                return Ok(());
+            }
+            // Managers that cannot hand out the queue still have it closed,
+            // which ends the streams following the task
+            match queue_manager.get(&task.id).await {
+                Ok(Some(queue)) => {
+                    let update = TaskStatusUpdateEvent::new(task.id.to_string(), task.context_id.clone(), task.status.clone(), true);
+                    if let Err(e) = queue.enqueue_event(Event::TaskStatusUpdate(update)).await {
+                        error!("Failed to publish timeout of task {}: {}", task.id, e);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => warn!("Cannot publish timeout of task {}: {}", task.id, e),
+            }
+            queue_manager.close(&task.id).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether a task in `state` is waiting on its agent rather than finished or waiting on the user
+fn is_reapable(state: &TaskState) -> bool {
+    !state.is_terminal() && !matches!(state, TaskState::InputRequired | TaskState::AuthRequired)
+}
+
+/// When the task's status last changed, if its timestamp can be read
+fn last_updated(task: &Task) -> Option<DateTime<Utc>> {
+    let timestamp = task.status.timestamp.as_deref()?;
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|time| time.with_timezone(&Utc))
+        .or_else(|_| timestamp.parse::<DateTime<Utc>>())
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::server::events::{InMemoryQueueManager, EventConsumer};
+    use crate::a2a::server::tasks::InMemoryTaskStore;
+    use crate::a2a::utils::message::get_message_text;
+    use futures::StreamExt;
+
+    fn task_updated_ago(id: &str, state: TaskState, age: chrono::Duration) -> Task {
+        let mut status = TaskStatus::new(state);
+        status.timestamp = Some((Utc::now() - age).to_rfc3339());
+        Task::new("ctx-1".to_string(), status).with_task_id(id.to_string())
+    }
+
+    #[tokio::test]
+    async fn test_reaper_fails_stale_working_task() {
+        let store = Arc::new(InMemoryTaskStore::new());
+        store.save(task_updated_ago("stale", TaskState::Working, chrono::Duration::hours(2))).await.unwrap();
+        store.save(task_updated_ago("fresh", TaskState::Working, chrono::Duration::seconds(5))).await.unwrap();
+        store.save(task_updated_ago("done", TaskState::Completed, chrono::Duration::hours(2))).await.unwrap();
+        store.save(task_updated_ago("waiting", TaskState::InputRequired, chrono::Duration::hours(2))).await.unwrap();
+        store.save(task_updated_ago("login", TaskState::AuthRequired, chrono::Duration::hours(2))).await.unwrap();
+
+        let queue_manager = Arc::new(InMemoryQueueManager::default());
+        let queue = queue_manager.create_queue("stale").await.unwrap();
+        let reaper = TaskReaper::new(store.clone(), Duration::from_secs(3600))
+            .with_queue_manager(queue_manager.clone());
+
+        assert_eq!(reaper.reap_once().await.unwrap(), vec!["stale".to_string()]);
+
+        let stale = store.get("stale").await.unwrap().unwrap();
+        assert_eq!(stale.status.state, TaskState::Failed);
+        assert!(get_message_text(stale.status.message.as_deref().unwrap(), "").contains("timed out"));
+        assert_eq!(store.get("fresh").await.unwrap().unwrap().status.state, TaskState::Working);
+        assert_eq!(store.get("done").await.unwrap().unwrap().status.state, TaskState::Completed);
+        assert_eq!(store.get("waiting").await.unwrap().unwrap().status.state, TaskState::InputRequired);
+        assert_eq!(store.get("login").await.unwrap().unwrap().status.state, TaskState::AuthRequired);
+
+        let events: Vec<Event> = EventConsumer::new(queue).consume_all().map(|event| event.unwrap()).collect().await;
+        assert!(matches!(
+            events.as_slice(),
+            [Event::TaskStatusUpdate(update)] if update.r#final && update.status.state == TaskState::Failed
+        ));
+        assert!(!queue_manager.has_queue("stale"));
+
+        // Reaped tasks are terminal and not reaped again
+        assert!(reaper.reap_once().await.unwrap().is_empty());
+    }
+}
@@ -6,7 +6,10 @@
 //! This implementation aligns with the Python version which uses string IDs
 //! for better compatibility.
 
-use crate::{Task, A2AError};
+use crate::{Message, Task, A2AError};
+use crate::a2a::models::TaskArtifactUpdateEvent;
+use crate::a2a::server::health::HealthCheck;
+use crate::a2a::server::tasks::task_manager::ArtifactAssembler;
 use async_trait::async_trait;
 
 /// Task Store interface for persisting and retrieving Task objects
@@ -33,6 +36,29 @@ pub trait TaskStore: Send + Sync {
     async fn list_by_context(&self, _context_id: &str) -> Result<Vec<Task>, A2AError> {
         Err(A2AError::unsupported_operation("Task listing by context not supported"))
     }
+
+    /// Appends a message to a task's history
+    ///
+    /// The default implementation loads the task, appends the message and
+    /// saves the whole task again. Stores that can append in place should
+    /// override it, so long conversations are not re-serialized on every
+    /// message.
+    async fn append_history(&self, task_id: &str, message: Message) -> Result<(), A2AError> {
+        let mut task = self.get(task_id).await?.ok_or_else(|| A2AError::task_not_found(task_id))?;
+        task.history.get_or_insert_with(Vec::new).push(message);
+        self.save(task).await
+    }
+
+    /// Applies one streamed artifact chunk to a task's artifacts
+    ///
+    /// Chunks sent with `append` add their parts to the artifact with the
+    /// same ID; other chunks replace it. The default implementation loads,
+    /// modifies and saves the whole task.
+    async fn append_artifact_chunk(&self, task_id: &str, update: &TaskArtifactUpdateEvent) -> Result<(), A2AError> {
+        let mut task = self.get(task_id).await?.ok_or_else(|| A2AError::task_not_found(task_id))?;
+        ArtifactAssembler::default().merge_artifact_chunk(task.artifacts.get_or_insert_with(Vec::new), update);
+        self.save(task).await
+    }
 }
 
 /// In-memory implementation of TaskStore
@@ -100,6 +126,31 @@ impl TaskStore for InMemoryTaskStore {
             .collect();
         Ok(filtered_tasks)
     }
+
+    async fn append_history(&self, task_id: &str, message: Message) -> Result<(), A2AError> {
+        let mut tasks = self.tasks.write().await;
+        let task = tasks.get_mut(task_id).ok_or_else(|| A2AError::task_not_found(task_id))?;
+        task.history.get_or_insert_with(Vec::new).push(message);
+        Ok(())
+    }
+
+    async fn append_artifact_chunk(&self, task_id: &str, update: &TaskArtifactUpdateEvent) -> Result<(), A2AError> {
+        let mut tasks = self.tasks.write().await;
+        let task = tasks.get_mut(task_id).ok_or_else(|| A2AError::task_not_found(task_id))?;
+        ArtifactAssembler::default().merge_artifact_chunk(task.artifacts.get_or_insert_with(Vec::new), update);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HealthCheck for InMemoryTaskStore {
+    fn name(&self) -> &str {
+        "task_store"
+    }
+
+    async fn check(&self) -> Result<(), A2AError> {
+        Ok(())
+    }
 }
 
 /// Database implementation of TaskStore (placeholder for future implementation)
@@ -144,7 +195,7 @@ mod tests {
     
     fn create_test_task(id: &str, context_id: &str) -> Task {
         Task {
-            id: id.to_string(),
+            id: id.to_string().into(),
             context_id: context_id.to_string(),
             status: TaskStatus {
                 state: TaskState::Submitted,
@@ -230,4 +281,26 @@ mod tests {
         let context2_tasks = store.list_by_context("550e8400-e29b-41d4-a716-446655440002").await.unwrap();
         assert_eq!(context2_tasks.len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_in_memory_task_store_appends_history_in_order() {
+        use crate::{Part, Role};
+        use crate::a2a::utils::message::get_message_text;
+
+        let store = InMemoryTaskStore::new();
+        let task = create_test_task("task-1", "ctx-1");
+        store.save(task).await.unwrap();
+
+        for text in ["first", "second", "third"] {
+            let message = Message::new(Role::User, vec![Part::text(text.to_string())]);
+            store.append_history("task-1", message).await.unwrap();
+        }
+
+        let history = store.get("task-1").await.unwrap().unwrap().history.unwrap();
+        let texts: Vec<_> = history.iter().map(|message| get_message_text(message, "")).collect();
+        assert_eq!(texts, vec!["first", "second", "third"]);
+
+        let message = Message::new(Role::User, vec![Part::text("lost".to_string())]);
+        assert!(store.append_history("missing", message).await.is_err());
+    }
 }
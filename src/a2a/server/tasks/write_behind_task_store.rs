@@ -0,0 +1,263 @@
+//! Write-behind caching decorator for TaskStore
+//!
+//! Agents publish many small updates per task, and saving each of them to a
+//! persistent store is slow. This module provides a decorator that buffers
+//! writes in memory and flushes them to the backing store in batches.
+
+use crate::{Task, A2AError};
+use crate::a2a::server::tasks::task_store::TaskStore;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::error;
+
+/// TaskStore decorator that buffers writes and flushes them periodically
+///
+/// Saved tasks are kept in memory and written to the backing store every
+/// `flush_interval`, immediately once a task reaches a terminal state, and
+/// when the store is dropped. Reads check the buffer first, so they always
+/// see the latest saved version of a task.
+///
+/// Must be created within a Tokio runtime, which runs the periodic flush.
+pub struct WriteBehindTaskStore<S: TaskStore + 'static> {
+    inner: Arc<Inner<S>>,
+    flusher: JoinHandle<()>,
+}
+
+struct Inner<S> {
+    store: S,
+    /// Tasks saved since they were last written to the backing store
+    pending: Mutex<HashMap<String, Task>>,
+}
+
+impl<S: TaskStore + 'static> WriteBehindTaskStore<S> {
+    /// Wraps `store`, flushing buffered writes every `flush_interval`
+    pub fn new(store: S, flush_interval: Duration) -> Self {
+        let inner = Arc::new(Inner {
+            store,
+            pending: Mutex::new(HashMap::new()),
+        });
+        let flusher = tokio::spawn(periodic_flush(Arc::downgrade(&inner), flush_interval));
+        Self { inner, flusher }
+    }
+
+    /// Returns the backing store
+    pub fn backing_store(&self) -> &S {
+        &self.inner.store
+    }
+
+    /// Returns the number of tasks with writes not yet flushed
+    pub fn pending_count(&self) -> usize {
+        self.inner.pending.lock().unwrap().len()
+    }
+
+    /// Writes all buffered tasks to the backing store
+    pub async fn flush(&self) -> Result<(), A2AError> {
+        self.inner.flush().await
+    }
+}
+
+impl<S: TaskStore> Inner<S> {
+    async fn flush(&self) -> Result<(), A2AError> {
+        let task_ids: Vec<String> = self.pending.lock().unwrap().keys().cloned().collect();
+        for task_id in task_ids {
+            self.flush_task(&task_id).await?;
+        }
+        Ok(())
+    }
+
+    /// Writes one buffered task, keeping it buffered until the write succeeds
+    async fn flush_task(&self, task_id: &str) -> Result<(), A2AError> {
+        let task = match self.pending.lock().unwrap().get(task_id) {
+            Some(task) => task.clone(),
+            None => return Ok(()),
+        };
+        self.store.save(task.clone()).await?;
+
+        // A newer version saved during the write stays buffered for the next flush
+        let mut pending = self.pending.lock().unwrap();
+        if pending.get(task_id) == Some(&task) {
+            pending.remove(task_id);
+        }
+        Ok(())
+    }
+}
+
+async fn periodic_flush<S: TaskStore>(inner: Weak<Inner<S>>, flush_interval: Duration) {
+    let mut interval = tokio::time::interval(flush_interval);
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let Some(inner) = inner.upgrade() else {
+            return;
+        };
+        if let Err(e) = inner.flush().await {
+            error!("Failed to flush buffered tasks: {}", e);
+        }
+    }
+}
+
+#[async_trait]
+impl<S: TaskStore + 'static> TaskStore for WriteBehindTaskStore<S> {
+    async fn save(&self, task: Task) -> Result<(), A2AError> {
+        let task_id = task.id.to_string();
+        let terminal = task.status.state.is_terminal();
+        self.inner.pending.lock().unwrap().insert(task_id.clone(), task);
+
+        if terminal {
+            self.inner.flush_task(&task_id).await?;
+        }
+        Ok(())
+    }
+
+    async fn get(&self, task_id: &str) -> Result<Option<Task>, A2AError> {
+        if let Some(task) = self.inner.pending.lock().unwrap().get(task_id) {
+            return Ok(Some(task.clone()));
+        }
+        self.inner.store.get(task_id).await
+    }
+
+    async fn delete(&self, task_id: &str) -> Result<(), A2AError> {
+        self.inner.pending.lock().unwrap().remove(task_id);
+        self.inner.store.delete(task_id).await
+    }
+
+    async fn list(&self) -> Result<Vec<Task>, A2AError> {
+        self.flush().await?;
+        self.inner.store.list().await
+    }
+
+    async fn list_by_context(&self, context_id: &str) -> Result<Vec<Task>, A2AError> {
+        self.flush().await?;
+        self.inner.store.list_by_context(context_id).await
+    }
+}
+
+impl<S: TaskStore + 'static> Drop for WriteBehindTaskStore<S> {
+    fn drop(&mut self) {
+        self.flusher.abort();
+        if self.inner.pending.lock().unwrap().is_empty() {
+            return;
+        }
+
+        // Buffered writes must not be lost, so block until they are flushed
+        // where the runtime allows it and hand them to the runtime otherwise
+        let inner = self.inner.clone();
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) if handle.runtime_flavor() == tokio::runtime::RuntimeFlavor::MultiThread => {
+                if let Err(e) = tokio::task::block_in_place(|| handle.block_on(inner.flush())) {
+                    error!("Failed to flush buffered tasks on drop: {}", e);
+                }
+            }
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Err(e) = inner.flush().await {
+                        error!("Failed to flush buffered tasks on drop: {}", e);
+                    }
+                });
+            }
+            Err(_) => match tokio::runtime::Builder::new_current_thread().enable_all().build() {
+                Ok(runtime) => {
+                    if let Err(e) = runtime.block_on(inner.flush()) {
+                        error!("Failed to flush buffered tasks on drop: {}", e);
+                    }
+                }
+                Err(e) => error!("Failed to create a runtime to flush buffered tasks: {}", e),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::server::tasks::InMemoryTaskStore;
+    use crate::{TaskState, TaskStatus};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Backing store shared with the test that counts the writes it receives
+    #[derive(Clone, Default)]
+    struct CountingStore {
+        tasks: Arc<InMemoryTaskStore>,
+        saves: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl TaskStore for CountingStore {
+        async fn save(&self, task: Task) -> Result<(), A2AError> {
+            self.saves.fetch_add(1, Ordering::SeqCst);
+            self.tasks.save(task).await
+        }
+
+        async fn get(&self, task_id: &str) -> Result<Option<Task>, A2AError> {
+            self.tasks.get(task_id).await
+        }
+
+        async fn delete(&self, task_id: &str) -> Result<(), A2AError> {
+            self.tasks.delete(task_id).await
+        }
+    }
+
+    fn task_in(state: TaskState) -> Task {
+        Task::new("ctx-1".to_string(), TaskStatus::new(state)).with_task_id("task-1".to_string())
+    }
+
+    #[tokio::test]
+    async fn test_reads_see_buffered_writes() {
+        let backing = CountingStore::default();
+        let store = WriteBehindTaskStore::new(backing.clone(), Duration::from_secs(3600));
+
+        store.save(task_in(TaskState::Submitted)).await.unwrap();
+        store.save(task_in(TaskState::Working)).await.unwrap();
+
+        let task = store.get("task-1").await.unwrap().unwrap();
+        assert_eq!(task.status.state, TaskState::Working);
+        assert!(backing.get("task-1").await.unwrap().is_none());
+        assert_eq!(store.pending_count(), 1);
+
+        store.flush().await.unwrap();
+        let flushed = backing.get("task-1").await.unwrap().unwrap();
+        assert_eq!(flushed.status.state, TaskState::Working);
+        assert_eq!(backing.saves.load(Ordering::SeqCst), 1);
+        assert_eq!(store.pending_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_terminal_state_flushes_immediately() {
+        let backing = CountingStore::default();
+        let store = WriteBehindTaskStore::new(backing.clone(), Duration::from_secs(3600));
+
+        store.save(task_in(TaskState::Working)).await.unwrap();
+        store.save(task_in(TaskState::Completed)).await.unwrap();
+
+        let task = backing.get("task-1").await.unwrap().unwrap();
+        assert_eq!(task.status.state, TaskState::Completed);
+        assert_eq!(backing.saves.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_periodic_flush() {
+        let backing = CountingStore::default();
+        let store = WriteBehindTaskStore::new(backing.clone(), Duration::from_millis(20));
+
+        store.save(task_in(TaskState::Working)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        assert!(backing.get("task-1").await.unwrap().is_some());
+        assert_eq!(store.pending_count(), 0);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_drop_flushes_buffered_writes() {
+        let backing = CountingStore::default();
+        let store = WriteBehindTaskStore::new(backing.clone(), Duration::from_secs(3600));
+
+        store.save(task_in(TaskState::InputRequired)).await.unwrap();
+        drop(store);
+
+        let task = backing.get("task-1").await.unwrap().unwrap();
+        assert_eq!(task.status.state, TaskState::InputRequired);
+    }
+}
@@ -0,0 +1,141 @@
+//! Canonical JSON serialization
+//!
+//! Hashes and signatures over JSON must not depend on key order or
+//! formatting. [`canonical_json`] follows the JSON Canonicalization Scheme
+//! (RFC 8785): object keys are sorted by their UTF-16 code units, no
+//! whitespace is emitted, strings use the minimal JSON escapes and numbers
+//! are written the way ECMAScript prints them. Like every number in
+//! I-JSON, integers are IEEE 754 doubles, so those beyond 2^53 are written
+//! as the double nearest to them.
+
+use serde_json::{Number, Value};
+
+/// Serializes `value` in canonical form
+pub fn canonical_json(value: &Value) -> String {
+    let mut out = String::new();
+    write_value(value, &mut out);
+    out
+}
+
+fn write_value(value: &Value, out: &mut String) {
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => out.push_str(&format_number(n)),
+        Value::String(s) => write_string(s, out),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_value(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(key, out);
+                out.push(':');
+                write_value(item, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    // serde_json escapes only what JSON requires, with lowercase hex digits
+    out.push_str(&serde_json::to_string(s).unwrap_or_default());
+}
+
+/// Largest magnitude up to which every integer is exactly representable as a double
+const MAX_SAFE_INTEGER: u64 = 1 << 53;
+
+fn format_number(n: &Number) -> String {
+    let exact = n
+        .as_i64()
+        .map(|i| i.unsigned_abs())
+        .or_else(|| n.as_u64())
+        .is_some_and(|i| i <= MAX_SAFE_INTEGER);
+    if exact {
+        return n.to_string();
+    }
+    format_f64(n.as_f64().unwrap_or_default())
+}
+
+/// Formats a finite double like ECMAScript's `Number.prototype.toString`
+fn format_f64(f: f64) -> String {
+    if f == 0.0 {
+        return "0".to_string();
+    }
+    // `{:e}` gives the shortest round-tripping digits, e.g. "-1.25e-7"
+    let scientific = format!("{:e}", f.abs());
+    let (mantissa, exponent) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+    let digits: String = mantissa.chars().filter(|c| *c != '.').collect();
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().unwrap_or(0) + 1;
+
+    let body = if k <= n && n <= 21 {
+        format!("{}{}", digits, "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        format!("{}.{}", &digits[..n as usize], &digits[n as usize..])
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{}", "0".repeat((-n) as usize), digits)
+    } else {
+        let sign = if n - 1 < 0 { '-' } else { '+' };
+        let fraction = if k > 1 { format!(".{}", &digits[1..]) } else { String::new() };
+        format!("{}{}e{}{}", &digits[..1], fraction, sign, (n - 1).abs())
+    };
+    if f < 0.0 {
+        format!("-{}", body)
+    } else {
+        body
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_canonical_json_ignores_key_order() {
+        let a: Value = serde_json::from_str(r#"{"b": [1, {"y": true, "x": null}], "a": "text"}"#).unwrap();
+        let b: Value = serde_json::from_str(r#"{ "a":"text", "b":[1,{"x":null,"y":true}] }"#).unwrap();
+
+        assert_eq!(canonical_json(&a), canonical_json(&b));
+        assert_eq!(canonical_json(&a), r#"{"a":"text","b":[1,{"x":null,"y":true}]}"#);
+    }
+
+    #[test]
+    fn test_canonical_json_rfc8785_values() {
+        // Keys sort by UTF-16 code units, so U+1F600 comes before U+FB33
+        let value = json!({"\u{fb33}": 1, "\u{1f600}": 2, "\n": "é\u{1}/"});
+        assert_eq!(canonical_json(&value), "{\"\\n\":\"é\\u0001/\",\"\u{1f600}\":2,\"\u{fb33}\":1}");
+
+        let numbers = json!([0.0, -0.0, 1.0, 1.5, -100.25, 1e21, 1e20, 1e-7, 0.000001, 333333333.3333333, 4.5e-12]);
+        assert_eq!(
+            canonical_json(&numbers),
+            "[0,0,1,1.5,-100.25,1e+21,100000000000000000000,1e-7,0.000001,333333333.3333333,4.5e-12]"
+        );
+
+        // Integers beyond 2^53 are written as the nearest double
+        let integers = json!([
+            9007199254740992u64,
+            9007199254740993u64,
+            -9007199254740993i64,
+            u64::MAX
+        ]);
+        assert_eq!(
+            canonical_json(&integers),
+            "[9007199254740992,9007199254740992,-9007199254740992,18446744073709552000]"
+        );
+    }
+}
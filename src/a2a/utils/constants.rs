@@ -6,6 +6,9 @@
 /// Well-known path for the agent card
 pub const AGENT_CARD_WELL_KNOWN_PATH: &str = "/.well-known/agent-card.json";
 
+/// Media type of agent cards served as YAML
+pub const YAML_CONTENT_TYPE: &str = "application/yaml";
+
 /// Previous well-known path for the agent card (deprecated)
 pub const PREV_AGENT_CARD_WELL_KNOWN_PATH: &str = "/.well-known/agent.json";
 
@@ -15,6 +18,24 @@ pub const EXTENDED_AGENT_CARD_PATH: &str = "/agent/authenticatedExtendedCard";
 /// Default RPC URL
 pub const DEFAULT_RPC_URL: &str = "/";
 
+/// Path for the liveness endpoint
+pub const HEALTH_PATH: &str = "/healthz";
+
+/// Path for the readiness endpoint
+pub const READINESS_PATH: &str = "/readyz";
+
+/// Header telling the server when the client stops waiting for a response
+///
+/// Holds either the milliseconds remaining or an absolute RFC 3339 time.
+pub const DEADLINE_HEADER: &str = "X-A2A-Deadline";
+
+/// Header listing extension URIs, requested by clients and echoed back
+/// by servers for the extensions they activated
+pub const EXTENSIONS_HEADER: &str = "A2A-Extensions";
+
+/// Path for the per-task timing metrics admin endpoint, served to identified callers only
+pub const TASK_METRICS_PATH: &str = "/admin/tasks/:task_id/metrics";
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -25,5 +46,10 @@ mod tests {
         assert_eq!(PREV_AGENT_CARD_WELL_KNOWN_PATH, "/.well-known/agent.json");
         assert_eq!(EXTENDED_AGENT_CARD_PATH, "/agent/authenticatedExtendedCard");
         assert_eq!(DEFAULT_RPC_URL, "/");
+        assert_eq!(HEALTH_PATH, "/healthz");
+        assert_eq!(READINESS_PATH, "/readyz");
+        assert_eq!(DEADLINE_HEADER, "X-A2A-Deadline");
+        assert_eq!(EXTENSIONS_HEADER, "A2A-Extensions");
+        assert_eq!(TASK_METRICS_PATH, "/admin/tasks/:task_id/metrics");
     }
 }
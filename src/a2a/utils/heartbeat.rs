@@ -0,0 +1,55 @@
+//! Recognizing heartbeat status updates
+//!
+//! A heartbeat is a non-final `working` status update whose metadata marks
+//! it as a heartbeat and may carry the agent's progress. Servers publish them
+//! with `emit_heartbeat`; both sides use these helpers to tell them apart
+//! from real status changes.
+
+use crate::TaskStatusUpdateEvent;
+use serde_json::Value;
+
+/// Metadata key set to `true` on heartbeat status updates
+pub const HEARTBEAT_METADATA_KEY: &str = "heartbeat";
+
+/// Metadata key carrying a heartbeat's progress, a fraction from 0.0 to 1.0
+pub const HEARTBEAT_PROGRESS_KEY: &str = "progress";
+
+/// Returns whether a status update is a heartbeat
+pub fn is_heartbeat(update: &TaskStatusUpdateEvent) -> bool {
+    update
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(HEARTBEAT_METADATA_KEY))
+        == Some(&Value::Bool(true))
+}
+
+/// Returns the progress carried by a heartbeat, if any
+pub fn heartbeat_progress(update: &TaskStatusUpdateEvent) -> Option<f32> {
+    if !is_heartbeat(update) {
+        return None;
+    }
+    update
+        .metadata
+        .as_ref()?
+        .get(HEARTBEAT_PROGRESS_KEY)
+        .and_then(Value::as_f64)
+        .map(|progress| progress as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TaskState, TaskStatus};
+
+    #[test]
+    fn test_heartbeat_progress_ignores_plain_updates() {
+        let update = TaskStatusUpdateEvent::new(
+            "task-1".to_string(),
+            "ctx-1".to_string(),
+            TaskStatus::new(TaskState::Working),
+            false,
+        );
+        assert!(!is_heartbeat(&update));
+        assert_eq!(heartbeat_progress(&update), None);
+    }
+}
@@ -0,0 +1,128 @@
+//! Logging setup for A2A servers and clients
+//!
+//! This module configures a `tracing_subscriber` that writes either
+//! human-readable or JSON log lines. The `RUST_LOG` environment variable, when
+//! set, takes precedence over the level passed in code.
+
+use crate::a2a::error::A2AError;
+use std::str::FromStr;
+use tracing::{Level, Subscriber};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::EnvFilter;
+
+/// Output format for log lines
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Multi-line, human-readable output for local development
+    #[default]
+    Pretty,
+    /// One JSON object per line, for log aggregation in production
+    Json,
+}
+
+impl FromStr for LogFormat {
+    type Err = A2AError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "pretty" => Ok(LogFormat::Pretty),
+            "json" => Ok(LogFormat::Json),
+            other => Err(A2AError::invalid_params(&format!("Unknown log format: {}", other))),
+        }
+    }
+}
+
+/// Builds a subscriber writing logs in the given format to `writer`
+///
+/// Events below `level` are dropped unless `RUST_LOG` says otherwise.
+pub fn build_subscriber<W>(format: LogFormat, level: Level, writer: W) -> Box<dyn Subscriber + Send + Sync>
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(level.to_string()));
+    subscriber_with_filter(format, filter, writer)
+}
+
+/// Builds a subscriber writing the events `filter` lets through to `writer`
+fn subscriber_with_filter<W>(format: LogFormat, filter: EnvFilter, writer: W) -> Box<dyn Subscriber + Send + Sync>
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(writer);
+
+    match format {
+        LogFormat::Pretty => Box::new(builder.pretty().finish()),
+        LogFormat::Json => Box::new(builder.json().finish()),
+    }
+}
+
+/// Installs a global subscriber writing logs in the given format to stdout
+///
+/// Fails if a global subscriber has already been installed.
+pub fn init(format: LogFormat, level: Level) -> Result<(), A2AError> {
+    tracing::subscriber::set_global_default(build_subscriber(format, level, std::io::stdout))
+        .map_err(|e| A2AError::internal(&format!("Failed to initialize logging: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+
+    /// Writer that collects all output in a shared buffer
+    #[derive(Clone, Default)]
+    struct BufferWriter(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for BufferWriter {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl<'a> MakeWriter<'a> for BufferWriter {
+        type Writer = BufferWriter;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn test_json_format_emits_json_lines() {
+        let writer = BufferWriter::default();
+        // An explicit filter keeps the test independent of RUST_LOG
+        let subscriber = subscriber_with_filter(LogFormat::Json, EnvFilter::new("info"), writer.clone());
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(task_id = "task-1", "task created");
+            tracing::debug!("filtered out");
+        });
+
+        let output = String::from_utf8(writer.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0]["level"], "INFO");
+        assert_eq!(lines[0]["fields"]["message"], "task created");
+        assert_eq!(lines[0]["fields"]["task_id"], "task-1");
+    }
+
+    #[test]
+    fn test_log_format_from_str() {
+        assert_eq!("json".parse::<LogFormat>().unwrap(), LogFormat::Json);
+        assert_eq!("Pretty".parse::<LogFormat>().unwrap(), LogFormat::Pretty);
+        assert!("xml".parse::<LogFormat>().is_err());
+    }
+}
@@ -3,7 +3,14 @@
 //! This module provides helper functions that match the functionality
 //! in a2a-python/src/a2a/utils/message.py
 
-use crate::a2a::core_types::{Message, Part, Role};
+use crate::a2a::core_types::{FileContent, Message, Part, PartRoot, Role};
+use serde_json::Value;
+
+/// Metadata key marking a message as a chunk of a user message still being composed
+///
+/// Servers buffer the parts of partial messages and run the agent once the
+/// message with the same `message_id` arrives without this flag.
+pub const PARTIAL_MESSAGE_METADATA_KEY: &str = "a2a/partial";
 
 /// Creates a new agent message containing a single TextPart
 /// 
@@ -53,6 +60,92 @@ pub fn get_text_parts(parts: &[Part]) -> Vec<String> {
         .collect()
 }
 
+/// Whether `message` is flagged as a partial chunk
+pub fn is_partial_message(message: &Message) -> bool {
+    message
+        .metadata
+        .as_ref()
+        .and_then(|metadata| metadata.get(PARTIAL_MESSAGE_METADATA_KEY))
+        .and_then(Value::as_bool)
+        .unwrap_or(false)
+}
+
+/// Appends `parts` to a message, joining text onto a trailing text part
+///
+/// Chunks of streamed user input split text at arbitrary points, so the text
+/// is concatenated without a delimiter.
+pub fn append_message_parts(message: &mut Message, parts: Vec<Part>) {
+    for part in parts {
+        if let (Some(last), PartRoot::Text(next)) = (message.parts.last_mut(), part.root()) {
+            let last_root = match last {
+                Part::WithRoot { root } => root,
+                Part::Direct(root) => root,
+            };
+            if let PartRoot::Text(text) = last_root {
+                text.text.push_str(&next.text);
+                continue;
+            }
+        }
+        message.parts.push(part);
+    }
+}
+
+/// Approximate size in bytes of a message's content, for quota enforcement
+///
+/// Sums the UTF-8 length of text parts, the decoded size of base64 file
+/// parts, the length of file URIs and the serialized size of data and
+/// unknown parts. Metadata is not counted.
+pub fn message_size_bytes(message: &Message) -> usize {
+    message
+        .parts
+        .iter()
+        .map(|part| match part.root() {
+            PartRoot::Text(text) => text.text.len(),
+            PartRoot::File(file) => match &file.file {
+                FileContent::Bytes(file) => decoded_base64_len(&file.bytes),
+                FileContent::Uri(file) => file.uri.len(),
+            },
+            PartRoot::Data(data) => serde_json::to_string(&data.data).map(|json| json.len()).unwrap_or(0),
+            PartRoot::Unknown(part) => serde_json::to_string(&part.value).map(|json| json.len()).unwrap_or(0),
+        })
+        .sum()
+}
+
+/// Rough number of model tokens a message's text and data amount to
+///
+/// Each text or data part counts as the larger of its whitespace-separated
+/// word count and a quarter of its characters, rounded up. File and unknown
+/// parts are not counted, since their cost depends on how the agent
+/// processes them.
+pub fn message_estimated_tokens(message: &Message) -> usize {
+    message
+        .parts
+        .iter()
+        .map(|part| match part.root() {
+            PartRoot::Text(text) => estimate_tokens(&text.text),
+            PartRoot::Data(data) => serde_json::to_string(&data.data)
+                .map(|json| estimate_tokens(&json))
+                .unwrap_or(0),
+            PartRoot::File(_) | PartRoot::Unknown(_) => 0,
+        })
+        .sum()
+}
+
+fn estimate_tokens(text: &str) -> usize {
+    let words = text.split_whitespace().count();
+    words.max(text.chars().count().div_ceil(4))
+}
+
+/// Size of the data a base64 string decodes to, without decoding it
+fn decoded_base64_len(encoded: &str) -> usize {
+    let symbols = encoded
+        .trim_end_matches('=')
+        .bytes()
+        .filter(|byte| !byte.is_ascii_whitespace())
+        .count();
+    symbols * 3 / 4
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,4 +194,39 @@ mod tests {
         let text_parts = get_text_parts(&parts);
         assert_eq!(text_parts, vec!["Hello", "World"]);
     }
+
+    #[test]
+    fn test_append_message_parts_joins_text() {
+        let mut message = Message::new(Role::User, vec![Part::text("Hel".to_string())]);
+        append_message_parts(
+            &mut message,
+            vec![Part::text("lo".to_string()), Part::data(serde_json::json!({"n": 1})), Part::text("!".to_string())],
+        );
+
+        assert_eq!(message.parts.len(), 3);
+        assert_eq!(get_text_parts(&message.parts), vec!["Hello", "!"]);
+        assert!(!is_partial_message(&message));
+    }
+
+    #[test]
+    fn test_message_size_and_tokens_of_mixed_message() {
+        // "aGVsbG8gd29ybGQ=" is "hello world" (11 bytes)
+        let message = Message::new(
+            Role::User,
+            vec![
+                Part::text("The quick brown fox".to_string()),
+                Part::file_bytes("aGVsbG8gd29ybGQ=".to_string()),
+                Part::data(serde_json::json!({"a": 1})),
+                Part::file_uri("https://example.com/a.png".parse().unwrap()),
+            ],
+        );
+
+        assert_eq!(message_size_bytes(&message), 19 + 11 + 7 + 25);
+        // 19 chars -> 5 tokens, `{"a":1}` -> 2 tokens, files are not counted
+        assert_eq!(message_estimated_tokens(&message), 5 + 2);
+
+        let words = Message::new(Role::User, vec![Part::text("a b c d e f g h".to_string())]);
+        assert_eq!(message_estimated_tokens(&words), 8);
+        assert_eq!(message_size_bytes(&Message::new(Role::User, vec![])), 0);
+    }
 }
@@ -0,0 +1,166 @@
+//! Typed access to A2A metadata maps
+//!
+//! Many A2A objects (`Message`, `Task`, `Artifact`, request params, ...) carry
+//! an optional `metadata` map of arbitrary JSON values. This module provides a
+//! `Metadata` newtype for building such maps and the `MetadataExt` trait for
+//! reading typed values out of the existing `metadata` fields.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
+
+/// A metadata map with typed accessors
+///
+/// Serializes exactly like the underlying `HashMap<String, Value>`, so it can
+/// be converted to and from the `metadata` fields of the protocol types.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Metadata(HashMap<String, Value>);
+
+impl Metadata {
+    /// Creates an empty metadata map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a value, returning the updated map
+    pub fn set(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.0.insert(key.into(), value.into());
+        self
+    }
+
+    /// Returns the string value for `key`, if present and a string
+    pub fn get_str(&self, key: &str) -> Option<&str> {
+        self.0.get_str(key)
+    }
+
+    /// Returns the integer value for `key`, if present and an integer
+    pub fn get_i64(&self, key: &str) -> Option<i64> {
+        self.0.get_i64(key)
+    }
+
+    /// Returns the boolean value for `key`, if present and a boolean
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.0.get_bool(key)
+    }
+
+    /// Consumes the wrapper and returns the underlying map
+    pub fn into_inner(self) -> HashMap<String, Value> {
+        self.0
+    }
+}
+
+impl Deref for Metadata {
+    type Target = HashMap<String, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for Metadata {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl From<HashMap<String, Value>> for Metadata {
+    fn from(map: HashMap<String, Value>) -> Self {
+        Self(map)
+    }
+}
+
+impl From<Metadata> for HashMap<String, Value> {
+    fn from(metadata: Metadata) -> Self {
+        metadata.0
+    }
+}
+
+/// Typed getters for the `metadata` fields of A2A objects
+///
+/// Implemented for both `HashMap<String, Value>` and
+/// `Option<HashMap<String, Value>>`, so it can be called directly on fields
+/// such as `message.metadata` or `task.metadata`. Absent keys and values of
+/// the wrong type both yield `None`.
+pub trait MetadataExt {
+    /// Returns the raw value for `key`, if present
+    fn get_value(&self, key: &str) -> Option<&Value>;
+
+    /// Returns the string value for `key`, if present and a string
+    fn get_str(&self, key: &str) -> Option<&str> {
+        self.get_value(key).and_then(Value::as_str)
+    }
+
+    /// Returns the integer value for `key`, if present and an integer
+    fn get_i64(&self, key: &str) -> Option<i64> {
+        self.get_value(key).and_then(Value::as_i64)
+    }
+
+    /// Returns the boolean value for `key`, if present and a boolean
+    fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get_value(key).and_then(Value::as_bool)
+    }
+}
+
+impl MetadataExt for HashMap<String, Value> {
+    fn get_value(&self, key: &str) -> Option<&Value> {
+        self.get(key)
+    }
+}
+
+impl MetadataExt for Option<HashMap<String, Value>> {
+    fn get_value(&self, key: &str) -> Option<&Value> {
+        self.as_ref().and_then(|map| map.get(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::core_types::{Message, Part, Role};
+    use serde_json::json;
+
+    #[test]
+    fn test_metadata_typed_getters() {
+        let metadata = Metadata::new()
+            .set("name", "alice")
+            .set("count", 3)
+            .set("enabled", true);
+
+        assert_eq!(metadata.get_str("name"), Some("alice"));
+        assert_eq!(metadata.get_i64("count"), Some(3));
+        assert_eq!(metadata.get_bool("enabled"), Some(true));
+    }
+
+    #[test]
+    fn test_metadata_missing_and_wrong_type() {
+        let metadata = Metadata::new().set("count", "three");
+
+        assert_eq!(metadata.get_str("missing"), None);
+        assert_eq!(metadata.get_i64("count"), None);
+        assert_eq!(metadata.get_bool("count"), None);
+    }
+
+    #[test]
+    fn test_metadata_serde_is_transparent() {
+        let metadata = Metadata::new().set("key", "value");
+        let json = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(json, json!({"key": "value"}));
+
+        let parsed: Metadata = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, metadata);
+    }
+
+    #[test]
+    fn test_metadata_ext_on_message() {
+        let message = Message::new(Role::User, vec![Part::text("hi".to_string())])
+            .with_metadata(Metadata::new().set("priority", 5).into());
+
+        assert_eq!(message.metadata.get_i64("priority"), Some(5));
+        assert_eq!(message.metadata.get_str("priority"), None);
+
+        let bare = Message::new(Role::User, vec![Part::text("hi".to_string())]);
+        assert_eq!(bare.metadata.get_bool("anything"), None);
+    }
+}
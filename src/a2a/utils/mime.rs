@@ -0,0 +1,62 @@
+//! MIME type inference for file parts
+//!
+//! Helpers used by the `*_with_inferred_type` file part constructors to fill
+//! in `mime_type` from a URI's extension or from the magic bytes of inline
+//! file content.
+
+use base64::Engine;
+use url::Url;
+
+/// Number of base64 characters decoded when sniffing inline file content
+const SNIFF_PREFIX_LEN: usize = 1024;
+
+/// Infers a MIME type from the extension of a URI's path
+pub fn infer_mime_type_from_uri(uri: &Url) -> Option<String> {
+    mime_guess::from_path(uri.path())
+        .first()
+        .map(|mime| mime.essence_str().to_string())
+}
+
+/// Infers a MIME type from the magic bytes at the start of file content
+pub fn infer_mime_type_from_bytes(bytes: &[u8]) -> Option<String> {
+    infer::get(bytes).map(|kind| kind.mime_type().to_string())
+}
+
+/// Infers a MIME type from base64-encoded file content
+///
+/// Only the start of the content is decoded. Returns `None` if the content is
+/// not valid base64 or the type is not recognized.
+pub fn infer_mime_type_from_base64(encoded: &str) -> Option<String> {
+    let prefix = encoded.get(..SNIFF_PREFIX_LEN).unwrap_or(encoded);
+    let bytes = base64::engine::general_purpose::STANDARD.decode(prefix).ok()?;
+    infer_mime_type_from_bytes(&bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PNG_HEADER: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 0x0D];
+
+    #[test]
+    fn test_infer_mime_type_from_uri() {
+        let pdf = Url::parse("https://example.com/docs/report.pdf?version=2").unwrap();
+        assert_eq!(infer_mime_type_from_uri(&pdf), Some("application/pdf".to_string()));
+
+        let unknown = Url::parse("https://example.com/docs/report").unwrap();
+        assert_eq!(infer_mime_type_from_uri(&unknown), None);
+    }
+
+    #[test]
+    fn test_infer_mime_type_from_bytes() {
+        assert_eq!(infer_mime_type_from_bytes(PNG_HEADER), Some("image/png".to_string()));
+        assert_eq!(infer_mime_type_from_bytes(b"plain text"), None);
+    }
+
+    #[test]
+    fn test_infer_mime_type_from_base64() {
+        let encoded = base64::engine::general_purpose::STANDARD.encode(PNG_HEADER);
+        assert_eq!(infer_mime_type_from_base64(&encoded), Some("image/png".to_string()));
+        assert_eq!(infer_mime_type_from_base64("not base64!"), None);
+    }
+}
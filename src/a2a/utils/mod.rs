@@ -4,14 +4,27 @@
 //! matching the functionality provided in a2a-python/src/a2a/utils/.
 
 pub mod artifact;
+pub mod canonical;
 pub mod constants;
+pub mod heartbeat;
+pub mod logging;
 pub mod message;
+pub mod metadata;
+pub mod mime;
+pub mod negotiation;
 pub mod parts;
+pub mod redact;
+pub mod signing;
 pub mod task;
+pub mod telemetry;
 
 // Re-export utility functions for convenience
 pub use artifact::*;
+pub use canonical::canonical_json;
 pub use constants::*;
+pub use metadata::{Metadata, MetadataExt};
+pub use mime::{infer_mime_type_from_base64, infer_mime_type_from_bytes, infer_mime_type_from_uri};
+pub use negotiation::{negotiate_for_skill, negotiate_output_mode, validate_input_mode_for_skill, validate_message_input_modes};
 
 // Re-export message utilities with explicit naming to avoid conflicts
 pub use message::{
@@ -0,0 +1,229 @@
+//! Content-type negotiation against an agent card
+//!
+//! Agents advertise the MIME types they accept and produce through the card's
+//! default input/output modes, and skills may narrow those further. These
+//! helpers pick a mode both sides support, consulting the targeted skill's
+//! modes before falling back to the card defaults.
+
+use crate::a2a::core_types::{FileContent, Message, Part, PartRoot};
+use crate::a2a::error::A2AError;
+use crate::a2a::models::{AgentCard, AgentSkill};
+
+/// Picks the output mode to use for a request to the given skill
+///
+/// Modes come from the skill's `output_modes` when `skill_id` names a skill
+/// that declares them, and from the card's `default_output_modes` otherwise.
+/// The first of the client's `accepted` modes the agent supports wins; an
+/// empty `accepted` list accepts anything. Accepted modes may use wildcards
+/// such as `text/*` or `*/*`.
+pub fn negotiate_for_skill(
+    card: &AgentCard,
+    skill_id: Option<&str>,
+    accepted: &[String],
+) -> Result<String, A2AError> {
+    let supported = match find_skill(card, skill_id)? {
+        Some(AgentSkill { output_modes: Some(modes), .. }) => modes,
+        _ => &card.default_output_modes,
+    };
+
+    if accepted.is_empty() {
+        return supported.first().cloned().ok_or_else(|| {
+            A2AError::content_type_not_supported("Agent does not declare any output modes")
+        });
+    }
+
+    accepted
+        .iter()
+        .find_map(|pattern| supported.iter().find(|mode| mode_matches(pattern, mode)))
+        .cloned()
+        .ok_or_else(|| {
+            A2AError::content_type_not_supported(&format!(
+                "None of the accepted output modes {:?} are supported; available: {:?}",
+                accepted, supported
+            ))
+        })
+}
+
+/// Picks the output mode to use for a request without a targeted skill
+pub fn negotiate_output_mode(card: &AgentCard, accepted: &[String]) -> Result<String, A2AError> {
+    negotiate_for_skill(card, None, accepted)
+}
+
+/// Checks that the given input mode is accepted by the skill (or the card)
+pub fn validate_input_mode_for_skill(
+    card: &AgentCard,
+    skill_id: Option<&str>,
+    input_mode: &str,
+) -> Result<(), A2AError> {
+    let supported = match find_skill(card, skill_id)? {
+        Some(AgentSkill { input_modes: Some(modes), .. }) => modes,
+        _ => &card.default_input_modes,
+    };
+
+    if supported.iter().any(|mode| mode_matches(mode, input_mode)) {
+        Ok(())
+    } else {
+        Err(A2AError::content_type_not_supported(&format!(
+            "Input mode {} is not supported; available: {:?}",
+            input_mode, supported
+        )))
+    }
+}
+
+/// Checks that every part of a message uses an input mode the card accepts
+///
+/// Text parts count as `text/plain` and data parts as `application/json`.
+/// File parts are checked against their declared `mime_type` and skipped
+/// when it is missing. A card without default input modes accepts anything,
+/// and a bare type such as `text` accepts its whole MIME family.
+pub fn validate_message_input_modes(card: &AgentCard, message: &Message) -> Result<(), A2AError> {
+    if card.default_input_modes.is_empty() {
+        return Ok(());
+    }
+    message
+        .parts
+        .iter()
+        .filter_map(part_input_mode)
+        .try_for_each(|mode| validate_input_mode_for_skill(card, None, mode))
+}
+
+/// Returns the MIME type a part is sent as, if known
+fn part_input_mode(part: &Part) -> Option<&str> {
+    match part.root() {
+        PartRoot::Text(_) => Some("text/plain"),
+        PartRoot::Data(_) => Some("application/json"),
+        PartRoot::File(file) => match &file.file {
+            FileContent::Uri(file) => file.mime_type.as_deref(),
+            FileContent::Bytes(file) => file.mime_type.as_deref(),
+        },
+        PartRoot::Unknown(_) => None,
+    }
+}
+
+fn find_skill<'a>(card: &'a AgentCard, skill_id: Option<&str>) -> Result<Option<&'a AgentSkill>, A2AError> {
+    match skill_id {
+        Some(id) => card
+            .skills
+            .iter()
+            .find(|skill| skill.id == id)
+            .map(Some)
+            .ok_or_else(|| A2AError::invalid_params(&format!("Unknown skill: {}", id))),
+        None => Ok(None),
+    }
+}
+
+/// Returns whether `mode` satisfies `pattern`, which may contain wildcards
+fn mode_matches(pattern: &str, mode: &str) -> bool {
+    let pattern = pattern.trim().to_ascii_lowercase();
+    let mode = mode.trim().to_ascii_lowercase();
+    if pattern == "*/*" || pattern == mode {
+        return true;
+    }
+    // A bare type such as `text` stands for its whole family, like `text/*`
+    let family = pattern.strip_suffix("/*").or_else(|| (!pattern.contains('/')).then_some(pattern.as_str()));
+    match family {
+        Some(prefix) => mode.split('/').next() == Some(prefix),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::a2a::jsonrpc::error_codes::CONTENT_TYPE_NOT_SUPPORTED;
+    use crate::a2a::models::AgentCapabilities;
+
+    fn modes(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    fn test_card() -> AgentCard {
+        let narrow = AgentSkill::new(
+            "summarize".to_string(),
+            "Summarize".to_string(),
+            "Summarizes documents".to_string(),
+            vec![],
+        )
+        .with_input_modes(modes(&["application/pdf"]))
+        .with_output_modes(modes(&["text/plain"]));
+        let inherited = AgentSkill::new(
+            "chat".to_string(),
+            "Chat".to_string(),
+            "General chat".to_string(),
+            vec![],
+        );
+
+        AgentCard::new(
+            "Test Agent".to_string(),
+            "Agent for negotiation tests".to_string(),
+            "http://localhost:8080".to_string(),
+            "1.0.0".to_string(),
+            modes(&["text/plain", "application/pdf", "image/png"]),
+            modes(&["application/json", "text/plain", "text/html"]),
+            AgentCapabilities::new(),
+            vec![narrow, inherited],
+        )
+    }
+
+    #[test]
+    fn test_skill_output_modes_narrow_card_defaults() {
+        let card = test_card();
+        let accepted = modes(&["application/json", "text/plain"]);
+
+        assert_eq!(negotiate_output_mode(&card, &accepted).unwrap(), "application/json");
+        assert_eq!(negotiate_for_skill(&card, Some("summarize"), &accepted).unwrap(), "text/plain");
+
+        let json_only = modes(&["application/json"]);
+        let error = negotiate_for_skill(&card, Some("summarize"), &json_only).unwrap_err();
+        assert_eq!(error.code(), CONTENT_TYPE_NOT_SUPPORTED);
+    }
+
+    #[test]
+    fn test_skill_without_modes_falls_back_to_card() {
+        let card = test_card();
+
+        let accepted = modes(&["text/*"]);
+        assert_eq!(negotiate_for_skill(&card, Some("chat"), &accepted).unwrap(), "text/plain");
+        assert_eq!(negotiate_for_skill(&card, Some("chat"), &[]).unwrap(), "application/json");
+        assert!(negotiate_for_skill(&card, Some("missing"), &accepted).is_err());
+    }
+
+    #[test]
+    fn test_skill_input_modes() {
+        let card = test_card();
+
+        assert!(validate_input_mode_for_skill(&card, None, "image/png").is_ok());
+        assert!(validate_input_mode_for_skill(&card, Some("summarize"), "application/pdf").is_ok());
+        assert!(validate_input_mode_for_skill(&card, Some("summarize"), "image/png").is_err());
+    }
+
+    #[test]
+    fn test_message_input_modes() {
+        use crate::a2a::core_types::{FilePart, Role};
+
+        let card = test_card();
+        let file = |mime_type: Option<&str>| {
+            let mut part = FilePart::new_bytes("JVBERi0=".to_string());
+            if let FileContent::Bytes(ref mut file) = part.file {
+                file.mime_type = mime_type.map(str::to_string);
+            }
+            Part::Direct(PartRoot::File(part))
+        };
+
+        let supported = Message::new(Role::User, vec![Part::text("hi".to_string()), file(Some("application/pdf")), file(None)]);
+        assert!(validate_message_input_modes(&card, &supported).is_ok());
+
+        let unsupported = Message::new(Role::User, vec![file(Some("video/mp4"))]);
+        let error = validate_message_input_modes(&card, &unsupported).unwrap_err();
+        assert_eq!(error.code(), CONTENT_TYPE_NOT_SUPPORTED);
+
+        let data = Message::new(Role::User, vec![Part::data(serde_json::json!({"a": 1}))]);
+        assert!(validate_message_input_modes(&card, &data).is_err());
+
+        let mut families = card.clone();
+        families.default_input_modes = modes(&["text", "application"]);
+        assert!(validate_message_input_modes(&families, &supported).is_ok());
+        assert!(validate_message_input_modes(&families, &data).is_ok());
+        assert!(validate_message_input_modes(&families, &unsupported).is_err());
+    }
+}
@@ -0,0 +1,59 @@
+//! Redaction of sensitive values in JSON payloads
+//!
+//! Shared by the client's `LoggingTransport` and the server's
+//! `RequestRecorder`, so credentials are replaced the same way wherever
+//! payloads are logged or stored.
+
+use serde_json::Value;
+
+/// Replacement for redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Keys whose values are redacted by default, compared case-insensitively
+pub const DEFAULT_REDACTED_KEYS: &[&str] = &[
+    "authorization",
+    "token",
+    "credentials",
+    "password",
+    "secret",
+    "client_secret",
+    "api_key",
+    "bytes",
+];
+
+/// Replaces the values under any of `keys`, ignoring case, anywhere in `value`
+pub fn redact_json(value: &mut Value, keys: &[String]) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                if keys.iter().any(|redacted| key.eq_ignore_ascii_case(redacted)) {
+                    *child = Value::String(REDACTED.to_string());
+                } else {
+                    redact_json(child, keys);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| redact_json(item, keys)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_json() {
+        let mut value = serde_json::json!({
+            "parts": [{"kind": "file", "file": {"bytes": "aGVsbG8=", "name": "a.txt"}}],
+            "authentication": {"schemes": ["Bearer"], "Credentials": "secret-token"},
+        });
+        let keys: Vec<String> = DEFAULT_REDACTED_KEYS.iter().map(|key| key.to_string()).collect();
+        redact_json(&mut value, &keys);
+
+        assert_eq!(value["parts"][0]["file"]["bytes"], REDACTED);
+        assert_eq!(value["parts"][0]["file"]["name"], "a.txt");
+        assert_eq!(value["authentication"]["Credentials"], REDACTED);
+        assert_eq!(value["authentication"]["schemes"][0], "Bearer");
+    }
+}
@@ -0,0 +1,261 @@
+//! Agent card signatures
+//!
+//! Agents can prove the authenticity of their card by signing it. Each entry
+//! of `AgentCard::signatures` is a JWS with a detached payload: an object
+//! with the base64url-encoded `protected` header and `signature`. The signed
+//! payload is the card without its `signatures` and null fields, in the
+//! canonical JSON form of [`canonical_json`], matching the Python SDK.
+//!
+//! Signatures are checked against a JSON Web Key Set. The EdDSA (Ed25519),
+//! ES256 and RS256 algorithms are supported. Received cards are verified as
+//! the JSON they arrived as, since fields this crate does not model would be
+//! lost by deserializing them first.
+
+use crate::a2a::error::A2AError;
+use crate::a2a::models::AgentCard;
+use crate::a2a::utils::canonical::canonical_json;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use ring::signature::{self, Ed25519KeyPair, KeyPair, RsaPublicKeyComponents, UnparsedPublicKey};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+/// A public key in JSON Web Key format
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Jwk {
+    /// Key type: "OKP", "EC" or "RSA"
+    pub kty: String,
+    /// Key ID, matched against the `kid` of signature headers
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    /// Algorithm the key is restricted to, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    /// Curve of OKP and EC keys ("Ed25519", "P-256")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crv: Option<String>,
+    /// Public key (OKP) or x coordinate (EC), base64url-encoded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x: Option<String>,
+    /// y coordinate of EC keys, base64url-encoded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub y: Option<String>,
+    /// Modulus of RSA keys, base64url-encoded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<String>,
+    /// Exponent of RSA keys, base64url-encoded
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e: Option<String>,
+}
+
+impl Jwk {
+    /// An Ed25519 public key
+    pub fn ed25519(kid: &str, public_key: &[u8]) -> Self {
+        Self {
+            kty: "OKP".to_string(),
+            kid: Some(kid.to_string()),
+            alg: Some("EdDSA".to_string()),
+            crv: Some("Ed25519".to_string()),
+            x: Some(URL_SAFE_NO_PAD.encode(public_key)),
+            ..Default::default()
+        }
+    }
+
+    /// Checks `signature` over `message` with this key, using algorithm `alg`
+    fn verify(&self, alg: &str, message: &[u8], signature: &[u8]) -> bool {
+        if self.alg.as_deref().is_some_and(|key_alg| key_alg != alg) {
+            return false;
+        }
+        let param = |value: &Option<String>| value.as_deref().and_then(|value| URL_SAFE_NO_PAD.decode(value).ok());
+        match (alg, self.kty.as_str(), self.crv.as_deref()) {
+            ("EdDSA", "OKP", Some("Ed25519")) => param(&self.x).is_some_and(|x| {
+                UnparsedPublicKey::new(&signature::ED25519, x).verify(message, signature).is_ok()
+            }),
+            ("ES256", "EC", Some("P-256")) => match (param(&self.x), param(&self.y)) {
+                (Some(x), Some(y)) => {
+                    let point = [&[0x04][..], &x, &y].concat();
+                    UnparsedPublicKey::new(&signature::ECDSA_P256_SHA256_FIXED, point)
+                        .verify(message, signature)
+                        .is_ok()
+                }
+                _ => false,
+            },
+            ("RS256", "RSA", _) => match (param(&self.n), param(&self.e)) {
+                (Some(n), Some(e)) => RsaPublicKeyComponents { n, e }
+                    .verify(&signature::RSA_PKCS1_2048_8192_SHA256, message, signature)
+                    .is_ok(),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+/// A set of trusted public keys
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct JwkSet {
+    pub keys: Vec<Jwk>,
+}
+
+impl JwkSet {
+    pub fn new(keys: Vec<Jwk>) -> Self {
+        Self { keys }
+    }
+}
+
+/// The protected header of a card signature
+#[derive(Debug, Deserialize)]
+struct ProtectedHeader {
+    alg: String,
+    kid: Option<String>,
+}
+
+/// One entry of `AgentCard::signatures`
+#[derive(Debug, Deserialize)]
+struct CardSignature {
+    protected: String,
+    signature: String,
+}
+
+/// Returns the canonical form of a card that its signatures cover
+pub fn canonicalize_agent_card(card: &AgentCard) -> Result<Vec<u8>, A2AError> {
+    Ok(canonicalize_agent_card_json(&card_to_json(card)?))
+}
+
+/// Returns the canonical form of a card's JSON that its signatures cover
+pub fn canonicalize_agent_card_json(card: &Value) -> Vec<u8> {
+    let mut value = card.clone();
+    if let Value::Object(map) = &mut value {
+        map.remove("signatures");
+    }
+    remove_nulls(&mut value);
+    canonical_json(&value).into_bytes()
+}
+
+fn card_to_json(card: &AgentCard) -> Result<Value, A2AError> {
+    serde_json::to_value(card).map_err(|e| A2AError::json_error(format!("Failed to serialize agent card: {}", e)))
+}
+
+fn remove_nulls(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, child| !child.is_null());
+            map.values_mut().for_each(remove_nulls);
+        }
+        Value::Array(items) => items.iter_mut().for_each(remove_nulls),
+        _ => {}
+    }
+}
+
+/// Checks that at least one of the card's signatures was made by a trusted key
+///
+/// Only covers the fields `AgentCard` models; use [`verify_agent_card_json`]
+/// for cards received from an agent.
+pub fn verify_agent_card(card: &AgentCard, keys: &JwkSet) -> Result<(), A2AError> {
+    verify_agent_card_json(&card_to_json(card)?, keys)
+}
+
+/// Checks that at least one signature of a card, as received, was made by a trusted key
+///
+/// Fails with `InvalidAgentResponse` if the card is unsigned or no signature
+/// verifies. Signatures naming a `kid` are only checked against the key with
+/// that ID.
+pub fn verify_agent_card_json(card: &Value, keys: &JwkSet) -> Result<(), A2AError> {
+    let signatures = match card.get("signatures").and_then(Value::as_array) {
+        Some(signatures) if !signatures.is_empty() => signatures,
+        _ => return Err(A2AError::invalid_response("Agent card is not signed")),
+    };
+    let payload = URL_SAFE_NO_PAD.encode(canonicalize_agent_card_json(card));
+
+    let verified = signatures.iter().any(|signature| {
+        let Ok(signature) = serde_json::from_value::<CardSignature>(signature.clone()) else {
+            return false;
+        };
+        let header = URL_SAFE_NO_PAD
+            .decode(&signature.protected)
+            .ok()
+            .and_then(|header| serde_json::from_slice::<ProtectedHeader>(&header).ok());
+        let (Some(header), Ok(signature_bytes)) = (header, URL_SAFE_NO_PAD.decode(&signature.signature)) else {
+            return false;
+        };
+        let signing_input = format!("{}.{}", signature.protected, payload);
+        keys.keys
+            .iter()
+            .filter(|key| header.kid.is_none() || key.kid == header.kid)
+            .any(|key| key.verify(&header.alg, signing_input.as_bytes(), &signature_bytes))
+    });
+
+    if verified {
+        Ok(())
+    } else {
+        Err(A2AError::invalid_response("Agent card signature could not be verified"))
+    }
+}
+
+/// Signs a card with an Ed25519 key given by its 32-byte seed
+///
+/// Returns the card with the new signature appended to its `signatures`,
+/// along with the public key to publish for verification.
+pub fn sign_agent_card_ed25519(card: &AgentCard, kid: &str, seed: &[u8]) -> Result<(AgentCard, Jwk), A2AError> {
+    let key_pair = Ed25519KeyPair::from_seed_unchecked(seed)
+        .map_err(|_| A2AError::invalid_params("Ed25519 seed must be 32 bytes"))?;
+    let protected = URL_SAFE_NO_PAD.encode(json!({"alg": "EdDSA", "kid": kid, "typ": "JOSE"}).to_string());
+    let payload = URL_SAFE_NO_PAD.encode(canonicalize_agent_card(card)?);
+    let signature = key_pair.sign(format!("{}.{}", protected, payload).as_bytes());
+
+    let mut signed = card.clone();
+    signed
+        .signatures
+        .get_or_insert_with(Vec::new)
+        .push(json!({"protected": protected, "signature": URL_SAFE_NO_PAD.encode(signature.as_ref())}));
+    Ok((signed, Jwk::ed25519(kid, key_pair.public_key().as_ref())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_card_ignores_signatures_and_nulls() {
+        let card = AgentCard::test_default("Signed Agent", "http://localhost:8080");
+        let signed = card.clone().with_signatures(vec![json!({"protected": "x", "signature": "y"})]);
+
+        let canonical = canonicalize_agent_card(&card).unwrap();
+        assert_eq!(canonical, canonicalize_agent_card(&signed).unwrap());
+        let text = String::from_utf8(canonical).unwrap();
+        assert!(!text.contains("null") && !text.contains("signatures") && !text.contains("\": "));
+    }
+
+    #[test]
+    fn test_signature_checked_against_matching_kid() {
+        let card = AgentCard::test_default("Signed Agent", "http://localhost:8080");
+        let (signed, key) = sign_agent_card_ed25519(&card, "key-1", &[7u8; 32]).unwrap();
+        let (_, other_key) = sign_agent_card_ed25519(&card, "key-2", &[9u8; 32]).unwrap();
+
+        assert!(verify_agent_card(&signed, &JwkSet::new(vec![other_key.clone(), key.clone()])).is_ok());
+        assert!(verify_agent_card(&signed, &JwkSet::new(vec![other_key])).is_err());
+        let renamed = Jwk { kid: Some("key-2".to_string()), ..key };
+        assert!(verify_agent_card(&signed, &JwkSet::new(vec![renamed])).is_err());
+        assert!(verify_agent_card(&card, &JwkSet::default()).is_err());
+    }
+
+    #[test]
+    fn test_received_card_verified_with_unmodeled_fields() {
+        let card = AgentCard::test_default("Signed Agent", "http://localhost:8080");
+        let mut received = serde_json::to_value(&card).unwrap();
+        received["vendorExtension"] = json!({"tier": "gold"});
+
+        let key_pair = Ed25519KeyPair::from_seed_unchecked(&[7u8; 32]).unwrap();
+        let protected = URL_SAFE_NO_PAD.encode(json!({"alg": "EdDSA", "kid": "key-1"}).to_string());
+        let payload = URL_SAFE_NO_PAD.encode(canonicalize_agent_card_json(&received));
+        let signature = key_pair.sign(format!("{}.{}", protected, payload).as_bytes());
+        received["signatures"] = json!([{"protected": protected, "signature": URL_SAFE_NO_PAD.encode(signature.as_ref())}]);
+        let keys = JwkSet::new(vec![Jwk::ed25519("key-1", key_pair.public_key().as_ref())]);
+
+        assert!(verify_agent_card_json(&received, &keys).is_ok());
+        let parsed: AgentCard = serde_json::from_value(received.clone()).unwrap();
+        assert!(verify_agent_card(&parsed, &keys).is_err());
+        received["vendorExtension"]["tier"] = json!("platinum");
+        assert!(verify_agent_card_json(&received, &keys).is_err());
+    }
+}
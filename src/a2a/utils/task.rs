@@ -3,9 +3,10 @@
 //! This module provides helper functions that match the functionality
 //! in a2a-python/src/a2a/utils/task.py
 
-use crate::a2a::core_types::{Message, TaskState, TaskStatus};
+use crate::a2a::core_types::{FileContent, Message, PartRoot, TaskState, TaskStatus};
 use crate::a2a::models::{Artifact, Task};
 use crate::a2a::error::A2AError;
+use std::collections::HashSet;
 use uuid::Uuid;
 
 /// Creates a new Task object from an initial user message
@@ -107,6 +108,73 @@ pub fn apply_history_length(task: Task, history_length: Option<i32>) -> Task {
     task
 }
 
+/// Changes between two versions of a task, as reported by `diff_tasks`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TaskDiff {
+    /// The previous and new state, if the state changed
+    pub state_change: Option<(TaskState, TaskState)>,
+    /// Artifacts whose IDs do not appear in the old task, in order
+    pub new_artifacts: Vec<Artifact>,
+    /// History messages whose IDs do not appear in the old task, in order
+    pub new_history: Vec<Message>,
+    /// Whether the status message was added, removed or replaced
+    pub status_message_changed: bool,
+}
+
+impl TaskDiff {
+    /// Returns whether no changes were found
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Reports what changed between two versions of the same task
+///
+/// Artifacts are matched by artifact ID and history messages by message ID,
+/// so updates to an existing artifact are not reported as new artifacts.
+pub fn diff_tasks(old: &Task, new: &Task) -> TaskDiff {
+    let old_artifact_ids: HashSet<&str> = old.artifacts.iter().flatten()
+        .map(|artifact| artifact.artifact_id.as_str())
+        .collect();
+    let old_message_ids: HashSet<&str> = old.history.iter().flatten()
+        .map(|message| message.message_id.as_str())
+        .collect();
+
+    TaskDiff {
+        state_change: (old.status.state != new.status.state)
+            .then(|| (old.status.state.clone(), new.status.state.clone())),
+        new_artifacts: new.artifacts.iter().flatten()
+            .filter(|artifact| !old_artifact_ids.contains(artifact.artifact_id.as_str()))
+            .cloned()
+            .collect(),
+        new_history: new.history.iter().flatten()
+            .filter(|message| !old_message_ids.contains(message.message_id.as_str()))
+            .cloned()
+            .collect(),
+        status_message_changed: old.status.message != new.status.message,
+    }
+}
+
+/// Collects the URIs of all files referenced by a task
+///
+/// Walks the parts of every history message, then every artifact, in order.
+/// Files sent inline as bytes are skipped.
+pub fn collect_file_uris(task: &Task) -> Vec<String> {
+    let history_parts = task.history.iter().flatten().flat_map(|message| &message.parts);
+    let artifact_parts = task.artifacts.iter().flatten().flat_map(|artifact| &artifact.parts);
+
+    history_parts
+        .chain(artifact_parts)
+        .filter_map(|part| match part.root() {
+            PartRoot::File(file_part) => match &file_part.file {
+                FileContent::Uri(file) => Some(file.uri.clone()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -265,4 +333,99 @@ mod tests {
         assert!(limited_task.history.is_some());
         assert_eq!(limited_task.history.as_ref().unwrap().len(), 2);
     }
+
+    #[test]
+    fn test_collect_file_uris() {
+        let url = |s: &str| url::Url::parse(s).unwrap();
+        let history = vec![
+            Message::new(
+                Role::User,
+                vec![
+                    Part::text("See attached".to_string()),
+                    Part::file_uri(url("https://example.com/input.pdf")),
+                    Part::file_bytes("aGVsbG8=".to_string()),
+                ],
+            ),
+            Message::new(Role::Agent, vec![Part::file_uri(url("https://example.com/draft.txt"))]),
+        ];
+        let artifacts = vec![
+            Artifact::new(vec![Part::file_bytes("d29ybGQ=".to_string())]),
+            Artifact::new(vec![
+                Part::file_uri(url("https://example.com/report.pdf")),
+                Part::text("Summary".to_string()),
+            ]),
+        ];
+
+        let task = Task::new(
+            "ctx-123".to_string(),
+            TaskStatus::new(TaskState::Completed),
+        )
+            .with_history(history)
+            .with_artifacts(artifacts);
+
+        assert_eq!(
+            collect_file_uris(&task),
+            vec![
+                "https://example.com/input.pdf",
+                "https://example.com/draft.txt",
+                "https://example.com/report.pdf",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_file_uris_without_files() {
+        let task = Task::new(
+            "ctx-123".to_string(),
+            TaskStatus::new(TaskState::Working),
+        );
+
+        assert!(collect_file_uris(&task).is_empty());
+    }
+
+    #[test]
+    fn test_diff_tasks_working_to_completed() {
+        let request = Message::new(Role::User, vec![Part::text("Summarize".to_string())]);
+        let draft = Artifact::new(vec![Part::text("Draft".to_string())]);
+        let working = Task::new(
+            "ctx-123".to_string(),
+            TaskStatus::new(TaskState::Working),
+        )
+            .with_history(vec![request])
+            .with_artifacts(vec![draft]);
+
+        let summary = Artifact::new(vec![Part::text("Summary".to_string())]);
+        let mut completed = working.clone();
+        completed.status = TaskStatus::new(TaskState::Completed);
+        completed.artifacts.as_mut().unwrap().push(summary.clone());
+
+        let diff = diff_tasks(&working, &completed);
+
+        assert_eq!(diff.state_change, Some((TaskState::Working, TaskState::Completed)));
+        assert_eq!(diff.new_artifacts, vec![summary]);
+        assert!(diff.new_history.is_empty());
+        assert!(!diff.status_message_changed);
+    }
+
+    #[test]
+    fn test_diff_tasks_history_and_status_message() {
+        let old = Task::new(
+            "ctx-123".to_string(),
+            TaskStatus::new(TaskState::Working),
+        )
+            .with_history(vec![Message::new(Role::User, vec![Part::text("1".to_string())])]);
+
+        let reply = Message::new(Role::Agent, vec![Part::text("2".to_string())]);
+        let mut new = old.clone();
+        new.history.as_mut().unwrap().push(reply.clone());
+        new.status.message = Some(Box::new(reply.clone()));
+
+        let diff = diff_tasks(&old, &new);
+
+        assert_eq!(diff.state_change, None);
+        assert!(diff.new_artifacts.is_empty());
+        assert_eq!(diff.new_history, vec![reply]);
+        assert!(diff.status_message_changed);
+        assert!(diff_tasks(&new, &new).is_empty());
+    }
 }
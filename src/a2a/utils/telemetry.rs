@@ -0,0 +1,102 @@
+//! Distributed tracing helpers
+//!
+//! This module propagates W3C Trace Context (`traceparent`) headers across
+//! the client/agent boundary using the `tracing-opentelemetry` span
+//! extensions. Spans only carry trace ids when an `OpenTelemetryLayer` is part
+//! of the installed subscriber; otherwise nothing is injected and extracted
+//! contexts are ignored.
+
+use axum::http::HeaderMap;
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry::Context;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use std::collections::HashMap;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Header carrying the W3C trace context
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+
+/// Returns the `traceparent` header value for the current span
+///
+/// Returns `None` when the current span is not part of a valid trace.
+pub fn current_traceparent() -> Option<String> {
+    let mut carrier = HashMap::new();
+    TraceContextPropagator::new().inject_context(&Span::current().context(), &mut carrier);
+    carrier.remove(TRACEPARENT_HEADER)
+}
+
+/// Extracts the caller's trace context from incoming request headers
+pub fn extract_trace_context(headers: &HeaderMap) -> Context {
+    TraceContextPropagator::new().extract(&HeaderExtractor(headers))
+}
+
+/// Creates the span for an incoming request, continuing the caller's trace
+pub fn request_span(method: &str, headers: &HeaderMap) -> Span {
+    let span = tracing::info_span!("a2a.request", method = %method, otel.kind = "server");
+    span.set_parent(extract_trace_context(headers));
+    span
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+    use opentelemetry_sdk::trace::TracerProvider;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    const REMOTE_TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+    const REMOTE_SPAN_ID: &str = "00f067aa0ba902b7";
+
+    fn otel_subscriber() -> impl tracing::Subscriber + Send + Sync {
+        let tracer = TracerProvider::builder().build().tracer("a2a-test");
+        tracing_subscriber::registry().with(tracing_opentelemetry::layer().with_tracer(tracer))
+    }
+
+    #[test]
+    fn test_request_span_continues_remote_trace() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            TRACEPARENT_HEADER,
+            HeaderValue::from_str(&format!("00-{}-{}-01", REMOTE_TRACE_ID, REMOTE_SPAN_ID)).unwrap(),
+        );
+
+        tracing::subscriber::with_default(otel_subscriber(), || {
+            let span = request_span("message/send", &headers);
+            let context = span.context();
+            let span_context = context.span().span_context().clone();
+
+            assert_eq!(span_context.trace_id().to_string(), REMOTE_TRACE_ID);
+            assert_ne!(span_context.span_id().to_string(), REMOTE_SPAN_ID);
+            assert!(span_context.is_sampled());
+
+            let traceparent = span.in_scope(current_traceparent).unwrap();
+            assert!(traceparent.starts_with(&format!("00-{}-", REMOTE_TRACE_ID)));
+        });
+    }
+
+    #[test]
+    fn test_no_traceparent_without_trace() {
+        assert_eq!(current_traceparent(), None);
+
+        tracing::subscriber::with_default(otel_subscriber(), || {
+            let span = request_span("message/send", &HeaderMap::new());
+            let context = span.context();
+            // Without an incoming header the request starts a new trace
+            assert!(context.span().span_context().is_valid());
+        });
+    }
+}
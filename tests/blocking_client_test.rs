@@ -0,0 +1,98 @@
+//! Integration tests for the synchronous client wrapper
+//!
+//! Each test runs the same calls through `BlockingClient` and through the
+//! async client it wraps, against an in-process transport.
+
+#![cfg(feature = "blocking")]
+
+use a2a_rust::a2a::{
+    client::client_trait::{BaseClient, Client, SendOutcome},
+    client::{BlockingClient, ClientConfig},
+    client::transports::in_process::InProcessTransport,
+    core_types::{Message, Part, Role, TaskState},
+    models::*,
+    server::{
+        agent_execution::agent_executor::EchoAgentExecutor,
+        id_generator::SequentialIDGenerator,
+        request_handlers::{DefaultRequestHandler, RequestHandler},
+        tasks::InMemoryTaskStore,
+    },
+    utils::message::get_message_text,
+};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+fn test_card() -> AgentCard {
+    let mut card = AgentCard::test_default("Blocking Agent", "in-process://blocking");
+    card.capabilities = AgentCapabilities::new().with_streaming(true);
+    card
+}
+
+fn base_client(handler: Arc<dyn RequestHandler>, streaming: bool) -> BaseClient {
+    let card = test_card();
+    let transport = InProcessTransport::new(handler, card.clone());
+    BaseClient::new(card, ClientConfig::new().with_streaming(streaming), Box::new(transport), vec![], vec![])
+}
+
+fn user_message(text: &str) -> Message {
+    Message::new(Role::User, vec![Part::text(text.to_string())])
+}
+
+fn reply_text(outcome: SendOutcome) -> String {
+    match outcome {
+        SendOutcome::Message(reply) => get_message_text(&reply, ""),
+        other => panic!("Expected a message outcome, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_blocking_send_message_matches_async_client() {
+    let handler: Arc<dyn RequestHandler> = Arc::new(
+        DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+            .with_agent_executor(Arc::new(EchoAgentExecutor::new())),
+    );
+    let async_client = base_client(handler.clone(), false);
+    let blocking = BlockingClient::new(Box::new(base_client(handler.clone(), false))).unwrap();
+    let streaming = BlockingClient::new(Box::new(base_client(handler, true))).unwrap();
+
+    let expected = Runtime::new()
+        .unwrap()
+        .block_on(async_client.send_message_sync(user_message("hello"), None, None, None))
+        .unwrap();
+    let expected = reply_text(expected);
+
+    assert_eq!(reply_text(blocking.send_message(user_message("hello")).unwrap()), expected);
+    assert_eq!(reply_text(streaming.send_message(user_message("hello")).unwrap()), expected);
+}
+
+#[test]
+fn test_blocking_task_calls_match_async_client() {
+    let handler: Arc<dyn RequestHandler> = Arc::new(
+        DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+            .with_id_generators(Arc::new(SequentialIDGenerator::new()), Arc::new(SequentialIDGenerator::new())),
+    );
+    let async_client = base_client(handler.clone(), false);
+    let blocking = BlockingClient::new(Box::new(base_client(handler, false))).unwrap();
+    let runtime = Runtime::new().unwrap();
+
+    let task = match blocking.send_message(user_message("track this")).unwrap() {
+        SendOutcome::Task(task) => task,
+        other => panic!("Expected a task outcome, got {:?}", other),
+    };
+    assert_eq!(task.id, "1");
+    assert_eq!(task.status.state, TaskState::Working);
+
+    let query = TaskQueryParams::new(task.id.to_string());
+    let from_blocking = blocking.get_task(query.clone()).unwrap();
+    let from_async = runtime.block_on(async_client.get_task(query.clone(), None, None)).unwrap();
+    assert_eq!(serde_json::to_value(&from_blocking).unwrap(), serde_json::to_value(&from_async).unwrap());
+
+    let canceled = blocking.cancel_task(TaskIdParams::new(task.id.to_string())).unwrap();
+    assert_eq!(canceled.status.state, TaskState::Canceled);
+    let from_async = runtime.block_on(async_client.get_task(query, None, None)).unwrap();
+    assert_eq!(serde_json::to_value(&canceled).unwrap(), serde_json::to_value(&from_async).unwrap());
+
+    let card = blocking.get_card().unwrap();
+    let expected = runtime.block_on(async_client.get_card(None, None)).unwrap();
+    assert_eq!(serde_json::to_value(&card).unwrap(), serde_json::to_value(&expected).unwrap());
+}
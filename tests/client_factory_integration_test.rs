@@ -7,7 +7,8 @@
 
 use a2a_rust::a2a::client::factory::{ClientFactory, minimal_agent_card};
 use a2a_rust::a2a::client::config::ClientConfig;
-use a2a_rust::a2a::client::client_trait::{ClientCallInterceptor, ClientCallContext};
+use a2a_rust::a2a::client::client_trait::{BaseClient, Client, ClientCallInterceptor, ClientCallContext, ClientTransport};
+use a2a_rust::a2a::client::transports::jsonrpc::JsonRpcTransport;
 use a2a_rust::a2a::client::auth::{AuthInterceptor, InMemoryContextCredentialStore};
 use a2a_rust::a2a::models::*;
 use a2a_rust::a2a::core_types::*;
@@ -96,17 +97,18 @@ impl a2a_rust::a2a::client::client_trait::ClientTransport for MockTransport {
                 extensions: None,
                 reference_task_ids: None,
                 kind: "message".to_string(),
+                part_form: None,
             };
             Ok(TaskOrMessage::Message(message))
         }
     }
     
-    async fn send_message_streaming(
-        &self,
+    async fn send_message_streaming<'a>(
+        &'a self,
         _params: MessageSendParams,
         _context: Option<&ClientCallContext>,
         _extensions: Option<Vec<String>>,
-    ) -> Result<Pin<Box<dyn futures::Stream<Item = Result<TaskOrMessage, A2AError>> + Send>>, A2AError> {
+    ) -> Result<Pin<Box<dyn futures::Stream<Item = Result<TaskOrMessage, A2AError>> + Send + 'a>>, A2AError> {
         Err(A2AError::unsupported_operation("Streaming not supported in mock"))
     }
     
@@ -155,12 +157,12 @@ impl a2a_rust::a2a::client::client_trait::ClientTransport for MockTransport {
         Err(A2AError::unsupported_operation("Task callbacks not supported in mock"))
     }
     
-    async fn resubscribe(
-        &self,
+    async fn resubscribe<'a>(
+        &'a self,
         _request: TaskIdParams,
         _context: Option<&ClientCallContext>,
         _extensions: Option<Vec<String>>,
-    ) -> Result<Pin<Box<dyn futures::Stream<Item = Result<a2a_rust::a2a::client::client_trait::ClientEvent, A2AError>> + Send>>, A2AError> {
+    ) -> Result<Pin<Box<dyn futures::Stream<Item = Result<a2a_rust::a2a::client::client_trait::ClientEvent, A2AError>> + Send + 'a>>, A2AError> {
         Err(A2AError::unsupported_operation("Resubscription not supported in mock"))
     }
     
@@ -465,3 +467,986 @@ fn test_error_handling_in_factory() {
         }
     }
 }
+
+/// Builds a client for an agent that protects its extended card with a bearer token
+fn extended_card_client(url: String, token: Option<&str>, supports_extended_card: bool) -> BaseClient {
+    let mut security_schemes = HashMap::new();
+    security_schemes.insert(
+        "bearerAuth".to_string(),
+        SecurityScheme::HTTPAuth(HTTPAuthSecurityScheme {
+            scheme: "bearer".to_string(),
+            bearer_format: None,
+            description: None,
+        }),
+    );
+
+    let card = AgentCard::new(
+        "Test Agent".to_string(),
+        "Test agent".to_string(),
+        url.clone(),
+        "1.0.0".to_string(),
+        vec![],
+        vec![],
+        AgentCapabilities::new(),
+        vec![],
+    )
+    .with_security_schemes(security_schemes)
+    .with_security(vec![HashMap::from([("bearerAuth".to_string(), vec![])])])
+    .with_supports_authenticated_extended_card(supports_extended_card);
+
+    let mut store = InMemoryContextCredentialStore::new();
+    if let Some(token) = token {
+        store.add_credential("bearerAuth", token);
+    }
+
+    let transport = JsonRpcTransport::new(url, Some(card.clone()))
+        .unwrap()
+        .with_interceptors(vec![Box::new(AuthInterceptor::new(Arc::new(store)))]);
+
+    BaseClient::new(card, ClientConfig::new(), Box::new(transport), vec![], vec![])
+}
+
+#[tokio::test]
+async fn test_get_authenticated_extended_card() {
+    let mut server = mockito::Server::new_async().await;
+
+    let extended_card = AgentCard::new(
+        "Test Agent (extended)".to_string(),
+        "Extended card".to_string(),
+        server.url(),
+        "1.0.0".to_string(),
+        vec![],
+        vec![],
+        AgentCapabilities::new(),
+        vec![],
+    );
+
+    let authorized = server
+        .mock("POST", "/")
+        .match_header("authorization", "Bearer secret-token")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "method": "agent/getAuthenticatedExtendedCard"
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "result": &extended_card}).to_string())
+        .create_async()
+        .await;
+    let unauthorized = server
+        .mock("POST", "/")
+        .match_header("authorization", mockito::Matcher::Missing)
+        .with_status(401)
+        .create_async()
+        .await;
+
+    let client = extended_card_client(server.url(), Some("secret-token"), true);
+    let card = client.get_authenticated_extended_card(None).await.unwrap();
+    assert_eq!(card.name, "Test Agent (extended)");
+    authorized.assert_async().await;
+
+    let anonymous = extended_card_client(server.url(), None, true);
+    assert!(anonymous.get_authenticated_extended_card(None).await.is_err());
+    unauthorized.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_get_authenticated_extended_card_not_configured() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": "1",
+                "error": {"code": -32007, "message": "Authenticated Extended Card is not configured"}
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    // The card advertises the extended card but the agent has none configured
+    let client = extended_card_client(server.url(), Some("secret-token"), true);
+    let error = client.get_authenticated_extended_card(None).await.unwrap_err();
+    assert!(matches!(error, A2AError::AuthenticatedExtendedCardNotConfigured(_)));
+    mock.assert_async().await;
+
+    // The card does not advertise an extended card, so no request is made
+    let client = extended_card_client(server.url(), Some("secret-token"), false);
+    let error = client.get_authenticated_extended_card(None).await.unwrap_err();
+    assert!(matches!(error, A2AError::AuthenticatedExtendedCardNotConfigured(_)));
+}
+
+/// Returns a local URL on which nothing is listening
+async fn refused_url() -> String {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+    format!("http://{}", addr)
+}
+
+/// Builds a card whose preferred endpoint is `primary` with `fallback` as an additional interface
+fn card_with_fallback(primary: String, fallback: String) -> AgentCard {
+    AgentCard::new(
+        "Test Agent".to_string(),
+        "Test agent".to_string(),
+        primary,
+        "1.0.0".to_string(),
+        vec![],
+        vec![],
+        AgentCapabilities::new(),
+        vec![],
+    )
+    .with_preferred_transport("JSONRPC".to_string())
+    .with_additional_interfaces(vec![AgentInterface::new(fallback, "JSONRPC".to_string())])
+}
+
+#[tokio::test]
+async fn test_client_factory_falls_back_to_reachable_transport() {
+    let mut server = mockito::Server::new_async().await;
+    let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+        .with_task_id("task-1".to_string());
+    let get_task = server
+        .mock("POST", "/")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({"method": "tasks/get"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "result": &task}).to_string())
+        .create_async()
+        .await;
+
+    let card = card_with_fallback(refused_url().await, server.url());
+    let factory = ClientFactory::with_config(ClientConfig::new().with_probe_transports(true));
+    assert_eq!(factory.transport_candidates(&card).len(), 2);
+
+    let client = factory.create(card, None, None, None).await.unwrap();
+    let fetched = client
+        .get_task(TaskQueryParams::new("task-1".to_string()), None, None)
+        .await
+        .unwrap();
+    assert_eq!(fetched.id, "task-1");
+    get_task.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_client_factory_reports_all_failed_transports() {
+    let primary = refused_url().await;
+    let fallback = refused_url().await;
+    let card = card_with_fallback(primary.clone(), fallback.clone());
+
+    let factory = ClientFactory::with_config(ClientConfig::new().with_probe_transports(true));
+    let error = match factory.create(card.clone(), None, None, None).await {
+        Ok(_) => panic!("Expected all transports to fail"),
+        Err(error) => error,
+    };
+    assert!(error.message().contains(&primary));
+    assert!(error.message().contains(&fallback));
+
+    // Without probing the preferred transport is used as is
+    let factory = ClientFactory::with_config(ClientConfig::new());
+    assert!(factory.create(card, None, None, None).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_agent_error_mapped_to_typed_variant() {
+    let mut server = mockito::Server::new_async().await;
+    let mock = server
+        .mock("POST", "/")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "id": "1",
+                "error": {"code": -32001, "message": "Task not found: task-1", "data": {"task_id": "task-1"}}
+            })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+
+    let card = minimal_agent_card(server.url(), None);
+    let transport = JsonRpcTransport::new(server.url(), Some(card)).unwrap();
+    let error = transport
+        .get_task(TaskQueryParams::new("task-1".to_string()), None, None)
+        .await
+        .unwrap_err();
+
+    match error {
+        A2AError::TaskNotFound(e) => {
+            assert_eq!(e.message, "Task not found: task-1");
+            assert_eq!(e.data, Some(serde_json::json!({"task_id": "task-1"})));
+        }
+        other => panic!("Expected TaskNotFound, got {:?}", other),
+    }
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_delete_task_callback_accepts_null_and_empty_results() {
+    let mut server = mockito::Server::new_async().await;
+    let card = minimal_agent_card(server.url(), None);
+    let transport = JsonRpcTransport::new(server.url(), Some(card)).unwrap();
+    let params = DeleteTaskPushNotificationConfigParams {
+        id: "task-1".to_string(),
+        push_notification_config_id: "cfg-1".to_string(),
+        metadata: None,
+    };
+
+    for result in [serde_json::Value::Null, serde_json::json!({}), serde_json::json!({"id": "cfg-1"})] {
+        let mock = server
+            .mock("POST", "/")
+            .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+                "method": "tasks/pushNotificationConfig/delete",
+                "params": {"id": "task-1", "push_notification_config_id": "cfg-1"}
+            })))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "result": result}).to_string())
+            .create_async()
+            .await;
+
+        let deleted = transport.delete_task_callback(params.clone(), None, None).await;
+        if result.as_object().is_some_and(|fields| !fields.is_empty()) {
+            assert!(matches!(deleted, Err(A2AError::InvalidAgentResponse(_))));
+        } else {
+            deleted.unwrap();
+        }
+        mock.remove_async().await;
+    }
+}
+
+#[tokio::test]
+async fn test_streaming_decodes_ndjson_response() {
+    use futures::StreamExt;
+
+    let mut server = mockito::Server::new_async().await;
+    let status = |state: &str, last: bool| {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "1",
+            "result": {
+                "kind": "status-update",
+                "task_id": "task-1",
+                "context_id": "ctx-1",
+                "status": {"state": state},
+                "final": last
+            }
+        })
+        .to_string()
+    };
+    let mock = server
+        .mock("POST", "/")
+        .match_header("accept", mockito::Matcher::Regex("application/x-ndjson".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/x-ndjson")
+        .with_body(format!("{}\n\n{}", status("working", false), status("completed", true)))
+        .create_async()
+        .await;
+
+    let card = minimal_agent_card(server.url(), None);
+    let transport = JsonRpcTransport::new(server.url(), Some(card)).unwrap();
+    let params = MessageSendParams::new(Message::new(Role::User, vec![Part::text("Hello".to_string())]));
+    let events: Vec<TaskOrMessage> = transport
+        .send_message_streaming(params, None, None)
+        .await
+        .unwrap()
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+
+    let states: Vec<TaskState> = events
+        .into_iter()
+        .map(|event| match event {
+            TaskOrMessage::TaskUpdate(update) => update.status.state,
+            other => panic!("Expected a status update, got {:?}", other),
+        })
+        .collect();
+    assert_eq!(states, vec![TaskState::Working, TaskState::Completed]);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_jsonrpc_transport_sends_deadline_header() {
+    let mut server = mockito::Server::new_async().await;
+    let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+        .with_task_id("task-1".to_string());
+    let get_task = server
+        .mock("POST", "/")
+        .match_header("x-a2a-deadline", "5000")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "result": &task}).to_string())
+        .create_async()
+        .await;
+
+    let card = minimal_agent_card(server.url(), None);
+    let transport = JsonRpcTransport::new(server.url(), Some(card))
+        .unwrap()
+        .with_timeout(std::time::Duration::from_secs(5));
+    let fetched = transport
+        .get_task(TaskQueryParams::new("task-1".to_string()), None, None)
+        .await
+        .unwrap();
+    assert_eq!(fetched.id, "task-1");
+    get_task.assert_async().await;
+
+    // The timeout also bounds the HTTP request against an agent that never answers
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move {
+        let mut connections = Vec::new();
+        while let Ok((socket, _)) = listener.accept().await {
+            connections.push(socket);
+        }
+    });
+    let transport = JsonRpcTransport::new(url.clone(), Some(minimal_agent_card(url, None)))
+        .unwrap()
+        .with_timeout(std::time::Duration::from_millis(100));
+    let started = std::time::Instant::now();
+    assert!(transport
+        .get_task(TaskQueryParams::new("task-1".to_string()), None, None)
+        .await
+        .is_err());
+    assert!(started.elapsed() < std::time::Duration::from_secs(5), "timed out after {:?}", started.elapsed());
+}
+
+#[tokio::test]
+async fn test_client_factory_applies_transport_specific_headers() {
+    let mut server = mockito::Server::new_async().await;
+    let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+        .with_task_id("task-1".to_string());
+    let get_task = server
+        .mock("POST", "/")
+        .match_header("x-global", "1")
+        .match_header("x-tenant", "jsonrpc")
+        .match_header("x-grpc-only", mockito::Matcher::Missing)
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "result": &task}).to_string())
+        .create_async()
+        .await;
+
+    let config = ClientConfig::new()
+        .with_header("X-Global", "1")
+        .with_header("X-Tenant", "global")
+        .with_transport_header(TransportProtocol::Jsonrpc, "X-Tenant", "jsonrpc")
+        .with_transport_header(TransportProtocol::Grpc, "X-Grpc-Only", "1");
+    let factory = ClientFactory::with_config(config);
+    let client = factory.create(minimal_agent_card(server.url(), None), None, None, None).await.unwrap();
+
+    let fetched = client
+        .get_task(TaskQueryParams::new("task-1".to_string()), None, None)
+        .await
+        .unwrap();
+    assert_eq!(fetched.id, "task-1");
+    get_task.assert_async().await;
+}
+
+fn card_requiring_extension(url: String, uri: &str) -> AgentCard {
+    let mut card = minimal_agent_card(url, None);
+    card.capabilities = AgentCapabilities::new().with_extensions(vec![
+        AgentExtension::new(uri.to_string()).with_required(true),
+        AgentExtension::new("ext:optional".to_string()),
+    ]);
+    card
+}
+
+#[tokio::test]
+async fn test_client_factory_requests_supported_required_extension() {
+    let mut server = mockito::Server::new_async().await;
+    let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+        .with_task_id("task-1".to_string());
+    let get_task = server
+        .mock("POST", "/")
+        .match_header("a2a-extensions", "ext:tracing,ext:required")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "result": &task}).to_string())
+        .create_async()
+        .await;
+
+    let config = ClientConfig::new()
+        .with_extensions(vec!["ext:tracing".to_string()])
+        .with_supported_extensions(vec!["ext:required".to_string(), "ext:optional".to_string()]);
+    let factory = ClientFactory::with_config(config);
+    let card = card_requiring_extension(server.url(), "ext:required");
+    let client = factory.create(card, None, None, None).await.unwrap();
+
+    client.get_task(TaskQueryParams::new("task-1".to_string()), None, None).await.unwrap();
+    get_task.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_client_factory_fails_fast_on_unsupported_required_extension() {
+    let mut server = mockito::Server::new_async().await;
+    let untouched = server.mock("POST", "/").expect(0).create_async().await;
+
+    let factory = ClientFactory::with_config(ClientConfig::new().with_supported_extensions(vec!["ext:other".to_string()]));
+    let card = card_requiring_extension(server.url(), "ext:required");
+    match factory.create(card, None, None, None).await {
+        Err(A2AError::UnsupportedOperation(e)) => assert!(e.message.contains("ext:required")),
+        Err(e) => panic!("Expected UnsupportedOperation, got {:?}", e),
+        Ok(_) => panic!("Expected the required extension to be rejected"),
+    }
+    untouched.assert_async().await;
+}
+
+async fn mock_send_with_output_modes(server: &mut mockito::ServerGuard, modes: &[&str]) -> mockito::Mock {
+    let reply = Message::new(Role::Agent, vec![Part::text("ok".to_string())]);
+    server
+        .mock("POST", "/")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "method": "message/send",
+            "params": {"configuration": {"accepted_output_modes": modes}}
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "result": &reply}).to_string())
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_client_sends_default_accepted_output_modes() {
+    use a2a_rust::a2a::client::config::DEFAULT_ACCEPTED_OUTPUT_MODES;
+
+    let mut server = mockito::Server::new_async().await;
+    let send = mock_send_with_output_modes(&mut server, DEFAULT_ACCEPTED_OUTPUT_MODES).await;
+
+    let config = ClientConfig::new().with_streaming(false).with_polling(true).with_default_accepted_output_modes();
+    let client = ClientFactory::with_config(config)
+        .create(minimal_agent_card(server.url(), None), None, None, None)
+        .await
+        .unwrap();
+    let message = Message::new(Role::User, vec![Part::text("hello".to_string())]);
+    client.send_message_sync(message, None, None, None).await.unwrap();
+    send.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_per_message_output_modes_override_client_default() {
+    use a2a_rust::a2a::client::config::MessageSendConfiguration;
+
+    let mut server = mockito::Server::new_async().await;
+    let send = mock_send_with_output_modes(&mut server, &["image/png"]).await;
+
+    let config = ClientConfig::new().with_streaming(false).with_polling(true).with_default_accepted_output_modes();
+    let client = ClientFactory::with_config(config)
+        .create(minimal_agent_card(server.url(), None), None, None, None)
+        .await
+        .unwrap();
+    let context = ClientCallContext::new().with_send_configuration(
+        MessageSendConfiguration::new().with_accepted_output_modes(vec!["image/png".to_string()]),
+    );
+    let message = Message::new(Role::User, vec![Part::text("draw".to_string())]);
+    client.send_message_sync(message, Some(&context), None, None).await.unwrap();
+    send.assert_async().await;
+}
+
+async fn mock_get_task(server: &mut mockito::ServerGuard, id: &str, state: TaskState, hits: usize) -> mockito::Mock {
+    let task = Task::new("ctx-1".to_string(), TaskStatus::new(state)).with_task_id(id.to_string());
+    server
+        .mock("POST", "/")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({"method": "tasks/get", "params": {"id": id}})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "result": &task}).to_string())
+        .expect(hits)
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_get_task_caches_only_terminal_tasks() {
+    let mut server = mockito::Server::new_async().await;
+    let completed = mock_get_task(&mut server, "done", TaskState::Completed, 3).await;
+    let working = mock_get_task(&mut server, "running", TaskState::Working, 2).await;
+    let cancel = server
+        .mock("POST", "/")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({"method": "tasks/cancel"})))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "error": {"code": -32002, "message": "Task cannot be canceled"}}).to_string())
+        .create_async()
+        .await;
+
+    let config = ClientConfig::new().with_streaming(false).with_polling(true).with_task_cache_size(16);
+    let client = ClientFactory::with_config(config)
+        .create(minimal_agent_card(server.url(), None), None, None, None)
+        .await
+        .unwrap();
+    for _ in 0..2 {
+        let task = client.get_task(TaskQueryParams::new("done".to_string()), None, None).await.unwrap();
+        assert_eq!(task.status.state, TaskState::Completed);
+        let task = client.get_task(TaskQueryParams::new("running".to_string()), None, None).await.unwrap();
+        assert_eq!(task.status.state, TaskState::Working);
+    }
+
+    // Only the first query for the completed task went out; bypassing the
+    // cache fetches it again
+    let bypass = ClientCallContext::new().with_task_cache_bypass();
+    client.get_task(TaskQueryParams::new("done".to_string()), Some(&bypass), None).await.unwrap();
+
+    // Canceling a task drops it from the cache
+    assert!(client.cancel_task(TaskIdParams::new("done".to_string()), None, None).await.is_err());
+    client.get_task(TaskQueryParams::new("done".to_string()), None, None).await.unwrap();
+    client.get_task(TaskQueryParams::new("done".to_string()), None, None).await.unwrap();
+    completed.assert_async().await;
+    working.assert_async().await;
+    cancel.assert_async().await;
+}
+
+fn file_message(mime_type: &str) -> Message {
+    let file = FilePart {
+        file: FileContent::Uri(FileWithUri {
+            uri: "https://example.com/upload".to_string(),
+            mime_type: Some(mime_type.to_string()),
+            name: None,
+        }),
+        kind: "file".to_string(),
+        metadata: None,
+    };
+    Message::new(Role::User, vec![Part::Direct(PartRoot::File(file))])
+}
+
+#[tokio::test]
+async fn test_precheck_message_rejects_unsupported_input_mode_before_sending() {
+    use futures::StreamExt;
+    use a2a_rust::a2a::jsonrpc::error_codes::CONTENT_TYPE_NOT_SUPPORTED;
+
+    let mut server = mockito::Server::new_async().await;
+    let reply = Message::new(Role::Agent, vec![Part::text("ok".to_string())]);
+    let mock = server
+        .mock("POST", "/")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "result": &reply}).to_string())
+        .expect(2)
+        .create_async()
+        .await;
+
+    let mut card = AgentCard::test_default("Test Agent", &server.url());
+    card.default_input_modes = vec!["text/plain".to_string(), "image/*".to_string()];
+    let client = |config: ClientConfig| {
+        let transport = JsonRpcTransport::new(server.url(), Some(card.clone())).unwrap();
+        BaseClient::new(card.clone(), config.with_streaming(false), Box::new(transport), vec![], vec![])
+    };
+
+    let checked = client(ClientConfig::new().with_precheck_messages(true));
+    assert_eq!(checked.precheck_message(&file_message("video/mp4")).unwrap_err().code(), CONTENT_TYPE_NOT_SUPPORTED);
+    let mut events = checked.send_message(file_message("video/mp4"), None, None, None).await;
+    assert_eq!(events.next().await.unwrap().unwrap_err().code(), CONTENT_TYPE_NOT_SUPPORTED);
+    assert!(events.next().await.is_none());
+    drop(events);
+
+    assert!(checked.precheck_message(&file_message("image/png")).is_ok());
+    let mut events = checked.send_message(file_message("image/png"), None, None, None).await;
+    assert!(events.next().await.unwrap().is_ok());
+    drop(events);
+
+    let unchecked = client(ClientConfig::new());
+    let mut events = unchecked.send_message(file_message("video/mp4"), None, None, None).await;
+    assert!(events.next().await.unwrap().is_ok());
+    drop(events);
+
+    mock.assert_async().await;
+}
+
+/// Log writer collecting output in memory
+#[derive(Clone, Default)]
+struct LogBuffer(Arc<std::sync::Mutex<Vec<u8>>>);
+
+impl std::io::Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> tracing_subscriber::fmt::MakeWriter<'a> for LogBuffer {
+    type Writer = LogBuffer;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+#[test]
+fn test_agent_card_interfaces() {
+    use a2a_rust::a2a::utils::logging::{build_subscriber, LogFormat};
+
+    let card = AgentCard::test_default("Multi Agent", "https://agent.example.com/a2a")
+        .with_preferred_transport("JSONRPC".to_string())
+        .with_additional_interfaces(vec![
+            AgentInterface::new("https://agent.example.com/rest".to_string(), "HTTP_JSON".to_string()),
+            AgentInterface::new("https://grpc.example.com:443".to_string(), "GRPC".to_string()),
+            AgentInterface::new("not a url".to_string(), "HTTP_JSON".to_string()),
+        ]);
+
+    let logs = LogBuffer::default();
+    let subscriber = build_subscriber(LogFormat::Json, tracing::Level::WARN, logs.clone());
+    let interfaces = tracing::subscriber::with_default(subscriber, || card.interfaces());
+
+    let interfaces: Vec<(TransportProtocol, String)> = interfaces
+        .into_iter()
+        .map(|(transport, url)| (transport, url.to_string()))
+        .collect();
+    assert_eq!(
+        interfaces,
+        vec![
+            (TransportProtocol::Jsonrpc, "https://agent.example.com/a2a".to_string()),
+            (TransportProtocol::HttpJson, "https://agent.example.com/rest".to_string()),
+            (TransportProtocol::Grpc, "https://grpc.example.com/".to_string()),
+        ]
+    );
+
+    let output = String::from_utf8(logs.0.lock().unwrap().clone()).unwrap();
+    assert_eq!(output.lines().count(), 1, "unexpected logs: {}", output);
+    assert!(output.contains("WARN") && output.contains("not a url"), "unexpected logs: {}", output);
+
+    // Transport selection only offers the well-formed interfaces
+    let factory = ClientFactory::with_config(
+        ClientConfig::new().with_supported_transports(vec![TransportProtocol::HttpJson, TransportProtocol::Grpc]),
+    );
+    assert_eq!(
+        factory.transport_candidates(&card),
+        vec![
+            (TransportProtocol::HttpJson, "https://agent.example.com/rest".to_string()),
+            (TransportProtocol::Grpc, "https://grpc.example.com:443".to_string()),
+        ]
+    );
+}
+
+/// Accepts one connection, reads the request head and never answers
+///
+/// Resolves `received` once the request has arrived, then reports whether
+/// the client closed the connection within `window`.
+async fn silent_server(
+    listener: tokio::net::TcpListener,
+    received: tokio::sync::oneshot::Sender<()>,
+    window: std::time::Duration,
+) -> bool {
+    use tokio::io::AsyncReadExt;
+
+    let (mut socket, _) = listener.accept().await.unwrap();
+    let mut request = Vec::new();
+    let mut buf = [0u8; 4096];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+        let n = socket.read(&mut buf).await.unwrap();
+        assert!(n > 0, "client closed before sending a request");
+        request.extend_from_slice(&buf[..n]);
+    }
+    received.send(()).unwrap();
+
+    // Drain the body; a read of zero bytes means the client hung up
+    let closed = async {
+        loop {
+            match socket.read(&mut buf).await {
+                Ok(0) | Err(_) => return,
+                Ok(_) => continue,
+            }
+        }
+    };
+    tokio::time::timeout(window, closed).await.is_ok()
+}
+
+#[tokio::test]
+async fn test_dropping_request_future_closes_connection() {
+    for streaming in [false, true] {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (received_tx, received_rx) = tokio::sync::oneshot::channel();
+        let server = tokio::spawn(silent_server(listener, received_tx, std::time::Duration::from_secs(2)));
+
+        let transport = JsonRpcTransport::new(url.clone(), Some(minimal_agent_card(url, None))).unwrap();
+        let request: Pin<Box<dyn std::future::Future<Output = ()> + Send + '_>> = if streaming {
+            let params = MessageSendParams::new(Message::new(Role::User, vec![Part::text("hi".to_string())]));
+            Box::pin(async {
+                let _ = transport.send_message_streaming(params, None, None).await;
+            })
+        } else {
+            Box::pin(async {
+                let _ = transport.get_task(TaskQueryParams::new("task-1".to_string()), None, None).await;
+            })
+        };
+
+        // Drive the request until the server has it, then drop the future
+        tokio::select! {
+            _ = request => panic!("Expected the request to hang (streaming: {})", streaming),
+            _ = received_rx => {}
+        }
+
+        assert!(
+            server.await.unwrap(),
+            "connection was not closed after the request future was dropped (streaming: {})",
+            streaming
+        );
+    }
+}
+
+/// Serves `card` at the well-known agent card path
+async fn serve_card(server: &mut mockito::ServerGuard, card: &AgentCard) -> mockito::Mock {
+    server
+        .mock("GET", "/.well-known/agent-card.json")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(card).unwrap())
+        .create_async()
+        .await
+}
+
+#[tokio::test]
+async fn test_card_resolver_verifies_signatures() {
+    use a2a_rust::a2a::client::card_resolver::A2ACardResolver;
+    use a2a_rust::a2a::utils::signing::{sign_agent_card_ed25519, JwkSet};
+
+    let mut server = mockito::Server::new_async().await;
+    let card = AgentCard::test_default("Signed Agent", &server.url());
+    let (signed, key) = sign_agent_card_ed25519(&card, "card-key", &[42u8; 32]).unwrap();
+    let trusted = JwkSet::new(vec![key]);
+
+    let mock = serve_card(&mut server, &signed).await;
+    let resolved = A2ACardResolver::new(server.url())
+        .with_verification(trusted.clone())
+        .get_agent_card()
+        .await
+        .unwrap();
+    assert_eq!(resolved, signed);
+    mock.remove_async().await;
+
+    let mut tampered = signed.clone();
+    tampered.url = "http://attacker.example.com".to_string();
+    let mock = serve_card(&mut server, &tampered).await;
+    let error = A2ACardResolver::new(server.url())
+        .with_verification(trusted.clone())
+        .get_agent_card()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, A2AError::InvalidAgentResponse(_)), "unexpected error: {:?}", error);
+
+    // Without verification any card is accepted
+    let resolved = A2ACardResolver::new(server.url()).get_agent_card().await.unwrap();
+    assert_eq!(resolved.url, "http://attacker.example.com");
+    mock.remove_async().await;
+
+    let mock = serve_card(&mut server, &card).await;
+    let error = A2ACardResolver::new(server.url())
+        .with_verification(trusted)
+        .get_agent_card_with_path(None, None)
+        .await
+        .unwrap_err();
+    assert!(error.message().contains("not signed"));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_card_resolver_accepts_yaml_cards() {
+    use a2a_rust::a2a::client::card_resolver::A2ACardResolver;
+
+    let mut server = mockito::Server::new_async().await;
+    let card = AgentCard::test_default("YAML Agent", &server.url());
+    let mock = server
+        .mock("GET", "/.well-known/agent-card.json")
+        .match_header("accept", mockito::Matcher::Regex("^application/json".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/yaml")
+        .with_body(serde_yaml_ng::to_string(&card).unwrap())
+        .create_async()
+        .await;
+
+    let resolved = A2ACardResolver::new(server.url()).get_agent_card().await.unwrap();
+    assert_eq!(resolved, card);
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_card_resolver_times_out_on_silent_server() {
+    use a2a_rust::a2a::client::card_resolver::A2ACardResolver;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (received_tx, _received_rx) = tokio::sync::oneshot::channel();
+    let server = tokio::spawn(silent_server(listener, received_tx, std::time::Duration::from_secs(5)));
+
+    let resolver = A2ACardResolver::new(url).with_timeout(std::time::Duration::from_millis(200));
+    let error = tokio::time::timeout(std::time::Duration::from_secs(5), resolver.get_agent_card())
+        .await
+        .expect("card resolution should time out rather than hang")
+        .unwrap_err();
+    assert!(error.message().contains("timed out"), "unexpected error: {:?}", error);
+    assert!(server.await.unwrap(), "connection should be closed after the timeout");
+}
+
+#[tokio::test]
+async fn test_card_watcher_yields_only_changed_cards() {
+    use a2a_rust::a2a::client::card_resolver::A2ACardResolver;
+    use futures::StreamExt;
+    use std::time::Duration;
+
+    let mut server = mockito::Server::new_async().await;
+    let card_path = "/.well-known/agent-card.json";
+    let v1 = AgentCard::test_default("Watched Agent", &server.url());
+    let mut v2 = v1.clone();
+    v2.version = "2.0.0".to_string();
+    let serve = |card: &AgentCard, etag: &str| {
+        (serde_json::to_string(card).unwrap(), format!("\"{}\"", etag))
+    };
+
+    let (body, etag) = serve(&v1, "v1");
+    let _initial = server
+        .mock("GET", card_path)
+        .match_header("if-none-match", mockito::Matcher::Missing)
+        .with_header("content-type", "application/json")
+        .with_header("etag", &etag)
+        .with_body(body)
+        .create_async()
+        .await;
+    let unchanged = server
+        .mock("GET", card_path)
+        .match_header("if-none-match", etag.as_str())
+        .with_status(304)
+        .expect_at_least(1)
+        .create_async()
+        .await;
+
+    let mut watcher = Box::pin(A2ACardResolver::new(server.url()).watch(Duration::from_millis(50)));
+    let first = tokio::time::timeout(Duration::from_secs(5), watcher.next()).await.unwrap().unwrap();
+    assert_eq!(first, v1);
+    assert!(tokio::time::timeout(Duration::from_millis(200), watcher.next()).await.is_err());
+    unchanged.assert_async().await;
+
+    // The agent is redeployed with a new version of its card
+    unchanged.remove_async().await;
+    let (body, v2_etag) = serve(&v2, "v2");
+    let _updated = server
+        .mock("GET", card_path)
+        .match_header("if-none-match", etag.as_str())
+        .with_header("content-type", "application/json")
+        .with_header("etag", &v2_etag)
+        .with_body(body)
+        .create_async()
+        .await;
+    let _revalidated = server
+        .mock("GET", card_path)
+        .match_header("if-none-match", v2_etag.as_str())
+        .with_status(304)
+        .create_async()
+        .await;
+
+    let changed = tokio::time::timeout(Duration::from_secs(5), watcher.next()).await.unwrap().unwrap();
+    assert_eq!(changed.version, "2.0.0");
+    assert!(tokio::time::timeout(Duration::from_millis(200), watcher.next()).await.is_err());
+}
+
+/// Creates a client for `card` and returns the factory's error
+async fn create_error(factory: &ClientFactory, card: AgentCard) -> A2AError {
+    match factory.create(card, None, None, None).await {
+        Ok(_) => panic!("Expected transport selection to fail"),
+        Err(error) => error,
+    }
+}
+
+#[tokio::test]
+async fn test_client_factory_downgrades_to_supported_interface() {
+    let mut server = mockito::Server::new_async().await;
+    let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+        .with_task_id("task-1".to_string());
+    let get_task = server
+        .mock("POST", "/")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "result": &task}).to_string())
+        .create_async()
+        .await;
+
+    let card = minimal_agent_card("http://localhost:50051".to_string(), Some(vec!["GRPC".to_string()]))
+        .with_additional_interfaces(vec![AgentInterface::new(server.url(), "JSONRPC".to_string())]);
+    let factory = ClientFactory::with_config(ClientConfig::new());
+    assert_eq!(
+        factory.transport_downgrade(&card),
+        Some((TransportProtocol::Grpc, TransportProtocol::Jsonrpc))
+    );
+    assert_eq!(factory.determine_transport(&card).unwrap(), (TransportProtocol::Jsonrpc, server.url()));
+
+    let client = factory.create(card, None, None, None).await.unwrap();
+    client.get_task(TaskQueryParams::new("task-1".to_string()), None, None).await.unwrap();
+    get_task.assert_async().await;
+
+    // No downgrade when the preferred transport is supported
+    let jsonrpc_card = minimal_agent_card(server.url(), None);
+    assert_eq!(factory.transport_downgrade(&jsonrpc_card), None);
+}
+
+#[tokio::test]
+async fn test_client_factory_without_supported_fallback_fails() {
+    let card = minimal_agent_card("http://localhost:50051".to_string(), Some(vec!["GRPC".to_string(), "HTTP_JSON".to_string()]));
+    let factory = ClientFactory::with_config(ClientConfig::new());
+    assert_eq!(factory.transport_downgrade(&card), None);
+
+    let data = create_error(&factory, card).await.data().cloned().unwrap();
+    assert_eq!(data["reason"], "no_common_transport");
+    assert_eq!(data["candidates"], serde_json::json!(["GRPC", "HTTP_JSON"]));
+}
+
+#[tokio::test]
+async fn test_transport_selection_errors_are_structured() {
+    use a2a_rust::a2a::client::errors::TransportSelectionError;
+
+    let factory = ClientFactory::with_config(ClientConfig::new().with_probe_transports(true));
+
+    let grpc_only = minimal_agent_card("http://localhost:50051".to_string(), Some(vec!["GRPC".to_string()]));
+    let error = create_error(&factory, grpc_only.clone()).await;
+    assert_eq!(error.code(), -32603);
+    let data = error.data().unwrap();
+    assert_eq!(data["reason"], "no_common_transport");
+    assert_eq!(data["candidates"], serde_json::json!(["GRPC"]));
+    assert_eq!(data["supported"], serde_json::json!(["JSONRPC"]));
+    assert!(factory.determine_transport(&grpc_only).is_err());
+
+    let bad_url = minimal_agent_card("not a url".to_string(), None);
+    let error = create_error(&factory, bad_url).await;
+    let data = error.data().unwrap();
+    assert_eq!(data["reason"], "invalid_endpoint");
+    assert_eq!(data["candidates"][0]["transport"], "JSONRPC");
+    assert_eq!(data["candidates"][0]["url"], "not a url");
+
+    let primary = refused_url().await;
+    let fallback = refused_url().await;
+    let error = create_error(&factory, card_with_fallback(primary.clone(), fallback.clone())).await;
+    let data = error.data().unwrap();
+    assert_eq!(data["reason"], "all_transports_failed");
+    let attempts = data["candidates"].as_array().unwrap();
+    assert_eq!(attempts.len(), 2);
+    assert!(attempts[0].as_str().unwrap().starts_with(&format!("JSONRPC at {}", primary)));
+    assert!(attempts[1].as_str().unwrap().starts_with(&format!("JSONRPC at {}", fallback)));
+
+    let converted = A2AError::from(TransportSelectionError::AllTransportsFailed(vec!["a".to_string()]));
+    assert_eq!(converted.message(), "Transport error: All transports failed: a");
+}
+
+#[tokio::test]
+async fn test_factory_rejects_contradictory_config() {
+    let invalid = [
+        ClientConfig::new().with_streaming(false),
+        ClientConfig::new().with_client_preference(true).with_supported_transports(vec![]),
+        ClientConfig::new().with_timeout(std::time::Duration::ZERO),
+    ];
+    for config in invalid {
+        let error = match ClientFactory::with_config(config)
+            .create(minimal_agent_card("http://localhost:8080".to_string(), None), None, None, None)
+            .await
+        {
+            Ok(_) => panic!("Expected a configuration error"),
+            Err(error) => error,
+        };
+        assert!(matches!(error, A2AError::Internal(_)));
+        assert!(error.message().starts_with("Configuration error: "), "{}", error.message());
+    }
+}
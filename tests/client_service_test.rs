@@ -0,0 +1,118 @@
+//! Tests for using the client as a Tower service
+
+use a2a_rust::a2a::{
+    client::client_trait::BaseClient,
+    client::transports::in_process::InProcessTransport,
+    client::{A2AClientService, ClientConfig, ClientFactory},
+    core_types::{Message, Part, Role},
+    error::A2AError,
+    jsonrpc::{A2ARequest, A2AResponse, SendMessageResult},
+    models::*,
+    server::{
+        agent_execution::agent_executor::EchoAgentExecutor,
+        agent_execution::{AgentExecutor, RequestContext},
+        events::EventQueue,
+        request_handlers::DefaultRequestHandler,
+        tasks::InMemoryTaskStore,
+    },
+    utils::message::get_message_text,
+};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use tower::{Service, ServiceBuilder, ServiceExt};
+
+/// Echoes messages after a delay
+struct SlowEchoExecutor {
+    delay: Duration,
+}
+
+#[async_trait]
+impl AgentExecutor for SlowEchoExecutor {
+    async fn execute(&self, context: RequestContext, event_queue: Arc<dyn EventQueue>) -> Result<(), A2AError> {
+        tokio::time::sleep(self.delay).await;
+        EchoAgentExecutor::new().execute(context, event_queue).await
+    }
+
+    async fn cancel(&self, context: RequestContext, event_queue: Arc<dyn EventQueue>) -> Result<(), A2AError> {
+        EchoAgentExecutor::new().cancel(context, event_queue).await
+    }
+}
+
+fn service(delay: Duration) -> A2AClientService {
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_agent_executor(Arc::new(SlowEchoExecutor { delay }));
+    let card = AgentCard::test_default("Slow Agent", "http://localhost:8080");
+    let transport = InProcessTransport::new(Arc::new(handler), card.clone());
+    let client = BaseClient::new(card, ClientConfig::new(), Box::new(transport), vec![], vec![]);
+    A2AClientService::new(Arc::new(client))
+}
+
+fn send(text: &str) -> A2ARequest {
+    let message = Message::new(Role::User, vec![Part::text(text.to_string())]);
+    A2ARequest::SendMessage { params: MessageSendParams::new(message) }
+}
+
+#[tokio::test]
+async fn test_service_answers_requests() {
+    let mut service = service(Duration::ZERO);
+
+    match service.ready().await.unwrap().call(send("ping")).await.unwrap() {
+        A2AResponse::SendMessage(SendMessageResult::Message(reply)) => {
+            assert_eq!(get_message_text(&reply, ""), "Echo: ping")
+        }
+        other => panic!("Expected a message reply, got {:?}", other),
+    }
+
+    let error = service
+        .ready()
+        .await
+        .unwrap()
+        .call(A2ARequest::GetTask { params: TaskQueryParams::new("missing".to_string()) })
+        .await
+        .unwrap_err();
+    assert!(matches!(error, A2AError::TaskNotFound(_)));
+
+    let error = service
+        .call(A2ARequest::SendStreamingMessage { params: MessageSendParams::from_text("hi") })
+        .await
+        .unwrap_err();
+    assert!(matches!(error, A2AError::UnsupportedOperation(_)));
+}
+
+#[tokio::test]
+async fn test_timeout_layer_cancels_slow_calls() {
+    let mut service = ServiceBuilder::new()
+        .timeout(Duration::from_millis(50))
+        .service(service(Duration::from_secs(5)));
+
+    let error = service.ready().await.unwrap().call(send("ping")).await.unwrap_err();
+    assert!(error.is::<tower::timeout::error::Elapsed>(), "{}", error);
+}
+
+#[tokio::test]
+async fn test_service_forwards_history_length() {
+    let mut server = mockito::Server::new_async().await;
+    let reply = Message::new(Role::Agent, vec![Part::text("ok".to_string())]);
+    let send = server
+        .mock("POST", "/")
+        .match_body(mockito::Matcher::PartialJson(serde_json::json!({
+            "method": "message/send",
+            "params": {"configuration": {"history_length": 2}}
+        })))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::json!({"jsonrpc": "2.0", "id": "1", "result": &reply}).to_string())
+        .create_async()
+        .await;
+    let client = ClientFactory::with_config(ClientConfig::new().with_streaming(false).with_polling(true))
+        .create(AgentCard::test_default("Agent", &server.url()), None, None, None)
+        .await
+        .unwrap();
+    let mut service = A2AClientService::new(Arc::from(client));
+
+    let mut params = MessageSendParams::from_text("hi");
+    params.configuration = Some(MessageSendConfiguration::new().with_history_length(2));
+    service.ready().await.unwrap().call(A2ARequest::SendMessage { params }).await.unwrap();
+    send.assert_async().await;
+}
@@ -0,0 +1,70 @@
+//! Shared helpers for integration tests
+//!
+//! `TestServer` runs the Rust `A2AServer` on an ephemeral local port so that
+//! clients can be tested against it over real HTTP, without an external
+//! server.
+
+#![allow(dead_code)]
+
+use a2a_rust::a2a::{
+    models::*,
+    server::{
+        agent_execution::agent_executor::EchoAgentExecutor,
+        apps::jsonrpc::A2AServerBuilder,
+        context::DefaultServerCallContextBuilder,
+        request_handlers::{DefaultRequestHandler, RequestHandler},
+        tasks::InMemoryTaskStore,
+    },
+    utils::constants::DEFAULT_RPC_URL,
+};
+use std::sync::Arc;
+use tokio::task::JoinHandle;
+
+/// An `A2AServer` listening on `127.0.0.1`, shut down when dropped
+pub struct TestServer {
+    /// Base URL of the server, e.g. `http://127.0.0.1:49152`
+    pub base_url: String,
+    /// The card the server publishes, whose `url` is the JSON-RPC endpoint
+    pub agent_card: AgentCard,
+    server: JoinHandle<()>,
+}
+
+impl TestServer {
+    /// Start a server whose agent echoes every message back as "Echo: <text>"
+    pub async fn echo() -> Self {
+        let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+            .with_agent_executor(Arc::new(EchoAgentExecutor::new()));
+        Self::start(Arc::new(handler), |card| card).await
+    }
+
+    /// Start a server for `handler`
+    ///
+    /// `customize_card` adjusts the default test card, whose URL already
+    /// points at the server, before it is published.
+    pub async fn start(handler: Arc<dyn RequestHandler>, customize_card: impl FnOnce(AgentCard) -> AgentCard) -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let rpc_url = format!("{}{}", base_url, DEFAULT_RPC_URL);
+        let agent_card = customize_card(AgentCard::test_default("Test Agent", &rpc_url));
+
+        let router = A2AServerBuilder::new()
+            .with_agent_card(agent_card.clone())
+            .with_request_handler(handler)
+            .with_context_builder(Arc::new(DefaultServerCallContextBuilder))
+            .build()
+            .unwrap()
+            .build_router()
+            .await;
+        let server = tokio::spawn(async move {
+            axum::serve(listener, router).await.unwrap();
+        });
+
+        Self { base_url, agent_card, server }
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.server.abort();
+    }
+}
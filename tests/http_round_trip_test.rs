@@ -0,0 +1,39 @@
+//! Client-to-server round trips over real HTTP
+//!
+//! These tests run the Rust client against the Rust server started by
+//! `common::TestServer`.
+
+mod common;
+
+use a2a_rust::a2a::{
+    client::card_resolver::A2ACardResolver,
+    client::client_trait::SendOutcome,
+    client::{ClientConfig, ClientFactory},
+    core_types::{Message, Part, Role},
+    utils::message::get_message_text,
+};
+use common::TestServer;
+
+#[tokio::test]
+async fn test_client_round_trip_against_rust_server() {
+    let server = TestServer::echo().await;
+
+    let card = A2ACardResolver::new(server.base_url.clone()).get_agent_card().await.unwrap();
+    assert_eq!(card, server.agent_card);
+
+    let client = ClientFactory::with_config(ClientConfig::new())
+        .create(card, None, None, None)
+        .await
+        .unwrap();
+    let message = Message::new(Role::User, vec![Part::text("ping".to_string())]);
+    match client.send_message_sync(message, None, None, None).await.unwrap() {
+        SendOutcome::Message(reply) => assert_eq!(get_message_text(&reply, ""), "Echo: ping"),
+        other => panic!("Expected a message reply, got {:?}", other),
+    }
+
+    // The server stops when the harness is dropped
+    let base_url = server.base_url.clone();
+    drop(server);
+    tokio::task::yield_now().await;
+    assert!(A2ACardResolver::new(base_url).get_agent_card().await.is_err());
+}
@@ -0,0 +1,784 @@
+//! Integration tests for the in-process client transport
+//!
+//! These tests drive server-side request handlers through the client
+//! transport interface without starting an HTTP server.
+
+use a2a_rust::a2a::{
+    client::client_trait::{BaseClient, Client, ClientEventOrMessage, ClientTransport, SendOutcome, TaskUpdateEvent},
+    client::ClientConfig,
+    client::transports::in_process::InProcessTransport,
+    core_types::{Message, Part, Role, TaskState, TaskStatus},
+    error::A2AError,
+    models::*,
+    server::{
+        agent_execution::agent_executor::EchoAgentExecutor,
+        agent_execution::{emit_heartbeat, heartbeat_progress, is_heartbeat, AgentExecutor, RequestContext},
+        context::ServerCallContext,
+        events::{Event as QueueEvent, EventQueue, InMemoryEventQueue},
+        id_generator::SequentialIDGenerator,
+        request_handlers::{
+            request_handler::{Event, TaskPushNotificationConfigQueryParams},
+            DefaultRequestHandler, MessageSendResult, RequestHandler,
+        },
+        tasks::InMemoryTaskStore,
+    },
+    utils::message::get_message_text,
+};
+use async_trait::async_trait;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// Handler that replies to every message with the same text
+struct EchoHandler;
+
+impl EchoHandler {
+    fn reply(message: &Message) -> Message {
+        let text = format!("Echo: {}", get_message_text(message, " "));
+        Message::new(Role::Agent, vec![Part::text(text)])
+    }
+}
+
+#[async_trait]
+impl RequestHandler for EchoHandler {
+    async fn on_get_task(
+        &self,
+        _params: TaskQueryParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<Option<Task>, A2AError> {
+        Ok(None)
+    }
+
+    async fn on_cancel_task(
+        &self,
+        _params: TaskIdParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<Option<Task>, A2AError> {
+        Ok(None)
+    }
+
+    async fn on_message_send(
+        &self,
+        params: MessageSendParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<MessageSendResult, A2AError> {
+        Ok(MessageSendResult::Message(Self::reply(&params.message)))
+    }
+
+    async fn on_message_send_stream(
+        &self,
+        params: MessageSendParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<BoxStream<'static, Result<Event, A2AError>>, A2AError> {
+        let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+            .with_task_id("task-1".to_string());
+        let completed = TaskStatusUpdateEvent::new(
+            task.id.to_string(),
+            task.context_id.clone(),
+            TaskStatus::new(TaskState::Completed),
+            true,
+        );
+        let events = vec![
+            Ok(Event::Task(task)),
+            Ok(Event::Message(Self::reply(&params.message))),
+            Ok(Event::TaskStatusUpdate(completed)),
+        ];
+        Ok(Box::pin(futures::stream::iter(events)))
+    }
+
+    async fn on_set_task_push_notification_config(
+        &self,
+        _params: TaskPushNotificationConfig,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        Err(A2AError::push_notification_not_supported())
+    }
+
+    async fn on_get_task_push_notification_config(
+        &self,
+        _params: TaskPushNotificationConfigQueryParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        Err(A2AError::push_notification_not_supported())
+    }
+
+    async fn on_resubscribe_to_task(
+        &self,
+        params: TaskIdParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<BoxStream<'static, Result<Event, A2AError>>, A2AError> {
+        Err(A2AError::task_not_found(&params.id))
+    }
+
+    async fn on_list_task_push_notification_config(
+        &self,
+        _params: TaskIdParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<Vec<TaskPushNotificationConfig>, A2AError> {
+        Ok(vec![])
+    }
+
+    async fn on_delete_task_push_notification_config(
+        &self,
+        _params: DeleteTaskPushNotificationConfigParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<(), A2AError> {
+        Ok(())
+    }
+}
+
+fn test_card() -> AgentCard {
+    let mut card = AgentCard::test_default("Echo Agent", "in-process://echo");
+    card.capabilities = AgentCapabilities::new().with_streaming(true);
+    card
+}
+
+fn user_message(text: &str) -> MessageSendParams {
+    MessageSendParams::new(Message::new(Role::User, vec![Part::text(text.to_string())]))
+}
+
+#[tokio::test]
+async fn test_in_process_send_message() {
+    let transport = InProcessTransport::new(Arc::new(EchoHandler), test_card());
+
+    match transport.send_message(user_message("hello"), None, None).await.unwrap() {
+        TaskOrMessage::Message(reply) => assert_eq!(get_message_text(&reply, ""), "Echo: hello"),
+        other => panic!("Expected a message reply, got {:?}", other),
+    }
+    assert_eq!(transport.get_card(None, None).await.unwrap().name, "Echo Agent");
+}
+
+#[tokio::test]
+async fn test_ask_returns_echoed_text() {
+    let card = test_card();
+    let transport = InProcessTransport::new(Arc::new(EchoHandler), card.clone());
+    let client = BaseClient::new(card, ClientConfig::new(), Box::new(transport), vec![], vec![]);
+
+    assert_eq!(client.ask("hello there").await.unwrap(), "Echo: hello there");
+}
+
+/// Agent that fails every task
+struct FailingExecutor;
+
+#[async_trait]
+impl AgentExecutor for FailingExecutor {
+    async fn execute(&self, _context: RequestContext, _event_queue: Arc<dyn EventQueue>) -> Result<(), A2AError> {
+        Err(A2AError::internal("model unavailable"))
+    }
+
+    async fn cancel(&self, _context: RequestContext, _event_queue: Arc<dyn EventQueue>) -> Result<(), A2AError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_ask_fails_for_unsuccessful_tasks() {
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_agent_executor(Arc::new(FailingExecutor));
+    let client = sync_client(Arc::new(handler));
+
+    let error = client.ask("hello there").await.unwrap_err();
+    assert!(error.message().contains("Failed"), "unexpected error: {}", error.message());
+}
+
+#[tokio::test]
+async fn test_in_process_send_message_streaming() {
+    let transport = InProcessTransport::new(Arc::new(EchoHandler), test_card());
+
+    let events: Vec<TaskOrMessage> = transport
+        .send_message_streaming(user_message("stream me"), None, None)
+        .await
+        .unwrap()
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 3);
+    assert!(matches!(events[0], TaskOrMessage::Task(_)));
+    match &events[1] {
+        TaskOrMessage::Message(reply) => assert_eq!(get_message_text(reply, ""), "Echo: stream me"),
+        other => panic!("Expected a message reply, got {:?}", other),
+    }
+    match &events[2] {
+        TaskOrMessage::TaskUpdate(update) => {
+            assert!(update.r#final);
+            assert_eq!(update.status.state, TaskState::Completed);
+        }
+        other => panic!("Expected a final status update, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_in_process_default_handler_with_echo_executor() {
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_agent_executor(Arc::new(EchoAgentExecutor::new()))
+        .with_id_generators(Arc::new(SequentialIDGenerator::new()), Arc::new(SequentialIDGenerator::new()));
+    let transport = InProcessTransport::new(Arc::new(handler), test_card());
+
+    let reply = match transport.send_message(user_message("ping"), None, None).await.unwrap() {
+        TaskOrMessage::Message(reply) => reply,
+        other => panic!("Expected a message reply, got {:?}", other),
+    };
+    assert_eq!(get_message_text(&reply, ""), "Echo: ping");
+
+    // The message reply ends the turn, but the task it was created for is stored
+    let task = transport
+        .get_task(TaskQueryParams::new("1".to_string()), None, None)
+        .await
+        .unwrap();
+    assert_eq!(task.id, "1");
+
+    let missing = transport
+        .get_task(TaskQueryParams::new("missing".to_string()), None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(missing, A2AError::TaskNotFound(_)));
+}
+
+fn sync_client(handler: Arc<dyn RequestHandler>) -> BaseClient {
+    let card = test_card();
+    let transport = InProcessTransport::new(handler, card.clone());
+    BaseClient::new(card, ClientConfig::new().with_streaming(true), Box::new(transport), vec![], vec![])
+}
+
+#[tokio::test]
+async fn test_send_message_sync_returns_message_outcome() {
+    let client = sync_client(Arc::new(EchoHandler));
+
+    let message = Message::new(Role::User, vec![Part::text("hello".to_string())]);
+    match client.send_message_sync(message, None, None, None).await.unwrap() {
+        SendOutcome::Message(reply) => assert_eq!(get_message_text(&reply, ""), "Echo: hello"),
+        other => panic!("Expected a message outcome, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_send_message_sync_returns_task_outcome() {
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_id_generators(Arc::new(SequentialIDGenerator::new()), Arc::new(SequentialIDGenerator::new()));
+    let client = sync_client(Arc::new(handler));
+
+    let message = Message::new(Role::User, vec![Part::text("track this".to_string())]);
+    match client.send_message_sync(message, None, None, None).await.unwrap() {
+        SendOutcome::Task(task) => {
+            assert_eq!(task.id, "1");
+            assert_eq!(task.status.state, TaskState::Working);
+            assert_eq!(get_message_text(&task.history.unwrap()[0], ""), "track this");
+        }
+        other => panic!("Expected a task outcome, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_partial_message_chunks_reach_executor_concatenated() {
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_agent_executor(Arc::new(EchoAgentExecutor::new()))
+        .with_id_generators(Arc::new(SequentialIDGenerator::new()), Arc::new(SequentialIDGenerator::new()));
+    let client = sync_client(Arc::new(handler));
+
+    let first = Message::new(Role::User, vec![Part::text("Hello, ".to_string())]);
+    let message_id = first.message_id.clone();
+    let accumulated = client.send_message_chunk(first, None).await.unwrap();
+    assert_eq!(get_message_text(&accumulated, ""), "Hello, ");
+
+    // Chunks are only buffered, so no task has been started yet
+    let missing = client.get_task(TaskQueryParams::new("1".to_string()), None, None).await;
+    assert!(matches!(missing, Err(A2AError::TaskNotFound(_))));
+
+    let mut last = Message::new(Role::User, vec![Part::text("world".to_string())]);
+    last.message_id = message_id;
+    let events: Vec<_> = client.send_message(last, None, None, None).await.collect().await;
+    let replies: Vec<String> = events
+        .iter()
+        .filter_map(|event| match event {
+            Ok(ClientEventOrMessage::Message(reply)) => Some(get_message_text(reply, "")),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(replies, vec!["Echo: Hello, world".to_string()]);
+
+    let task = client.get_task(TaskQueryParams::new("1".to_string()), None, None).await.unwrap();
+    assert_eq!(get_message_text(&task.history.unwrap()[0], ""), "Hello, world");
+}
+
+/// Handler serving a single task whose state is advanced by the test
+struct ScriptedTaskHandler {
+    task: Mutex<Task>,
+    updates: broadcast::Sender<TaskStatusUpdateEvent>,
+    resubscribe_error: Option<A2AError>,
+}
+
+impl ScriptedTaskHandler {
+    fn new() -> Self {
+        let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+            .with_task_id("task-1".to_string());
+        Self {
+            task: Mutex::new(task),
+            updates: broadcast::channel(16).0,
+            resubscribe_error: None,
+        }
+    }
+
+    /// Fails every resubscription with `error`
+    fn with_resubscribe_error(mut self, error: A2AError) -> Self {
+        self.resubscribe_error = Some(error);
+        self
+    }
+
+    /// Moves the task to `state`, publishing the update to subscribers if `publish` is set
+    fn advance(&self, state: TaskState, publish: bool) {
+        let mut task = self.task.lock().unwrap();
+        task.status = TaskStatus::new(state.clone());
+        if publish {
+            let is_final = state == TaskState::Completed;
+            let update =
+                TaskStatusUpdateEvent::new(task.id.to_string(), task.context_id.clone(), TaskStatus::new(state), is_final);
+            let _ = self.updates.send(update);
+        }
+    }
+}
+
+#[async_trait]
+impl RequestHandler for ScriptedTaskHandler {
+    async fn on_get_task(
+        &self,
+        params: TaskQueryParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<Option<Task>, A2AError> {
+        let task = self.task.lock().unwrap().clone();
+        Ok((task.id == params.id).then_some(task))
+    }
+
+    async fn on_cancel_task(
+        &self,
+        _params: TaskIdParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<Option<Task>, A2AError> {
+        Ok(None)
+    }
+
+    async fn on_message_send(
+        &self,
+        _params: MessageSendParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<MessageSendResult, A2AError> {
+        Err(A2AError::unsupported_operation("Scripted handler does not accept messages"))
+    }
+
+    async fn on_message_send_stream(
+        &self,
+        _params: MessageSendParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<BoxStream<'static, Result<Event, A2AError>>, A2AError> {
+        Err(A2AError::unsupported_operation("Scripted handler does not accept messages"))
+    }
+
+    async fn on_set_task_push_notification_config(
+        &self,
+        _params: TaskPushNotificationConfig,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        Err(A2AError::push_notification_not_supported())
+    }
+
+    async fn on_get_task_push_notification_config(
+        &self,
+        _params: TaskPushNotificationConfigQueryParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        Err(A2AError::push_notification_not_supported())
+    }
+
+    async fn on_resubscribe_to_task(
+        &self,
+        _params: TaskIdParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<BoxStream<'static, Result<Event, A2AError>>, A2AError> {
+        if let Some(error) = &self.resubscribe_error {
+            return Err(error.clone());
+        }
+        let mut updates = self.updates.subscribe();
+        let task = self.task.lock().unwrap().clone();
+        let stream = async_stream::stream! {
+            yield Ok(Event::Task(task));
+            while let Ok(update) = updates.recv().await {
+                let is_final = update.r#final;
+                yield Ok(Event::TaskStatusUpdate(update));
+                if is_final {
+                    break;
+                }
+            }
+        };
+        Ok(Box::pin(stream))
+    }
+
+    async fn on_list_task_push_notification_config(
+        &self,
+        _params: TaskIdParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<Vec<TaskPushNotificationConfig>, A2AError> {
+        Ok(vec![])
+    }
+
+    async fn on_delete_task_push_notification_config(
+        &self,
+        _params: DeleteTaskPushNotificationConfigParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<(), A2AError> {
+        Ok(())
+    }
+}
+
+fn scripted_client(handler: Arc<ScriptedTaskHandler>, streaming: bool) -> Arc<BaseClient> {
+    let card = test_card();
+    let transport = InProcessTransport::new(handler, card.clone());
+    let config = ClientConfig::new().with_streaming(streaming);
+    Arc::new(BaseClient::new(card, config, Box::new(transport), vec![], vec![]))
+}
+
+fn spawn_wait(
+    client: &Arc<BaseClient>,
+    target: TaskState,
+) -> tokio::task::JoinHandle<Result<Task, A2AError>> {
+    let client = client.clone();
+    tokio::spawn(async move { client.wait_for_state("task-1", &[target], Duration::from_secs(5)).await })
+}
+
+#[tokio::test]
+async fn test_wait_for_state_follows_stream() {
+    let handler = Arc::new(ScriptedTaskHandler::new());
+    let client = scripted_client(handler.clone(), true);
+
+    let waiting = spawn_wait(&client, TaskState::InputRequired);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!waiting.is_finished());
+
+    handler.advance(TaskState::InputRequired, true);
+    let task = waiting.await.unwrap().unwrap();
+    assert_eq!(task.status.state, TaskState::InputRequired);
+
+    let waiting = spawn_wait(&client, TaskState::Completed);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!waiting.is_finished());
+
+    handler.advance(TaskState::Completed, true);
+    let task = waiting.await.unwrap().unwrap();
+    assert_eq!(task.status.state, TaskState::Completed);
+
+    // A task already in a target state is returned immediately
+    let task = client
+        .wait_for_state("task-1", &[TaskState::Completed], Duration::from_millis(10))
+        .await
+        .unwrap();
+    assert_eq!(task.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_wait_for_state_polls_without_streaming() {
+    let handler = Arc::new(ScriptedTaskHandler::new());
+    let client = scripted_client(handler.clone(), false);
+
+    let waiting = spawn_wait(&client, TaskState::InputRequired);
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!waiting.is_finished());
+
+    // Without a published update the change is only visible through tasks/get
+    handler.advance(TaskState::InputRequired, false);
+    let task = waiting.await.unwrap().unwrap();
+    assert_eq!(task.status.state, TaskState::InputRequired);
+}
+
+#[tokio::test]
+async fn test_wait_for_state_fails_on_other_terminal_state() {
+    for streaming in [true, false] {
+        let handler = Arc::new(ScriptedTaskHandler::new());
+        let client = scripted_client(handler.clone(), streaming);
+
+        let waiting = spawn_wait(&client, TaskState::Completed);
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        handler.advance(TaskState::Failed, true);
+
+        let result = tokio::time::timeout(Duration::from_secs(1), waiting).await.expect("wait did not fail fast");
+        let error = result.unwrap().unwrap_err();
+        assert!(error.message().contains("ended as Failed"), "{}", error.message());
+    }
+}
+
+#[tokio::test]
+async fn test_wait_for_state_propagates_stream_errors() {
+    let handler = ScriptedTaskHandler::new().with_resubscribe_error(A2AError::internal("stream broke"));
+    let client = scripted_client(Arc::new(handler), true);
+
+    let error = client
+        .wait_for_state("task-1", &[TaskState::Completed], Duration::from_secs(5))
+        .await
+        .unwrap_err();
+    assert_eq!(error.message(), "stream broke");
+}
+
+#[tokio::test]
+async fn test_wait_for_state_times_out() {
+    let client = scripted_client(Arc::new(ScriptedTaskHandler::new()), true);
+
+    let error = client
+        .wait_for_state("task-1", &[TaskState::Completed], Duration::from_millis(100))
+        .await
+        .unwrap_err();
+    assert!(error.message().contains("did not reach"));
+}
+
+/// Handler whose agent works silently between heartbeats before completing
+struct HeartbeatHandler {
+    heartbeats: usize,
+    gap: Duration,
+}
+
+#[async_trait]
+impl RequestHandler for HeartbeatHandler {
+    async fn on_get_task(
+        &self,
+        _params: TaskQueryParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<Option<Task>, A2AError> {
+        Ok(None)
+    }
+
+    async fn on_cancel_task(
+        &self,
+        _params: TaskIdParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<Option<Task>, A2AError> {
+        Ok(None)
+    }
+
+    async fn on_message_send(
+        &self,
+        _params: MessageSendParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<MessageSendResult, A2AError> {
+        Err(A2AError::unsupported_operation("streaming only"))
+    }
+
+    async fn on_message_send_stream(
+        &self,
+        _params: MessageSendParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<BoxStream<'static, Result<Event, A2AError>>, A2AError> {
+        let queue = Arc::new(InMemoryEventQueue::new()?);
+        let (heartbeats, gap) = (self.heartbeats, self.gap);
+        let producer = queue.clone();
+        tokio::spawn(async move {
+            let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working))
+                .with_task_id("task-1".to_string());
+            producer.enqueue_event(QueueEvent::Task(task)).await.unwrap();
+            for i in 0..heartbeats {
+                tokio::time::sleep(gap).await;
+                let progress = (i + 1) as f32 / heartbeats as f32;
+                emit_heartbeat(producer.as_ref(), "task-1", "ctx-1", Some(progress)).await.unwrap();
+            }
+            tokio::time::sleep(gap).await;
+            let completed = TaskStatusUpdateEvent::new(
+                "task-1".to_string(),
+                "ctx-1".to_string(),
+                TaskStatus::new(TaskState::Completed),
+                true,
+            );
+            producer.enqueue_event(QueueEvent::TaskStatusUpdate(completed)).await.unwrap();
+        });
+
+        Ok(Box::pin(async_stream::stream! {
+            loop {
+                let event = match queue.dequeue_event(false).await {
+                    Ok(event) => event,
+                    Err(e) => {
+                        yield Err(e);
+                        break;
+                    }
+                };
+                let last = event.is_final();
+                yield Ok(Event::from(event));
+                if last {
+                    break;
+                }
+            }
+        }))
+    }
+
+    async fn on_set_task_push_notification_config(
+        &self,
+        _params: TaskPushNotificationConfig,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        Err(A2AError::push_notification_not_supported())
+    }
+
+    async fn on_get_task_push_notification_config(
+        &self,
+        _params: TaskPushNotificationConfigQueryParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<TaskPushNotificationConfig, A2AError> {
+        Err(A2AError::push_notification_not_supported())
+    }
+
+    async fn on_resubscribe_to_task(
+        &self,
+        params: TaskIdParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<BoxStream<'static, Result<Event, A2AError>>, A2AError> {
+        Err(A2AError::task_not_found(&params.id))
+    }
+
+    async fn on_list_task_push_notification_config(
+        &self,
+        _params: TaskIdParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<Vec<TaskPushNotificationConfig>, A2AError> {
+        Ok(vec![])
+    }
+
+    async fn on_delete_task_push_notification_config(
+        &self,
+        _params: DeleteTaskPushNotificationConfigParams,
+        _context: Option<&ServerCallContext>,
+    ) -> Result<(), A2AError> {
+        Ok(())
+    }
+}
+
+fn heartbeat_client(heartbeats: usize, gap: Duration, inactivity: Duration) -> BaseClient {
+    let card = test_card();
+    let transport = InProcessTransport::new(Arc::new(HeartbeatHandler { heartbeats, gap }), card.clone());
+    let config = ClientConfig::new().with_stream_inactivity_timeout(inactivity);
+    BaseClient::new(card, config, Box::new(transport), vec![], vec![])
+}
+
+#[tokio::test]
+async fn test_heartbeats_reset_inactivity_timeout() {
+    // The whole run takes longer than the window, but no gap does
+    let client = heartbeat_client(5, Duration::from_millis(60), Duration::from_millis(200));
+
+    let events: Vec<ClientEventOrMessage> = client
+        .send_message(Message::new(Role::User, vec![Part::text("work".to_string())]), None, None, None)
+        .await
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+
+    let updates: Vec<&TaskStatusUpdateEvent> = events
+        .iter()
+        .filter_map(|event| match event {
+            ClientEventOrMessage::Event((_, Some(TaskUpdateEvent::Status(update)))) => Some(update),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(updates.iter().filter(|update| is_heartbeat(update)).count(), 5);
+    assert_eq!(heartbeat_progress(updates[4]), Some(1.0));
+    assert_eq!(updates.last().unwrap().status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_silent_stream_fails_after_inactivity_timeout() {
+    let client = heartbeat_client(0, Duration::from_secs(5), Duration::from_millis(100));
+
+    let mut events = client
+        .send_message(Message::new(Role::User, vec![Part::text("work".to_string())]), None, None, None)
+        .await;
+
+    assert!(matches!(events.next().await, Some(Ok(ClientEventOrMessage::Event((_, None))))));
+    let error = events.next().await.unwrap().unwrap_err();
+    assert!(error.message().contains("No event received"));
+    assert!(events.next().await.is_none());
+}
+
+/// Agent that works on its task until it is canceled
+struct NeverEndingExecutor;
+
+#[async_trait]
+impl AgentExecutor for NeverEndingExecutor {
+    async fn execute(&self, _context: RequestContext, _event_queue: Arc<dyn EventQueue>) -> Result<(), A2AError> {
+        tokio::time::sleep(Duration::from_secs(30)).await;
+        Ok(())
+    }
+
+    async fn cancel(&self, _context: RequestContext, _event_queue: Arc<dyn EventQueue>) -> Result<(), A2AError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_cancel_handle_cancels_in_flight_task() {
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_agent_executor(Arc::new(NeverEndingExecutor))
+        .with_id_generators(Arc::new(SequentialIDGenerator::new()), Arc::new(SequentialIDGenerator::new()));
+    let client = sync_client(Arc::new(handler));
+
+    let (mut events, handle) = client
+        .send_message_cancellable(Message::new(Role::User, vec![Part::text("work".to_string())]), None, None, None)
+        .await;
+    match events.next().await {
+        Some(Ok(ClientEventOrMessage::Event((task, None)))) => assert_eq!(task.id, "1"),
+        other => panic!("Expected the new task, got {:?}", other),
+    }
+
+    handle.cancel();
+    assert!(handle.is_cancelled());
+    let remaining: Vec<ClientEventOrMessage> = tokio::time::timeout(Duration::from_secs(5), events.map(|event| event.unwrap()).collect())
+        .await
+        .expect("stream did not end after cancel");
+    match remaining.last() {
+        Some(ClientEventOrMessage::Event((task, None))) => {
+            assert_eq!(task.id, "1");
+            assert_eq!(task.status.state, TaskState::Canceled);
+        }
+        other => panic!("Expected the canceled task, got {:?}", other),
+    }
+
+    let stored = client.get_task(TaskQueryParams::new("1".to_string()), None, None).await.unwrap();
+    assert_eq!(stored.status.state, TaskState::Canceled);
+}
+
+#[tokio::test]
+async fn test_strict_client_rejects_undeclared_methods_locally() {
+    let mut card = AgentCard::test_default("Echo Agent", "in-process://echo");
+    card.capabilities = AgentCapabilities::new().with_streaming(false);
+    let transport = InProcessTransport::new(Arc::new(EchoHandler), card.clone());
+    let config = ClientConfig::new().with_streaming(true).with_strict_capabilities(true);
+    let client = BaseClient::new(card, config, Box::new(transport), vec![], vec![]);
+
+    // Streaming falls back to message/send, which every agent serves
+    let message = Message::new(Role::User, vec![Part::text("hello".to_string())]);
+    let events: Vec<_> = client.send_message(message, None, None, None).await.collect().await;
+    assert!(matches!(events.as_slice(), [Ok(ClientEventOrMessage::Message(_))]));
+
+    let events: Vec<_> = client.resubscribe(TaskIdParams::new("task-1".to_string()), None, None).await.collect().await;
+    assert!(matches!(events.as_slice(), [Err(A2AError::UnsupportedOperation(_))]));
+    let result = client.get_authenticated_extended_card(None).await;
+    assert!(matches!(result, Err(A2AError::UnsupportedOperation(_))));
+    let result = client
+        .delete_task_callback(DeleteTaskPushNotificationConfigParams::new("task-1".to_string(), "config-1".to_string()), None, None)
+        .await;
+    assert!(matches!(result, Err(A2AError::UnsupportedOperation(_))));
+
+    // The handler would answer with PushNotificationNotSupported
+    let config = TaskPushNotificationConfig::new(
+        "task-1".to_string(),
+        PushNotificationConfig::new(url::Url::parse("https://example.com/callback").unwrap()),
+    );
+    let result = client.set_task_callback(config, None, None).await;
+    assert!(matches!(result, Err(A2AError::UnsupportedOperation(_))));
+}
+
+#[tokio::test]
+async fn test_strict_client_streams_when_card_supports_it() {
+    let card = test_card();
+    let transport = InProcessTransport::new(Arc::new(EchoHandler), card.clone());
+    let config = ClientConfig::new().with_streaming(true).with_strict_capabilities(true);
+    let client = BaseClient::new(card, config, Box::new(transport), vec![], vec![]);
+
+    let message = Message::new(Role::User, vec![Part::text("hello".to_string())]);
+    let events: Vec<_> = client.send_message(message, None, None, None).await.collect().await;
+    assert_eq!(events.len(), 3);
+    assert!(events.iter().all(|event| event.is_ok()));
+}
@@ -30,6 +30,7 @@ fn test_message_serialization_compatibility() {
         metadata: None,
         extensions: None,
         reference_task_ids: None,
+        part_form: None,
     };
 
     // Serialize to JSON
@@ -61,7 +62,7 @@ fn test_task_serialization_compatibility() {
     // Create a task that matches Python's Task structure
     let task = Task {
         kind: "task".to_string(),
-        id: "task-123".to_string(),
+        id: "task-123".into(),
         context_id: "ctx-456".to_string(),
         status: TaskStatus {
             state: TaskState::Working,
@@ -89,6 +90,7 @@ fn test_task_serialization_compatibility() {
                 metadata: None,
                 extensions: None,
                 reference_task_ids: None,
+                part_form: None,
             }
         ]),
         metadata: None,
@@ -258,6 +260,7 @@ fn test_roundtrip_compatibility() {
         }),
         extensions: Some(vec!["ext-1".to_string(), "ext-2".to_string()]),
         reference_task_ids: Some(vec!["ref-1".to_string()]),
+        part_form: None,
     };
 
     // Serialize to JSON
@@ -93,6 +93,7 @@ async fn test_message_send_to_python_server() {
                 metadata: None,
                 extensions: None,
                 reference_task_ids: None,
+                part_form: None,
             };
 
             println!(" Sending test message to Python server: {:?}", message);
@@ -177,6 +178,7 @@ async fn test_task_management_with_python_server() {
                 metadata: None,
                 extensions: None,
                 reference_task_ids: None,
+                part_form: None,
             };
 
             println!("Creating task for testing...");
@@ -195,7 +197,7 @@ async fn test_task_management_with_python_server() {
             while let Some(result) = stream.next().await {
                 match result {
                     Ok(ClientEventOrMessage::Event((task, _))) => {
-                        task_id = Some(task.id.clone());
+                        task_id = Some(task.id.to_string());
                         println!("✓ Created task: {}", task.id);
                         break;
                     }
@@ -288,6 +290,7 @@ async fn test_streaming_with_python_server() {
                 metadata: None,
                 extensions: None,
                 reference_task_ids: None,
+                part_form: None,
             };
 
             println!("Testing streaming with message: {:?}", message);
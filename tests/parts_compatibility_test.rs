@@ -206,6 +206,7 @@ fn test_message_with_parts_compatibility() {
         extensions: None,
         reference_task_ids: None,
         kind: "message".to_string(),
+        part_form: None,
     };
 
     let serialized = serde_json::to_string(&message).unwrap();
@@ -284,3 +285,79 @@ fn test_file_part_convenience_methods() {
         _ => panic!("Expected file part"),
     }
 }
+
+#[test]
+fn test_file_part_inferred_mime_types() {
+    use url::Url;
+
+    let uri_part = Part::file_uri_with_inferred_type(Url::parse("https://example.com/report.pdf").unwrap());
+    // Base64 of the 8-byte PNG signature followed by the IHDR chunk length
+    let bytes_part = Part::file_bytes_with_inferred_type("iVBORw0KGgoAAAAN".to_string());
+
+    match uri_part.root() {
+        PartRoot::File(FilePart { file: FileContent::Uri(fwu), .. }) => {
+            assert_eq!(fwu.mime_type.as_deref(), Some("application/pdf"));
+        }
+        _ => panic!("Expected URI file part"),
+    }
+
+    match bytes_part.root() {
+        PartRoot::File(FilePart { file: FileContent::Bytes(fwb), .. }) => {
+            assert_eq!(fwb.mime_type.as_deref(), Some("image/png"));
+            assert_eq!(fwb.bytes, "iVBORw0KGgoAAAAN");
+        }
+        _ => panic!("Expected bytes file part"),
+    }
+
+    // The plain constructors still leave the MIME type unset
+    match Part::file_bytes("iVBORw0KGgoAAAAN".to_string()).root() {
+        PartRoot::File(FilePart { file: FileContent::Bytes(fwb), .. }) => assert_eq!(fwb.mime_type, None),
+        _ => panic!("Expected bytes file part"),
+    }
+}
+
+#[test]
+fn test_part_form_round_trip() {
+    let direct = Part::text("Hello".to_string());
+    let with_root = direct.clone().into_with_root();
+
+    assert_eq!(with_root.form(), PartForm::WithRoot);
+    assert_eq!(serde_json::to_value(&with_root).unwrap()["root"]["text"], "Hello");
+    assert_eq!(serde_json::to_value(&direct).unwrap()["text"], "Hello");
+
+    for part in [direct.clone(), with_root.clone()] {
+        let json = serde_json::to_string(&part).unwrap();
+        let parsed: Part = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, part);
+        assert_eq!(parsed.clone().into_direct(), direct);
+        assert_eq!(parsed.into_with_root(), with_root);
+    }
+}
+
+#[test]
+fn test_message_part_form_normalization() {
+    let message = Message::new(
+        Role::User,
+        vec![
+            Part::text("Hello".to_string()),
+            Part::data(serde_json::json!({"key": "value"})).into_with_root(),
+        ],
+    );
+
+    let as_built = serde_json::to_value(&message).unwrap();
+    assert!(as_built["parts"][0].get("root").is_none());
+    assert!(as_built["parts"][1].get("root").is_some());
+
+    for form in [PartForm::Direct, PartForm::WithRoot] {
+        let normalized = message.clone().with_part_form(form);
+        assert_eq!(normalized.parts, message.parts);
+
+        let json = serde_json::to_value(&normalized).unwrap();
+        for part in json["parts"].as_array().unwrap() {
+            assert_eq!(part.get("root").is_some(), form == PartForm::WithRoot);
+        }
+
+        let parsed: Message = serde_json::from_value(json).unwrap();
+        assert!(parsed.parts.iter().all(|part| part.form() == form));
+    }
+}
@@ -1,9 +1,43 @@
 use a2a_rust::a2a::models::*;
-use a2a_rust::a2a::core_types::{Message, Role, Part};
-use a2a_rust::a2a::server::request_handlers::{DefaultRequestHandler, RequestHandler};
+use a2a_rust::a2a::core_types::{Message, Role, Part, TaskState, TaskStatus};
+use a2a_rust::a2a::server::agent_execution::{AgentExecutor, RequestContext};
+use a2a_rust::a2a::server::events::{Event, EventQueue};
+use a2a_rust::a2a::server::context::ServerCallContext;
+use a2a_rust::a2a::server::id_generator::SequentialIDGenerator;
+use a2a_rust::a2a::server::request_handlers::{DefaultRequestHandler, JSONRPCHandler, RequestHandler};
 use a2a_rust::a2a::server::tasks::{InMemoryTaskStore, InMemoryPushNotificationConfigStore, HttpPushNotificationSender};
+use async_trait::async_trait;
 use std::sync::Arc;
-use mockito::Server;
+use mockito::{Matcher, Server};
+use serde_json::{json, Value};
+
+/// Agent that works on the task and completes it without replying
+struct CompletingExecutor;
+
+#[async_trait]
+impl AgentExecutor for CompletingExecutor {
+    async fn execute(
+        &self,
+        context: RequestContext,
+        event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        let task_id = context.task_id.clone().unwrap_or_default();
+        let context_id = context.context_id.clone().unwrap_or_default();
+        for (state, r#final) in [(TaskState::Working, false), (TaskState::Completed, true)] {
+            let update = TaskStatusUpdateEvent::new(task_id.clone(), context_id.clone(), TaskStatus::new(state), r#final);
+            event_queue.enqueue_event(Event::TaskStatusUpdate(update)).await?;
+        }
+        Ok(())
+    }
+
+    async fn cancel(
+        &self,
+        _context: RequestContext,
+        _event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        Ok(())
+    }
+}
 
 #[tokio::test]
 async fn test_default_handler_auto_push() {
@@ -53,3 +87,135 @@ async fn test_default_handler_auto_push() {
     // Wait a bit for async push to complete if necessary (though HttpPushNotificationSender is awaited in DefaultRequestHandler)
     mock.assert_async().await;
 }
+
+fn push_config(id: &str) -> Value {
+    json!({
+        "task_id": "task-1",
+        "push_notification_config": {"id": id, "url": format!("https://example.com/{}", id)}
+    })
+}
+
+async fn call(handler: &JSONRPCHandler, method: &str, params: Value) -> Value {
+    let request = json!({"jsonrpc": "2.0", "method": method, "params": params, "id": 1});
+    let response = handler.handle_request(request, &ServerCallContext::new()).await.unwrap();
+    response["result"].clone()
+}
+
+fn config_ids(configs: &Value) -> Vec<&str> {
+    let mut ids: Vec<&str> = configs
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["pushNotificationConfig"]["id"].as_str().unwrap())
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[tokio::test]
+async fn test_multiple_push_configs_per_task() {
+    let card = AgentCard::new(
+        "Push Agent".to_string(),
+        "Agent with push notifications".to_string(),
+        "http://localhost:8080".to_string(),
+        "1.0.0".to_string(),
+        vec![],
+        vec![],
+        AgentCapabilities::new().with_push_notifications(true),
+        vec![],
+    );
+    let request_handler = DefaultRequestHandler::new(
+        Arc::new(InMemoryTaskStore::new()),
+        Some(Arc::new(InMemoryPushNotificationConfigStore::new())),
+        None,
+    );
+    let handler = JSONRPCHandler::new(card, Arc::new(request_handler));
+
+    call(&handler, "tasks/pushNotificationConfig/set", push_config("cfg-a")).await;
+    call(&handler, "tasks/pushNotificationConfig/set", push_config("cfg-b")).await;
+    // Setting an existing ID replaces that config rather than adding another
+    call(&handler, "tasks/pushNotificationConfig/set", push_config("cfg-b")).await;
+
+    let configs = call(&handler, "tasks/pushNotificationConfig/list", json!({"id": "task-1"})).await;
+    assert_eq!(config_ids(&configs), vec!["cfg-a", "cfg-b"]);
+
+    let config = call(
+        &handler,
+        "tasks/pushNotificationConfig/get",
+        json!({"id": "task-1", "push_notification_config_id": "cfg-b"}),
+    )
+    .await;
+    assert_eq!(config["pushNotificationConfig"]["url"], "https://example.com/cfg-b");
+
+    call(
+        &handler,
+        "tasks/pushNotificationConfig/delete",
+        json!({"id": "task-1", "push_notification_config_id": "cfg-a"}),
+    )
+    .await;
+
+    let configs = call(&handler, "tasks/pushNotificationConfig/list", json!({"id": "task-1"})).await;
+    assert_eq!(config_ids(&configs), vec!["cfg-b"]);
+}
+
+#[tokio::test]
+async fn test_inline_push_config_notified_on_completion() {
+    let mut server = Server::new_async().await;
+    let completed = server
+        .mock("POST", "/webhook")
+        .match_header("X-A2A-Notification-Token", "inline-token")
+        .match_body(Matcher::PartialJson(json!({"id": "1", "status": {"state": "completed"}})))
+        .with_status(200)
+        .expect(1)
+        .create_async()
+        .await;
+    let working = server
+        .mock("POST", "/webhook")
+        .match_body(Matcher::PartialJson(json!({"status": {"state": "working"}})))
+        .with_status(200)
+        .create_async()
+        .await;
+
+    let push_config_store = Arc::new(InMemoryPushNotificationConfigStore::new());
+    let request_handler = DefaultRequestHandler::new(
+        Arc::new(InMemoryTaskStore::new()),
+        Some(push_config_store.clone()),
+        Some(Arc::new(HttpPushNotificationSender::new(push_config_store))),
+    )
+    .with_agent_executor(Arc::new(CompletingExecutor))
+    .with_id_generators(Arc::new(SequentialIDGenerator::new()), Arc::new(SequentialIDGenerator::new()));
+    let card = AgentCard::new(
+        "Push Agent".to_string(),
+        "Agent with push notifications".to_string(),
+        "http://localhost:8080".to_string(),
+        "1.0.0".to_string(),
+        vec![],
+        vec![],
+        AgentCapabilities::new().with_push_notifications(true),
+        vec![],
+    );
+    let handler = JSONRPCHandler::new(card, Arc::new(request_handler));
+
+    let params = json!({
+        "message": {
+            "kind": "message",
+            "messageId": "msg-1",
+            "role": "user",
+            "parts": [{"kind": "text", "text": "ping"}]
+        },
+        "configuration": {
+            "push_notification_config": {
+                "url": format!("{}/webhook", server.url()),
+                "token": "inline-token"
+            }
+        }
+    });
+    call(&handler, "message/send", params).await;
+
+    // The inline config is registered under the task's id
+    let configs = call(&handler, "tasks/pushNotificationConfig/list", json!({"id": "1"})).await;
+    assert_eq!(config_ids(&configs), vec!["1"]);
+
+    completed.assert_async().await;
+    working.assert_async().await;
+}
@@ -4,12 +4,20 @@
 //! are working correctly, following the same pattern as existing tests in the project.
 
 use a2a_rust::a2a::{
-    core_types::{Message, Part, Role},
+    core_types::{Message, Part, Role, TaskState, TaskStatus},
     models::*,
     server::{
+        agent_execution::{AgentExecutor, RequestContext},
         apps::jsonrpc::{A2AServerBuilder, ServerConfig},
-        context::DefaultServerCallContextBuilder,
-        request_handlers::request_handler::MockRequestHandler,
+        context::{DefaultServerCallContextBuilder, DuplicateMessagePolicy, ServerCallContext, ServerCallContextBuilder},
+        id_generator::SequentialIDGenerator,
+        metrics::TaskMetrics,
+        request_handlers::{
+            request_handler::{Event, MockRequestHandler}, DefaultRequestHandler, MessageSendResult,
+            RequestHandler, TASK_OWNER_METADATA_KEY,
+        },
+        events::{Event as QueueEvent, EventQueue},
+        tasks::{InMemoryTaskStore, TaskEvent, TaskManager, TaskStore},
     },
     utils::constants::*,
 };
@@ -19,27 +27,16 @@ use axum::{
     response::Response,
     Router,
 };
+use async_trait::async_trait;
+use futures::StreamExt;
 use serde_json::json;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tower::util::ServiceExt;
 
-/// Helper function to create a test agent card
-fn create_test_agent_card() -> AgentCard {
-    AgentCard::new(
-        "Test Agent".to_string(),
-        "A test agent for testing".to_string(),
-        "http://localhost:8080".to_string(),
-        "1.0.0".to_string(),
-        vec!["text/plain".to_string()],
-        vec!["text/plain".to_string()],
-        AgentCapabilities::new(),
-        vec![],
-    )
-}
-
 #[tokio::test]
 async fn test_jsonrpc_message_send() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = Arc::new(MockRequestHandler::new());
     let context_builder = Arc::new(DefaultServerCallContextBuilder);
 
@@ -98,7 +95,7 @@ async fn test_jsonrpc_message_send() {
 
 #[tokio::test]
 async fn test_jsonrpc_invalid_method() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = Arc::new(MockRequestHandler::new());
     let context_builder = Arc::new(DefaultServerCallContextBuilder);
 
@@ -145,7 +142,7 @@ async fn test_jsonrpc_invalid_method() {
 
 #[tokio::test]
 async fn test_jsonrpc_invalid_json() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = Arc::new(MockRequestHandler::new());
     let context_builder = Arc::new(DefaultServerCallContextBuilder);
 
@@ -185,7 +182,7 @@ async fn test_jsonrpc_invalid_json() {
 
 #[tokio::test]
 async fn test_agent_card_endpoint() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = Arc::new(MockRequestHandler::new());
     let context_builder = Arc::new(DefaultServerCallContextBuilder);
 
@@ -223,7 +220,7 @@ async fn test_agent_card_endpoint() {
 
 #[tokio::test]
 async fn test_extended_agent_card_endpoint() {
-    let mut agent_card = create_test_agent_card();
+    let mut agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     agent_card.supports_authenticated_extended_card = Some(true);
 
     let extended_card = AgentCard::new(
@@ -275,7 +272,7 @@ async fn test_extended_agent_card_endpoint() {
 
 #[tokio::test]
 async fn test_jsonrpc_message_send_with_configuration() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = Arc::new(MockRequestHandler::new());
     let context_builder = Arc::new(DefaultServerCallContextBuilder);
 
@@ -338,7 +335,7 @@ async fn test_jsonrpc_message_send_with_configuration() {
 
 #[tokio::test]
 async fn test_jsonrpc_message_send_with_context() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = Arc::new(MockRequestHandler::new());
     let context_builder = Arc::new(DefaultServerCallContextBuilder);
 
@@ -398,7 +395,7 @@ async fn test_jsonrpc_message_send_with_context() {
 
 #[tokio::test]
 async fn test_jsonrpc_task_get() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = Arc::new(MockRequestHandler::new());
     let context_builder = Arc::new(DefaultServerCallContextBuilder);
 
@@ -450,7 +447,7 @@ async fn test_jsonrpc_task_get() {
 
 #[tokio::test]
 async fn test_jsonrpc_task_cancel() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = Arc::new(MockRequestHandler::new());
     let context_builder = Arc::new(DefaultServerCallContextBuilder);
 
@@ -498,3 +495,1104 @@ async fn test_jsonrpc_task_cancel() {
     // Result could be null, an object, or there could be an error field
     assert!(response_json.get("result").is_some() || response_json.get("error").is_some());
 }
+
+#[tokio::test]
+async fn test_input_required_round_trip() {
+    let task_store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(task_store.clone(), None, None);
+
+    // First message starts a new task
+    let first = Message::new(Role::User, vec![Part::text("Book a flight".to_string())])
+        .with_message_id("msg-1".to_string());
+    let task = match handler.on_message_send(MessageSendParams::new(first), None).await.unwrap() {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+
+    // The agent pauses and asks for more input without finishing the task
+    let mut agent = TaskManager::new(
+        Some(task.id.to_string()),
+        Some(task.context_id.clone()),
+        task_store.clone(),
+        None,
+        None,
+    )
+    .unwrap();
+    let prompt = Message::new(Role::Agent, vec![Part::text("Where to?".to_string())]);
+    agent
+        .save_task_event(TaskEvent::StatusUpdate(TaskStatusUpdateEvent::new(
+            task.id.to_string(),
+            task.context_id.clone(),
+            TaskStatus::new(TaskState::InputRequired).with_message(prompt),
+            false,
+        )))
+        .await
+        .unwrap();
+
+    let paused = handler
+        .on_get_task(TaskQueryParams::new(task.id.to_string()), None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(paused.status.state, TaskState::InputRequired);
+
+    // Second message with the same task id resumes the task
+    let second = Message::new(Role::User, vec![Part::text("Paris".to_string())])
+        .with_message_id("msg-2".to_string())
+        .with_task_id(task.id.to_string());
+    let resumed = match handler.on_message_send(MessageSendParams::new(second), None).await.unwrap() {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+    assert_eq!(resumed.id, task.id);
+    assert_eq!(resumed.context_id, task.context_id);
+    assert_eq!(resumed.status.state, TaskState::Working);
+
+    // The agent finishes the resumed task under the new request
+    let mut agent = TaskManager::new(
+        Some(task.id.to_string()),
+        Some(task.context_id.clone()),
+        task_store.clone(),
+        None,
+        None,
+    )
+    .unwrap();
+    let completed = agent
+        .save_task_event(TaskEvent::StatusUpdate(TaskStatusUpdateEvent::new(
+            task.id.to_string(),
+            task.context_id.clone(),
+            TaskStatus::new(TaskState::Completed),
+            true,
+        )))
+        .await
+        .unwrap();
+    assert_eq!(completed.status.state, TaskState::Completed);
+
+    let stored = task_store.get(&task.id).await.unwrap().unwrap();
+    let user_messages: Vec<_> = stored
+        .history
+        .unwrap()
+        .into_iter()
+        .filter(|m| m.role == Role::User)
+        .map(|m| m.message_id)
+        .collect();
+    assert_eq!(user_messages, vec!["msg-1".to_string(), "msg-2".to_string()]);
+
+    // A finished task cannot be resumed
+    let third = Message::new(Role::User, vec![Part::text("Again".to_string())])
+        .with_task_id(task.id.to_string());
+    assert!(handler.on_message_send(MessageSendParams::new(third), None).await.is_err());
+}
+
+#[tokio::test]
+async fn test_message_send_continues_existing_task() {
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None);
+    let send = |message: Message| handler.on_message_send(MessageSendParams::new(message), None);
+
+    let task = match send(Message::new(Role::User, vec![Part::text("Step 1".to_string())])).await.unwrap() {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+    for (step, expected_len) in [("Step 2", 2), ("Step 3", 3)] {
+        let follow_up = Message::new(Role::User, vec![Part::text(step.to_string())])
+            .with_task_id(task.id.to_string())
+            .with_context_id(task.context_id.clone());
+        let continued = match send(follow_up).await.unwrap() {
+            MessageSendResult::Task(task) => task,
+            MessageSendResult::Message(_) => panic!("Expected Task result"),
+        };
+        assert_eq!(continued.id, task.id);
+        assert_eq!(continued.history.as_ref().unwrap().len(), expected_len);
+    }
+
+    let wrong_context = Message::new(Role::User, vec![Part::text("Step 4".to_string())])
+        .with_task_id(task.id.to_string())
+        .with_context_id("other-context".to_string());
+    assert!(matches!(send(wrong_context).await, Err(a2a_rust::A2AError::InvalidParams(_))));
+
+    let unknown = Message::new(Role::User, vec![Part::text("Hello".to_string())])
+        .with_task_id("missing-task".to_string());
+    assert!(matches!(send(unknown).await, Err(a2a_rust::A2AError::TaskNotFound(_))));
+}
+
+/// Sends a message and then replays it with the same id in the same context
+async fn send_duplicate_message(
+    policy: DuplicateMessagePolicy,
+) -> (Task, Result<MessageSendResult, a2a_rust::A2AError>) {
+    send_duplicate_message_with_text(policy, "Hello").await
+}
+
+/// Sends a message and then one with the same id and `replay_text` in the same context
+async fn send_duplicate_message_with_text(
+    policy: DuplicateMessagePolicy,
+    replay_text: &str,
+) -> (Task, Result<MessageSendResult, a2a_rust::A2AError>) {
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_duplicate_message_policy(policy);
+
+    let message = Message::new(Role::User, vec![Part::text("Hello".to_string())])
+        .with_message_id("msg-1".to_string());
+    let task = match handler
+        .on_message_send(MessageSendParams::new(message.clone()), None)
+        .await
+        .unwrap()
+    {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+
+    let mut replay = message;
+    replay.parts = vec![Part::text(replay_text.to_string())];
+    let replay = replay
+        .with_task_id(task.id.to_string())
+        .with_context_id(task.context_id.clone());
+    let result = handler.on_message_send(MessageSendParams::new(replay), None).await;
+    (task, result)
+}
+
+#[tokio::test]
+async fn test_duplicate_message_rejected() {
+    let (_, result) = send_duplicate_message(DuplicateMessagePolicy::Reject).await;
+
+    let error = result.unwrap_err();
+    assert_eq!(error.code(), a2a_rust::a2a::jsonrpc::standard_error_codes::INVALID_PARAMS);
+    assert!(error.message().contains("msg-1"));
+}
+
+#[tokio::test]
+async fn test_duplicate_message_ignored() {
+    let (task, result) = send_duplicate_message(DuplicateMessagePolicy::Ignore).await;
+
+    match result.unwrap() {
+        MessageSendResult::Task(replayed) => {
+            assert_eq!(replayed.id, task.id);
+            assert_eq!(replayed.history.unwrap().len(), 1);
+        }
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    }
+
+    // Only a retry with the same content is ignored
+    let (_, result) =
+        send_duplicate_message_with_text(DuplicateMessagePolicy::Ignore, "Goodbye").await;
+    assert!(matches!(result, Err(a2a_rust::A2AError::InvalidParams(_))));
+}
+
+/// Agent that takes a while before completing the task
+struct SlowExecutor {
+    delay: Duration,
+}
+
+#[async_trait]
+impl AgentExecutor for SlowExecutor {
+    async fn execute(
+        &self,
+        context: RequestContext,
+        event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        tokio::time::sleep(self.delay).await;
+        event_queue
+            .enqueue_event(QueueEvent::TaskStatusUpdate(TaskStatusUpdateEvent::new(
+                context.task_id.clone().unwrap(),
+                context.context_id.clone().unwrap(),
+                TaskStatus::new(TaskState::Completed),
+                true,
+            )))
+            .await
+    }
+
+    async fn cancel(
+        &self,
+        _context: RequestContext,
+        _event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        Ok(())
+    }
+}
+
+fn slow_handler(delay: Duration) -> DefaultRequestHandler {
+    DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_agent_executor(Arc::new(SlowExecutor { delay }))
+}
+
+fn send_params_with_blocking(blocking: bool) -> MessageSendParams {
+    let message = Message::new(Role::User, vec![Part::text("Take your time".to_string())]);
+    MessageSendParams::new(message)
+        .with_configuration(MessageSendConfiguration::new().with_blocking(blocking))
+}
+
+#[tokio::test]
+async fn test_non_blocking_message_send() {
+    let delay = Duration::from_millis(300);
+    let handler = slow_handler(delay);
+
+    let start = Instant::now();
+    let task = match handler.on_message_send(send_params_with_blocking(false), None).await.unwrap() {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+    assert!(start.elapsed() < delay);
+    assert_eq!(task.status.state, TaskState::Working);
+
+    // Resubscribing follows the background execution to its final event
+    let events: Vec<_> = handler
+        .on_resubscribe_to_task(TaskIdParams::new(task.id.to_string()), None)
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    match events.last() {
+        Some(Ok(Event::TaskStatusUpdate(update))) => {
+            assert!(update.r#final);
+            assert_eq!(update.status.state, TaskState::Completed);
+        }
+        other => panic!("Expected final status update, got {:?}", other),
+    }
+
+    let completed = handler
+        .on_get_task(TaskQueryParams::new(task.id.to_string()), None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(completed.status.state, TaskState::Completed);
+}
+
+#[tokio::test]
+async fn test_blocking_message_send_waits_for_completion() {
+    let handler = slow_handler(Duration::from_millis(50));
+
+    match handler.on_message_send(send_params_with_blocking(true), None).await.unwrap() {
+        MessageSendResult::Task(task) => assert_eq!(task.status.state, TaskState::Completed),
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    }
+}
+
+#[tokio::test]
+async fn test_deadline_header_aborts_slow_execution() {
+    let handler = Arc::new(slow_handler(Duration::from_secs(5)));
+    let server = A2AServerBuilder::new()
+        .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+        .with_request_handler(handler.clone())
+        .with_context_builder(Arc::new(DefaultServerCallContextBuilder))
+        .with_config(ServerConfig {
+            task_id_generator: Arc::new(SequentialIDGenerator::new()),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+    let router: Router = server.build_router().await;
+
+    let jsonrpc_request = json!({
+        "jsonrpc": "2.0",
+        "method": "message/send",
+        "params": {
+            "message": {
+                "kind": "message",
+                "messageId": "msg-deadline",
+                "role": "user",
+                "parts": [{"kind": "text", "text": "Take your time"}]
+            }
+        },
+        "id": 1
+    });
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(DEFAULT_RPC_URL)
+        .header("content-type", "application/json")
+        .header(DEADLINE_HEADER, "200")
+        .body(Body::from(serde_json::to_string(&jsonrpc_request).unwrap()))
+        .unwrap();
+
+    let start = Instant::now();
+    let response: Response = router.oneshot(request).await.unwrap();
+    let elapsed = start.elapsed();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert!(elapsed >= Duration::from_millis(200), "answered after {:?}", elapsed);
+    assert!(elapsed < Duration::from_secs(2), "answered after {:?}", elapsed);
+    assert_eq!(response_json["error"]["code"], -32603);
+    assert!(response_json["error"]["message"].as_str().unwrap().contains("Deadline exceeded"));
+
+    // The agent was stopped and its task canceled
+    let task = handler
+        .on_get_task(TaskQueryParams::new("1".to_string()), None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(task.status.state, TaskState::Canceled);
+}
+
+#[tokio::test]
+async fn test_deadline_cancels_streamed_and_background_runs() {
+    let handler = slow_handler(Duration::from_secs(5));
+    let mut context = ServerCallContext::new();
+    context.deadline = Some(Instant::now() + Duration::from_millis(100));
+
+    let start = Instant::now();
+    let events: Vec<_> = handler
+        .on_message_send_stream(send_params_with_blocking(true), Some(&context))
+        .await
+        .unwrap()
+        .collect()
+        .await;
+    assert!(start.elapsed() < Duration::from_secs(2), "stream ended after {:?}", start.elapsed());
+    match events.last() {
+        Some(Ok(Event::TaskStatusUpdate(update))) => {
+            assert_eq!(update.status.state, TaskState::Canceled);
+            assert!(update.r#final);
+        }
+        other => panic!("Expected a final canceled status, got {:?}", other),
+    }
+
+    context.deadline = Some(Instant::now() + Duration::from_millis(100));
+    let task = match handler.on_message_send(send_params_with_blocking(false), Some(&context)).await.unwrap() {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+    assert_eq!(task.status.state, TaskState::Working);
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    let task = handler.on_get_task(TaskQueryParams::new(task.id.to_string()), None).await.unwrap().unwrap();
+    assert_eq!(task.status.state, TaskState::Canceled);
+}
+
+/// Identifies callers by the `x-user` header
+struct HeaderUserContextBuilder;
+
+#[async_trait]
+impl ServerCallContextBuilder for HeaderUserContextBuilder {
+    async fn build(&self, headers: &axum::http::HeaderMap) -> ServerCallContext {
+        match headers.get("x-user").and_then(|value| value.to_str().ok()) {
+            Some(user) => ServerCallContext::with_user(a2a_rust::a2a::auth::user::AuthenticatedUser::new(user.to_string())),
+            None => ServerCallContext::new(),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_task_metrics_endpoint() {
+    let delay = Duration::from_millis(20);
+    let metrics = TaskMetrics::new();
+    let handler = Arc::new(slow_handler(delay).with_task_metrics(metrics.clone()));
+    let server = A2AServerBuilder::new()
+        .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+        .with_request_handler(handler.clone())
+        .with_context_builder(Arc::new(HeaderUserContextBuilder))
+        .with_task_metrics(metrics)
+        .build()
+        .unwrap();
+    let router: Router = server.build_router().await;
+
+    let task = match handler.on_message_send(send_params_with_blocking(true), None).await.unwrap() {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+    assert_eq!(task.status.state, TaskState::Completed);
+
+    // Anonymous callers cannot read the timings
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(TASK_METRICS_PATH.replace(":task_id", &task.id))
+        .body(Body::empty())
+        .unwrap();
+    let response: Response = router.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(TASK_METRICS_PATH.replace(":task_id", &task.id))
+        .header("x-user", "operator")
+        .body(Body::empty())
+        .unwrap();
+    let response: Response = router.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let timings: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    // The slow agent publishes a single, terminal event after its delay
+    assert_eq!(timings["task_id"], task.id.as_str());
+    assert_eq!(timings["event_count"], 1);
+    let first_event_ms = timings["first_event_ms"].as_f64().unwrap();
+    let total_ms = timings["total_ms"].as_f64().unwrap();
+    assert!(first_event_ms >= delay.as_millis() as f64, "first event after {}ms", first_event_ms);
+    assert!(total_ms >= first_event_ms);
+
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(TASK_METRICS_PATH.replace(":task_id", "unknown"))
+        .header("x-user", "operator")
+        .body(Body::empty())
+        .unwrap();
+    let response: Response = router.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_dropped_stream_keeps_task_running() {
+    let handler = slow_handler(Duration::from_millis(100));
+
+    let mut stream = handler
+        .on_message_send_stream(send_params_with_blocking(false), None)
+        .await
+        .unwrap();
+    let task = match stream.next().await {
+        Some(Ok(Event::Task(task))) => task,
+        other => panic!("Expected initial task, got {:?}", other),
+    };
+    assert_eq!(task.status.state, TaskState::Working);
+
+    // Closing the stream detaches the client without canceling the task
+    drop(stream);
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    loop {
+        let current = handler
+            .on_get_task(TaskQueryParams::new(task.id.to_string()), None)
+            .await
+            .unwrap()
+            .unwrap();
+        match current.status.state {
+            TaskState::Completed => break,
+            TaskState::Working => assert!(Instant::now() < deadline, "task never completed"),
+            other => panic!("Expected the task to keep running, got {:?}", other),
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+}
+
+/// Agent that streams one artifact chunk, then waits to be released before completing
+struct ChunkedExecutor {
+    release: Arc<tokio::sync::Notify>,
+}
+
+#[async_trait]
+impl AgentExecutor for ChunkedExecutor {
+    async fn execute(
+        &self,
+        context: RequestContext,
+        event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        let task_id = context.task_id.clone().unwrap();
+        let context_id = context.context_id.clone().unwrap();
+        let chunk = a2a_rust::a2a::utils::artifact::new_text_artifact("report".to_string(), "partial".to_string(), None);
+        event_queue
+            .enqueue_event(QueueEvent::TaskArtifactUpdate(TaskArtifactUpdateEvent::new(
+                task_id.clone(),
+                context_id.clone(),
+                chunk,
+            )))
+            .await?;
+
+        self.release.notified().await;
+        event_queue
+            .enqueue_event(QueueEvent::TaskStatusUpdate(TaskStatusUpdateEvent::new(
+                task_id,
+                context_id,
+                TaskStatus::new(TaskState::Completed),
+                true,
+            )))
+            .await
+    }
+
+    async fn cancel(
+        &self,
+        _context: RequestContext,
+        _event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_get_task_returns_live_snapshot() {
+    let release = Arc::new(tokio::sync::Notify::new());
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_agent_executor(Arc::new(ChunkedExecutor { release: release.clone() }));
+
+    let task = match handler.on_message_send(send_params_with_blocking(false), None).await.unwrap() {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+
+    let get_task = || handler.on_get_task(TaskQueryParams::new(task.id.to_string()), None);
+    let mut snapshot = get_task().await.unwrap().unwrap();
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while snapshot.artifacts.is_none() && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        snapshot = get_task().await.unwrap().unwrap();
+    }
+
+    // The partial artifact is visible while the agent is still working
+    assert_eq!(snapshot.status.state, TaskState::Working);
+    assert_eq!(snapshot.history.as_ref().map(Vec::len), Some(1));
+    let artifacts = snapshot.artifacts.unwrap();
+    assert_eq!(artifacts.len(), 1);
+    assert_eq!(artifacts[0].name.as_deref(), Some("report"));
+
+    release.notify_one();
+    let mut completed = get_task().await.unwrap().unwrap();
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while completed.status.state != TaskState::Completed && Instant::now() < deadline {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        completed = get_task().await.unwrap().unwrap();
+    }
+    assert_eq!(completed.status.state, TaskState::Completed);
+    assert_eq!(completed.artifacts.unwrap().len(), 1);
+}
+
+/// Agent that asks for a file on the first turn and records what it sees on each turn
+struct FileRequestingExecutor {
+    seen: Arc<std::sync::Mutex<Vec<RequestContext>>>,
+}
+
+#[async_trait]
+impl AgentExecutor for FileRequestingExecutor {
+    async fn execute(
+        &self,
+        context: RequestContext,
+        event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        let first_turn = context.current_task.is_none();
+        let task_id = context.task_id.clone().unwrap();
+        let context_id = context.context_id.clone().unwrap();
+        self.seen.lock().unwrap().push(context);
+
+        let status = if first_turn {
+            let prompt = Message::new(Role::Agent, vec![Part::text("Please attach the invoice".to_string())]);
+            TaskStatus::new(TaskState::InputRequired).with_message(prompt)
+        } else {
+            TaskStatus::new(TaskState::Completed)
+        };
+        event_queue
+            .enqueue_event(QueueEvent::TaskStatusUpdate(TaskStatusUpdateEvent::new(
+                task_id, context_id, status, true,
+            )))
+            .await
+    }
+
+    async fn cancel(
+        &self,
+        _context: RequestContext,
+        _event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        Ok(())
+    }
+}
+
+fn file_bytes_of(message: &Message) -> Vec<String> {
+    use a2a_rust::a2a::core_types::{FileContent, PartRoot};
+
+    message
+        .parts
+        .iter()
+        .filter_map(|part| match part.root() {
+            PartRoot::File(file) => match &file.file {
+                FileContent::Bytes(bytes) => Some(bytes.bytes.clone()),
+                FileContent::Uri(_) => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_resume_with_file_attachment() {
+    let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let task_store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(task_store.clone(), None, None)
+        .with_agent_executor(Arc::new(FileRequestingExecutor { seen: seen.clone() }));
+
+    let first = Message::new(Role::User, vec![Part::text("Process my expense".to_string())]);
+    let task = match handler.on_message_send(MessageSendParams::new(first), None).await.unwrap() {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+    assert_eq!(task.status.state, TaskState::InputRequired);
+
+    let follow_up = Message::new(
+        Role::User,
+        vec![Part::text("Here it is".to_string()), Part::file_bytes("aW52b2ljZQ==".to_string())],
+    )
+    .with_task_id(task.id.to_string());
+    let completed = match handler.on_message_send(MessageSendParams::new(follow_up), None).await.unwrap() {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+    assert_eq!(completed.status.state, TaskState::Completed);
+
+    // On resume the agent sees the new file both in the message and in the task history
+    let seen = seen.lock().unwrap();
+    assert_eq!(seen.len(), 2);
+    let resumed = &seen[1];
+    assert_eq!(file_bytes_of(resumed.message().unwrap()), vec!["aW52b2ljZQ==".to_string()]);
+    let history = resumed.current_task.as_ref().unwrap().history.clone().unwrap();
+    let roles: Vec<Role> = history.iter().map(|message| message.role.clone()).collect();
+    assert_eq!(roles, vec![Role::User, Role::Agent, Role::User]);
+    assert_eq!(file_bytes_of(history.last().unwrap()), vec!["aW52b2ljZQ==".to_string()]);
+
+    // The file part is kept in the stored history
+    let stored = task_store.get(&task.id).await.unwrap().unwrap();
+    let stored_files: Vec<String> = stored.history.unwrap().iter().flat_map(file_bytes_of).collect();
+    assert_eq!(stored_files, vec!["aW52b2ljZQ==".to_string()]);
+}
+
+#[tokio::test]
+async fn test_cancel_tasks_by_context() {
+    use a2a_rust::a2a::auth::user::AuthenticatedUser;
+    use a2a_rust::a2a::jsonrpc::standard_error_codes::INVALID_REQUEST;
+
+    let store = Arc::new(InMemoryTaskStore::new());
+    let task = |id: &str, context_id: &str, state: TaskState| {
+        let mut task = Task::new(context_id.to_string(), TaskStatus::new(state)).with_task_id(id.to_string());
+        task.metadata = Some([(TASK_OWNER_METADATA_KEY.to_string(), json!("operator"))].into());
+        task
+    };
+    for task in [
+        task("active-1", "ctx-1", TaskState::Working),
+        task("active-2", "ctx-1", TaskState::InputRequired),
+        task("done", "ctx-1", TaskState::Completed),
+        task("other", "ctx-2", TaskState::Working),
+    ] {
+        store.save(task).await.unwrap();
+    }
+    let handler = DefaultRequestHandler::new(store.clone(), None, None);
+
+    let anonymous = ServerCallContext::new();
+    let error = handler
+        .on_cancel_tasks_by_context(ContextIdParams::new("ctx-1".to_string()), Some(&anonymous))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code(), INVALID_REQUEST);
+    assert_eq!(store.get("active-1").await.unwrap().unwrap().status.state, TaskState::Working);
+
+    let intruder = ServerCallContext::with_user(AuthenticatedUser::new("intruder".to_string()));
+    let error = handler
+        .on_cancel_tasks_by_context(ContextIdParams::new("ctx-1".to_string()), Some(&intruder))
+        .await
+        .unwrap_err();
+    assert_eq!(error.code(), INVALID_REQUEST);
+    assert_eq!(store.get("active-2").await.unwrap().unwrap().status.state, TaskState::InputRequired);
+
+    let operator = ServerCallContext::with_user(AuthenticatedUser::new("operator".to_string()));
+    let mut canceled = handler
+        .on_cancel_tasks_by_context(ContextIdParams::new("ctx-1".to_string()), Some(&operator))
+        .await
+        .unwrap();
+    canceled.sort();
+    assert_eq!(canceled, vec!["active-1".to_string(), "active-2".to_string()]);
+
+    let state_of = |id: &'static str| {
+        let store = store.clone();
+        async move { store.get(id).await.unwrap().unwrap().status.state }
+    };
+    assert_eq!(state_of("active-1").await, TaskState::Canceled);
+    assert_eq!(state_of("active-2").await, TaskState::Canceled);
+    assert_eq!(state_of("done").await, TaskState::Completed);
+    assert_eq!(state_of("other").await, TaskState::Working);
+
+    // Tasks record the authenticated user who created them as their owner
+    let message = Message::new(Role::User, vec![Part::text("Hello".to_string())]);
+    let MessageSendResult::Task(created) = handler
+        .on_message_send(MessageSendParams::new(message), Some(&operator))
+        .await
+        .unwrap()
+    else {
+        panic!("expected a task");
+    };
+    assert_eq!(created.metadata.unwrap()[TASK_OWNER_METADATA_KEY], json!("operator"));
+}
+
+#[tokio::test]
+async fn test_task_reaper_fails_stale_tasks_in_background() {
+    let task_store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(task_store.clone(), None, None);
+
+    let mut status = TaskStatus::new(TaskState::Working);
+    status.timestamp = Some((chrono::Utc::now() - chrono::Duration::hours(1)).to_rfc3339());
+    let task = Task::new("ctx-1".to_string(), status).with_task_id("crashed".to_string());
+    task_store.save(task).await.unwrap();
+
+    let reaper = handler
+        .task_reaper(Duration::from_secs(600))
+        .with_interval(Duration::from_millis(20))
+        .spawn();
+    let deadline = Instant::now() + Duration::from_secs(2);
+    let state = loop {
+        let state = task_store.get("crashed").await.unwrap().unwrap().status.state;
+        if state != TaskState::Working || Instant::now() > deadline {
+            break state;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    };
+    reaper.abort();
+    assert_eq!(state, TaskState::Failed);
+}
+
+/// Executor that asks for authentication on the first message and rejects follow-ups
+struct GatekeeperExecutor;
+
+#[async_trait]
+impl AgentExecutor for GatekeeperExecutor {
+    async fn execute(
+        &self,
+        context: RequestContext,
+        event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        use a2a_rust::a2a::server::agent_execution::{reject_task, require_auth};
+
+        let task_id = context.task_id.clone().unwrap();
+        let context_id = context.context_id.clone().unwrap();
+        if context.current_task.is_some() {
+            reject_task(event_queue.as_ref(), &task_id, &context_id, "Credentials not accepted").await
+        } else {
+            require_auth(event_queue.as_ref(), &task_id, &context_id, "oauth").await
+        }
+    }
+
+    async fn cancel(
+        &self,
+        _context: RequestContext,
+        _event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_auth_required_then_rejected() {
+    use a2a_rust::a2a::server::agent_execution::required_auth_scheme;
+
+    let task_store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(task_store.clone(), None, None)
+        .with_agent_executor(Arc::new(GatekeeperExecutor));
+
+    let message = Message::new(Role::User, vec![Part::text("Read my calendar".to_string())]);
+    let task = match handler.on_message_send(MessageSendParams::new(message), None).await.unwrap() {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+    assert_eq!(task.status.state, TaskState::AuthRequired);
+    let stored = task_store.get(&task.id).await.unwrap().unwrap();
+    assert_eq!(required_auth_scheme(&stored.status), Some("oauth"));
+
+    // The paused task can be resumed, and the rejection ends it
+    let follow_up = Message::new(Role::User, vec![Part::text("Here is my token".to_string())])
+        .with_task_id(task.id.to_string())
+        .with_context_id(task.context_id.clone());
+    let task = match handler.on_message_send(MessageSendParams::new(follow_up), None).await.unwrap() {
+        MessageSendResult::Task(task) => task,
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    };
+    assert_eq!(task.status.state, TaskState::Rejected);
+    assert_eq!(required_auth_scheme(&task.status), None);
+}
+
+/// Agent that answers with a direct message instead of working on a task
+struct DirectReplyExecutor;
+
+#[async_trait]
+impl AgentExecutor for DirectReplyExecutor {
+    async fn execute(
+        &self,
+        context: RequestContext,
+        event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        let reply = Message::new(Role::Agent, vec![Part::text(format!("Reply to: {}", context.get_user_input(" ")))]);
+        event_queue.enqueue_event(QueueEvent::Message(reply)).await
+    }
+
+    async fn cancel(
+        &self,
+        _context: RequestContext,
+        _event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_message_stream_with_direct_message_reply() {
+    let task_store = Arc::new(InMemoryTaskStore::new());
+    let handler = DefaultRequestHandler::new(task_store.clone(), None, None)
+        .with_agent_executor(Arc::new(DirectReplyExecutor));
+    let params = MessageSendParams::new(Message::new(Role::User, vec![Part::text("hi".to_string())]));
+
+    // A direct reply leaves no task behind
+    let result = handler.on_message_send(params.clone(), None).await.unwrap();
+    assert!(matches!(result, MessageSendResult::Message(_)));
+    assert!(task_store.list().await.unwrap().is_empty());
+
+    let events: Vec<Event> = handler
+        .on_message_send_stream(params, None)
+        .await
+        .unwrap()
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+
+    match events.as_slice() {
+        [Event::Message(reply)] => {
+            assert_eq!(a2a_rust::a2a::utils::message::get_message_text(reply, ""), "Reply to: hi");
+        }
+        other => panic!("Expected a single message event, got {:?}", other),
+    }
+    assert!(task_store.list().await.unwrap().is_empty());
+}
+
+/// Agent that streams a text artifact holding sensitive data, then completes
+struct SensitiveArtifactExecutor;
+
+#[async_trait]
+impl AgentExecutor for SensitiveArtifactExecutor {
+    async fn execute(
+        &self,
+        context: RequestContext,
+        event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        let task_id = context.task_id.clone().unwrap();
+        let context_id = context.context_id.clone().unwrap();
+        let artifact = Artifact::new(vec![Part::text("Card number 4111 1111 1111 1111".to_string())]);
+        event_queue
+            .enqueue_event(QueueEvent::TaskArtifactUpdate(TaskArtifactUpdateEvent::new(
+                task_id.clone(),
+                context_id.clone(),
+                artifact,
+            )))
+            .await?;
+        event_queue
+            .enqueue_event(QueueEvent::TaskStatusUpdate(TaskStatusUpdateEvent::new(
+                task_id,
+                context_id,
+                TaskStatus::new(TaskState::Completed),
+                true,
+            )))
+            .await
+    }
+
+    async fn cancel(
+        &self,
+        _context: RequestContext,
+        _event_queue: Arc<dyn EventQueue>,
+    ) -> Result<(), a2a_rust::A2AError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_event_transform_redacts_streamed_artifacts() {
+    use a2a_rust::a2a::client::client_trait::ClientTransport;
+    use a2a_rust::a2a::client::transports::jsonrpc::JsonRpcTransport;
+    use a2a_rust::a2a::core_types::PartRoot;
+
+    let redact = |event: Event, _ctx: &ServerCallContext| match event {
+        Event::TaskArtifactUpdate(mut update) => {
+            for part in update.artifact.parts.iter_mut() {
+                if matches!(part.root(), PartRoot::Text(_)) {
+                    *part = Part::text("[REDACTED]".to_string());
+                }
+            }
+            Some(Event::TaskArtifactUpdate(update))
+        }
+        other => Some(other),
+    };
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}{}", listener.local_addr().unwrap(), DEFAULT_RPC_URL);
+    let mut agent_card = AgentCard::test_default("Redacting Agent", &url);
+    agent_card.capabilities = AgentCapabilities::new().with_streaming(true);
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_agent_executor(Arc::new(SensitiveArtifactExecutor));
+    let server = A2AServerBuilder::new()
+        .with_agent_card(agent_card.clone())
+        .with_request_handler(Arc::new(handler))
+        .with_context_builder(Arc::new(DefaultServerCallContextBuilder))
+        .with_event_transform(Arc::new(redact))
+        .build()
+        .unwrap();
+    let router = server.build_router().await;
+    tokio::spawn(async move { axum::serve(listener, router).await });
+
+    let transport = JsonRpcTransport::new(url, Some(agent_card)).unwrap();
+    let params = MessageSendParams::new(Message::new(Role::User, vec![Part::text("pay".to_string())]));
+    let events: Vec<TaskOrMessage> = transport
+        .send_message_streaming(params, None, None)
+        .await
+        .unwrap()
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+
+    let texts: Vec<String> = events
+        .iter()
+        .filter_map(|event| match event {
+            TaskOrMessage::TaskArtifactUpdateEvent(update) => {
+                Some(a2a_rust::a2a::utils::artifact::get_artifact_text(&update.artifact, ""))
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(texts, vec!["[REDACTED]".to_string()]);
+    assert!(matches!(events.last(), Some(TaskOrMessage::TaskUpdate(update)) if update.r#final));
+}
+
+#[tokio::test]
+async fn test_resubscribe_is_served_as_sse() {
+    use a2a_rust::a2a::client::client_trait::ClientTransport;
+    use a2a_rust::a2a::client::transports::jsonrpc::JsonRpcTransport;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}{}", listener.local_addr().unwrap(), DEFAULT_RPC_URL);
+    let mut agent_card = AgentCard::test_default("Streaming Agent", &url);
+    agent_card.capabilities = AgentCapabilities::new().with_streaming(true);
+    let store = Arc::new(InMemoryTaskStore::new());
+    let task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working));
+    store.save(task.clone()).await.unwrap();
+    let server = A2AServerBuilder::new()
+        .with_agent_card(agent_card.clone())
+        .with_request_handler(Arc::new(DefaultRequestHandler::new(store, None, None)))
+        .with_context_builder(Arc::new(DefaultServerCallContextBuilder))
+        .build()
+        .unwrap();
+    let router = server.build_router().await;
+    tokio::spawn(async move { axum::serve(listener, router).await });
+
+    // A task without a running agent only reports its current state
+    let transport = JsonRpcTransport::new(url, Some(agent_card)).unwrap();
+    let events: Vec<_> = transport
+        .resubscribe(TaskIdParams::new(task.id.to_string()), None, None)
+        .await
+        .unwrap()
+        .map(|event| event.unwrap())
+        .collect()
+        .await;
+    match events.as_slice() {
+        [(resubscribed, None)] => assert_eq!(resubscribed.id, task.id),
+        other => panic!("Expected the current task, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_cancel_records_state_transition() {
+    let store = Arc::new(InMemoryTaskStore::new());
+    let capabilities = AgentCapabilities::new().with_state_transition_history(true);
+    let handler = DefaultRequestHandler::new(store.clone(), None, None).with_capabilities(&capabilities);
+
+    let mut task = Task::new("ctx-1".to_string(), TaskStatus::new(TaskState::Working));
+    task.set_status_history(vec![
+        TaskStateTransition::from_status(&TaskStatus::new(TaskState::Submitted)),
+        TaskStateTransition::from_status(&task.status),
+    ]);
+    store.save(task.clone()).await.unwrap();
+
+    let canceled = handler.on_cancel_task(TaskIdParams::new(task.id.to_string()), None).await.unwrap().unwrap();
+    let states: Vec<TaskState> = canceled.status_history().unwrap().into_iter().map(|transition| transition.state).collect();
+    assert_eq!(states, vec![TaskState::Submitted, TaskState::Working, TaskState::Canceled]);
+    assert_eq!(store.get(&task.id).await.unwrap().unwrap().status_history(), canceled.status_history());
+}
+
+#[tokio::test]
+async fn test_cancel_aborts_running_agent() {
+    let handler = Arc::new(
+        slow_handler(Duration::from_secs(30))
+            .with_id_generators(Arc::new(SequentialIDGenerator::new()), Arc::new(SequentialIDGenerator::new())),
+    );
+    let sending = handler.clone();
+    let send = tokio::spawn(async move { sending.on_message_send(send_params_with_blocking(true), None).await });
+    tokio::time::sleep(Duration::from_millis(100)).await;
+
+    let canceled = handler.on_cancel_task(TaskIdParams::new("1".to_string()), None).await.unwrap().unwrap();
+    assert_eq!(canceled.status.state, TaskState::Canceled);
+
+    // The blocking send ends with the canceled task instead of waiting for the agent
+    let result = tokio::time::timeout(Duration::from_secs(5), send).await.expect("agent run was not aborted");
+    match result.unwrap().unwrap() {
+        MessageSendResult::Task(task) => assert_eq!(task.status.state, TaskState::Canceled),
+        MessageSendResult::Message(_) => panic!("Expected Task result"),
+    }
+    let stored = handler.on_get_task(TaskQueryParams::new("1".to_string()), None).await.unwrap().unwrap();
+    assert_eq!(stored.status.state, TaskState::Canceled);
+
+    let error = handler.on_cancel_task(TaskIdParams::new("1".to_string()), None).await.unwrap_err();
+    assert!(matches!(error, a2a_rust::A2AError::TaskNotCancelable(_)));
+}
+
+#[tokio::test]
+async fn test_message_referencing_tasks_extends_their_chain() {
+    use a2a_rust::a2a::jsonrpc::standard_error_codes::INVALID_PARAMS;
+    use a2a_rust::a2a::server::agent_execution::RELATED_CHAIN_METADATA_KEY;
+
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_max_related_depth(1);
+    let send = |references: Vec<String>| {
+        let mut message = Message::new(Role::User, vec![Part::text("Spawn".to_string())]);
+        message.reference_task_ids = Some(references);
+        handler.on_message_send(MessageSendParams::new(message), None)
+    };
+
+    let MessageSendResult::Task(parent) = send(vec![]).await.unwrap() else {
+        panic!("expected a task");
+    };
+    let MessageSendResult::Task(child) = send(vec![parent.id.to_string()]).await.unwrap() else {
+        panic!("expected a task");
+    };
+    assert_eq!(child.metadata.unwrap()[RELATED_CHAIN_METADATA_KEY], json!([parent.id]));
+
+    let error = send(vec![child.id.to_string()]).await.unwrap_err();
+    assert_eq!(error.code(), INVALID_PARAMS);
+    assert!(error.message().contains("max_related_depth of 1"), "{}", error.message());
+}
+
+/// Sends a chunk of the partial message `message_id` in `context_id`
+async fn send_chunk(
+    handler: &DefaultRequestHandler,
+    message_id: &str,
+    context_id: &str,
+    text: &str,
+) -> Result<MessageSendResult, a2a_rust::A2AError> {
+    let mut chunk = Message::new(Role::User, vec![Part::text(text.to_string())])
+        .with_context_id(context_id.to_string());
+    chunk.message_id = message_id.to_string();
+    chunk.metadata = Some([(a2a_rust::a2a::utils::message::PARTIAL_MESSAGE_METADATA_KEY.to_string(), json!(true))].into());
+    handler.on_message_send(MessageSendParams::new(chunk), None).await
+}
+
+fn accumulated_text(result: Result<MessageSendResult, a2a_rust::A2AError>) -> String {
+    match result.unwrap() {
+        MessageSendResult::Message(message) => a2a_rust::a2a::utils::message::get_message_text(&message, ""),
+        other => panic!("Expected the accumulated message, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_partial_messages_are_scoped_and_bounded() {
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_partial_message_limits(Duration::from_millis(100), 256);
+
+    // The same message ID in another context is a different message
+    assert_eq!(accumulated_text(send_chunk(&handler, "msg-1", "ctx-1", "Hello, ").await), "Hello, ");
+    assert_eq!(accumulated_text(send_chunk(&handler, "msg-1", "ctx-2", "Bye").await), "Bye");
+    assert_eq!(accumulated_text(send_chunk(&handler, "msg-1", "ctx-1", "world").await), "Hello, world");
+
+    // A message idle for longer than the TTL starts over
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_eq!(accumulated_text(send_chunk(&handler, "msg-1", "ctx-1", "again").await), "again");
+
+    // A message growing past the size limit is rejected and dropped
+    let result = send_chunk(&handler, "msg-2", "ctx-1", &"x".repeat(300)).await;
+    assert!(matches!(result, Err(a2a_rust::A2AError::InvalidParams(_))));
+    assert_eq!(accumulated_text(send_chunk(&handler, "msg-2", "ctx-1", "small").await), "small");
+}
+
+#[tokio::test]
+async fn test_message_limits_are_enforced_by_the_handler() {
+    let handler = DefaultRequestHandler::new(Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_message_limits(MessageLimits { max_parts_per_message: Some(1), max_part_bytes: None });
+    let params = |count: usize| {
+        MessageSendParams::new(Message::new(Role::User, (0..count).map(|i| Part::text(i.to_string())).collect()))
+    };
+
+    handler.on_message_send(params(1), None).await.unwrap();
+    let error = handler.on_message_send(params(2), None).await.unwrap_err();
+    assert!(error.message().contains("2 parts"), "{}", error.message());
+    let error = handler.on_message_send_stream(params(2), None).await.err().unwrap();
+    assert!(matches!(error, a2a_rust::A2AError::InvalidParams(_)));
+}
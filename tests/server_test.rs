@@ -5,9 +5,15 @@
 use a2a_rust::a2a::{
     models::*,
     server::{
-        apps::jsonrpc::{A2AServerBuilder, ServerConfig},
+        agent_execution::{AgentExecutor, RequestContext},
+        apps::jsonrpc::{A2AServerBuilder, AgentCardApiKey, ErrorDetailLevel, ServerConfig},
         context::DefaultServerCallContextBuilder,
-        request_handlers::request_handler::MockRequestHandler,
+        events::{Event, EventQueue},
+        health::HealthCheck,
+        recorder::{read_recording, replay, RequestRecorder},
+        id_generator::SequentialIDGenerator,
+        request_handlers::{request_handler::MockRequestHandler, DefaultRequestHandler},
+        tasks::InMemoryTaskStore,
     },
     utils::constants::*,
 };
@@ -17,12 +23,14 @@ use axum::{
     response::Response,
     Router,
 };
+use a2a_rust::{A2AError, Message, Part, Role};
+use async_trait::async_trait;
 use serde_json::json;
 use tower::util::ServiceExt;
 
 #[tokio::test]
 async fn test_server_builder() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = std::sync::Arc::new(MockRequestHandler::new());
     let context_builder = std::sync::Arc::new(DefaultServerCallContextBuilder);
 
@@ -37,7 +45,7 @@ async fn test_server_builder() {
 
 #[tokio::test]
 async fn test_server_agent_card_endpoint() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = std::sync::Arc::new(MockRequestHandler::new());
     let context_builder = std::sync::Arc::new(DefaultServerCallContextBuilder);
 
@@ -75,9 +83,138 @@ async fn test_server_agent_card_endpoint() {
     assert_eq!(response_json["description"], agent_card.description);
 }
 
+#[tokio::test]
+async fn test_server_agent_card_content_negotiation() {
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
+    let build_router = |enable_yaml_agent_card: bool| {
+        let server = A2AServerBuilder::new()
+            .with_agent_card(agent_card.clone())
+            .with_request_handler(std::sync::Arc::new(MockRequestHandler::new()))
+            .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+            .with_config(ServerConfig { enable_yaml_agent_card, ..Default::default() })
+            .build()
+            .unwrap();
+        async move { server.build_router().await }
+    };
+
+    // YAML is opt-in
+    let router: Router = build_router(false).await;
+    let request = Request::builder().method(Method::GET).uri(AGENT_CARD_WELL_KNOWN_PATH).header("accept", "application/yaml");
+    let response: Response = router.oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+    assert_eq!(response.headers()["content-type"], "application/json");
+
+    let router: Router = build_router(true).await;
+    for (accept, expected) in [
+        (Some("application/yaml"), "application/yaml"),
+        (Some("application/json"), "application/json"),
+        (Some("application/json, application/yaml;q=0.9"), "application/json"),
+        (Some("*/*"), "application/json"),
+        (None, "application/json"),
+    ] {
+        let mut request = Request::builder().method(Method::GET).uri(AGENT_CARD_WELL_KNOWN_PATH);
+        if let Some(accept) = accept {
+            request = request.header("accept", accept);
+        }
+        let response: Response = router.clone().oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], expected, "Accept: {:?}", accept);
+        assert!(response.headers().get_all("vary").iter().any(|vary| vary == "Accept"));
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let card: AgentCard = if expected == YAML_CONTENT_TYPE {
+            serde_yaml_ng::from_slice(&body).unwrap()
+        } else {
+            serde_json::from_slice(&body).unwrap()
+        };
+        assert_eq!(card, agent_card);
+    }
+}
+
+#[tokio::test]
+async fn test_server_agent_card_etag() {
+    let server = A2AServerBuilder::new()
+        .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+        .with_request_handler(std::sync::Arc::new(MockRequestHandler::new()))
+        .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+        .build()
+        .unwrap();
+    let router: Router = server.build_router().await;
+    let fetch = |if_none_match: Option<&str>| {
+        let mut request = Request::builder().method(Method::GET).uri(AGENT_CARD_WELL_KNOWN_PATH);
+        if let Some(if_none_match) = if_none_match {
+            request = request.header("if-none-match", if_none_match);
+        }
+        router.clone().oneshot(request.body(Body::empty()).unwrap())
+    };
+
+    let response: Response = fetch(None).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let etag = response.headers()["etag"].to_str().unwrap().to_string();
+    assert!(etag.starts_with('"') && etag.ends_with('"'));
+
+    // The same card keeps its tag, so revalidation needs no body
+    for if_none_match in [etag.clone(), format!("W/{}", etag), format!("\"other\", {}", etag), "*".to_string()] {
+        let response: Response = fetch(Some(&if_none_match)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED, "If-None-Match: {}", if_none_match);
+        assert_eq!(response.headers()["etag"], etag.as_str());
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert!(body.is_empty());
+    }
+
+    let response: Response = fetch(Some("\"other\"")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+async fn fetch_card(router: &Router, api_key: Option<&str>) -> (StatusCode, serde_json::Value) {
+    let mut request = Request::builder().method(Method::GET).uri(AGENT_CARD_WELL_KNOWN_PATH);
+    if let Some(api_key) = api_key {
+        request = request.header("X-API-Key", api_key);
+    }
+    let response: Response = router.clone().oneshot(request.body(Body::empty()).unwrap()).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_server_agent_card_api_key() {
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
+    let build_router = |api_key: AgentCardApiKey| {
+        let server = A2AServerBuilder::new()
+            .with_agent_card(agent_card.clone())
+            .with_request_handler(std::sync::Arc::new(MockRequestHandler::new()))
+            .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+            .with_config(ServerConfig { agent_card_api_key: Some(api_key), ..Default::default() })
+            .build()
+            .unwrap();
+        async move { server.build_router().await }
+    };
+
+    let api_key = AgentCardApiKey::new("X-API-Key", "secret");
+    assert!(!format!("{:?}", api_key).contains("secret"));
+    let router = build_router(api_key).await;
+    assert_eq!(fetch_card(&router, None).await.0, StatusCode::UNAUTHORIZED);
+    let request = Request::builder().method(Method::GET).uri(AGENT_CARD_WELL_KNOWN_PATH).body(Body::empty()).unwrap();
+    let response: Response = router.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.headers()["www-authenticate"], "ApiKey header=\"X-API-Key\"");
+    assert_eq!(fetch_card(&router, Some("wrong")).await.0, StatusCode::UNAUTHORIZED);
+    let (status, card) = fetch_card(&router, Some("secret")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(serde_json::from_value::<AgentCard>(card).unwrap(), agent_card);
+
+    // With a public card, callers without the key get the minimal card
+    let mut public_card = agent_card.clone();
+    public_card.description = "Public summary".to_string();
+    let router = build_router(AgentCardApiKey::new("X-API-Key", "secret").with_public_card(public_card.clone())).await;
+    let (status, card) = fetch_card(&router, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(serde_json::from_value::<AgentCard>(card).unwrap(), public_card);
+    assert_eq!(fetch_card(&router, Some("secret")).await.1["description"], agent_card.description);
+}
+
 #[tokio::test]
 async fn test_server_jsonrpc_endpoint() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = std::sync::Arc::new(MockRequestHandler::new());
     let context_builder = std::sync::Arc::new(DefaultServerCallContextBuilder);
 
@@ -138,7 +275,7 @@ async fn test_server_jsonrpc_endpoint() {
 
 #[tokio::test]
 async fn test_server_jsonrpc_error_handling() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = std::sync::Arc::new(MockRequestHandler::new());
     let context_builder = std::sync::Arc::new(DefaultServerCallContextBuilder);
 
@@ -180,7 +317,7 @@ async fn test_server_jsonrpc_error_handling() {
 
 #[tokio::test]
 async fn test_server_jsonrpc_method_not_found() {
-    let agent_card = create_test_agent_card();
+    let agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     let request_handler = std::sync::Arc::new(MockRequestHandler::new());
     let context_builder = std::sync::Arc::new(DefaultServerCallContextBuilder);
 
@@ -227,9 +364,43 @@ async fn test_server_jsonrpc_method_not_found() {
     assert_eq!(response_json["error"]["code"], -32601); // Method not found
 }
 
+#[tokio::test]
+async fn test_server_jsonrpc_version_validation() {
+    let server = A2AServerBuilder::new()
+        .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+        .with_request_handler(std::sync::Arc::new(MockRequestHandler::new()))
+        .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+        .build()
+        .unwrap();
+    let router: Router = server.build_router().await;
+
+    let cases = [
+        (json!({"jsonrpc": "1.0", "method": "tasks/get", "params": {"id": "task-1"}, "id": 1}), -32600),
+        (json!({"method": "tasks/get", "params": {"id": "task-1"}, "id": 2}), -32600),
+        // A valid request reaches the handler, which does not know the task
+        (json!({"jsonrpc": "2.0", "method": "tasks/get", "params": {"id": "task-1"}, "id": 3}), -32001),
+    ];
+
+    for (jsonrpc_request, expected_code) in cases {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(DEFAULT_RPC_URL)
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_string(&jsonrpc_request).unwrap()))
+            .unwrap();
+
+        let response: Response = router.clone().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response_json["id"], jsonrpc_request["id"]);
+        assert_eq!(response_json["error"]["code"], expected_code);
+    }
+}
+
 #[tokio::test]
 async fn test_server_extended_agent_card_endpoint() {
-    let mut agent_card = create_test_agent_card();
+    let mut agent_card = AgentCard::test_default("Test Agent", "http://localhost:8080");
     agent_card.supports_authenticated_extended_card = Some(true);
 
     let extended_card = AgentCard::new(
@@ -281,15 +452,602 @@ async fn test_server_extended_agent_card_endpoint() {
     assert_eq!(response_json["description"], extended_card.description);
 }
 
-fn create_test_agent_card() -> AgentCard {
-    AgentCard::new(
-        "Test Agent".to_string(),
-        "A test agent for testing".to_string(),
-        "http://localhost:8080".to_string(),
-        "1.0.0".to_string(),
-        vec!["text/plain".to_string()],
-        vec!["text/plain".to_string()],
-        AgentCapabilities::new(),
-        vec![],
+/// Dependency stub that always reports itself as unavailable
+struct FailingDependency;
+
+#[async_trait]
+impl HealthCheck for FailingDependency {
+    fn name(&self) -> &str {
+        "failing_dependency"
+    }
+
+    async fn check(&self) -> Result<(), A2AError> {
+        Err(A2AError::internal("dependency down"))
+    }
+}
+
+async fn build_health_router(checks: Vec<std::sync::Arc<dyn HealthCheck>>) -> Router {
+    let config = ServerConfig {
+        bind_addr: "127.0.0.1:0".parse().unwrap(),
+        enable_health: true,
+        ..Default::default()
+    };
+
+    let mut builder = A2AServerBuilder::new()
+        .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+        .with_request_handler(std::sync::Arc::new(MockRequestHandler::new()))
+        .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+        .with_config(config);
+    for check in checks {
+        builder = builder.with_health_check(check);
+    }
+
+    builder.build().unwrap().build_router().await
+}
+
+async fn get_status(router: Router, path: &str) -> StatusCode {
+    let request = Request::builder()
+        .method(Method::GET)
+        .uri(path)
+        .body(Body::empty())
+        .unwrap();
+
+    router.oneshot(request).await.unwrap().status()
+}
+
+#[tokio::test]
+async fn test_server_health_endpoints() {
+    let router = build_health_router(vec![std::sync::Arc::new(InMemoryTaskStore::new())]).await;
+
+    assert_eq!(get_status(router.clone(), HEALTH_PATH).await, StatusCode::OK);
+    assert_eq!(get_status(router, READINESS_PATH).await, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_server_readiness_reports_failing_dependency() {
+    let router = build_health_router(vec![
+        std::sync::Arc::new(InMemoryTaskStore::new()),
+        std::sync::Arc::new(FailingDependency),
+    ])
+    .await;
+
+    assert_eq!(get_status(router.clone(), HEALTH_PATH).await, StatusCode::OK);
+    assert_eq!(get_status(router, READINESS_PATH).await, StatusCode::SERVICE_UNAVAILABLE);
+}
+
+#[tokio::test]
+async fn test_server_health_disabled_by_default() {
+    let server = A2AServerBuilder::new()
+        .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+        .with_request_handler(std::sync::Arc::new(MockRequestHandler::new()))
+        .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+        .build()
+        .unwrap();
+    let router: Router = server.build_router().await;
+
+    assert_eq!(get_status(router, HEALTH_PATH).await, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_server_task_id_generator() {
+    let config = ServerConfig {
+        task_id_generator: std::sync::Arc::new(SequentialIDGenerator::new()),
+        ..Default::default()
+    };
+    let request_handler = DefaultRequestHandler::new(
+        std::sync::Arc::new(InMemoryTaskStore::new()),
+        None,
+        None,
+    );
+    let server = A2AServerBuilder::new()
+        .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+        .with_request_handler(std::sync::Arc::new(request_handler))
+        .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+        .with_config(config)
+        .build()
+        .unwrap();
+    let router: Router = server.build_router().await;
+
+    let mut task_ids = Vec::new();
+    for i in 1..=2 {
+        let jsonrpc_request = json!({
+            "jsonrpc": "2.0",
+            "method": "message/send",
+            "params": {
+                "message": {
+                    "kind": "message",
+                    "messageId": format!("msg-{}", i),
+                    "role": "user",
+                    "parts": [{"kind": "text", "text": "Hello"}]
+                }
+            },
+            "id": i
+        });
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(DEFAULT_RPC_URL)
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_string(&jsonrpc_request).unwrap()))
+            .unwrap();
+
+        let response = router.clone().oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        task_ids.push(response_json["result"]["id"].clone());
+    }
+
+    assert_eq!(task_ids, vec![json!("1"), json!("2")]);
+}
+
+#[tokio::test]
+async fn test_server_propagate_request_metadata() {
+    for propagate in [true, false] {
+        let keys = if propagate {
+            vec!["correlation_id".to_string(), "tenant".to_string()]
+        } else {
+            vec![]
+        };
+        let request_handler = DefaultRequestHandler::new(
+            std::sync::Arc::new(InMemoryTaskStore::new()),
+            None,
+            None,
+        )
+        .with_propagate_request_metadata(keys);
+        let server = A2AServerBuilder::new()
+            .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+            .with_request_handler(std::sync::Arc::new(request_handler))
+            .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+            .build()
+            .unwrap();
+        let router: Router = server.build_router().await;
+
+        let jsonrpc_request = json!({
+            "jsonrpc": "2.0",
+            "method": "message/send",
+            "params": {
+                "message": {
+                    "kind": "message",
+                    "messageId": "msg-1",
+                    "role": "user",
+                    "parts": [{"kind": "text", "text": "Hello"}]
+                },
+                "metadata": {"correlation_id": "req-42", "secret": "not echoed"}
+            },
+            "id": 1
+        });
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(DEFAULT_RPC_URL)
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_string(&jsonrpc_request).unwrap()))
+            .unwrap();
+
+        let response = router.oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        let metadata = &response_json["result"]["metadata"];
+        if propagate {
+            assert_eq!(metadata, &json!({"correlation_id": "req-42"}));
+        } else {
+            assert!(metadata.is_null(), "unexpected metadata: {}", metadata);
+        }
+    }
+}
+
+#[test]
+fn test_error_detail_level_apply() {
+    use a2a_rust::a2a::jsonrpc::{error_codes, standard_error_codes, JSONRPCError};
+
+    let internal = JSONRPCError::new(
+        standard_error_codes::INTERNAL_ERROR,
+        "connection refused: postgres://db:5432".to_string(),
     )
+    .with_data(json!({"backtrace": "store.rs:42"}));
+    let not_found = JSONRPCError::new(error_codes::TASK_NOT_FOUND, "Task not found: task-1".to_string())
+        .with_data(json!({"task_id": "task-1"}));
+
+    assert_eq!(ErrorDetailLevel::Full.apply(&internal), internal);
+    assert_eq!(ErrorDetailLevel::Full.apply(&not_found), not_found);
+
+    let message_only = ErrorDetailLevel::MessageOnly.apply(&internal);
+    assert_eq!(message_only.message, internal.message);
+    assert_eq!(message_only.data, None);
+
+    let minimal = ErrorDetailLevel::Minimal.apply(&internal);
+    assert_eq!(minimal.code, standard_error_codes::INTERNAL_ERROR);
+    assert_eq!(minimal.message, "Internal error");
+    assert_eq!(minimal.data, None);
+
+    // A2A-specific errors keep their message, which describes the request
+    let minimal = ErrorDetailLevel::Minimal.apply(&not_found);
+    assert_eq!(minimal.message, "Task not found: task-1");
+    assert_eq!(minimal.data, None);
+}
+
+#[tokio::test]
+async fn test_server_error_detail_level() {
+    for (level, scrubbed) in [(ErrorDetailLevel::Full, false), (ErrorDetailLevel::Minimal, true)] {
+        let config = ServerConfig {
+            error_detail_level: level,
+            ..Default::default()
+        };
+        let server = A2AServerBuilder::new()
+            .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+            .with_request_handler(std::sync::Arc::new(MockRequestHandler::new()))
+            .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+            .with_config(config)
+            .build()
+            .unwrap();
+        let router: Router = server.build_router().await;
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(DEFAULT_RPC_URL)
+            .header("content-type", "application/json")
+            .body(Body::from("{\"jsonrpc\": \"2.0\", \"method\":"))
+            .unwrap();
+        let response: Response = router.oneshot(request).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response_json["error"]["code"], -32700);
+        let message = response_json["error"]["message"].as_str().unwrap();
+        if scrubbed {
+            assert_eq!(message, "Parse error");
+        } else {
+            assert!(message.starts_with("Invalid JSON: EOF"), "unexpected message: {}", message);
+        }
+    }
+}
+
+/// Posts `body` to the JSON-RPC endpoint and returns the parsed response
+async fn post_rpc(content_type: Option<&str>, body: &'static str) -> (StatusCode, serde_json::Value) {
+    let server = A2AServerBuilder::new()
+        .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+        .with_request_handler(std::sync::Arc::new(MockRequestHandler::new()))
+        .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+        .build()
+        .unwrap();
+    let router: Router = server.build_router().await;
+
+    let mut request = Request::builder().method(Method::POST).uri(DEFAULT_RPC_URL);
+    if let Some(content_type) = content_type {
+        request = request.header("content-type", content_type);
+    }
+    let response: Response = router.oneshot(request.body(Body::from(body)).unwrap()).await.unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_server_rejects_non_json_content_type() {
+    let body = r#"{"jsonrpc": "2.0", "id": 1, "method": "tasks/get", "params": {"id": "task-1"}}"#;
+
+    for content_type in [Some("text/plain"), None] {
+        let (status, response_json) = post_rpc(content_type, body).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(response_json["jsonrpc"], "2.0");
+        assert!(response_json["id"].is_null());
+        assert_eq!(response_json["error"]["code"], -32600); // Invalid request
+    }
+
+    // The mock handler knows no tasks, so an accepted request fails with TaskNotFound
+    let (_, response_json) = post_rpc(Some("application/json; charset=utf-8"), body).await;
+    assert_eq!(response_json["error"]["code"], -32001, "unexpected error: {}", response_json);
+}
+
+#[tokio::test]
+async fn test_server_rejects_non_json_body() {
+    let (status, response_json) = post_rpc(Some("application/json"), "method=tasks/get&id=1").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(response_json["jsonrpc"], "2.0");
+    assert!(response_json["id"].is_null());
+    assert_eq!(response_json["error"]["code"], -32700); // Parse error
+    assert!(response_json["error"]["message"].as_str().unwrap().starts_with("Invalid JSON"));
+}
+
+#[test]
+fn test_agent_card_test_default_is_valid() {
+    let card = AgentCard::test_default("Test Agent", "http://localhost:8080");
+    assert_eq!(card.name, "Test Agent");
+    assert_eq!(card.url, "http://localhost:8080");
+    card.validate().unwrap();
+
+    let skill = AgentSkill::new("echo".to_string(), "Echo".to_string(), "Echoes".to_string(), vec![]);
+    let mut invalid = card;
+    invalid.skills = vec![skill.clone(), skill];
+    invalid.version = String::new();
+    invalid.url = "localhost".to_string();
+    let message = invalid.validate().unwrap_err().message().to_string();
+    assert!(message.contains("version must not be empty"), "{}", message);
+    assert!(message.contains("is not an absolute URL"), "{}", message);
+    assert!(message.contains("skill id \"echo\" is not unique"), "{}", message);
+}
+
+#[test]
+fn test_agent_card_provider_round_trip() {
+    let card = AgentCard::test_default("Test Agent", "http://localhost:8080");
+    assert!(card.provider().is_none());
+
+    let mut card_json = serde_json::to_value(&card).unwrap();
+    card_json["provider"] = json!({"organization": "Example Org", "url": "https://example.com"});
+    let parsed: AgentCard = serde_json::from_value(card_json.clone()).unwrap();
+    let provider = parsed.provider().unwrap();
+    assert_eq!(provider.organization, "Example Org");
+    assert_eq!(provider.url, "https://example.com");
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), card_json);
+
+    let built = card.with_provider(AgentProvider::new("Example Org".to_string(), "https://example.com".to_string()));
+    assert_eq!(built, parsed);
+}
+
+#[test]
+fn test_agent_card_supported_methods_follow_capabilities() {
+    let mut card = AgentCard::test_default("Test Agent", "http://localhost:8080");
+    card.capabilities = AgentCapabilities::new().with_streaming(false);
+    assert!(card.supports_method("message/send"));
+    assert!(card.supports_method("tasks/cancel"));
+    assert!(!card.supports_method("message/stream"));
+    assert!(!card.supports_method("tasks/resubscribe"));
+    assert!(!card.supports_method("tasks/pushNotificationConfig/set"));
+    assert!(!card.supports_method("no/suchMethod"));
+
+    card.capabilities = AgentCapabilities::new().with_streaming(true).with_push_notifications(true);
+    assert!(card.supports_method("message/stream"));
+    assert!(card.supports_method("tasks/resubscribe"));
+    assert!(card.supports_method("tasks/pushNotificationConfig/list"));
+    assert!(!card.supports_method("agent/getAuthenticatedExtendedCard"));
+}
+
+#[test]
+fn test_message_send_params_validate_limits() {
+    use a2a_rust::a2a::core_types::{Message, Part, Role};
+    use base64::Engine;
+
+    let limits = MessageLimits {
+        max_parts_per_message: Some(3),
+        max_part_bytes: Some(1024),
+    };
+    let encode = |len: usize| base64::engine::general_purpose::STANDARD.encode(vec![7u8; len]);
+    let params = |parts: Vec<Part>| MessageSendParams::new(Message::new(Role::User, parts));
+
+    // The encoded file is larger than the limit, but its content is not
+    let normal = params(vec![Part::text("Hello".to_string()), Part::file_bytes(encode(1000))]);
+    normal.validate(&limits).unwrap();
+    normal.validate(&MessageLimits::default()).unwrap();
+
+    let too_many = params((0..4).map(|i| Part::text(i.to_string())).collect());
+    let error = too_many.validate(&limits).unwrap_err();
+    assert_eq!(error.code(), -32602);
+    assert!(error.message().contains("4 parts"), "{}", error.message());
+
+    let oversized = params(vec![Part::text("Hello".to_string()), Part::file_bytes(encode(1025))]);
+    let error = oversized.validate(&limits).unwrap_err();
+    assert_eq!(error.code(), -32602);
+    assert!(error.message().contains("Part 1 is 1025 bytes"), "{}", error.message());
+}
+
+#[tokio::test]
+async fn test_server_rejects_message_with_too_many_parts() {
+    let handler = DefaultRequestHandler::new(std::sync::Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_message_limits(MessageLimits { max_parts_per_message: Some(2), max_part_bytes: None });
+    let server = A2AServerBuilder::new()
+        .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+        .with_request_handler(std::sync::Arc::new(handler))
+        .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+        .build()
+        .unwrap();
+    let router: Router = server.build_router().await;
+
+    let parts: Vec<_> = (0..3).map(|i| json!({"kind": "text", "text": i.to_string()})).collect();
+    let jsonrpc_request = json!({
+        "jsonrpc": "2.0",
+        "method": "message/send",
+        "params": {
+            "message": {"kind": "message", "messageId": "msg-1", "role": "user", "parts": parts}
+        },
+        "id": 1
+    });
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(DEFAULT_RPC_URL)
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_string(&jsonrpc_request).unwrap()))
+        .unwrap();
+
+    let response = router.oneshot(request).await.unwrap();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(response_json["error"]["code"], -32602);
+    assert!(response_json["result"].is_null());
+}
+
+#[tokio::test]
+async fn test_request_recorder_writes_redacted_entries() {
+    let path = std::env::temp_dir().join(format!("a2a-recording-{}.jsonl", uuid::Uuid::new_v4()));
+    let recorder = RequestRecorder::to_file(&path).unwrap();
+    let config = ServerConfig {
+        request_recorder: Some(recorder.clone()),
+        ..Default::default()
+    };
+    let server = A2AServerBuilder::new()
+        .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+        .with_request_handler(std::sync::Arc::new(MockRequestHandler::new()))
+        .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+        .with_config(config)
+        .build()
+        .unwrap();
+    let router: Router = server.build_router().await;
+
+    let bodies = [
+        json!({"jsonrpc": "2.0", "id": 1, "method": "tasks/get", "params": {"id": "task-1"}}),
+        json!({"jsonrpc": "2.0", "id": 2, "method": "tasks/get", "params": {"id": "task-2", "metadata": {"token": "s3cr3t"}}}),
+    ];
+    for body in &bodies {
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(DEFAULT_RPC_URL)
+            .header("content-type", "application/json")
+            .header("authorization", "Bearer s3cr3t")
+            .header("x-request-id", "abc")
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    recorder.flush().await;
+    let contents = std::fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains("s3cr3t"), "recording not redacted: {}", contents);
+    let recorded = read_recording(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(recorded.len(), 2);
+    for (entry, body) in recorded.iter().zip(&bodies) {
+        assert_eq!(entry.method.as_deref(), Some("tasks/get"));
+        assert_eq!(entry.headers["authorization"], "[REDACTED]");
+        assert_eq!(entry.headers["x-request-id"], "abc");
+        assert_eq!(entry.body["params"]["id"], body["params"]["id"]);
+        assert!(chrono::DateTime::parse_from_rfc3339(&entry.timestamp).is_ok());
+    }
+    assert_eq!(recorded[1].body["params"]["metadata"]["token"], "[REDACTED]");
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}{}", listener.local_addr().unwrap(), DEFAULT_RPC_URL);
+    tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+    let responses = replay(&recorded, &url).await.unwrap();
+    assert_eq!(responses.len(), 2);
+    for response in responses {
+        assert_eq!(response.status, 200);
+        let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(body["error"]["code"], -32001, "unexpected replay response: {}", body);
+    }
+}
+
+#[tokio::test]
+async fn test_server_lenient_method_names() {
+    let router = |strict_method_names: bool| async move {
+        A2AServerBuilder::new()
+            .with_agent_card(AgentCard::test_default("Test Agent", "http://localhost:8080"))
+            .with_request_handler(std::sync::Arc::new(MockRequestHandler::new()))
+            .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+            .with_config(ServerConfig { strict_method_names, ..Default::default() })
+            .build()
+            .unwrap()
+            .build_router()
+            .await
+    };
+    let (strict, lenient) = (router(true).await, router(false).await);
+
+    for method in ["Tasks/Get", "tasks/get/", "TASKS/GET//"] {
+        let body = json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": {"id": "task-1"}}).to_string();
+        let call = |router: Router| {
+            let body = body.clone();
+            async move {
+                let request = Request::builder()
+                    .method(Method::POST)
+                    .uri(DEFAULT_RPC_URL)
+                    .header("content-type", "application/json")
+                    .body(Body::from(body))
+                    .unwrap();
+                let response: Response = router.oneshot(request).await.unwrap();
+                let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                serde_json::from_slice::<serde_json::Value>(&body).unwrap()
+            }
+        };
+
+        assert_eq!(call(strict.clone()).await["error"]["code"], -32601, "method {}", method);
+        let response_json = call(lenient.clone()).await;
+        assert_eq!(response_json["error"]["code"], -32001, "method {}: {}", method, response_json);
+    }
+}
+
+/// Replies with the extensions activated for the request, as seen by the executor
+struct ExtensionReportingExecutor;
+
+#[async_trait]
+impl AgentExecutor for ExtensionReportingExecutor {
+    async fn execute(
+        &self,
+        context: RequestContext,
+        event_queue: std::sync::Arc<dyn EventQueue>,
+    ) -> Result<(), A2AError> {
+        let mut activated: Vec<String> = context
+            .requested_extensions()
+            .into_iter()
+            .filter(|uri| context.is_extension_activated(uri))
+            .collect();
+        activated.sort();
+        let reply = Message::new(Role::Agent, vec![Part::text(activated.join(","))]);
+        event_queue.enqueue_event(Event::Message(reply)).await
+    }
+
+    async fn cancel(
+        &self,
+        _context: RequestContext,
+        _event_queue: std::sync::Arc<dyn EventQueue>,
+    ) -> Result<(), A2AError> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_server_activates_supported_message_extensions() {
+    const TRACE: &str = "https://example.com/ext/trace/v1";
+    let mut card = AgentCard::test_default("Test Agent", "http://localhost:8080");
+    card.capabilities = AgentCapabilities::new().with_extensions(vec![AgentExtension::new(TRACE.to_string())]);
+    let handler = DefaultRequestHandler::new(std::sync::Arc::new(InMemoryTaskStore::new()), None, None)
+        .with_agent_executor(std::sync::Arc::new(ExtensionReportingExecutor));
+    let router = A2AServerBuilder::new()
+        .with_agent_card(card)
+        .with_request_handler(std::sync::Arc::new(handler))
+        .with_context_builder(std::sync::Arc::new(DefaultServerCallContextBuilder))
+        .build()
+        .unwrap()
+        .build_router()
+        .await;
+
+    let send = |extensions: Vec<&str>| {
+        let body = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "message/send",
+            "params": {"message": {
+                "kind": "message",
+                "messageId": "msg-1",
+                "role": "user",
+                "parts": [{"kind": "text", "text": "hi"}],
+                "extensions": extensions
+            }}
+        });
+        let router = router.clone();
+        async move {
+            let request = Request::builder()
+                .method(Method::POST)
+                .uri(DEFAULT_RPC_URL)
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap();
+            let response: Response = router.oneshot(request).await.unwrap();
+            let header = response
+                .headers()
+                .get(EXTENSIONS_HEADER)
+                .map(|value| value.to_str().unwrap().to_string());
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+            let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+            (header, body["result"]["parts"][0]["text"].as_str().unwrap().to_string())
+        }
+    };
+
+    // Only the extension the card declares is activated
+    let (header, seen_by_executor) = send(vec![TRACE, "https://example.com/ext/unknown"]).await;
+    assert_eq!(header.as_deref(), Some(TRACE));
+    assert_eq!(seen_by_executor, TRACE);
+
+    let (header, seen_by_executor) = send(vec![]).await;
+    assert_eq!(header, None);
+    assert_eq!(seen_by_executor, "");
 }
@@ -4,6 +4,8 @@
 //! mirroring the test structure from a2a-python/tests/test_types.py
 
 use a2a_rust::a2a::core_types::*;
+use a2a_rust::a2a::models::{APIKeySecurityScheme, HTTPAuthSecurityScheme, MessageSendParams, OAuth2SecurityScheme, SecurityScheme};
+use serde_json::json;
 use serde_json;
 use uuid::Uuid;
 use url::Url;
@@ -133,6 +135,45 @@ fn test_part_union_data() {
     }
 }
 
+#[test]
+fn test_part_union_unknown_kind_round_trips() {
+    let json = json!({
+        "kind": "video",
+        "uri": "https://example.com/clip.mp4",
+        "durationSeconds": 12.5,
+        "metadata": {"codec": "h264"}
+    });
+
+    let part: Part = serde_json::from_value(json.clone()).unwrap();
+    match part.root() {
+        PartRoot::Unknown(unknown) => {
+            assert_eq!(unknown.kind, "video");
+            assert_eq!(unknown.value, json);
+        }
+        other => panic!("Expected Unknown part, got {:?}", other),
+    }
+    assert_eq!(serde_json::to_value(&part).unwrap(), json);
+
+    // Unknown parts survive inside messages and in the Python `root` form
+    let message_json = json!({
+        "kind": "message",
+        "messageId": "msg-1",
+        "role": "user",
+        "parts": [{"kind": "text", "text": "watch this"}, {"root": json}]
+    });
+    let message: Message = serde_json::from_value(message_json.clone()).unwrap();
+    assert!(matches!(message.parts[0].root(), PartRoot::Text(_)));
+    assert!(matches!(message.parts[1].root(), PartRoot::Unknown(_)));
+    assert_eq!(serde_json::to_value(&message).unwrap()["parts"][1], message_json["parts"][1]);
+}
+
+#[test]
+fn test_part_kind_selects_variant() {
+    // A known kind must match its fields rather than fall through to another variant
+    assert!(serde_json::from_value::<Part>(json!({"kind": "data", "text": "hi"})).is_err());
+    assert!(serde_json::from_value::<Part>(json!({"text": "hi"})).is_err());
+}
+
 #[test]
 fn test_message_creation() {
     let part = Part::text("Hello".to_string());
@@ -148,6 +189,7 @@ fn test_message_creation() {
         extensions: None,
         reference_task_ids: None,
         kind: "message".to_string(),
+        part_form: None,
     };
 
     assert_eq!(message.role, Role::User);
@@ -169,6 +211,7 @@ fn test_message_with_multiple_parts() {
         extensions: None,
         reference_task_ids: None,
         kind: "message".to_string(),
+        part_form: None,
     };
 
     assert_eq!(message.role, Role::Agent);
@@ -200,6 +243,7 @@ fn test_task_status_with_message() {
         extensions: None,
         reference_task_ids: None,
         kind: "message".to_string(),
+        part_form: None,
     };
 
     let status = TaskStatus {
@@ -292,6 +336,22 @@ fn test_message_convenience_methods() {
     assert_eq!(message.kind, "message");
 }
 
+#[test]
+fn test_message_send_params_from_text() {
+    let params = MessageSendParams::from_text("What is the weather?");
+    let other = MessageSendParams::from_text("What is the weather?");
+
+    assert_eq!(params.message.role, Role::User);
+    assert_eq!(params.message.parts.len(), 1);
+    match params.message.parts[0].root() {
+        PartRoot::Text(text) => assert_eq!(text.text, "What is the weather?"),
+        other => panic!("Expected a text part, got {:?}", other),
+    }
+    assert!(!params.message.message_id.is_empty());
+    assert_ne!(params.message.message_id, other.message.message_id);
+    assert!(params.configuration.is_none() && params.metadata.is_none());
+}
+
 #[test]
 fn test_task_status_convenience_methods() {
     let status = TaskStatus::new(TaskState::Working);
@@ -313,3 +373,132 @@ fn test_serialization() {
     assert_eq!(text_part.text, deserialized.text);
     assert_eq!(text_part.kind, deserialized.kind);
 }
+
+#[test]
+fn test_security_scheme_bearer() {
+    let scheme = SecurityScheme::bearer("JWT");
+    assert_eq!(
+        scheme,
+        SecurityScheme::HTTPAuth(HTTPAuthSecurityScheme {
+            scheme: "bearer".to_string(),
+            description: None,
+            bearer_format: Some("JWT".to_string()),
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&scheme).unwrap(),
+        json!({"type": "http", "scheme": "bearer", "bearer_format": "JWT", "description": null})
+    );
+}
+
+#[test]
+fn test_security_scheme_api_keys() {
+    let header = SecurityScheme::api_key_header("X-API-Key").with_description("API key authentication");
+    assert_eq!(
+        header,
+        SecurityScheme::APIKey(APIKeySecurityScheme {
+            name: "X-API-Key".to_string(),
+            in_: In::Header,
+            description: Some("API key authentication".to_string()),
+        })
+    );
+    assert_eq!(
+        serde_json::to_value(&header).unwrap(),
+        json!({"type": "apiKey", "name": "X-API-Key", "in": "header", "description": "API key authentication"})
+    );
+
+    let query = SecurityScheme::api_key_query("api_key");
+    assert!(matches!(&query, SecurityScheme::APIKey(scheme) if scheme.in_ == In::Query));
+    assert_eq!(
+        serde_json::to_value(&query).unwrap(),
+        json!({"type": "apiKey", "name": "api_key", "in": "query", "description": null})
+    );
+}
+
+#[test]
+fn test_security_scheme_oauth2() {
+    let flows = std::collections::HashMap::from([(
+        "client_credentials".to_string(),
+        json!({"token_url": "https://auth.example.com/token", "scopes": {}}),
+    )]);
+    let scheme = SecurityScheme::oauth2(flows.clone());
+    assert_eq!(scheme, SecurityScheme::OAuth2(OAuth2SecurityScheme { flows, description: None }));
+    assert_eq!(
+        serde_json::to_value(&scheme).unwrap(),
+        json!({
+            "type": "oauth2",
+            "flows": {"client_credentials": {"token_url": "https://auth.example.com/token", "scopes": {}}},
+            "description": null
+        })
+    );
+}
+
+#[test]
+fn test_security_scheme_accepts_variant_name_tags() {
+    let scheme: SecurityScheme =
+        serde_json::from_value(json!({"type": "HTTPAuth", "scheme": "bearer", "bearer_format": "JWT"})).unwrap();
+    assert_eq!(scheme, SecurityScheme::bearer("JWT"));
+}
+
+#[test]
+fn test_task_id_round_trips_uuids() {
+    let uuid = Uuid::new_v4();
+    let id: TaskId = uuid.to_string().parse().unwrap();
+    assert!(id.is_uuid());
+    assert_eq!(id.as_uuid(), Some(uuid));
+    assert_eq!(id.to_string(), uuid.to_string());
+    assert_eq!(id, TaskId::from(uuid));
+    assert_eq!(id, uuid.to_string());
+
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, format!("\"{}\"", uuid));
+    assert_eq!(serde_json::from_str::<TaskId>(&json).unwrap(), id);
+}
+
+#[test]
+fn test_task_id_keeps_non_uuid_strings() {
+    for raw in ["task-1", "1", "", "550E8400-E29B-41D4-A716-446655440000"] {
+        let id: TaskId = raw.parse().unwrap();
+        assert!(!id.is_uuid(), "{} should be kept raw", raw);
+        assert_eq!(id.to_string(), raw);
+        assert_eq!(id, raw);
+
+        let json = serde_json::to_value(&id).unwrap();
+        assert_eq!(json, serde_json::Value::String(raw.to_string()));
+        assert_eq!(serde_json::from_value::<TaskId>(json).unwrap(), id);
+    }
+    assert!(TaskId::new().is_uuid());
+    assert_ne!(TaskId::new(), TaskId::new());
+}
+
+#[test]
+fn test_artifact_extensions_round_trip() {
+    use a2a_rust::a2a::models::Artifact;
+    use a2a_rust::a2a::utils::MetadataExt;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Citation {
+        source: String,
+        page: u32,
+    }
+
+    let citations_uri = "https://example.com/ext/citations/v1";
+    let citation = Citation { source: "handbook.pdf".to_string(), page: 12 };
+    let artifact = Artifact::new(vec![Part::text("Summary".to_string())])
+        .with_extension("https://example.com/ext/tracing/v1")
+        .with_extension("https://example.com/ext/tracing/v1")
+        .with_extension_metadata(citations_uri, serde_json::to_value(&citation).unwrap())
+        .with_extension_metadata("https://example.com/ext/priority/v1", "high");
+
+    let json = serde_json::to_string(&artifact).unwrap();
+    let parsed: Artifact = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(parsed.extensions.as_ref().unwrap().len(), 3);
+    assert!(parsed.has_extension("https://example.com/ext/tracing/v1"));
+    assert!(parsed.has_extension(citations_uri));
+    assert!(!parsed.has_extension("https://example.com/ext/unknown"));
+    assert_eq!(parsed.extension_metadata::<Citation>(citations_uri), Some(citation));
+    assert_eq!(parsed.extension_metadata::<Citation>("https://example.com/ext/priority/v1"), None);
+    assert_eq!(parsed.metadata.get_str("https://example.com/ext/priority/v1"), Some("high"));
+}